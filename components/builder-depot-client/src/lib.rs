@@ -264,7 +264,28 @@ impl Client {
     /// * Package cannot be found
     /// * Remote Depot is not available
     pub fn show_package<I: Identifiable>(&self, ident: &I) -> Result<depotsrv::Package> {
-        let mut res = try!(self.inner.get(&self.path_show_package(ident)).send());
+        self.get_package(&path_show_package(ident))
+    }
+
+    /// Returns a package struct for the latest package promoted to a channel.
+    ///
+    /// As with `show_package`, an optional version scopes the release returned to the latest
+    /// release of that version in the channel, and a fully qualified identifier is only returned
+    /// if that exact package is in the channel.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found in the channel
+    /// * Remote Depot is not available
+    pub fn show_package_in_channel<I: Identifiable>(&self,
+                                                    ident: &I,
+                                                    channel: &str)
+                                                    -> Result<depotsrv::Package> {
+        self.get_package(&path_show_package_in_channel(ident, channel))
+    }
+
+    fn get_package(&self, path: &str) -> Result<depotsrv::Package> {
+        let mut res = try!(self.inner.get(path).send());
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -371,14 +392,6 @@ impl Client {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
 
-    fn path_show_package<I: Identifiable>(&self, package: &I) -> String {
-        if package.fully_qualified() {
-            format!("pkgs/{}", package)
-        } else {
-            format!("pkgs/{}/latest", package)
-        }
-    }
-
     fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
         where D: DisplayProgress + Sized
     {
@@ -431,3 +444,45 @@ fn err_from_response(mut response: hyper::client::Response) -> Error {
         }
     }
 }
+
+fn path_show_package<I: Identifiable>(package: &I) -> String {
+    if package.fully_qualified() {
+        format!("pkgs/{}", package)
+    } else {
+        format!("pkgs/{}/latest", package)
+    }
+}
+
+fn path_show_package_in_channel<I: Identifiable>(package: &I, channel: &str) -> String {
+    format!("channels/{}/{}", channel, path_show_package(package))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hab_core::package::PackageIdent;
+
+    use super::{path_show_package, path_show_package_in_channel};
+
+    #[test]
+    fn show_package_asks_for_the_latest_unless_fully_qualified() {
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        assert_eq!(path_show_package(&ident), "pkgs/core/redis/latest");
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        assert_eq!(path_show_package(&ident), "pkgs/core/redis/3.2.4/20170101000000");
+    }
+
+    #[test]
+    fn show_package_in_channel_asks_the_channel() {
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        assert_eq!(path_show_package_in_channel(&ident, "stable"),
+                   "channels/stable/pkgs/core/redis/latest");
+        let ident = PackageIdent::from_str("core/redis/3.2.4").unwrap();
+        assert_eq!(path_show_package_in_channel(&ident, "stable"),
+                   "channels/stable/pkgs/core/redis/3.2.4/latest");
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        assert_eq!(path_show_package_in_channel(&ident, "stable"),
+                   "channels/stable/pkgs/core/redis/3.2.4/20170101000000");
+    }
+}
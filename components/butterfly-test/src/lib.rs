@@ -19,6 +19,7 @@ extern crate habitat_butterfly;
 extern crate habitat_core;
 
 use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::net::{IpAddr, SocketAddr};
 use std::thread;
 use std::ops::{Deref, DerefMut, Range};
 use std::time::Duration;
@@ -27,6 +28,7 @@ use std::str::FromStr;
 use time::SteadyTime;
 
 use habitat_butterfly::server::Server;
+use habitat_butterfly::server::quorum::QuorumPolicy;
 use habitat_butterfly::member::{Member, Health};
 use habitat_butterfly::server::timing::Timing;
 use habitat_butterfly::rumor::service::Service;
@@ -41,20 +43,27 @@ use habitat_butterfly::trace::Trace;
 static SERVER_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>) -> Server {
+    start_server_on(name, ring_key, "127.0.0.1")
+}
+
+/// Start a server listening on `host`, which may be an IPv6 address like `::1`.
+pub fn start_server_on(name: &str, ring_key: Option<SymKey>, host: &str) -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
-    let listen_swim = format!("127.0.0.1:{}", swim_port);
-    let listen_gossip = format!("127.0.0.1:{}", gossip_port);
+    let ip: IpAddr = host.parse().expect("Test servers must listen on an IP address");
+    let listen_swim = SocketAddr::new(ip, swim_port as u16);
+    let listen_gossip = SocketAddr::new(ip, gossip_port as u16);
     let mut member = Member::new();
     member.set_swim_port(swim_port as i32);
     member.set_gossip_port(gossip_port as i32);
-    let server = Server::new(&listen_swim[..],
-                             &listen_gossip[..],
+    let server = Server::new(listen_swim,
+                             listen_gossip,
                              member,
                              Trace::default(),
                              ring_key,
-                             Some(String::from(name)))
+                             Some(String::from(name)),
+                             None)
         .unwrap();
     server.start(Timing::default()).expect("Cannot start server");
     server
@@ -65,7 +74,7 @@ pub fn member_from_server(server: &Server) -> Member {
     let server_member = server.member.read().expect("Member lock is poisoned");
     new_member.set_id(String::from(server_member.get_id()));
     new_member.set_incarnation(server_member.get_incarnation());
-    new_member.set_address(String::from("127.0.0.1"));
+    new_member.set_address(server.swim_addr().ip().to_string());
     new_member.set_swim_port(server.swim_port() as i32);
     new_member.set_gossip_port(server.gossip_port() as i32);
     new_member
@@ -100,6 +109,15 @@ impl SwimNet {
         SwimNet { members: members }
     }
 
+    /// A network of servers that only talk to each other over IPv6.
+    pub fn new_ipv6(count: usize) -> SwimNet {
+        let mut members = Vec::with_capacity(count);
+        for x in 0..count {
+            members.push(start_server_on(&format!("{}", x), None, "::1"));
+        }
+        SwimNet { members: members }
+    }
+
     pub fn new_ring_encryption(count: usize, ring_key: Option<SymKey>) -> SwimNet {
         let mut members = Vec::with_capacity(count);
         for x in 0..count {
@@ -437,12 +455,16 @@ impl SwimNet {
                                  ServiceGroup::new(service, "prod", None),
                                  filename,
                                  body_bytes);
-        self[member].insert_service_file(s);
+        self[member].insert_service_file_with_body(s).expect("Cannot chunk the service file");
     }
 
     pub fn add_election(&mut self, member: usize, service: &str, suitability: u64) {
         self[member].start_election(ServiceGroup::new(service, "prod", None), suitability, 0);
     }
+
+    pub fn set_quorum_policy(&mut self, member: usize, service: &str, policy: QuorumPolicy) {
+        self[member].set_quorum_policy(&ServiceGroup::new(service, "prod", None), policy);
+    }
 }
 
 #[macro_export]
@@ -0,0 +1,140 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bytes per gossip round, with and without wire compression.
+//!
+//! A round here is one push of every hot rumor in a census ring - one membership rumor and one
+//! service rumor per member - to a single peer, which is what each of the fanout peers receives
+//! while a ring is converging. Without compression every rumor is its own wire message; with it,
+//! rumors are batched and deflated before encryption. Run it with
+//! `cargo bench --bench wire_compression`.
+
+extern crate habitat_butterfly;
+extern crate habitat_core;
+extern crate protobuf;
+extern crate time;
+
+use std::str::FromStr;
+
+use habitat_butterfly::member::Member;
+use habitat_butterfly::message;
+use habitat_butterfly::message::swim::{Membership, Membership_Health, Rumor as ProtoRumor,
+                                       Rumor_Type as ProtoRumor_Type, Wire_Compression};
+use habitat_butterfly::rumor::Rumor;
+use habitat_butterfly::rumor::service::Service;
+use habitat_butterfly::server::push::MAX_BATCH_BYTES;
+use habitat_core::crypto::SymKey;
+use habitat_core::package::PackageIdent;
+use protobuf::Message;
+use time::PreciseTime;
+
+const RING_SIZES: &'static [usize] = &[10, 100, 1000];
+
+fn round_payloads(ring_size: usize) -> Vec<Vec<u8>> {
+    let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022")
+        .expect("Valid package identifier");
+    let mut payloads = Vec::with_capacity(ring_size * 2);
+    for n in 0..ring_size {
+        let mut member = Member::new();
+        member.set_address(format!("10.0.{}.{}", n / 256, n % 256));
+        member.set_swim_port(9638);
+        member.set_gossip_port(9638);
+        member.set_accepts_compression(true);
+        let mut membership = Membership::new();
+        membership.set_member(member.proto.clone());
+        membership.set_health(Membership_Health::ALIVE);
+        let mut rumor = ProtoRumor::new();
+        rumor.set_field_type(ProtoRumor_Type::Member);
+        rumor.set_from_id(String::from(member.get_id()));
+        rumor.set_member(membership);
+        payloads.push(rumor.write_to_bytes().expect("Membership rumor to bytes"));
+
+        let service = Service::new(member.get_id(),
+                                   &ident,
+                                   "default",
+                                   None,
+                                   format!("redis-{}.example.com", n),
+                                   member.get_address(),
+                                   vec![6379]);
+        payloads.push(service.write_to_bytes().expect("Service rumor to bytes"));
+    }
+    payloads
+}
+
+/// What a member sent before compression: one uncompressed wire message per rumor.
+fn plain_round(payloads: &[Vec<u8>], ring_key: Option<&SymKey>) -> (usize, i64) {
+    let start = PreciseTime::now();
+    let bytes = payloads.iter()
+        .map(|p| {
+            message::generate_wire(p.clone(), ring_key, Wire_Compression::NONE)
+                .expect("Generate wire message")
+                .len()
+        })
+        .sum();
+    (bytes, start.to(PreciseTime::now()).num_microseconds().unwrap_or(0))
+}
+
+/// What a member sends to peers that accept compression: deflated batches of rumors.
+fn deflated_round(payloads: &[Vec<u8>], ring_key: Option<&SymKey>) -> (usize, i64) {
+    let start = PreciseTime::now();
+    let mut bytes = 0;
+    let mut batch = Vec::new();
+    let mut batch_size = 0;
+    for payload in payloads.iter() {
+        batch_size += payload.len();
+        batch.push(payload.clone());
+        if batch_size >= MAX_BATCH_BYTES {
+            bytes += batch_wire_len(batch, ring_key);
+            batch = Vec::new();
+            batch_size = 0;
+        }
+    }
+    if !batch.is_empty() {
+        bytes += batch_wire_len(batch, ring_key);
+    }
+    (bytes, start.to(PreciseTime::now()).num_microseconds().unwrap_or(0))
+}
+
+fn batch_wire_len(batch: Vec<Vec<u8>>, ring_key: Option<&SymKey>) -> usize {
+    message::generate_batch_wire(batch, ring_key, Wire_Compression::DEFLATE)
+        .expect("Generate batch wire message")
+        .len()
+}
+
+fn main() {
+    let ring_key = SymKey::generate_in_memory("benchmark").expect("Generate ring key");
+    println!("{:>9} {:>10} {:>12} {:>12} {:>7} {:>10} {:>10}",
+             "members",
+             "encrypted",
+             "plain bytes",
+             "deflated",
+             "ratio",
+             "plain us",
+             "deflate us");
+    for &ring_size in RING_SIZES {
+        let payloads = round_payloads(ring_size);
+        for &key in &[None, Some(&ring_key)] {
+            let (plain, plain_us) = plain_round(&payloads, key);
+            let (deflated, deflate_us) = deflated_round(&payloads, key);
+            println!("{:>9} {:>10} {:>12} {:>12} {:>7.2} {:>10} {:>10}",
+                     ring_size,
+                     key.is_some(),
+                     plain,
+                     deflated,
+                     deflated as f64 / plain as f64,
+                     plain_us,
+                     deflate_us);
+        }
+    }
+}
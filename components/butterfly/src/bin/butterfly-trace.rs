@@ -0,0 +1,277 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads the trace files written by every member of a ring, and prints a timeline for each
+//! member of everything sent to it, received from it, or said about it.
+//!
+//! Usage: `butterfly-trace [--member ID] FILE_OR_DIRECTORY...`
+//!
+//! Directories are searched for `.swimtrace` files. Lines that are not trace events are counted
+//! and skipped, so a file cut short by a crash can still be read.
+
+extern crate serde_json;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use serde_json::Value;
+
+/// The trace schema this tool understands.
+const TRACE_SCHEMA_VERSION: u64 = 1;
+
+struct Event {
+    timestamp: String,
+    line: String,
+    value: Value,
+}
+
+impl Event {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.value.as_object().and_then(|o| o.get(name)).and_then(|v| v.as_str())
+    }
+
+    /// Every member this event involves: the sender, the receiver, and the member it is about.
+    fn members(&self) -> Vec<&str> {
+        let mut members = Vec::new();
+        for name in &["from", "to", "member_id"] {
+            if let Some(id) = self.field(name) {
+                if !id.is_empty() && !members.contains(&id) {
+                    members.push(id);
+                }
+            }
+        }
+        members
+    }
+
+    fn describe(&self) -> String {
+        let mut description = format!("{} {}",
+                                      self.timestamp,
+                                      self.field("kind").unwrap_or("Unknown"));
+        for name in &["server", "from", "to", "member_id"] {
+            if let Some(value) = self.field(name) {
+                description.push_str(&format!(" {}={}", name, value));
+            }
+        }
+        if let Some(incarnation) = self.value
+            .as_object()
+            .and_then(|o| o.get("incarnation"))
+            .and_then(|v| v.as_u64()) {
+            description.push_str(&format!(" incarnation={}", incarnation));
+        }
+        if let Some(kind) = self.field("rumor_kind") {
+            description.push_str(&format!(" rumor={}:{}:{}",
+                                          kind,
+                                          self.field("rumor_id").unwrap_or(""),
+                                          self.field("rumor_key").unwrap_or("")));
+        }
+        if let Some(detail) = self.field("detail") {
+            description.push_str(&format!(" ({})", detail.trim()));
+        }
+        description
+    }
+}
+
+fn usage() -> ! {
+    println!("Usage: butterfly-trace [--member ID] FILE_OR_DIRECTORY...");
+    process::exit(1);
+}
+
+fn trace_files(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        for entry in try!(fs::read_dir(path)) {
+            let entry = try!(entry);
+            let entry_path = entry.path();
+            if entry_path.extension().map(|e| e == "swimtrace").unwrap_or(false) {
+                files.push(entry_path);
+            }
+        }
+    } else {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+fn read_events(path: &Path, events: &mut Vec<Event>, skipped: &mut usize) -> io::Result<()> {
+    let file = try!(fs::File::open(path));
+    for line in BufReader::new(file).lines() {
+        let line = try!(line);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: Value = match serde_json::from_str(&line) {
+            Ok(value) => value,
+            Err(_) => {
+                *skipped += 1;
+                continue;
+            }
+        };
+        let (schema, timestamp) = match value.as_object() {
+            Some(object) => {
+                (object.get("schema").and_then(|v| v.as_u64()),
+                 object.get("timestamp").and_then(|v| v.as_str()).map(|t| String::from(t)))
+            }
+            None => (None, None),
+        };
+        match (schema, timestamp) {
+            (Some(TRACE_SCHEMA_VERSION), Some(timestamp)) => {
+                events.push(Event {
+                    timestamp: timestamp,
+                    line: line,
+                    value: value,
+                })
+            }
+            _ => *skipped += 1,
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let mut only_member: Option<String> = None;
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--member" => {
+                match args.next() {
+                    Some(id) => only_member = Some(id),
+                    None => usage(),
+                }
+            }
+            "-h" | "--help" => usage(),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    if paths.is_empty() {
+        usage();
+    }
+
+    let mut files = Vec::new();
+    for path in paths.iter() {
+        if let Err(e) = trace_files(path, &mut files) {
+            println!("Cannot read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut skipped = 0;
+    for file in files.iter() {
+        if let Err(e) = read_events(file, &mut events, &mut skipped) {
+            println!("Cannot read {}: {}", file.display(), e);
+            process::exit(1);
+        }
+    }
+    if skipped > 0 {
+        let _ = writeln!(io::stderr(), "Skipped {} lines that are not trace events", skipped);
+    }
+    // RFC 3339 timestamps in UTC, with a fixed number of digits, sort in time order. Ties are
+    // broken by the line itself, so the output is the same no matter what order files are read.
+    events.sort_by(|a, b| (&a.timestamp, &a.line).cmp(&(&b.timestamp, &b.line)));
+
+    let mut timelines: BTreeMap<&str, Vec<&Event>> = BTreeMap::new();
+    for event in events.iter() {
+        for member in event.members() {
+            timelines.entry(member).or_insert(Vec::new()).push(event);
+        }
+    }
+
+    for (member, timeline) in timelines.iter() {
+        if let Some(ref only) = only_member {
+            if only != member {
+                continue;
+            }
+        }
+        println!("== {} ({} events)", member, timeline.len());
+        for event in timeline.iter() {
+            println!("  {}", event.describe());
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use serde_json;
+
+    use super::{read_events, trace_files, Event};
+
+    const PING: &'static str = r#"{"schema":1,"timestamp":"2017-03-06T03:30:00.000000Z","kind":"SendPing","server":"kenny","from":"me","to":"them","member_id":"me"}"#;
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("butterfly-trace-test-{}", name));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn event(line: &str) -> Event {
+        Event {
+            timestamp: String::new(),
+            line: String::from(line),
+            value: serde_json::from_str(line).unwrap(),
+        }
+    }
+
+    #[test]
+    fn reads_events_and_skips_everything_else() {
+        let directory = directory("read");
+        let path = directory.join("kenny.swimtrace");
+        let mut file = fs::File::create(&path).unwrap();
+        writeln!(file, "{}", PING).unwrap();
+        writeln!(file, "").unwrap();
+        writeln!(file, "not json at all").unwrap();
+        writeln!(file, r#"{{"schema":2,"timestamp":"2017-03-06T03:30:00.000000Z"}}"#).unwrap();
+        write!(file, r#"{{"schema":1,"timest"#).unwrap();
+        let mut events = Vec::new();
+        let mut skipped = 0;
+        read_events(&path, &mut events, &mut skipped).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].timestamp, "2017-03-06T03:30:00.000000Z");
+        assert_eq!(skipped, 3);
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn finds_trace_files_in_directories() {
+        let directory = directory("files");
+        fs::File::create(directory.join("kenny.swimtrace")).unwrap();
+        fs::File::create(directory.join("notes.txt")).unwrap();
+        let mut files = Vec::new();
+        trace_files(&directory, &mut files).unwrap();
+        assert_eq!(files, vec![directory.join("kenny.swimtrace")]);
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn members_are_listed_once() {
+        assert_eq!(event(PING).members(), vec!["me", "them"]);
+    }
+
+    #[test]
+    fn describe_includes_the_interesting_fields() {
+        let event = event(r#"{"kind":"RecvRumor","from":"them","incarnation":3,"rumor_kind":"service","rumor_id":"me","rumor_key":"redis.default","detail":" new "}"#);
+        assert_eq!(event.describe(),
+                   " RecvRumor from=them incarnation=3 rumor=service:me:redis.default (new)");
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chunked bodies for large service files.
+//!
+//! A service file whose body is larger than `CHUNK_SIZE` is gossiped as metadata only - the
+//! content hash, size and number of chunks. The body itself lives in a `ChunkStore`, and members
+//! that are missing chunks ask their peers for them directly with a `FileChunkRequest`. Any
+//! member holding a chunk answers with a `FileChunk`, so the body spreads through the ring the
+//! same way the rumor does, without riding along on every gossip round.
+
+use std::cmp;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use habitat_core::crypto::hash;
+
+use message::swim::{FileChunk as ProtoFileChunk, FileChunkRequest as ProtoFileChunkRequest,
+                    Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
+
+/// Bodies up to this size are gossiped inline, as they always have been.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The most chunks we will ask a single peer for in one gossip round.
+pub const MAX_CHUNKS_PER_REQUEST: usize = 16;
+
+/// The most chunks a body may have, which makes for 64MB. A service file that claims more is
+/// refused, since we set aside room for every chunk as soon as the first arrives.
+pub const MAX_CHUNK_COUNT: u32 = 1024;
+
+/// The most threads we run at once sending chunks to peers that asked for them; requests that
+/// come in while they are all busy are dropped, and asked again on a later round.
+pub const MAX_CHUNK_WORKERS: usize = 4;
+
+/// Create a `FileChunk` message carrying one chunk of the body with the given content hash.
+pub fn file_chunk<S: Into<String>>(from_id: S,
+                                   content_hash: &str,
+                                   index: u32,
+                                   chunk_count: u32,
+                                   data: Vec<u8>)
+                                   -> ProtoRumor {
+    let mut chunk = ProtoFileChunk::new();
+    chunk.set_content_hash(String::from(content_hash));
+    chunk.set_index(index);
+    chunk.set_chunk_count(chunk_count);
+    chunk.set_data(data);
+    let mut rumor = ProtoRumor::new();
+    rumor.set_from_id(from_id.into());
+    rumor.set_field_type(ProtoRumor_Type::FileChunk);
+    rumor.set_file_chunk(chunk);
+    rumor
+}
+
+/// Create a `FileChunkRequest` message asking for the given chunks of a body.
+pub fn file_chunk_request<S: Into<String>>(from_id: S,
+                                           content_hash: &str,
+                                           indexes: Vec<u32>)
+                                           -> ProtoRumor {
+    let mut request = ProtoFileChunkRequest::new();
+    request.set_content_hash(String::from(content_hash));
+    request.set_index(indexes);
+    let mut rumor = ProtoRumor::new();
+    rumor.set_from_id(from_id.into());
+    rumor.set_field_type(ProtoRumor_Type::FileChunkRequest);
+    rumor.set_file_chunk_request(request);
+    rumor
+}
+
+#[derive(Debug)]
+struct Chunks {
+    chunks: Vec<Option<Vec<u8>>>,
+}
+
+impl Chunks {
+    fn new(chunk_count: u32) -> Chunks {
+        Chunks { chunks: vec![None; chunk_count as usize] }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.chunks.iter().all(|c| c.is_some())
+    }
+}
+
+/// Holds the chunks of every body we know about, keyed by content hash. Is thread-safe.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkStore {
+    bodies: Arc<RwLock<HashMap<String, Chunks>>>,
+}
+
+impl ChunkStore {
+    pub fn new() -> ChunkStore {
+        ChunkStore::default()
+    }
+
+    /// Split a body into chunks and store all of them, returning the content hash.
+    pub fn insert_body(&self, body: &[u8]) -> String {
+        let content_hash = hash::hash_bytes(body).expect("Hashing bytes in memory cannot fail");
+        let chunk_count = chunk_count(body.len());
+        for (index, data) in body.chunks(CHUNK_SIZE).enumerate() {
+            self.insert(&content_hash, index as u32, chunk_count, data.to_vec());
+        }
+        content_hash
+    }
+
+    /// Store one chunk. Returns true if we did not already have it.
+    pub fn insert(&self, content_hash: &str, index: u32, chunk_count: u32, data: Vec<u8>) -> bool {
+        if index >= chunk_count || chunk_count > MAX_CHUNK_COUNT || data.len() > CHUNK_SIZE {
+            return false;
+        }
+        let mut bodies = self.bodies.write().expect("Chunk store lock is poisoned");
+        let chunks = bodies.entry(String::from(content_hash))
+            .or_insert_with(|| Chunks::new(chunk_count));
+        if chunks.chunks.len() != chunk_count as usize {
+            debug!("Ignoring chunk {} of {} with a chunk count of {}, expected {}",
+                   index,
+                   content_hash,
+                   chunk_count,
+                   chunks.chunks.len());
+            return false;
+        }
+        let slot = &mut chunks.chunks[index as usize];
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(data);
+        true
+    }
+
+    /// Returns a copy of one chunk, if we have it.
+    pub fn get(&self, content_hash: &str, index: u32) -> Option<Vec<u8>> {
+        let bodies = self.bodies.read().expect("Chunk store lock is poisoned");
+        bodies.get(content_hash)
+            .and_then(|c| c.chunks.get(index as usize))
+            .and_then(|c| c.clone())
+    }
+
+    /// Returns the indexes of the chunks we are missing for a body.
+    pub fn missing(&self, content_hash: &str, chunk_count: u32) -> Vec<u32> {
+        let bodies = self.bodies.read().expect("Chunk store lock is poisoned");
+        match bodies.get(content_hash) {
+            Some(chunks) => {
+                chunks.chunks
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, c)| c.is_none())
+                    .map(|(index, _)| index as u32)
+                    .collect()
+            }
+            None => (0..chunk_count).collect(),
+        }
+    }
+
+    /// Returns the whole body, if we have every chunk and they match the content hash. A body
+    /// that does not match is thrown away, so that we fetch it again.
+    pub fn assemble(&self, content_hash: &str) -> Option<Vec<u8>> {
+        let body = {
+            let bodies = self.bodies.read().expect("Chunk store lock is poisoned");
+            match bodies.get(content_hash) {
+                Some(chunks) if chunks.is_complete() => {
+                    let mut body = Vec::new();
+                    for chunk in chunks.chunks.iter() {
+                        body.extend_from_slice(chunk.as_ref().unwrap());
+                    }
+                    body
+                }
+                _ => return None,
+            }
+        };
+        match hash::hash_bytes(&body) {
+            Ok(ref actual) if actual == content_hash => Some(body),
+            _ => {
+                warn!("Chunks for {} do not match their content hash; discarding them",
+                      content_hash);
+                self.bodies.write().expect("Chunk store lock is poisoned").remove(content_hash);
+                None
+            }
+        }
+    }
+
+    /// Drop every body whose content hash `keep` returns false for.
+    pub fn retain<F>(&self, keep: F)
+        where F: Fn(&str) -> bool
+    {
+        let mut bodies = self.bodies.write().expect("Chunk store lock is poisoned");
+        let stale: Vec<String> = bodies.keys().filter(|h| !keep(h)).cloned().collect();
+        for content_hash in stale.iter() {
+            bodies.remove(content_hash);
+        }
+    }
+}
+
+/// The number of chunks a body of `size` bytes is split into.
+pub fn chunk_count(size: usize) -> u32 {
+    ((size + CHUNK_SIZE - 1) / CHUNK_SIZE) as u32
+}
+
+/// The length of chunk `index` of a body of `size` bytes; every chunk is full but the last.
+pub fn chunk_len(size: u64, index: u32) -> u64 {
+    let start = index as u64 * CHUNK_SIZE as u64;
+    if start >= size {
+        0
+    } else {
+        cmp::min(size - start, CHUNK_SIZE as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::hash;
+
+    use super::{ChunkStore, CHUNK_SIZE, MAX_CHUNK_COUNT, chunk_count, chunk_len};
+
+    fn body(size: usize) -> Vec<u8> {
+        (0..size).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn chunk_count_rounds_up() {
+        assert_eq!(chunk_count(1), 1);
+        assert_eq!(chunk_count(CHUNK_SIZE), 1);
+        assert_eq!(chunk_count(CHUNK_SIZE + 1), 2);
+    }
+
+    #[test]
+    fn chunk_len_is_full_but_for_the_last() {
+        let size = (CHUNK_SIZE * 2 + 5) as u64;
+        assert_eq!(chunk_len(size, 0), CHUNK_SIZE as u64);
+        assert_eq!(chunk_len(size, 2), 5);
+        assert_eq!(chunk_len(size, 3), 0);
+    }
+
+    #[test]
+    fn insert_body_then_assemble() {
+        let store = ChunkStore::new();
+        let body = body(CHUNK_SIZE * 3 + 17);
+        let content_hash = store.insert_body(&body);
+        assert!(store.missing(&content_hash, 4).is_empty());
+        assert_eq!(store.assemble(&content_hash), Some(body));
+    }
+
+    #[test]
+    fn assemble_from_chunks_in_any_order() {
+        let source = ChunkStore::new();
+        let body = body(CHUNK_SIZE * 2 + 5);
+        let content_hash = source.insert_body(&body);
+
+        let store = ChunkStore::new();
+        assert_eq!(store.missing(&content_hash, 3), vec![0, 1, 2]);
+        for index in vec![2, 0] {
+            let chunk = source.get(&content_hash, index).unwrap();
+            assert!(store.insert(&content_hash, index, 3, chunk));
+        }
+        assert_eq!(store.missing(&content_hash, 3), vec![1]);
+        assert!(store.assemble(&content_hash).is_none());
+        store.insert(&content_hash, 1, 3, source.get(&content_hash, 1).unwrap());
+        assert_eq!(store.assemble(&content_hash), Some(body));
+    }
+
+    #[test]
+    fn assemble_discards_chunks_that_do_not_match() {
+        let store = ChunkStore::new();
+        let content_hash = hash::hash_bytes(b"the real body").unwrap();
+        store.insert(&content_hash, 0, 1, Vec::from("an impostor"));
+        assert!(store.assemble(&content_hash).is_none());
+        assert_eq!(store.missing(&content_hash, 1), vec![0]);
+    }
+
+    #[test]
+    fn insert_ignores_out_of_range_chunks() {
+        let store = ChunkStore::new();
+        assert!(!store.insert("abc", 3, 3, Vec::new()));
+        store.insert("abc", 0, 3, Vec::new());
+        assert!(!store.insert("abc", 0, 4, Vec::new()));
+    }
+
+    #[test]
+    fn insert_refuses_too_many_or_too_large_chunks() {
+        let store = ChunkStore::new();
+        assert!(!store.insert("abc", 0, MAX_CHUNK_COUNT + 1, Vec::new()));
+        assert!(!store.insert("abc", 0, 1, vec![0; CHUNK_SIZE + 1]));
+        assert_eq!(store.missing("abc", 1), vec![0]);
+    }
+
+    #[test]
+    fn retain_drops_unreferenced_bodies() {
+        let store = ChunkStore::new();
+        let kept = store.insert_body(b"keep me");
+        let dropped = store.insert_body(b"drop me");
+        store.retain(|h| h == kept);
+        assert!(store.get(&kept, 0).is_some());
+        assert!(store.get(&dropped, 0).is_none());
+    }
+}
@@ -14,18 +14,36 @@
 
 //! The Butterfly client library.
 //!
-//! This will connect to a given butterfly members `Pull` thread, and inject a rumor.
+//! This will connect to a given butterfly members `Pull` thread, and inject a rumor, or ask a
+//! member about the ring over its SWIM port.
 
-use habitat_core::crypto::SymKey;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::Duration;
+
+use habitat_core::crypto::{SigKeyPair, SymKey};
 use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message, RepeatedField};
 use zmq;
 
 use ZMQ_CONTEXT;
+use chunk::{self, CHUNK_SIZE};
+use keyring::KeyRing;
 use message;
+use message::swim::{Query, QueryResponse, Query_Type, RolloutPolicy, Rumor as ProtoRumor,
+                    ServiceConfig_Mode, Swim, Swim_Type, Wire_Compression};
 use rumor::Rumor;
+use rumor::election::Election;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use error::{Result, Error};
+use server::inbound::MAX_RECV_BYTES;
+
+/// How long to wait for each answer to a query before asking again.
+const QUERY_TIMEOUT_MS: u64 = 1000;
+
+/// How many times to ask before giving up on a member.
+const QUERY_ATTEMPTS: usize = 3;
 
 /// Holds a ZMQ Push socket, and an optional ring encryption key.
 pub struct Client {
@@ -50,6 +68,8 @@ impl Client {
             .expect("Failure to set the ZMQ push socket hwm");
         socket.set_sndtimeo(500)
             .expect("Failure to set the ZMQ send timeout");
+        socket.set_ipv6(true)
+            .expect("Failure to set the ZMQ push socket to use IPv6");
         let to_addr = format!("tcp://{}", addr.to_string());
         try!(socket.connect(&to_addr).map_err(Error::ZmqConnectError));
         Ok(Client {
@@ -58,37 +78,240 @@ impl Client {
         })
     }
 
-    /// Create a service configuration and send it to the server.
+    /// Create a service configuration, optionally signed by `signer`, and send it to the server.
+    /// `applied_by` names whoever applied it, for the config history; unlike the signer, it is
+    /// not checked. With a `rollout` policy, Supervisors take it up a batch at a time rather than
+    /// all at once.
     pub fn send_service_config(&mut self,
                                service_group: ServiceGroup,
                                incarnation: u64,
                                config: Vec<u8>,
-                               encrypted: bool)
+                               encrypted: bool,
+                               rollout: Option<&RolloutPolicy>,
+                               signer: Option<&SigKeyPair>,
+                               applied_by: Option<&str>)
                                -> Result<()> {
-        let mut sc = ServiceConfig::new("butterflyclient", service_group, config);
+        let sc = ServiceConfig::new("butterflyclient", service_group, config);
+        self.send_config_change(sc, incarnation, encrypted, rollout, signer, applied_by)
+    }
+
+    /// Like `send_service_config`, but `patch` is merged into the configuration the service group
+    /// already has, and the keys in `delete_keys`, dotted paths like `"http.port"`, are removed
+    /// from it, rather than the whole of it being replaced. `base_incarnation` is the incarnation
+    /// of the configuration the patch is made against, or 0 if there is none yet; a member that
+    /// never received it does not apply the patch.
+    pub fn send_service_config_patch(&mut self,
+                                     service_group: ServiceGroup,
+                                     incarnation: u64,
+                                     base_incarnation: u64,
+                                     patch: Vec<u8>,
+                                     delete_keys: Vec<String>,
+                                     encrypted: bool,
+                                     rollout: Option<&RolloutPolicy>,
+                                     signer: Option<&SigKeyPair>,
+                                     applied_by: Option<&str>)
+                                     -> Result<()> {
+        let mut sc = ServiceConfig::new("butterflyclient", service_group, patch);
+        sc.set_mode(ServiceConfig_Mode::Patch);
+        sc.set_base_incarnation(base_incarnation);
+        sc.set_delete_keys(RepeatedField::from_vec(delete_keys));
+        self.send_config_change(sc, incarnation, encrypted, rollout, signer, applied_by)
+    }
+
+    fn send_config_change(&mut self,
+                          mut sc: ServiceConfig,
+                          incarnation: u64,
+                          encrypted: bool,
+                          rollout: Option<&RolloutPolicy>,
+                          signer: Option<&SigKeyPair>,
+                          applied_by: Option<&str>)
+                          -> Result<()> {
         sc.set_incarnation(incarnation);
         sc.set_encrypted(encrypted);
+        if let Some(rollout) = rollout {
+            sc.set_rollout(rollout.clone());
+        }
+        if let Some(applied_by) = applied_by {
+            sc.set_applied_by(String::from(applied_by));
+        }
+        if let Some(signer) = signer {
+            try!(sc.sign(signer));
+        }
         self.send(sc)
     }
 
-    /// Create a service file and send it to the server.
+    /// Ask the ring to hand leadership of a service group to `member_id`, or to any member but
+    /// the current leader.
+    pub fn send_leader_transfer(&mut self,
+                                service_group: ServiceGroup,
+                                member_id: Option<&str>)
+                                -> Result<()> {
+        let request = Election::transfer_request("butterflyclient", service_group, member_id);
+        self.send(request)
+    }
+
+    /// Create a service file, optionally signed by `signer`, and send it to the server. Bodies
+    /// too large to gossip are sent as chunks following the service file itself.
     pub fn send_service_file<S: Into<String>>(&mut self,
                                               service_group: ServiceGroup,
                                               filename: S,
                                               incarnation: u64,
                                               body: Vec<u8>,
-                                              encrypted: bool)
+                                              encrypted: bool,
+                                              signer: Option<&SigKeyPair>)
                                               -> Result<()> {
         let mut sf = ServiceFile::new("butterflyclient", service_group, filename, body);
         sf.set_incarnation(incarnation);
         sf.set_encrypted(encrypted);
+        let chunked_body = try!(sf.chunk());
+        if let Some(signer) = signer {
+            try!(sf.sign(signer));
+        }
+        let content_hash = String::from(sf.get_content_hash());
+        let chunk_count = sf.get_chunk_count();
+        try!(self.send(sf));
+        if let Some(body) = chunked_body {
+            for (index, data) in body.chunks(CHUNK_SIZE).enumerate() {
+                try!(self.send_message(chunk::file_chunk("butterflyclient",
+                                                         &content_hash,
+                                                         index as u32,
+                                                         chunk_count,
+                                                         data.to_vec())));
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete a service file from every member of the service group, with a tombstone
+    /// optionally signed by `signer`. `incarnation` has to be higher than the file's own.
+    pub fn send_service_file_tombstone<S: Into<String>>(&mut self,
+                                                        service_group: ServiceGroup,
+                                                        filename: S,
+                                                        incarnation: u64,
+                                                        signer: Option<&SigKeyPair>)
+                                                        -> Result<()> {
+        let mut sf = ServiceFile::tombstone("butterflyclient", service_group, filename);
+        sf.set_incarnation(incarnation);
+        if let Some(signer) = signer {
+            try!(sf.sign(signer));
+        }
         self.send(sf)
     }
 
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = try!(rumor.write_to_bytes());
-        let wire_msg = try!(message::generate_wire(bytes, &self.ring_key));
+        self.send_bytes(bytes)
+    }
+
+    /// Send a message that is not a rumor, such as a file chunk, to the server.
+    fn send_message(&mut self, message: ProtoRumor) -> Result<()> {
+        let bytes = try!(message.write_to_bytes());
+        self.send_bytes(bytes)
+    }
+
+    fn send_bytes(&mut self, bytes: Vec<u8>) -> Result<()> {
+        // We can't tell which version of butterfly the server runs, so never compress.
+        let wire_msg = try!(message::generate_wire(bytes,
+                                                   self.ring_key.as_ref(),
+                                                   Wire_Compression::NONE));
         self.socket.send(&wire_msg, 0).map_err(Error::ZmqSendError)
     }
 }
+
+/// Ask the member at `addr` about the ring, and wait for the whole answer. When the answer comes
+/// a page at a time, the pages are put together into one response; the slices of a config body
+/// are put back together into the one config.
+pub fn query<A: ToSocketAddrs>(addr: A,
+                               ring_key: Option<SymKey>,
+                               mut query: Query)
+                               -> Result<QueryResponse> {
+    let addr = match try!(addr.to_socket_addrs().map_err(Error::CannotBind)).next() {
+        Some(addr) => addr,
+        None => {
+            return Err(Error::CannotBind(io::Error::new(io::ErrorKind::AddrNotAvailable,
+                                                        "No address discovered.")))
+        }
+    };
+    let bind_addr = match addr {
+        SocketAddr::V4(_) => "0.0.0.0:0",
+        SocketAddr::V6(_) => "[::]:0",
+    };
+    let socket = try!(UdpSocket::bind(bind_addr).map_err(Error::CannotBind));
+    try!(socket.set_read_timeout(Some(Duration::from_millis(QUERY_TIMEOUT_MS)))
+        .map_err(Error::SocketSetReadTimeout));
+    let key_ring = KeyRing::new(ring_key);
+    let mut recv_buffer: Vec<u8> = vec![0; MAX_RECV_BYTES];
+
+    query.set_offset(0);
+    query.clear_after();
+    let mut answer = try!(query_page(&socket, addr, &key_ring, &query, &mut recv_buffer));
+    let mut next_offset = answer.get_next_offset();
+    let mut next_after = answer.take_next_after();
+    while next_offset > query.get_offset() ||
+          (!next_after.is_empty() && next_after != query.get_after()) {
+        if next_after.is_empty() {
+            query.set_offset(next_offset);
+        } else {
+            query.set_after(next_after);
+        }
+        let mut page = try!(query_page(&socket, addr, &key_ring, &query, &mut recv_buffer));
+        for membership in page.take_membership().into_iter() {
+            answer.mut_membership().push(membership);
+        }
+        for service in page.take_service().into_iter() {
+            answer.mut_service().push(service);
+        }
+        for file in page.take_service_file().into_iter() {
+            answer.mut_service_file().push(file);
+        }
+        for config in page.take_config_history().into_iter() {
+            answer.mut_config_history().push(config);
+        }
+        answer.mut_configs_too_large().extend_from_slice(page.get_configs_too_large());
+        if query.get_field_type() == Query_Type::ConfigBody {
+            let body = page.mut_service_config().take_config();
+            answer.mut_service_config().mut_config().extend_from_slice(&body);
+        }
+        next_offset = page.get_next_offset();
+        next_after = page.take_next_after();
+    }
+    answer.clear_next_offset();
+    answer.clear_after();
+    Ok(answer)
+}
+
+/// Send one query, and wait for the answer to it; ask again if it doesn't come in time.
+fn query_page(socket: &UdpSocket,
+              addr: SocketAddr,
+              key_ring: &KeyRing,
+              query: &Query,
+              recv_buffer: &mut [u8])
+              -> Result<QueryResponse> {
+    let mut swim = Swim::new();
+    swim.set_field_type(Swim_Type::QUERY);
+    swim.set_query(query.clone());
+    let wire = try!(message::generate_wire(try!(swim.write_to_bytes()),
+                                           key_ring.current(),
+                                           Wire_Compression::NONE));
+    for _ in 0..QUERY_ATTEMPTS {
+        try!(socket.send_to(&wire, addr).map_err(Error::QuerySocket));
+        loop {
+            let length = match socket.recv_from(recv_buffer) {
+                Ok((length, _from)) => length,
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock ||
+                              e.kind() == io::ErrorKind::TimedOut => break,
+                Err(e) => return Err(Error::QuerySocket(e)),
+            };
+            let payload = try!(message::unwrap_wire(&recv_buffer[..length], key_ring));
+            let mut msg: Swim = try!(protobuf::parse_from_bytes(&payload));
+            // Anything else is a late answer to a query we have since sent again
+            if msg.get_field_type() == Swim_Type::QUERY_RESPONSE &&
+               msg.get_query_response().get_offset() == query.get_offset() &&
+               msg.get_query_response().get_after() == query.get_after() {
+                return Ok(msg.take_query_response());
+            }
+        }
+    }
+    Err(Error::QueryTimeout(addr.to_string()))
+}
@@ -23,19 +23,30 @@ use std::error;
 use std::fmt;
 use std::result;
 
+use chunk;
+
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
     BadMessage(String),
     CannotBind(io::Error),
+    Compression(io::Error),
+    ConfigChainGap(String, u64, u64),
+    DataFile(io::Error),
+    FileTooLarge(String, u64),
     HabitatCore(habitat_core::error::Error),
     NonExistentRumor(String, String),
     ProtobufError(protobuf::ProtobufError),
+    QuerySocket(io::Error),
+    QueryTimeout(String),
+    RingKeyInUse(String),
     ServiceConfigNotUtf8(FromUtf8Error),
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    UnknownRingKey(String),
+    UnsignedRumor(String),
     ZmqConnectError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
@@ -45,6 +56,24 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::BadMessage(ref err) => format!("Bad Message: {:?}", err),
             Error::CannotBind(ref err) => format!("Cannot bind to port: {:?}", err),
+            Error::Compression(ref err) => {
+                format!("Cannot compress or decompress wire payload: {}", err)
+            }
+            Error::ConfigChainGap(ref sg, incarnation, missing) => {
+                format!("Cannot bring the config for {} up to incarnation {}; we never received \
+                         incarnation {}, which it patches",
+                        sg,
+                        incarnation,
+                        missing)
+            }
+            Error::DataFile(ref err) => format!("Cannot read or write the data file: {}", err),
+            Error::FileTooLarge(ref filename, size) => {
+                format!("Cannot gossip {}; at {} bytes it is larger than the {} bytes a service \
+                         file may be",
+                        filename,
+                        size,
+                        chunk::CHUNK_SIZE as u64 * chunk::MAX_CHUNK_COUNT as u64)
+            }
             Error::HabitatCore(ref err) => format!("{}", err),
             Error::NonExistentRumor(ref member_id, ref rumor_id) => {
                 format!("Non existent rumor asked to be written to bytes: {} {}",
@@ -52,6 +81,15 @@ impl fmt::Display for Error {
                         rumor_id)
             }
             Error::ProtobufError(ref err) => format!("ProtoBuf Error: {}", err),
+            Error::QuerySocket(ref err) => format!("Cannot send or receive a query: {}", err),
+            Error::QueryTimeout(ref addr) => {
+                format!("No answer to our query from {}; check the address, and that we have \
+                         the same ring key as the ring",
+                        addr)
+            }
+            Error::RingKeyInUse(ref key) => {
+                format!("Cannot retire ring key {}; it is the newest revision we have", key)
+            }
             Error::ServiceConfigNotUtf8(ref err) => {
                 format!("Cannot decode service configuration; it is not UTF-8: {}",
                         err)
@@ -63,6 +101,8 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::UnknownRingKey(ref key) => format!("Ring key {} is not trusted", key),
+            Error::UnsignedRumor(ref key) => format!("Rumor for {} is not signed", key),
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
@@ -78,15 +118,24 @@ impl error::Error for Error {
         match *self {
             Error::BadMessage(ref _err) => "Bad Protobuf Message; should be Ping/Ack/PingReq",
             Error::CannotBind(ref _err) => "Cannot bind to port",
+            Error::Compression(ref _err) => "Cannot compress or decompress wire payload",
+            Error::ConfigChainGap(_, _, _) => "A config patch is missing an earlier patch",
+            Error::DataFile(ref _err) => "Cannot read or write the data file",
+            Error::FileTooLarge(_, _) => "Service file is too large to gossip",
             Error::HabitatCore(ref _err) => "Habitat core error",
             Error::NonExistentRumor(ref _member_id, ref _rumor_id) => {
                 "Cannot write rumor to bytes because it does not exist"
             }
             Error::ProtobufError(ref err) => err.description(),
+            Error::QuerySocket(ref _err) => "Cannot send or receive a query",
+            Error::QueryTimeout(ref _addr) => "No answer to a query",
+            Error::RingKeyInUse(ref _key) => "Cannot retire the ring key currently in use",
             Error::ServiceConfigNotUtf8(ref _err) => "Cannot convert a service config to UTF-8",
             Error::SocketSetReadTimeout(ref _err) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(ref _err) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::UnknownRingKey(ref _key) => "Ring key is not trusted",
+            Error::UnsignedRumor(ref _key) => "Rumor is not signed",
             Error::ZmqConnectError(ref _err) => "Cannot connect ZMQ socket",
             Error::ZmqSendError(ref _err) => "Cannot send message through ZMQ socket",
         }
@@ -0,0 +1,214 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The set of ring key revisions a server trusts.
+//!
+//! Every revision in the `KeyRing` can be used to decrypt inbound traffic, but outbound traffic
+//! is encrypted with just one of them, the current revision. Trusting a revision does not make it
+//! current; that is a separate step, so that rotating a ring key is a matter of adding the new
+//! revision to every member, then switching every member to it, and then retiring the old
+//! revision. No member encrypts with a revision that the others cannot yet decrypt.
+
+use std::slice;
+
+use habitat_core::crypto::SymKey;
+
+use error::{Error, Result};
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyRing {
+    // Ordered newest revision first.
+    keys: Vec<SymKey>,
+    // The `name-rev` of the key we encrypt with.
+    current: Option<String>,
+}
+
+impl KeyRing {
+    /// Create a new KeyRing, optionally trusting an initial key, which becomes the current one.
+    pub fn new(ring_key: Option<SymKey>) -> KeyRing {
+        let mut key_ring = KeyRing::default();
+        if let Some(ring_key) = ring_key {
+            key_ring.insert(ring_key);
+        }
+        key_ring
+    }
+
+    /// Returns true if we trust no keys at all; in that case the wire is not encrypted.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The key we encrypt outbound traffic with.
+    pub fn current(&self) -> Option<&SymKey> {
+        match self.current {
+            Some(ref current) => self.get(current),
+            None => None,
+        }
+    }
+
+    /// The newest revision we trust, which may not be current yet.
+    pub fn newest(&self) -> Option<&SymKey> {
+        self.keys.first()
+    }
+
+    /// Returns the trusted key for the given `name-rev`, if we have it.
+    pub fn get(&self, name_with_rev: &str) -> Option<&SymKey> {
+        self.keys.iter().find(|k| k.name_with_rev() == name_with_rev)
+    }
+
+    /// Trust a new key revision, for decrypting only; the first key we trust also becomes
+    /// current. Returns false if the revision was already trusted.
+    pub fn insert(&mut self, key: SymKey) -> bool {
+        if self.get(&key.name_with_rev()).is_some() {
+            return false;
+        }
+        if self.current.is_none() {
+            self.current = Some(key.name_with_rev());
+        }
+        let position = self.keys.iter().position(|k| k.rev < key.rev).unwrap_or(self.keys.len());
+        self.keys.insert(position, key);
+        true
+    }
+
+    /// Start encrypting with a trusted key revision.
+    pub fn switch_to(&mut self, name_with_rev: &str) -> Result<()> {
+        if self.get(name_with_rev).is_none() {
+            return Err(Error::UnknownRingKey(String::from(name_with_rev)));
+        }
+        self.current = Some(String::from(name_with_rev));
+        Ok(())
+    }
+
+    /// Stop trusting a key revision. The current key cannot be retired until we have switched
+    /// to another.
+    pub fn retire(&mut self, name_with_rev: &str) -> Result<SymKey> {
+        if self.current.as_ref().map(|c| &c[..]) == Some(name_with_rev) {
+            return Err(Error::RingKeyInUse(String::from(name_with_rev)));
+        }
+        match self.keys.iter().position(|k| k.name_with_rev() == name_with_rev) {
+            Some(position) => Ok(self.keys.remove(position)),
+            None => Err(Error::UnknownRingKey(String::from(name_with_rev))),
+        }
+    }
+
+    /// The `name-rev` of every trusted key, newest first.
+    pub fn revisions(&self) -> Vec<String> {
+        self.keys.iter().map(|k| k.name_with_rev()).collect()
+    }
+
+    pub fn iter(&self) -> slice::Iter<SymKey> {
+        self.keys.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use error::Error;
+    use keyring::KeyRing;
+
+    fn key(rev: &str) -> SymKey {
+        let mut key = SymKey::generate_in_memory("wolverine")
+            .expect("Failed to generate an in memory symkey");
+        key.rev = String::from(rev);
+        key
+    }
+
+    #[test]
+    fn new_without_a_key_is_empty() {
+        let key_ring = KeyRing::new(None);
+        assert!(key_ring.is_empty());
+        assert!(key_ring.current().is_none());
+    }
+
+    #[test]
+    fn revisions_are_newest_first() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        assert!(key_ring.insert(key("201702020000")));
+        assert!(key_ring.insert(key("201612120000")));
+        assert_eq!(key_ring.newest().unwrap().name_with_rev(),
+                   "wolverine-201702020000");
+        assert_eq!(key_ring.revisions(),
+                   vec!["wolverine-201702020000",
+                        "wolverine-201701010000",
+                        "wolverine-201612120000"]);
+    }
+
+    #[test]
+    fn trusting_a_newer_revision_does_not_switch_to_it() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        assert!(key_ring.insert(key("201702020000")));
+        assert_eq!(key_ring.current().unwrap().name_with_rev(),
+                   "wolverine-201701010000");
+        key_ring.switch_to("wolverine-201702020000").unwrap();
+        assert_eq!(key_ring.current().unwrap().name_with_rev(),
+                   "wolverine-201702020000");
+    }
+
+    #[test]
+    fn the_first_key_trusted_is_current() {
+        let mut key_ring = KeyRing::new(None);
+        assert!(key_ring.insert(key("201701010000")));
+        assert_eq!(key_ring.current().unwrap().name_with_rev(),
+                   "wolverine-201701010000");
+    }
+
+    #[test]
+    fn switch_to_an_unknown_revision() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        match key_ring.switch_to("wolverine-201702020000") {
+            Err(Error::UnknownRingKey(_)) => {}
+            _ => panic!("Switched to a key we never trusted"),
+        }
+        assert_eq!(key_ring.current().unwrap().name_with_rev(),
+                   "wolverine-201701010000");
+    }
+
+    #[test]
+    fn insert_ignores_known_revisions() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        assert_eq!(key_ring.insert(key("201701010000")), false);
+        assert_eq!(key_ring.revisions().len(), 1);
+    }
+
+    #[test]
+    fn retire_removes_an_old_revision() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        key_ring.insert(key("201702020000"));
+        key_ring.switch_to("wolverine-201702020000").unwrap();
+        key_ring.retire("wolverine-201701010000").unwrap();
+        assert!(key_ring.get("wolverine-201701010000").is_none());
+        assert_eq!(key_ring.revisions(), vec!["wolverine-201702020000"]);
+    }
+
+    #[test]
+    fn retire_refuses_the_current_revision() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        key_ring.insert(key("201702020000"));
+        match key_ring.retire("wolverine-201701010000") {
+            Err(Error::RingKeyInUse(_)) => {}
+            _ => panic!("Retired the key we encrypt with"),
+        }
+    }
+
+    #[test]
+    fn retire_unknown_revision() {
+        let mut key_ring = KeyRing::new(Some(key("201701010000")));
+        match key_ring.retire("wolverine-201602020000") {
+            Err(Error::UnknownRingKey(_)) => {}
+            _ => panic!("Retired a key we never trusted"),
+        }
+    }
+}
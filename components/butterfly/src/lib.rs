@@ -40,6 +40,7 @@
 //!
 //! Start exploring the code base by following the thread of execution in the `server` module.
 
+extern crate flate2;
 extern crate habitat_core;
 #[macro_use]
 extern crate lazy_static;
@@ -48,14 +49,19 @@ extern crate log;
 extern crate protobuf;
 extern crate rand;
 extern crate serde;
+extern crate serde_json;
+#[cfg(test)]
+extern crate tempdir;
 extern crate time;
 extern crate uuid;
 extern crate zmq;
 
 #[macro_use]
 pub mod trace;
+pub mod chunk;
 pub mod client;
 pub mod error;
+pub mod keyring;
 pub mod member;
 pub mod message;
 pub mod rumor;
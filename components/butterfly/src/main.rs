@@ -47,6 +47,7 @@ fn main() {
                                      member,
                                      trace::Trace::default(),
                                      None,
+                                     None,
                                      None)
         .unwrap();
     println!("Server ID: {}", server.member_id);
@@ -14,16 +14,18 @@
 
 //! Tracks membership. Contains both the `Member` struct and the `MemberList`.
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, BTreeMap, HashMap};
 use std::fmt;
 use std::iter::IntoIterator;
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::ops::{Deref, DerefMut};
+use std::result;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use uuid::Uuid;
 use rand::{thread_rng, Rng};
+use serde::{Serialize, Serializer};
 use time::SteadyTime;
 
 use rumor::RumorKey;
@@ -41,6 +43,17 @@ pub enum Health {
     Confirmed,
 }
 
+impl Serialize for Health {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
+        let value = match *self {
+            Health::Alive => "alive",
+            Health::Suspect => "suspect",
+            Health::Confirmed => "confirmed",
+        };
+        serializer.serialize_str(value)
+    }
+}
+
 /// Maps our internal health to the wire protocols health.
 impl From<ProtoMembership_Health> for Health {
     fn from(pm_health: ProtoMembership_Health) -> Health {
@@ -98,21 +111,60 @@ impl Member {
         Member { proto: proto_member }
     }
 
+    /// Returns the IP address we reach this member on: the address it asked to be reached on, if
+    /// any, then the address we last heard from it on, then the first other address it
+    /// advertises.
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.addresses().into_iter().next()
+    }
+
+    /// Returns every IP address this member can be reached on, in the order `ip` prefers them.
+    /// Addresses that do not parse are skipped.
+    pub fn addresses(&self) -> Vec<IpAddr> {
+        let mut addresses = Vec::new();
+        let additional = self.get_additional_addresses().iter().map(|a| &a[..]);
+        let candidates = Some(self.get_advertised_address())
+            .into_iter()
+            .chain(Some(self.get_address()))
+            .chain(additional);
+        for address in candidates {
+            if let Ok(ip) = address.trim_matches(|c| c == '[' || c == ']').parse::<IpAddr>() {
+                let ip = canonical_ip(ip);
+                if !addresses.contains(&ip) {
+                    addresses.push(ip);
+                }
+            }
+        }
+        addresses
+    }
+
     /// Returns the socket address of this member.
     ///
     /// # Panics
     ///
-    /// This function panics if the address is un-parseable. In practice, it shouldn't be
-    /// un-parseable, since its set from the inbound socket directly.
+    /// This function panics if the member has no parseable address. In practice, it shouldn't
+    /// happen, since the address is set from the inbound socket directly.
     pub fn swim_socket_address(&self) -> SocketAddr {
-        let address_str = format!("{}:{}", self.get_address(), self.get_swim_port());
-        match address_str.parse() {
-            Ok(addr) => addr,
-            Err(e) => {
-                panic!("Cannot parse member {:?} address: {}", self, e);
-            }
+        match self.ip() {
+            Some(ip) => SocketAddr::new(ip, self.get_swim_port() as u16),
+            None => panic!("Cannot parse member {:?} address", self),
+        }
+    }
+}
+
+/// Turns an IPv4 address that arrived on a dual-stack socket, like `::ffff:10.0.0.1`, back into
+/// plain IPv4, so a member has one address no matter which kind of socket heard from it.
+pub fn canonical_ip(ip: IpAddr) -> IpAddr {
+    if let IpAddr::V6(v6) = ip {
+        let s = v6.segments();
+        if s[0] == 0 && s[1] == 0 && s[2] == 0 && s[3] == 0 && s[4] == 0 && s[5] == 0xffff {
+            return IpAddr::V4(Ipv4Addr::new((s[6] >> 8) as u8,
+                                            s[6] as u8,
+                                            (s[7] >> 8) as u8,
+                                            s[7] as u8));
         }
     }
+    ip
 }
 
 impl Deref for Member {
@@ -168,10 +220,48 @@ pub struct MemberList {
     members: Arc<RwLock<HashMap<UuidSimple, Member>>>,
     health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
     suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
-    initial_members: Arc<RwLock<Vec<Member>>>,
+    /// Each initial peer, as every member it may be reached on
+    initial_members: Arc<RwLock<Vec<Vec<Member>>>>,
     update_counter: Arc<AtomicUsize>,
 }
 
+/// A member along with our opinion of its health, for reporting.
+struct MemberHealth<'a> {
+    member: &'a Member,
+    health: Option<Health>,
+}
+
+impl<'a> Serialize for MemberHealth<'a> {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
+        let mut state = try!(serializer.serialize_struct("member_health", 2));
+        try!(serializer.serialize_struct_elt(&mut state, "member", &self.member.proto));
+        try!(serializer.serialize_struct_elt(&mut state, "health", &self.health));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+impl Serialize for MemberList {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
+        let members = self.members.read().expect("Member list lock is poisoned");
+        let health = self.health.read().expect("Health lock is poisoned");
+        let entries: BTreeMap<&String, MemberHealth> = members.iter()
+            .map(|(id, member)| {
+                (id,
+                 MemberHealth {
+                     member: member,
+                     health: health.get(id).cloned(),
+                 })
+            })
+            .collect();
+        let mut state = try!(serializer.serialize_struct("member_list", 2));
+        try!(serializer.serialize_struct_elt(&mut state, "members", &entries));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "update_counter",
+                                             self.get_update_counter()));
+        serializer.serialize_struct_end(state)
+    }
+}
+
 impl MemberList {
     /// Creates a new, empty, MemberList.
     pub fn new() -> MemberList {
@@ -196,6 +286,7 @@ impl MemberList {
         self.update_counter.load(Ordering::Relaxed)
     }
 
+    /// The number of initial peers, however many addresses each may be reached on.
     pub fn len_initial_members(&self) -> usize {
         let im = self.initial_members.read().expect("Initial members lock is poisoned");
         im.len()
@@ -203,14 +294,23 @@ impl MemberList {
 
     pub fn add_initial_member(&self, member: Member) {
         let mut im = self.initial_members.write().expect("Initial members lock is poisoned");
-        im.push(member);
+        im.push(vec![member]);
     }
 
+    /// Replaces the initial peers, such as when the addresses of our peers are discovered again.
+    /// Each peer is given as the members it may be reached on, such as one per address of a
+    /// host, and counts once however many there are.
+    pub fn set_initial_peers(&self, peers: Vec<Vec<Member>>) {
+        let mut im = self.initial_members.write().expect("Initial members lock is poisoned");
+        *im = peers.into_iter().filter(|peer| !peer.is_empty()).collect();
+    }
+
+    /// Calls the closure with every member of every initial peer.
     pub fn with_initial_members<F>(&self, mut with_closure: F) -> ()
         where F: FnMut(&Member)
     {
         let im = self.initial_members.read().expect("Initial members lock is poisoned");
-        for member in im.iter() {
+        for member in im.iter().flat_map(|peer| peer.iter()) {
             with_closure(member);
         }
     }
@@ -487,9 +587,11 @@ impl MemberList {
 #[cfg(test)]
 mod tests {
     mod member {
+        use std::net::IpAddr;
+
         use uuid::Uuid;
         use message::swim;
-        use member::Member;
+        use member::{canonical_ip, Member};
 
         // Sets the uuid to simple, and the incarnation to zero.
         #[test]
@@ -510,9 +612,44 @@ mod tests {
             let member: Member = proto.into();
             assert_eq!(proto2, member.proto);
         }
+
+        // Reaches a member on the address we heard from it on, then on what it advertises
+        #[test]
+        fn addresses() {
+            let mut member = Member::new();
+            member.mut_additional_addresses().push(String::from("::1"));
+            member.mut_additional_addresses().push(String::from("not-an-address"));
+            member.mut_additional_addresses().push(String::from("::ffff:10.0.0.1"));
+            member.set_swim_port(9638);
+            assert_eq!(member.swim_socket_address(), "[::1]:9638".parse().unwrap());
+            member.set_address(String::from("10.0.0.1"));
+            assert_eq!(member.addresses(),
+                       vec!["10.0.0.1".parse::<IpAddr>().unwrap(), "::1".parse().unwrap()]);
+            assert_eq!(member.swim_socket_address(), "10.0.0.1:9638".parse().unwrap());
+        }
+
+        // Behind NAT, the address a member asks for beats the one its packets came from
+        #[test]
+        fn advertised_address_comes_first() {
+            let mut member = Member::new();
+            member.set_address(String::from("172.17.0.2"));
+            member.set_advertised_address(String::from("203.0.113.7"));
+            member.set_swim_port(9638);
+            assert_eq!(member.swim_socket_address(), "203.0.113.7:9638".parse().unwrap());
+        }
+
+        #[test]
+        fn canonical_ip_unmaps_ipv4() {
+            let mapped: IpAddr = "::ffff:192.168.1.4".parse().unwrap();
+            assert_eq!(canonical_ip(mapped), "192.168.1.4".parse::<IpAddr>().unwrap());
+            let loopback: IpAddr = "::1".parse().unwrap();
+            assert_eq!(canonical_ip(loopback), loopback);
+        }
     }
 
     mod member_list {
+        use serde_json::{self, Value};
+
         use member::{Member, MemberList, Health, PINGREQ_TARGETS};
 
         fn populated_member_list(size: u64) -> MemberList {
@@ -544,6 +681,46 @@ mod tests {
             assert!(list_a != list_b);
         }
 
+        #[test]
+        fn an_initial_peer_counts_once_however_many_addresses_it_has() {
+            let ml = MemberList::new();
+            let mut v4 = Member::new();
+            v4.set_address(String::from("10.0.0.1"));
+            let mut v6 = Member::new();
+            v6.set_address(String::from("fd00::1"));
+            ml.set_initial_peers(vec![vec![v4, v6], vec![Member::new()], vec![]]);
+            assert_eq!(ml.len_initial_members(), 2);
+            let mut pinged = 0;
+            ml.with_initial_members(|_| pinged += 1);
+            assert_eq!(pinged, 3);
+        }
+
+        #[test]
+        fn health_serializes_as_its_name() {
+            assert_eq!(serde_json::to_string(&Health::Alive).unwrap(), "\"alive\"");
+            assert_eq!(serde_json::to_string(&Health::Suspect).unwrap(), "\"suspect\"");
+            assert_eq!(serde_json::to_string(&Health::Confirmed).unwrap(), "\"confirmed\"");
+        }
+
+        #[test]
+        fn serializes_each_member_with_its_health_by_id() {
+            let ml = MemberList::new();
+            let member = Member::new();
+            let id = String::from(member.get_id());
+            ml.insert(member, Health::Suspect);
+            let value: Value = serde_json::from_str(&serde_json::to_string(&ml).unwrap()).unwrap();
+            let list = value.as_object().expect("Member list is not an object");
+            assert!(list.get("update_counter").and_then(|v| v.as_u64()).is_some());
+            let members = list.get("members").and_then(|v| v.as_object()).unwrap();
+            assert_eq!(members.len(), 1);
+            let entry = members.get(&id).and_then(|v| v.as_object()).unwrap();
+            assert_eq!(entry.get("health").and_then(|v| v.as_str()), Some("suspect"));
+            let member = entry.get("member").and_then(|v| v.as_object()).unwrap();
+            assert_eq!(member.get("id").and_then(|v| v.as_str()), Some(&id[..]));
+            assert!(member.get("incarnation").and_then(|v| v.as_u64()).is_some());
+            assert!(member.get("swim_port").is_some());
+        }
+
         #[test]
         fn health_of() {
             let ml = populated_member_list(1);
@@ -14,60 +14,181 @@
 
 pub mod swim;
 
+use std::io::{self, Read, Write};
 use std::result;
 use std::str;
 
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
 use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
 
-use error::Result;
-use message::swim::Wire;
-use protobuf::{self, Message};
+use error::{Error, Result};
+use keyring::KeyRing;
+use message::swim::{RumorBatch, Wire, Wire_Compression};
+use protobuf::{self, Message, RepeatedField};
 
-pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Payloads smaller than this rarely shrink enough to be worth compressing.
+const MIN_COMPRESSION_SIZE: usize = 256;
+
+/// The largest payload we will inflate a compressed wire message to.
+const MAX_DECOMPRESSED_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Wrap a payload for the wire. If `compression` is requested the payload is compressed before it
+/// is encrypted, but only when that makes it smaller; only ask for compression when the receiver
+/// is known to accept it.
+pub fn generate_wire(payload: Vec<u8>,
+                     ring_key: Option<&SymKey>,
+                     compression: Wire_Compression)
+                     -> Result<Vec<u8>> {
+    wrap_wire(Wire::new(), payload, ring_key, compression)
+}
+
+/// Wrap several rumors in a single wire message. Compression works far better across a batch
+/// of similar rumors than on any one of them, but only receivers that accept compression know
+/// how to unpack a batch.
+pub fn generate_batch_wire(payloads: Vec<Vec<u8>>,
+                           ring_key: Option<&SymKey>,
+                           compression: Wire_Compression)
+                           -> Result<Vec<u8>> {
+    let mut batch = RumorBatch::new();
+    batch.set_rumor(RepeatedField::from_vec(payloads));
     let mut wire = Wire::new();
-    if let Some(ref ring_key) = *ring_key {
+    wire.set_batched(true);
+    wrap_wire(wire, try!(batch.write_to_bytes()), ring_key, compression)
+}
+
+fn wrap_wire(mut wire: Wire,
+             payload: Vec<u8>,
+             ring_key: Option<&SymKey>,
+             compression: Wire_Compression)
+             -> Result<Vec<u8>> {
+    let payload = match compression {
+        Wire_Compression::DEFLATE if payload.len() >= MIN_COMPRESSION_SIZE => {
+            let compressed = try!(deflate(&payload));
+            if compressed.len() < payload.len() {
+                wire.set_compression(Wire_Compression::DEFLATE);
+                compressed
+            } else {
+                payload
+            }
+        }
+        _ => payload,
+    };
+    if let Some(ring_key) = ring_key {
         wire.set_encrypted(true);
         let (nonce, encrypted_payload) = try!(ring_key.encrypt(&payload));
         wire.set_nonce(nonce);
         wire.set_payload(encrypted_payload);
+        wire.set_key_rev(ring_key.name_with_rev());
     } else {
         wire.set_payload(payload);
     }
     Ok(try!(wire.write_to_bytes()))
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+pub fn unwrap_wire(payload: &[u8], key_ring: &KeyRing) -> Result<Vec<u8>> {
+    let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
+    if wire.get_batched() {
+        return Err(Error::BadMessage(String::from("Unexpected batch of rumors")));
+    }
+    open_wire(&mut wire, key_ring)
+}
+
+/// Unwrap a wire message that holds either a single rumor or a batch of them.
+pub fn unwrap_wire_rumors(payload: &[u8], key_ring: &KeyRing) -> Result<Vec<Vec<u8>>> {
     let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
-    if let Some(ref ring_key) = *ring_key {
-        Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload())))
+    let payload = try!(open_wire(&mut wire, key_ring));
+    if wire.get_batched() {
+        let mut batch: RumorBatch = try!(protobuf::parse_from_bytes(&payload));
+        Ok(batch.take_rumor().into_vec())
     } else {
-        Ok(wire.take_payload())
+        Ok(vec![payload])
+    }
+}
+
+fn open_wire(wire: &mut Wire, key_ring: &KeyRing) -> Result<Vec<u8>> {
+    let payload = try!(decrypt_wire(wire, key_ring));
+    match wire.get_compression() {
+        Wire_Compression::DEFLATE => inflate(&payload),
+        Wire_Compression::NONE => Ok(payload),
+    }
+}
+
+fn decrypt_wire(wire: &mut Wire, key_ring: &KeyRing) -> Result<Vec<u8>> {
+    if key_ring.is_empty() {
+        return Ok(wire.take_payload());
+    }
+    if wire.has_key_rev() {
+        match key_ring.get(wire.get_key_rev()) {
+            Some(ring_key) => Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload()))),
+            None => Err(Error::UnknownRingKey(String::from(wire.get_key_rev()))),
+        }
+    } else {
+        // Members that predate key rotation don't tell us which revision they used, so try each
+        // key we trust, newest first.
+        let mut last_err = None;
+        for ring_key in key_ring.iter() {
+            match ring_key.decrypt(wire.get_nonce(), wire.get_payload()) {
+                Ok(decrypted) => return Ok(decrypted),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match last_err {
+            Some(e) => Err(Error::from(e)),
+            None => Ok(wire.take_payload()),
+        }
+    }
+}
+
+fn deflate(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::Default);
+    try!(encoder.write_all(bytes).map_err(Error::Compression));
+    encoder.finish().map_err(Error::Compression)
+}
+
+fn inflate(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut decoder = DeflateDecoder::new(bytes).take(MAX_DECOMPRESSED_SIZE + 1);
+    let mut inflated = Vec::new();
+    try!(decoder.read_to_end(&mut inflated).map_err(Error::Compression));
+    if inflated.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        return Err(Error::Compression(io::Error::new(io::ErrorKind::InvalidData,
+                                                     "decompressed payload is too large")));
     }
+    Ok(inflated)
 }
 
 impl Serialize for swim::Election {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
-        let mut state = try!(serializer.serialize_struct("election", 6));
+        let mut state = try!(serializer.serialize_struct("election", 7));
         try!(serializer.serialize_struct_elt(&mut state, "member_id", self.get_member_id()));
         try!(serializer.serialize_struct_elt(&mut state, "service_group", self.get_service_group()));
         try!(serializer.serialize_struct_elt(&mut state, "term", self.get_term()));
         try!(serializer.serialize_struct_elt(&mut state, "suitability", self.get_suitability()));
         try!(serializer.serialize_struct_elt(&mut state, "status", self.get_status()));
         try!(serializer.serialize_struct_elt(&mut state, "votes", self.get_votes()));
+        try!(serializer.serialize_struct_elt(&mut state, "lease", self.get_lease()));
         serializer.serialize_struct_end(state)
     }
 }
 
 impl Serialize for swim::Member {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
-        let mut state = try!(serializer.serialize_struct("member", 6));
+        let mut state = try!(serializer.serialize_struct("member", 9));
         try!(serializer.serialize_struct_elt(&mut state, "id", self.get_id()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "address", self.get_address()));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "additional_addresses",
+                                             self.get_additional_addresses()));
+        try!(serializer.serialize_struct_elt(&mut state,
+                                             "advertised_address",
+                                             self.get_advertised_address()));
         try!(serializer.serialize_struct_elt(&mut state, "swim_port", self.get_swim_port()));
         try!(serializer.serialize_struct_elt(&mut state, "gossip_port", self.get_gossip_port()));
         try!(serializer.serialize_struct_elt(&mut state, "persistent", self.get_persistent()));
+        try!(serializer.serialize_struct_elt(&mut state, "accepts_compression", self.get_accepts_compression()));
         serializer.serialize_struct_end(state)
     }
 }
@@ -108,12 +229,13 @@ impl Serialize for swim::Rumor {
 
 impl Serialize for swim::Service {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
-        let mut state = try!(serializer.serialize_struct("service", 9));
+        let mut state = try!(serializer.serialize_struct("service", 10));
         try!(serializer.serialize_struct_elt(&mut state, "member_id", self.get_member_id()));
         try!(serializer.serialize_struct_elt(&mut state, "service_group", self.get_service_group()));
         try!(serializer.serialize_struct_elt(&mut state, "package", self.get_package_ident()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "ip", self.get_ip()));
+        try!(serializer.serialize_struct_elt(&mut state, "ipv6", self.get_ipv6()));
         try!(serializer.serialize_struct_elt(&mut state, "hostname", self.get_hostname()));
         try!(serializer.serialize_struct_elt(&mut state, "port", self.get_port()));
         try!(serializer.serialize_struct_elt(&mut state, "exposes", self.get_exposes()));
@@ -124,10 +246,11 @@ impl Serialize for swim::Service {
 
 impl Serialize for swim::ServiceConfig {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
-        let mut state = try!(serializer.serialize_struct("service_config", 4));
+        let mut state = try!(serializer.serialize_struct("service_config", 5));
         try!(serializer.serialize_struct_elt(&mut state, "service_group", self.get_service_group()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "encrypted", self.get_encrypted()));
+        try!(serializer.serialize_struct_elt(&mut state, "signer", self.get_signer()));
         match str::from_utf8(self.get_config()) {
             Ok(c) => try!(serializer.serialize_struct_elt(&mut state, "config", c)),
             Err(_) => try!(serializer.serialize_struct_elt(&mut state, "config", self.get_config())),
@@ -138,11 +261,15 @@ impl Serialize for swim::ServiceConfig {
 
 impl Serialize for swim::ServiceFile {
     fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error> where S: Serializer {
-        let mut state = try!(serializer.serialize_struct("service_file", 5));
+        let mut state = try!(serializer.serialize_struct("service_file", 9));
         try!(serializer.serialize_struct_elt(&mut state, "service_group", self.get_service_group()));
         try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.get_incarnation()));
         try!(serializer.serialize_struct_elt(&mut state, "encrypted", self.get_encrypted()));
+        try!(serializer.serialize_struct_elt(&mut state, "signer", self.get_signer()));
         try!(serializer.serialize_struct_elt(&mut state, "filename", self.get_filename()));
+        try!(serializer.serialize_struct_elt(&mut state, "content_hash", self.get_content_hash()));
+        try!(serializer.serialize_struct_elt(&mut state, "size", self.get_size()));
+        try!(serializer.serialize_struct_elt(&mut state, "chunk_count", self.get_chunk_count()));
         match str::from_utf8(self.get_body()) {
             Ok(c) => try!(serializer.serialize_struct_elt(&mut state, "body", c)),
             Err(_) => try!(serializer.serialize_struct_elt(&mut state, "body", self.get_body())),
@@ -168,3 +295,61 @@ impl Serialize for swim::Rumor_Type {
         serializer.serialize_u8(*self as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+    use protobuf;
+
+    use keyring::KeyRing;
+    use message::swim::{Wire, Wire_Compression};
+    use super::{generate_batch_wire, generate_wire, unwrap_wire, unwrap_wire_rumors};
+
+    fn repetitive_payload() -> Vec<u8> {
+        (0..100).map(|_| "redis.default 10.0.0.1 6379\n").collect::<String>().into_bytes()
+    }
+
+    #[test]
+    fn compressed_wire_round_trips() {
+        let payload = repetitive_payload();
+        let bytes = generate_wire(payload.clone(), None, Wire_Compression::DEFLATE).unwrap();
+        let wire: Wire = protobuf::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(wire.get_compression(), Wire_Compression::DEFLATE);
+        assert!(wire.get_payload().len() < payload.len());
+        assert_eq!(unwrap_wire(&bytes, &KeyRing::default()).unwrap(), payload);
+    }
+
+    #[test]
+    fn compressed_and_encrypted_wire_round_trips() {
+        let ring_key = SymKey::generate_in_memory("wolverine").unwrap();
+        let payload = repetitive_payload();
+        let bytes = generate_wire(payload.clone(), Some(&ring_key), Wire_Compression::DEFLATE)
+            .unwrap();
+        let key_ring = KeyRing::new(Some(ring_key));
+        assert_eq!(unwrap_wire(&bytes, &key_ring).unwrap(), payload);
+    }
+
+    #[test]
+    fn batches_round_trip() {
+        let payloads = vec![repetitive_payload(), Vec::from("tiny"), repetitive_payload()];
+        let bytes = generate_batch_wire(payloads.clone(), None, Wire_Compression::DEFLATE)
+            .unwrap();
+        assert_eq!(unwrap_wire_rumors(&bytes, &KeyRing::default()).unwrap(), payloads);
+        assert!(unwrap_wire(&bytes, &KeyRing::default()).is_err());
+    }
+
+    #[test]
+    fn single_rumors_unwrap_as_a_batch_of_one() {
+        let bytes = generate_wire(Vec::from("tiny"), None, Wire_Compression::NONE).unwrap();
+        assert_eq!(unwrap_wire_rumors(&bytes, &KeyRing::default()).unwrap(),
+                   vec![Vec::from("tiny")]);
+    }
+
+    #[test]
+    fn small_payloads_are_not_compressed() {
+        let bytes = generate_wire(Vec::from("tiny"), None, Wire_Compression::DEFLATE).unwrap();
+        let wire: Wire = protobuf::parse_from_bytes(&bytes).unwrap();
+        assert!(!wire.has_compression());
+        assert_eq!(unwrap_wire(&bytes, &KeyRing::default()).unwrap(), b"tiny");
+    }
+}
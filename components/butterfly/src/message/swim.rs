@@ -29,6 +29,9 @@ pub struct Member {
     swim_port: ::std::option::Option<i32>,
     gossip_port: ::std::option::Option<i32>,
     persistent: ::std::option::Option<bool>,
+    accepts_compression: ::std::option::Option<bool>,
+    additional_addresses: ::protobuf::RepeatedField<::std::string::String>,
+    advertised_address: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -56,6 +59,9 @@ impl Member {
                     swim_port: ::std::option::Option::None,
                     gossip_port: ::std::option::Option::None,
                     persistent: ::std::option::Option::None,
+                    accepts_compression: ::std::option::Option::None,
+                    additional_addresses: ::protobuf::RepeatedField::new(),
+                    advertised_address: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -210,6 +216,86 @@ impl Member {
     pub fn get_persistent(&self) -> bool {
         self.persistent.unwrap_or(false)
     }
+
+    // optional bool accepts_compression = 7;
+
+    pub fn clear_accepts_compression(&mut self) {
+        self.accepts_compression = ::std::option::Option::None;
+    }
+
+    pub fn has_accepts_compression(&self) -> bool {
+        self.accepts_compression.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepts_compression(&mut self, v: bool) {
+        self.accepts_compression = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_accepts_compression(&self) -> bool {
+        self.accepts_compression.unwrap_or(false)
+    }
+
+    // repeated string additional_addresses = 8;
+
+    pub fn clear_additional_addresses(&mut self) {
+        self.additional_addresses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_additional_addresses(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.additional_addresses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_additional_addresses(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.additional_addresses
+    }
+
+    // Take field
+    pub fn take_additional_addresses(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.additional_addresses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_additional_addresses(&self) -> &[::std::string::String] {
+        &self.additional_addresses
+    }
+
+    // optional string advertised_address = 9;
+
+    pub fn clear_advertised_address(&mut self) {
+        self.advertised_address.clear();
+    }
+
+    pub fn has_advertised_address(&self) -> bool {
+        self.advertised_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_advertised_address(&mut self, v: ::std::string::String) {
+        self.advertised_address = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_advertised_address(&mut self) -> &mut ::std::string::String {
+        if self.advertised_address.is_none() {
+            self.advertised_address.set_default();
+        };
+        self.advertised_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_advertised_address(&mut self) -> ::std::string::String {
+        self.advertised_address.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_advertised_address(&self) -> &str {
+        match self.advertised_address.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
 impl ::protobuf::Message for Member {
@@ -255,6 +341,19 @@ impl ::protobuf::Message for Member {
                     let tmp = try!(is.read_bool());
                     self.persistent = ::std::option::Option::Some(tmp);
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.accepts_compression = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.additional_addresses));
+                },
+                9 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.advertised_address));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -285,6 +384,15 @@ impl ::protobuf::Message for Member {
         if self.persistent.is_some() {
             my_size += 2;
         };
+        if self.accepts_compression.is_some() {
+            my_size += 2;
+        };
+        for value in &self.additional_addresses {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        for value in &self.advertised_address {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -309,6 +417,15 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.persistent {
             try!(os.write_bool(6, v));
         };
+        if let Some(v) = self.accepts_compression {
+            try!(os.write_bool(7, v));
+        };
+        for v in &self.additional_addresses {
+            try!(os.write_string(8, &v));
+        };
+        if let Some(v) = self.advertised_address.as_ref() {
+            try!(os.write_string(9, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -381,6 +498,20 @@ impl ::protobuf::MessageStatic for Member {
                     Member::has_persistent,
                     Member::get_persistent,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "accepts_compression",
+                    Member::has_accepts_compression,
+                    Member::get_accepts_compression,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "additional_addresses",
+                    Member::get_additional_addresses,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "advertised_address",
+                    Member::has_advertised_address,
+                    Member::get_advertised_address,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Member>(
                     "Member",
                     fields,
@@ -399,6 +530,9 @@ impl ::protobuf::Clear for Member {
         self.clear_swim_port();
         self.clear_gossip_port();
         self.clear_persistent();
+        self.clear_accepts_compression();
+        self.clear_additional_addresses();
+        self.clear_advertised_address();
         self.unknown_fields.clear();
     }
 }
@@ -411,6 +545,9 @@ impl ::std::cmp::PartialEq for Member {
         self.swim_port == other.swim_port &&
         self.gossip_port == other.gossip_port &&
         self.persistent == other.persistent &&
+        self.accepts_compression == other.accepts_compression &&
+        self.additional_addresses == other.additional_addresses &&
+        self.advertised_address == other.advertised_address &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1411,6 +1548,9 @@ pub struct Election {
     suitability: ::std::option::Option<u64>,
     status: ::std::option::Option<Election_Status>,
     votes: ::protobuf::RepeatedField<::std::string::String>,
+    lease: ::std::option::Option<u64>,
+    transfer: ::std::option::Option<bool>,
+    lease_acks: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -1438,6 +1578,9 @@ impl Election {
                     suitability: ::std::option::Option::None,
                     status: ::std::option::Option::None,
                     votes: ::protobuf::RepeatedField::new(),
+                    lease: ::std::option::Option::None,
+                    transfer: ::std::option::Option::None,
+                    lease_acks: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1598,6 +1741,69 @@ impl Election {
     pub fn get_votes(&self) -> &[::std::string::String] {
         &self.votes
     }
+
+    // optional uint64 lease = 7;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = ::std::option::Option::None;
+    }
+
+    pub fn has_lease(&self) -> bool {
+        self.lease.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease.unwrap_or(0)
+    }
+
+    // optional bool transfer = 8;
+
+    pub fn clear_transfer(&mut self) {
+        self.transfer = ::std::option::Option::None;
+    }
+
+    pub fn has_transfer(&self) -> bool {
+        self.transfer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transfer(&mut self, v: bool) {
+        self.transfer = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_transfer(&self) -> bool {
+        self.transfer.unwrap_or(false)
+    }
+
+    // repeated string lease_acks = 9;
+
+    pub fn clear_lease_acks(&mut self) {
+        self.lease_acks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease_acks(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.lease_acks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_lease_acks(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.lease_acks
+    }
+
+    // Take field
+    pub fn take_lease_acks(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.lease_acks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_lease_acks(&self) -> &[::std::string::String] {
+        &self.lease_acks
+    }
 }
 
 impl ::protobuf::Message for Election {
@@ -1639,6 +1845,23 @@ impl ::protobuf::Message for Election {
                 6 => {
                     try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.votes));
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.lease = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.transfer = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.lease_acks));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -1669,6 +1892,15 @@ impl ::protobuf::Message for Election {
         for value in &self.votes {
             my_size += ::protobuf::rt::string_size(6, &value);
         };
+        for value in &self.lease {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.transfer.is_some() {
+            my_size += 2;
+        };
+        for value in &self.lease_acks {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1693,6 +1925,15 @@ impl ::protobuf::Message for Election {
         for v in &self.votes {
             try!(os.write_string(6, &v));
         };
+        if let Some(v) = self.lease {
+            try!(os.write_uint64(7, v));
+        };
+        if let Some(v) = self.transfer {
+            try!(os.write_bool(8, v));
+        };
+        for v in &self.lease_acks {
+            try!(os.write_string(9, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1764,6 +2005,20 @@ impl ::protobuf::MessageStatic for Election {
                     "votes",
                     Election::get_votes,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "lease",
+                    Election::has_lease,
+                    Election::get_lease,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "transfer",
+                    Election::has_transfer,
+                    Election::get_transfer,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "lease_acks",
+                    Election::get_lease_acks,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Election>(
                     "Election",
                     fields,
@@ -1782,6 +2037,9 @@ impl ::protobuf::Clear for Election {
         self.clear_suitability();
         self.clear_status();
         self.clear_votes();
+        self.clear_lease();
+        self.clear_transfer();
+        self.clear_lease_acks();
         self.unknown_fields.clear();
     }
 }
@@ -1794,6 +2052,9 @@ impl ::std::cmp::PartialEq for Election {
         self.suitability == other.suitability &&
         self.status == other.status &&
         self.votes == other.votes &&
+        self.lease == other.lease &&
+        self.transfer == other.transfer &&
+        self.lease_acks == other.lease_acks &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1862,6 +2123,11 @@ pub struct Service {
     exposes: ::std::vec::Vec<u32>,
     initialized: ::std::option::Option<bool>,
     package_ident: ::protobuf::SingularField<::std::string::String>,
+    ipv6: ::protobuf::SingularField<::std::string::String>,
+    config_incarnation: ::std::option::Option<u64>,
+    config_health: ::std::option::Option<Service_Health>,
+    package_health: ::std::option::Option<Service_Health>,
+    bad_releases: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -1892,6 +2158,11 @@ impl Service {
                     exposes: ::std::vec::Vec::new(),
                     initialized: ::std::option::Option::None,
                     package_ident: ::protobuf::SingularField::none(),
+                    ipv6: ::protobuf::SingularField::none(),
+                    config_incarnation: ::std::option::Option::None,
+                    config_health: ::std::option::Option::None,
+                    package_health: ::std::option::Option::None,
+                    bad_releases: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2160,6 +2431,124 @@ impl Service {
             None => "",
         }
     }
+
+    // optional string ipv6 = 10;
+
+    pub fn clear_ipv6(&mut self) {
+        self.ipv6.clear();
+    }
+
+    pub fn has_ipv6(&self) -> bool {
+        self.ipv6.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ipv6(&mut self, v: ::std::string::String) {
+        self.ipv6 = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ipv6(&mut self) -> &mut ::std::string::String {
+        if self.ipv6.is_none() {
+            self.ipv6.set_default();
+        };
+        self.ipv6.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ipv6(&mut self) -> ::std::string::String {
+        self.ipv6.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ipv6(&self) -> &str {
+        match self.ipv6.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 config_incarnation = 11;
+
+    pub fn clear_config_incarnation(&mut self) {
+        self.config_incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_config_incarnation(&self) -> bool {
+        self.config_incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_config_incarnation(&mut self, v: u64) {
+        self.config_incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_config_incarnation(&self) -> u64 {
+        self.config_incarnation.unwrap_or(0)
+    }
+
+    // optional .Service.Health config_health = 12;
+
+    pub fn clear_config_health(&mut self) {
+        self.config_health = ::std::option::Option::None;
+    }
+
+    pub fn has_config_health(&self) -> bool {
+        self.config_health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_config_health(&mut self, v: Service_Health) {
+        self.config_health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_config_health(&self) -> Service_Health {
+        self.config_health.unwrap_or(Service_Health::Pending)
+    }
+
+    // optional .Service.Health package_health = 13;
+
+    pub fn clear_package_health(&mut self) {
+        self.package_health = ::std::option::Option::None;
+    }
+
+    pub fn has_package_health(&self) -> bool {
+        self.package_health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_health(&mut self, v: Service_Health) {
+        self.package_health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_package_health(&self) -> Service_Health {
+        self.package_health.unwrap_or(Service_Health::Pending)
+    }
+
+    // repeated string bad_releases = 14;
+
+    pub fn clear_bad_releases(&mut self) {
+        self.bad_releases.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bad_releases(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.bad_releases = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bad_releases(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.bad_releases
+    }
+
+    // Take field
+    pub fn take_bad_releases(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.bad_releases, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_bad_releases(&self) -> &[::std::string::String] {
+        &self.bad_releases
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2210,6 +2599,33 @@ impl ::protobuf::Message for Service {
                 9 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package_ident));
                 },
+                10 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ipv6));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.config_incarnation = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.config_health = ::std::option::Option::Some(tmp);
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.package_health = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.bad_releases));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2249,6 +2665,21 @@ impl ::protobuf::Message for Service {
         for value in &self.package_ident {
             my_size += ::protobuf::rt::string_size(9, &value);
         };
+        for value in &self.ipv6 {
+            my_size += ::protobuf::rt::string_size(10, &value);
+        };
+        for value in &self.config_incarnation {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.config_health {
+            my_size += ::protobuf::rt::enum_size(12, *value);
+        };
+        for value in &self.package_health {
+            my_size += ::protobuf::rt::enum_size(13, *value);
+        };
+        for value in &self.bad_releases {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2282,6 +2713,21 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.package_ident.as_ref() {
             try!(os.write_string(9, &v));
         };
+        if let Some(v) = self.ipv6.as_ref() {
+            try!(os.write_string(10, &v));
+        };
+        if let Some(v) = self.config_incarnation {
+            try!(os.write_uint64(11, v));
+        };
+        if let Some(v) = self.config_health {
+            try!(os.write_enum(12, v.value()));
+        };
+        if let Some(v) = self.package_health {
+            try!(os.write_enum(13, v.value()));
+        };
+        for v in &self.bad_releases {
+            try!(os.write_string(14, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2368,6 +2814,30 @@ impl ::protobuf::MessageStatic for Service {
                     Service::has_package_ident,
                     Service::get_package_ident,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ipv6",
+                    Service::has_ipv6,
+                    Service::get_ipv6,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "config_incarnation",
+                    Service::has_config_incarnation,
+                    Service::get_config_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "config_health",
+                    Service::has_config_health,
+                    Service::get_config_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "package_health",
+                    Service::has_package_health,
+                    Service::get_package_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "bad_releases",
+                    Service::get_bad_releases,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2389,6 +2859,11 @@ impl ::protobuf::Clear for Service {
         self.clear_exposes();
         self.clear_initialized();
         self.clear_package_ident();
+        self.clear_ipv6();
+        self.clear_config_incarnation();
+        self.clear_config_health();
+        self.clear_package_health();
+        self.clear_bad_releases();
         self.unknown_fields.clear();
     }
 }
@@ -2404,6 +2879,11 @@ impl ::std::cmp::PartialEq for Service {
         self.exposes == other.exposes &&
         self.initialized == other.initialized &&
         self.package_ident == other.package_ident &&
+        self.ipv6 == other.ipv6 &&
+        self.config_incarnation == other.config_incarnation &&
+        self.config_health == other.config_health &&
+        self.package_health == other.package_health &&
+        self.bad_releases == other.bad_releases &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -2414,38 +2894,86 @@ impl ::std::fmt::Debug for Service {
     }
 }
 
-#[derive(Clone,Default)]
-pub struct ServiceConfig {
-    // message fields
-    service_group: ::protobuf::SingularField<::std::string::String>,
-    incarnation: ::std::option::Option<u64>,
-    encrypted: ::std::option::Option<bool>,
-    config: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Service_Health {
+    Pending = 1,
+    Healthy = 2,
+    Unhealthy = 3,
 }
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for ServiceConfig {}
-
-impl ServiceConfig {
-    pub fn new() -> ServiceConfig {
-        ::std::default::Default::default()
+impl ::protobuf::ProtobufEnum for Service_Health {
+    fn value(&self) -> i32 {
+        *self as i32
     }
 
-    pub fn default_instance() -> &'static ServiceConfig {
-        static mut instance: ::protobuf::lazy::Lazy<ServiceConfig> = ::protobuf::lazy::Lazy {
+    fn from_i32(value: i32) -> ::std::option::Option<Service_Health> {
+        match value {
+            1 => ::std::option::Option::Some(Service_Health::Pending),
+            2 => ::std::option::Option::Some(Service_Health::Healthy),
+            3 => ::std::option::Option::Some(Service_Health::Unhealthy),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Service_Health] = &[
+            Service_Health::Pending,
+            Service_Health::Healthy,
+            Service_Health::Unhealthy,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Service_Health>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ServiceConfig,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Service_Health", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Service_Health {
+}
+
+#[derive(Clone,Default)]
+pub struct RolloutPolicy {
+    // message fields
+    batch_size: ::std::option::Option<u32>,
+    batch_percent: ::std::option::Option<u32>,
+    health_gate: ::std::option::Option<bool>,
+    pause_secs: ::std::option::Option<u32>,
+    members: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RolloutPolicy {}
+
+impl RolloutPolicy {
+    pub fn new() -> RolloutPolicy {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RolloutPolicy {
+        static mut instance: ::protobuf::lazy::Lazy<RolloutPolicy> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RolloutPolicy,
         };
         unsafe {
             instance.get(|| {
-                ServiceConfig {
-                    service_group: ::protobuf::SingularField::none(),
-                    incarnation: ::std::option::Option::None,
-                    encrypted: ::std::option::Option::None,
-                    config: ::protobuf::SingularField::none(),
+                RolloutPolicy {
+                    batch_size: ::std::option::Option::None,
+                    batch_percent: ::std::option::Option::None,
+                    health_gate: ::std::option::Option::None,
+                    pause_secs: ::std::option::Option::None,
+                    members: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2453,118 +2981,109 @@ impl ServiceConfig {
         }
     }
 
-    // optional string service_group = 1;
+    // optional uint32 batch_size = 1;
 
-    pub fn clear_service_group(&mut self) {
-        self.service_group.clear();
+    pub fn clear_batch_size(&mut self) {
+        self.batch_size = ::std::option::Option::None;
     }
 
-    pub fn has_service_group(&self) -> bool {
-        self.service_group.is_some()
+    pub fn has_batch_size(&self) -> bool {
+        self.batch_size.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_service_group(&mut self, v: ::std::string::String) {
-        self.service_group = ::protobuf::SingularField::some(v);
+    pub fn set_batch_size(&mut self, v: u32) {
+        self.batch_size = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
-        if self.service_group.is_none() {
-            self.service_group.set_default();
-        };
-        self.service_group.as_mut().unwrap()
+    pub fn get_batch_size(&self) -> u32 {
+        self.batch_size.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_service_group(&mut self) -> ::std::string::String {
-        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    // optional uint32 batch_percent = 2;
+
+    pub fn clear_batch_percent(&mut self) {
+        self.batch_percent = ::std::option::Option::None;
     }
 
-    pub fn get_service_group(&self) -> &str {
-        match self.service_group.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn has_batch_percent(&self) -> bool {
+        self.batch_percent.is_some()
     }
 
-    // optional uint64 incarnation = 2;
+    // Param is passed by value, moved
+    pub fn set_batch_percent(&mut self, v: u32) {
+        self.batch_percent = ::std::option::Option::Some(v);
+    }
 
-    pub fn clear_incarnation(&mut self) {
-        self.incarnation = ::std::option::Option::None;
+    pub fn get_batch_percent(&self) -> u32 {
+        self.batch_percent.unwrap_or(0)
     }
 
-    pub fn has_incarnation(&self) -> bool {
-        self.incarnation.is_some()
+    // optional bool health_gate = 3;
+
+    pub fn clear_health_gate(&mut self) {
+        self.health_gate = ::std::option::Option::None;
+    }
+
+    pub fn has_health_gate(&self) -> bool {
+        self.health_gate.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_incarnation(&mut self, v: u64) {
-        self.incarnation = ::std::option::Option::Some(v);
+    pub fn set_health_gate(&mut self, v: bool) {
+        self.health_gate = ::std::option::Option::Some(v);
     }
 
-    pub fn get_incarnation(&self) -> u64 {
-        self.incarnation.unwrap_or(0)
+    pub fn get_health_gate(&self) -> bool {
+        self.health_gate.unwrap_or(false)
     }
 
-    // optional bool encrypted = 3;
+    // optional uint32 pause_secs = 4;
 
-    pub fn clear_encrypted(&mut self) {
-        self.encrypted = ::std::option::Option::None;
+    pub fn clear_pause_secs(&mut self) {
+        self.pause_secs = ::std::option::Option::None;
     }
 
-    pub fn has_encrypted(&self) -> bool {
-        self.encrypted.is_some()
+    pub fn has_pause_secs(&self) -> bool {
+        self.pause_secs.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_encrypted(&mut self, v: bool) {
-        self.encrypted = ::std::option::Option::Some(v);
+    pub fn set_pause_secs(&mut self, v: u32) {
+        self.pause_secs = ::std::option::Option::Some(v);
     }
 
-    pub fn get_encrypted(&self) -> bool {
-        self.encrypted.unwrap_or(false)
+    pub fn get_pause_secs(&self) -> u32 {
+        self.pause_secs.unwrap_or(0)
     }
 
-    // optional bytes config = 4;
-
-    pub fn clear_config(&mut self) {
-        self.config.clear();
-    }
+    // repeated string members = 5;
 
-    pub fn has_config(&self) -> bool {
-        self.config.is_some()
+    pub fn clear_members(&mut self) {
+        self.members.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_config(&mut self, v: ::std::vec::Vec<u8>) {
-        self.config = ::protobuf::SingularField::some(v);
+    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.members = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_config(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.config.is_none() {
-            self.config.set_default();
-        };
-        self.config.as_mut().unwrap()
+    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.members
     }
 
     // Take field
-    pub fn take_config(&mut self) -> ::std::vec::Vec<u8> {
-        self.config.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_config(&self) -> &[u8] {
-        match self.config.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_members(&self) -> &[::std::string::String] {
+        &self.members
     }
 }
 
-impl ::protobuf::Message for ServiceConfig {
+impl ::protobuf::Message for RolloutPolicy {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2574,24 +3093,35 @@ impl ::protobuf::Message for ServiceConfig {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.batch_size = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_uint64());
-                    self.incarnation = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_uint32());
+                    self.batch_percent = ::std::option::Option::Some(tmp);
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_bool());
-                    self.encrypted = ::std::option::Option::Some(tmp);
+                    self.health_gate = ::std::option::Option::Some(tmp);
                 },
                 4 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.config));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.pause_secs = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.members));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2605,17 +3135,20 @@ impl ::protobuf::Message for ServiceConfig {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.service_group {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        for value in &self.batch_size {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in &self.incarnation {
+        for value in &self.batch_percent {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        if self.encrypted.is_some() {
+        if self.health_gate.is_some() {
             my_size += 2;
         };
-        for value in &self.config {
-            my_size += ::protobuf::rt::bytes_size(4, &value);
+        for value in &self.pause_secs {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.members {
+            my_size += ::protobuf::rt::string_size(5, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2623,17 +3156,20 @@ impl ::protobuf::Message for ServiceConfig {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.service_group.as_ref() {
-            try!(os.write_string(1, &v));
+        if let Some(v) = self.batch_size {
+            try!(os.write_uint32(1, v));
         };
-        if let Some(v) = self.incarnation {
-            try!(os.write_uint64(2, v));
+        if let Some(v) = self.batch_percent {
+            try!(os.write_uint32(2, v));
         };
-        if let Some(v) = self.encrypted {
+        if let Some(v) = self.health_gate {
             try!(os.write_bool(3, v));
         };
-        if let Some(v) = self.config.as_ref() {
-            try!(os.write_bytes(4, &v));
+        if let Some(v) = self.pause_secs {
+            try!(os.write_uint32(4, v));
+        };
+        for v in &self.members {
+            try!(os.write_string(5, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2652,7 +3188,7 @@ impl ::protobuf::Message for ServiceConfig {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<ServiceConfig>()
+        ::std::any::TypeId::of::<RolloutPolicy>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2664,12 +3200,12 @@ impl ::protobuf::Message for ServiceConfig {
     }
 }
 
-impl ::protobuf::MessageStatic for ServiceConfig {
-    fn new() -> ServiceConfig {
-        ServiceConfig::new()
+impl ::protobuf::MessageStatic for RolloutPolicy {
+    fn new() -> RolloutPolicy {
+        RolloutPolicy::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ServiceConfig>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<RolloutPolicy>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2677,28 +3213,32 @@ impl ::protobuf::MessageStatic for ServiceConfig {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "service_group",
-                    ServiceConfig::has_service_group,
-                    ServiceConfig::get_service_group,
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "batch_size",
+                    RolloutPolicy::has_batch_size,
+                    RolloutPolicy::get_batch_size,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "incarnation",
-                    ServiceConfig::has_incarnation,
-                    ServiceConfig::get_incarnation,
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "batch_percent",
+                    RolloutPolicy::has_batch_percent,
+                    RolloutPolicy::get_batch_percent,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "encrypted",
-                    ServiceConfig::has_encrypted,
-                    ServiceConfig::get_encrypted,
+                    "health_gate",
+                    RolloutPolicy::has_health_gate,
+                    RolloutPolicy::get_health_gate,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "config",
-                    ServiceConfig::has_config,
-                    ServiceConfig::get_config,
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "pause_secs",
+                    RolloutPolicy::has_pause_secs,
+                    RolloutPolicy::get_pause_secs,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ServiceConfig>(
-                    "ServiceConfig",
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "members",
+                    RolloutPolicy::get_members,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RolloutPolicy>(
+                    "RolloutPolicy",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2707,66 +3247,80 @@ impl ::protobuf::MessageStatic for ServiceConfig {
     }
 }
 
-impl ::protobuf::Clear for ServiceConfig {
+impl ::protobuf::Clear for RolloutPolicy {
     fn clear(&mut self) {
-        self.clear_service_group();
-        self.clear_incarnation();
-        self.clear_encrypted();
-        self.clear_config();
+        self.clear_batch_size();
+        self.clear_batch_percent();
+        self.clear_health_gate();
+        self.clear_pause_secs();
+        self.clear_members();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for ServiceConfig {
-    fn eq(&self, other: &ServiceConfig) -> bool {
-        self.service_group == other.service_group &&
-        self.incarnation == other.incarnation &&
-        self.encrypted == other.encrypted &&
-        self.config == other.config &&
+impl ::std::cmp::PartialEq for RolloutPolicy {
+    fn eq(&self, other: &RolloutPolicy) -> bool {
+        self.batch_size == other.batch_size &&
+        self.batch_percent == other.batch_percent &&
+        self.health_gate == other.health_gate &&
+        self.pause_secs == other.pause_secs &&
+        self.members == other.members &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for ServiceConfig {
+impl ::std::fmt::Debug for RolloutPolicy {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct ServiceFile {
+pub struct ServiceConfig {
     // message fields
     service_group: ::protobuf::SingularField<::std::string::String>,
     incarnation: ::std::option::Option<u64>,
     encrypted: ::std::option::Option<bool>,
-    filename: ::protobuf::SingularField<::std::string::String>,
-    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    config: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    signer: ::protobuf::SingularField<::std::string::String>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    applied_by: ::protobuf::SingularField<::std::string::String>,
+    mode: ::std::option::Option<ServiceConfig_Mode>,
+    delete_keys: ::protobuf::RepeatedField<::std::string::String>,
+    rollout: ::protobuf::SingularPtrField<RolloutPolicy>,
+    base_incarnation: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for ServiceFile {}
+unsafe impl ::std::marker::Sync for ServiceConfig {}
 
-impl ServiceFile {
-    pub fn new() -> ServiceFile {
+impl ServiceConfig {
+    pub fn new() -> ServiceConfig {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static ServiceFile {
-        static mut instance: ::protobuf::lazy::Lazy<ServiceFile> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ServiceConfig {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceConfig> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ServiceFile,
+            ptr: 0 as *const ServiceConfig,
         };
         unsafe {
             instance.get(|| {
-                ServiceFile {
+                ServiceConfig {
                     service_group: ::protobuf::SingularField::none(),
                     incarnation: ::std::option::Option::None,
                     encrypted: ::std::option::Option::None,
-                    filename: ::protobuf::SingularField::none(),
-                    body: ::protobuf::SingularField::none(),
+                    config: ::protobuf::SingularField::none(),
+                    signer: ::protobuf::SingularField::none(),
+                    signature: ::protobuf::SingularField::none(),
+                    applied_by: ::protobuf::SingularField::none(),
+                    mode: ::std::option::Option::None,
+                    delete_keys: ::protobuf::RepeatedField::new(),
+                    rollout: ::protobuf::SingularPtrField::none(),
+                    base_incarnation: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2848,80 +3402,248 @@ impl ServiceFile {
         self.encrypted.unwrap_or(false)
     }
 
-    // optional string filename = 4;
+    // optional bytes config = 4;
 
-    pub fn clear_filename(&mut self) {
-        self.filename.clear();
+    pub fn clear_config(&mut self) {
+        self.config.clear();
     }
 
-    pub fn has_filename(&self) -> bool {
-        self.filename.is_some()
+    pub fn has_config(&self) -> bool {
+        self.config.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_filename(&mut self, v: ::std::string::String) {
-        self.filename = ::protobuf::SingularField::some(v);
+    pub fn set_config(&mut self, v: ::std::vec::Vec<u8>) {
+        self.config = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
-        if self.filename.is_none() {
-            self.filename.set_default();
+    pub fn mut_config(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.config.is_none() {
+            self.config.set_default();
         };
-        self.filename.as_mut().unwrap()
+        self.config.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_filename(&mut self) -> ::std::string::String {
-        self.filename.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_config(&mut self) -> ::std::vec::Vec<u8> {
+        self.config.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_filename(&self) -> &str {
-        match self.filename.as_ref() {
+    pub fn get_config(&self) -> &[u8] {
+        match self.config.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
 
-    // optional bytes body = 5;
+    // optional string signer = 5;
 
-    pub fn clear_body(&mut self) {
-        self.body.clear();
+    pub fn clear_signer(&mut self) {
+        self.signer.clear();
     }
 
-    pub fn has_body(&self) -> bool {
-        self.body.is_some()
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
-        self.body = ::protobuf::SingularField::some(v);
+    pub fn set_signer(&mut self, v: ::std::string::String) {
+        self.signer = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.body.is_none() {
-            self.body.set_default();
+    pub fn mut_signer(&mut self) -> &mut ::std::string::String {
+        if self.signer.is_none() {
+            self.signer.set_default();
         };
-        self.body.as_mut().unwrap()
+        self.signer.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
-        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_signer(&mut self) -> ::std::string::String {
+        self.signer.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_body(&self) -> &[u8] {
-        match self.body.as_ref() {
+    pub fn get_signer(&self) -> &str {
+        match self.signer.as_ref() {
             Some(v) => &v,
-            None => &[],
+            None => "",
         }
     }
+
+    // optional bytes signature = 6;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional string applied_by = 7;
+
+    pub fn clear_applied_by(&mut self) {
+        self.applied_by.clear();
+    }
+
+    pub fn has_applied_by(&self) -> bool {
+        self.applied_by.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_applied_by(&mut self, v: ::std::string::String) {
+        self.applied_by = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_applied_by(&mut self) -> &mut ::std::string::String {
+        if self.applied_by.is_none() {
+            self.applied_by.set_default();
+        };
+        self.applied_by.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_applied_by(&mut self) -> ::std::string::String {
+        self.applied_by.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_applied_by(&self) -> &str {
+        match self.applied_by.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .ServiceConfig.Mode mode = 8;
+
+    pub fn clear_mode(&mut self) {
+        self.mode = ::std::option::Option::None;
+    }
+
+    pub fn has_mode(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: ServiceConfig_Mode) {
+        self.mode = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_mode(&self) -> ServiceConfig_Mode {
+        self.mode.unwrap_or(ServiceConfig_Mode::Replace)
+    }
+
+    // repeated string delete_keys = 9;
+
+    pub fn clear_delete_keys(&mut self) {
+        self.delete_keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete_keys(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.delete_keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_delete_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.delete_keys
+    }
+
+    // Take field
+    pub fn take_delete_keys(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.delete_keys, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_delete_keys(&self) -> &[::std::string::String] {
+        &self.delete_keys
+    }
+
+    // optional .RolloutPolicy rollout = 10;
+
+    pub fn clear_rollout(&mut self) {
+        self.rollout.clear();
+    }
+
+    pub fn has_rollout(&self) -> bool {
+        self.rollout.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rollout(&mut self, v: RolloutPolicy) {
+        self.rollout = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rollout(&mut self) -> &mut RolloutPolicy {
+        if self.rollout.is_none() {
+            self.rollout.set_default();
+        };
+        self.rollout.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rollout(&mut self) -> RolloutPolicy {
+        self.rollout.take().unwrap_or_else(|| RolloutPolicy::new())
+    }
+
+    pub fn get_rollout(&self) -> &RolloutPolicy {
+        self.rollout.as_ref().unwrap_or_else(|| RolloutPolicy::default_instance())
+    }
+
+    // optional uint64 base_incarnation = 11;
+
+    pub fn clear_base_incarnation(&mut self) {
+        self.base_incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_base_incarnation(&self) -> bool {
+        self.base_incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_incarnation(&mut self, v: u64) {
+        self.base_incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_base_incarnation(&self) -> u64 {
+        self.base_incarnation.unwrap_or(0)
+    }
 }
 
-impl ::protobuf::Message for ServiceFile {
+impl ::protobuf::Message for ServiceConfig {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2948,10 +3670,36 @@ impl ::protobuf::Message for ServiceFile {
                     self.encrypted = ::std::option::Option::Some(tmp);
                 },
                 4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.filename));
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.config));
                 },
                 5 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signer));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature));
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.applied_by));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.mode = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.delete_keys));
+                },
+                10 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rollout));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.base_incarnation = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2974,11 +3722,30 @@ impl ::protobuf::Message for ServiceFile {
         if self.encrypted.is_some() {
             my_size += 2;
         };
-        for value in &self.filename {
-            my_size += ::protobuf::rt::string_size(4, &value);
+        for value in &self.config {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
         };
-        for value in &self.body {
-            my_size += ::protobuf::rt::bytes_size(5, &value);
+        for value in &self.signer {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in &self.signature {
+            my_size += ::protobuf::rt::bytes_size(6, &value);
+        };
+        for value in &self.applied_by {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        for value in &self.mode {
+            my_size += ::protobuf::rt::enum_size(8, *value);
+        };
+        for value in &self.delete_keys {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        for value in &self.rollout {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.base_incarnation {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2989,17 +3756,2537 @@ impl ::protobuf::Message for ServiceFile {
         if let Some(v) = self.service_group.as_ref() {
             try!(os.write_string(1, &v));
         };
-        if let Some(v) = self.incarnation {
-            try!(os.write_uint64(2, v));
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.encrypted {
+            try!(os.write_bool(3, v));
+        };
+        if let Some(v) = self.config.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.signer.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.signature.as_ref() {
+            try!(os.write_bytes(6, &v));
+        };
+        if let Some(v) = self.applied_by.as_ref() {
+            try!(os.write_string(7, &v));
+        };
+        if let Some(v) = self.mode {
+            try!(os.write_enum(8, v.value()));
+        };
+        for v in &self.delete_keys {
+            try!(os.write_string(9, &v));
+        };
+        if let Some(v) = self.rollout.as_ref() {
+            try!(os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.base_incarnation {
+            try!(os.write_uint64(11, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceConfig>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceConfig {
+    fn new() -> ServiceConfig {
+        ServiceConfig::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceConfig>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service_group",
+                    ServiceConfig::has_service_group,
+                    ServiceConfig::get_service_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    ServiceConfig::has_incarnation,
+                    ServiceConfig::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "encrypted",
+                    ServiceConfig::has_encrypted,
+                    ServiceConfig::get_encrypted,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "config",
+                    ServiceConfig::has_config,
+                    ServiceConfig::get_config,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "signer",
+                    ServiceConfig::has_signer,
+                    ServiceConfig::get_signer,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "signature",
+                    ServiceConfig::has_signature,
+                    ServiceConfig::get_signature,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "applied_by",
+                    ServiceConfig::has_applied_by,
+                    ServiceConfig::get_applied_by,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "mode",
+                    ServiceConfig::has_mode,
+                    ServiceConfig::get_mode,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "delete_keys",
+                    ServiceConfig::get_delete_keys,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "rollout",
+                    ServiceConfig::has_rollout,
+                    ServiceConfig::get_rollout,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "base_incarnation",
+                    ServiceConfig::has_base_incarnation,
+                    ServiceConfig::get_base_incarnation,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceConfig>(
+                    "ServiceConfig",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceConfig {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_encrypted();
+        self.clear_config();
+        self.clear_signer();
+        self.clear_signature();
+        self.clear_applied_by();
+        self.clear_mode();
+        self.clear_delete_keys();
+        self.clear_rollout();
+        self.clear_base_incarnation();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceConfig {
+    fn eq(&self, other: &ServiceConfig) -> bool {
+        self.service_group == other.service_group &&
+        self.incarnation == other.incarnation &&
+        self.encrypted == other.encrypted &&
+        self.config == other.config &&
+        self.signer == other.signer &&
+        self.signature == other.signature &&
+        self.applied_by == other.applied_by &&
+        self.mode == other.mode &&
+        self.delete_keys == other.delete_keys &&
+        self.rollout == other.rollout &&
+        self.base_incarnation == other.base_incarnation &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceConfig {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ServiceConfig_Mode {
+    Replace = 1,
+    Patch = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ServiceConfig_Mode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ServiceConfig_Mode> {
+        match value {
+            1 => ::std::option::Option::Some(ServiceConfig_Mode::Replace),
+            2 => ::std::option::Option::Some(ServiceConfig_Mode::Patch),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ServiceConfig_Mode] = &[
+            ServiceConfig_Mode::Replace,
+            ServiceConfig_Mode::Patch,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<ServiceConfig_Mode>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("ServiceConfig_Mode", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for ServiceConfig_Mode {
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceFile {
+    // message fields
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    encrypted: ::std::option::Option<bool>,
+    filename: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    signer: ::protobuf::SingularField<::std::string::String>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    content_hash: ::protobuf::SingularField<::std::string::String>,
+    size: ::std::option::Option<u64>,
+    chunk_count: ::std::option::Option<u32>,
+    deleted: ::std::option::Option<bool>,
+    deleted_at: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceFile {}
+
+impl ServiceFile {
+    pub fn new() -> ServiceFile {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceFile {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceFile> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceFile,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceFile {
+                    service_group: ::protobuf::SingularField::none(),
+                    incarnation: ::std::option::Option::None,
+                    encrypted: ::std::option::Option::None,
+                    filename: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    signer: ::protobuf::SingularField::none(),
+                    signature: ::protobuf::SingularField::none(),
+                    content_hash: ::protobuf::SingularField::none(),
+                    size: ::std::option::Option::None,
+                    chunk_count: ::std::option::Option::None,
+                    deleted: ::std::option::Option::None,
+                    deleted_at: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional string service_group = 1;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 incarnation = 2;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    // optional bool encrypted = 3;
+
+    pub fn clear_encrypted(&mut self) {
+        self.encrypted = ::std::option::Option::None;
+    }
+
+    pub fn has_encrypted(&self) -> bool {
+        self.encrypted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted(&mut self, v: bool) {
+        self.encrypted = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_encrypted(&self) -> bool {
+        self.encrypted.unwrap_or(false)
+    }
+
+    // optional string filename = 4;
+
+    pub fn clear_filename(&mut self) {
+        self.filename.clear();
+    }
+
+    pub fn has_filename(&self) -> bool {
+        self.filename.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filename(&mut self, v: ::std::string::String) {
+        self.filename = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
+        if self.filename.is_none() {
+            self.filename.set_default();
+        };
+        self.filename.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_filename(&mut self) -> ::std::string::String {
+        self.filename.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_filename(&self) -> &str {
+        match self.filename.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bytes body = 5;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        };
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional string signer = 6;
+
+    pub fn clear_signer(&mut self) {
+        self.signer.clear();
+    }
+
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signer(&mut self, v: ::std::string::String) {
+        self.signer = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signer(&mut self) -> &mut ::std::string::String {
+        if self.signer.is_none() {
+            self.signer.set_default();
+        };
+        self.signer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signer(&mut self) -> ::std::string::String {
+        self.signer.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_signer(&self) -> &str {
+        match self.signer.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bytes signature = 7;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional string content_hash = 8;
+
+    pub fn clear_content_hash(&mut self) {
+        self.content_hash.clear();
+    }
+
+    pub fn has_content_hash(&self) -> bool {
+        self.content_hash.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_hash(&mut self, v: ::std::string::String) {
+        self.content_hash = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_hash(&mut self) -> &mut ::std::string::String {
+        if self.content_hash.is_none() {
+            self.content_hash.set_default();
+        };
+        self.content_hash.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_hash(&mut self) -> ::std::string::String {
+        self.content_hash.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content_hash(&self) -> &str {
+        match self.content_hash.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 size = 9;
+
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size.unwrap_or(0)
+    }
+
+    // optional uint32 chunk_count = 10;
+
+    pub fn clear_chunk_count(&mut self) {
+        self.chunk_count = ::std::option::Option::None;
+    }
+
+    pub fn has_chunk_count(&self) -> bool {
+        self.chunk_count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_count(&mut self, v: u32) {
+        self.chunk_count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_chunk_count(&self) -> u32 {
+        self.chunk_count.unwrap_or(0)
+    }
+
+    // optional bool deleted = 11;
+
+    pub fn clear_deleted(&mut self) {
+        self.deleted = ::std::option::Option::None;
+    }
+
+    pub fn has_deleted(&self) -> bool {
+        self.deleted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted(&mut self, v: bool) {
+        self.deleted = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_deleted(&self) -> bool {
+        self.deleted.unwrap_or(false)
+    }
+
+    // optional int64 deleted_at = 12;
+
+    pub fn clear_deleted_at(&mut self) {
+        self.deleted_at = ::std::option::Option::None;
+    }
+
+    pub fn has_deleted_at(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_at(&mut self, v: i64) {
+        self.deleted_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_deleted_at(&self) -> i64 {
+        self.deleted_at.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ServiceFile {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.encrypted = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.filename));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signer));
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature));
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_hash));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.size = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.chunk_count = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.deleted = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.deleted_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.service_group {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.encrypted.is_some() {
+            my_size += 2;
+        };
+        for value in &self.filename {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.body {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        for value in &self.signer {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in &self.signature {
+            my_size += ::protobuf::rt::bytes_size(7, &value);
+        };
+        for value in &self.content_hash {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        for value in &self.size {
+            my_size += ::protobuf::rt::value_size(9, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.chunk_count {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.deleted.is_some() {
+            my_size += 2;
+        };
+        for value in &self.deleted_at {
+            my_size += ::protobuf::rt::value_size(12, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.encrypted {
+            try!(os.write_bool(3, v));
+        };
+        if let Some(v) = self.filename.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        if let Some(v) = self.signer.as_ref() {
+            try!(os.write_string(6, &v));
+        };
+        if let Some(v) = self.signature.as_ref() {
+            try!(os.write_bytes(7, &v));
+        };
+        if let Some(v) = self.content_hash.as_ref() {
+            try!(os.write_string(8, &v));
+        };
+        if let Some(v) = self.size {
+            try!(os.write_uint64(9, v));
+        };
+        if let Some(v) = self.chunk_count {
+            try!(os.write_uint32(10, v));
+        };
+        if let Some(v) = self.deleted {
+            try!(os.write_bool(11, v));
+        };
+        if let Some(v) = self.deleted_at {
+            try!(os.write_int64(12, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceFile>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceFile {
+    fn new() -> ServiceFile {
+        ServiceFile::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceFile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service_group",
+                    ServiceFile::has_service_group,
+                    ServiceFile::get_service_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    ServiceFile::has_incarnation,
+                    ServiceFile::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "encrypted",
+                    ServiceFile::has_encrypted,
+                    ServiceFile::get_encrypted,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "filename",
+                    ServiceFile::has_filename,
+                    ServiceFile::get_filename,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    ServiceFile::has_body,
+                    ServiceFile::get_body,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "signer",
+                    ServiceFile::has_signer,
+                    ServiceFile::get_signer,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "signature",
+                    ServiceFile::has_signature,
+                    ServiceFile::get_signature,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "content_hash",
+                    ServiceFile::has_content_hash,
+                    ServiceFile::get_content_hash,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "size",
+                    ServiceFile::has_size,
+                    ServiceFile::get_size,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "chunk_count",
+                    ServiceFile::has_chunk_count,
+                    ServiceFile::get_chunk_count,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "deleted",
+                    ServiceFile::has_deleted,
+                    ServiceFile::get_deleted,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor(
+                    "deleted_at",
+                    ServiceFile::has_deleted_at,
+                    ServiceFile::get_deleted_at,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
+                    "ServiceFile",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceFile {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_encrypted();
+        self.clear_filename();
+        self.clear_body();
+        self.clear_signer();
+        self.clear_signature();
+        self.clear_content_hash();
+        self.clear_size();
+        self.clear_chunk_count();
+        self.clear_deleted();
+        self.clear_deleted_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceFile {
+    fn eq(&self, other: &ServiceFile) -> bool {
+        self.service_group == other.service_group &&
+        self.incarnation == other.incarnation &&
+        self.encrypted == other.encrypted &&
+        self.filename == other.filename &&
+        self.body == other.body &&
+        self.signer == other.signer &&
+        self.signature == other.signature &&
+        self.content_hash == other.content_hash &&
+        self.size == other.size &&
+        self.chunk_count == other.chunk_count &&
+        self.deleted == other.deleted &&
+        self.deleted_at == other.deleted_at &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct FileChunk {
+    // message fields
+    content_hash: ::protobuf::SingularField<::std::string::String>,
+    index: ::std::option::Option<u32>,
+    chunk_count: ::std::option::Option<u32>,
+    data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for FileChunk {}
+
+impl FileChunk {
+    pub fn new() -> FileChunk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static FileChunk {
+        static mut instance: ::protobuf::lazy::Lazy<FileChunk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FileChunk,
+        };
+        unsafe {
+            instance.get(|| {
+                FileChunk {
+                    content_hash: ::protobuf::SingularField::none(),
+                    index: ::std::option::Option::None,
+                    chunk_count: ::std::option::Option::None,
+                    data: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional string content_hash = 1;
+
+    pub fn clear_content_hash(&mut self) {
+        self.content_hash.clear();
+    }
+
+    pub fn has_content_hash(&self) -> bool {
+        self.content_hash.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_hash(&mut self, v: ::std::string::String) {
+        self.content_hash = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_hash(&mut self) -> &mut ::std::string::String {
+        if self.content_hash.is_none() {
+            self.content_hash.set_default();
+        };
+        self.content_hash.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_hash(&mut self) -> ::std::string::String {
+        self.content_hash.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content_hash(&self) -> &str {
+        match self.content_hash.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 index = 2;
+
+    pub fn clear_index(&mut self) {
+        self.index = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        self.index.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index.unwrap_or(0)
+    }
+
+    // optional uint32 chunk_count = 3;
+
+    pub fn clear_chunk_count(&mut self) {
+        self.chunk_count = ::std::option::Option::None;
+    }
+
+    pub fn has_chunk_count(&self) -> bool {
+        self.chunk_count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_count(&mut self, v: u32) {
+        self.chunk_count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_chunk_count(&self) -> u32 {
+        self.chunk_count.unwrap_or(0)
+    }
+
+    // optional bytes data = 4;
+
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.data.is_none() {
+            self.data.set_default();
+        };
+        self.data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_data(&self) -> &[u8] {
+        match self.data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for FileChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_hash));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.index = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.chunk_count = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.data));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.content_hash {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.index {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.chunk_count {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.data {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.content_hash.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.index {
+            try!(os.write_uint32(2, v));
+        };
+        if let Some(v) = self.chunk_count {
+            try!(os.write_uint32(3, v));
+        };
+        if let Some(v) = self.data.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<FileChunk>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for FileChunk {
+    fn new() -> FileChunk {
+        FileChunk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FileChunk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "content_hash",
+                    FileChunk::has_content_hash,
+                    FileChunk::get_content_hash,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "index",
+                    FileChunk::has_index,
+                    FileChunk::get_index,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "chunk_count",
+                    FileChunk::has_chunk_count,
+                    FileChunk::get_chunk_count,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "data",
+                    FileChunk::has_data,
+                    FileChunk::get_data,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FileChunk>(
+                    "FileChunk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for FileChunk {
+    fn clear(&mut self) {
+        self.clear_content_hash();
+        self.clear_index();
+        self.clear_chunk_count();
+        self.clear_data();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for FileChunk {
+    fn eq(&self, other: &FileChunk) -> bool {
+        self.content_hash == other.content_hash &&
+        self.index == other.index &&
+        self.chunk_count == other.chunk_count &&
+        self.data == other.data &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for FileChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct FileChunkRequest {
+    // message fields
+    content_hash: ::protobuf::SingularField<::std::string::String>,
+    index: ::std::vec::Vec<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for FileChunkRequest {}
+
+impl FileChunkRequest {
+    pub fn new() -> FileChunkRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static FileChunkRequest {
+        static mut instance: ::protobuf::lazy::Lazy<FileChunkRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FileChunkRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                FileChunkRequest {
+                    content_hash: ::protobuf::SingularField::none(),
+                    index: ::std::vec::Vec::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional string content_hash = 1;
+
+    pub fn clear_content_hash(&mut self) {
+        self.content_hash.clear();
+    }
+
+    pub fn has_content_hash(&self) -> bool {
+        self.content_hash.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_hash(&mut self, v: ::std::string::String) {
+        self.content_hash = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_hash(&mut self) -> &mut ::std::string::String {
+        if self.content_hash.is_none() {
+            self.content_hash.set_default();
+        };
+        self.content_hash.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_hash(&mut self) -> ::std::string::String {
+        self.content_hash.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content_hash(&self) -> &str {
+        match self.content_hash.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated uint32 index = 2;
+
+    pub fn clear_index(&mut self) {
+        self.index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: ::std::vec::Vec<u32>) {
+        self.index = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.index
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
+    }
+
+    pub fn get_index(&self) -> &[u32] {
+        &self.index
+    }
+}
+
+impl ::protobuf::Message for FileChunkRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_hash));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.index));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.content_hash {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.index {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.content_hash.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        for v in &self.index {
+            try!(os.write_uint32(2, *v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<FileChunkRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for FileChunkRequest {
+    fn new() -> FileChunkRequest {
+        FileChunkRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FileChunkRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "content_hash",
+                    FileChunkRequest::has_content_hash,
+                    FileChunkRequest::get_content_hash,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_u32_accessor(
+                    "index",
+                    FileChunkRequest::get_index,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FileChunkRequest>(
+                    "FileChunkRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for FileChunkRequest {
+    fn clear(&mut self) {
+        self.clear_content_hash();
+        self.clear_index();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for FileChunkRequest {
+    fn eq(&self, other: &FileChunkRequest) -> bool {
+        self.content_hash == other.content_hash &&
+        self.index == other.index &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for FileChunkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Query {
+    // message fields
+    field_type: ::std::option::Option<Query_Type>,
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    offset: ::std::option::Option<u32>,
+    incarnation: ::std::option::Option<u64>,
+    after: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Query {}
+
+impl Query {
+    pub fn new() -> Query {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Query {
+        static mut instance: ::protobuf::lazy::Lazy<Query> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Query,
+        };
+        unsafe {
+            instance.get(|| {
+                Query {
+                    field_type: ::std::option::Option::None,
+                    service_group: ::protobuf::SingularField::none(),
+                    offset: ::std::option::Option::None,
+                    incarnation: ::std::option::Option::None,
+                    after: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional .Query.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Query_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Query_Type {
+        self.field_type.unwrap_or(Query_Type::Members)
+    }
+
+    // optional string service_group = 2;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 offset = 3;
+
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u32) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    // optional uint64 incarnation = 4;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    // optional string after = 5;
+
+    pub fn clear_after(&mut self) {
+        self.after.clear();
+    }
+
+    pub fn has_after(&self) -> bool {
+        self.after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after(&mut self, v: ::std::string::String) {
+        self.after = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_after(&mut self) -> &mut ::std::string::String {
+        if self.after.is_none() {
+            self.after.set_default();
+        };
+        self.after.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_after(&mut self) -> ::std::string::String {
+        self.after.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_after(&self) -> &str {
+        match self.after.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for Query {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.offset = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.after));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.field_type {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in &self.service_group {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.offset {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.incarnation {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.after {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            try!(os.write_enum(1, v.value()));
+        };
+        if let Some(v) = self.service_group.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.offset {
+            try!(os.write_uint32(3, v));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.after.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Query>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Query {
+    fn new() -> Query {
+        Query::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Query>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "type",
+                    Query::has_field_type,
+                    Query::get_field_type,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service_group",
+                    Query::has_service_group,
+                    Query::get_service_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "offset",
+                    Query::has_offset,
+                    Query::get_offset,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    Query::has_incarnation,
+                    Query::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "after",
+                    Query::has_after,
+                    Query::get_after,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Query>(
+                    "Query",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Query {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_service_group();
+        self.clear_offset();
+        self.clear_incarnation();
+        self.clear_after();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Query {
+    fn eq(&self, other: &Query) -> bool {
+        self.field_type == other.field_type &&
+        self.service_group == other.service_group &&
+        self.offset == other.offset &&
+        self.incarnation == other.incarnation &&
+        self.after == other.after &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Query_Type {
+    Members = 1,
+    Census = 2,
+    Config = 3,
+    ConfigHistory = 4,
+    ConfigBody = 5,
+}
+
+impl ::protobuf::ProtobufEnum for Query_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Query_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Query_Type::Members),
+            2 => ::std::option::Option::Some(Query_Type::Census),
+            3 => ::std::option::Option::Some(Query_Type::Config),
+            4 => ::std::option::Option::Some(Query_Type::ConfigHistory),
+            5 => ::std::option::Option::Some(Query_Type::ConfigBody),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Query_Type] = &[
+            Query_Type::Members,
+            Query_Type::Census,
+            Query_Type::Config,
+            Query_Type::ConfigHistory,
+            Query_Type::ConfigBody,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Query_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Query_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Query_Type {
+}
+
+#[derive(Clone,Default)]
+pub struct QueryResponse {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    membership: ::protobuf::RepeatedField<Membership>,
+    service: ::protobuf::RepeatedField<Service>,
+    leader_id: ::protobuf::SingularField<::std::string::String>,
+    service_config: ::protobuf::SingularPtrField<ServiceConfig>,
+    config_too_large: ::std::option::Option<bool>,
+    service_file: ::protobuf::RepeatedField<ServiceFile>,
+    next_offset: ::std::option::Option<u32>,
+    total: ::std::option::Option<u32>,
+    offset: ::std::option::Option<u32>,
+    config_history: ::protobuf::RepeatedField<ServiceConfig>,
+    configs_too_large: ::std::vec::Vec<u64>,
+    next_after: ::protobuf::SingularField<::std::string::String>,
+    after: ::protobuf::SingularField<::std::string::String>,
+    bodies_withheld: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for QueryResponse {}
+
+impl QueryResponse {
+    pub fn new() -> QueryResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static QueryResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                QueryResponse {
+                    member_id: ::protobuf::SingularField::none(),
+                    membership: ::protobuf::RepeatedField::new(),
+                    service: ::protobuf::RepeatedField::new(),
+                    leader_id: ::protobuf::SingularField::none(),
+                    service_config: ::protobuf::SingularPtrField::none(),
+                    config_too_large: ::std::option::Option::None,
+                    service_file: ::protobuf::RepeatedField::new(),
+                    next_offset: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
+                    offset: ::std::option::Option::None,
+                    config_history: ::protobuf::RepeatedField::new(),
+                    configs_too_large: ::std::vec::Vec::new(),
+                    next_after: ::protobuf::SingularField::none(),
+                    after: ::protobuf::SingularField::none(),
+                    bodies_withheld: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated .Membership membership = 2;
+
+    pub fn clear_membership(&mut self) {
+        self.membership.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership(&mut self, v: ::protobuf::RepeatedField<Membership>) {
+        self.membership = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_membership(&mut self) -> &mut ::protobuf::RepeatedField<Membership> {
+        &mut self.membership
+    }
+
+    // Take field
+    pub fn take_membership(&mut self) -> ::protobuf::RepeatedField<Membership> {
+        ::std::mem::replace(&mut self.membership, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_membership(&self) -> &[Membership] {
+        &self.membership
+    }
+
+    // repeated .Service service = 3;
+
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ::protobuf::RepeatedField<Service>) {
+        self.service = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service(&mut self) -> &mut ::protobuf::RepeatedField<Service> {
+        &mut self.service
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ::protobuf::RepeatedField<Service> {
+        ::std::mem::replace(&mut self.service, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_service(&self) -> &[Service] {
+        &self.service
+    }
+
+    // optional string leader_id = 4;
+
+    pub fn clear_leader_id(&mut self) {
+        self.leader_id.clear();
+    }
+
+    pub fn has_leader_id(&self) -> bool {
+        self.leader_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader_id(&mut self, v: ::std::string::String) {
+        self.leader_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_leader_id(&mut self) -> &mut ::std::string::String {
+        if self.leader_id.is_none() {
+            self.leader_id.set_default();
+        };
+        self.leader_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_leader_id(&mut self) -> ::std::string::String {
+        self.leader_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_leader_id(&self) -> &str {
+        match self.leader_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .ServiceConfig service_config = 5;
+
+    pub fn clear_service_config(&mut self) {
+        self.service_config.clear();
+    }
+
+    pub fn has_service_config(&self) -> bool {
+        self.service_config.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_config(&mut self, v: ServiceConfig) {
+        self.service_config = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_config(&mut self) -> &mut ServiceConfig {
+        if self.service_config.is_none() {
+            self.service_config.set_default();
+        };
+        self.service_config.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_config(&mut self) -> ServiceConfig {
+        self.service_config.take().unwrap_or_else(|| ServiceConfig::new())
+    }
+
+    pub fn get_service_config(&self) -> &ServiceConfig {
+        self.service_config.as_ref().unwrap_or_else(|| ServiceConfig::default_instance())
+    }
+
+    // optional bool config_too_large = 6;
+
+    pub fn clear_config_too_large(&mut self) {
+        self.config_too_large = ::std::option::Option::None;
+    }
+
+    pub fn has_config_too_large(&self) -> bool {
+        self.config_too_large.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_config_too_large(&mut self, v: bool) {
+        self.config_too_large = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_config_too_large(&self) -> bool {
+        self.config_too_large.unwrap_or(false)
+    }
+
+    // repeated .ServiceFile service_file = 7;
+
+    pub fn clear_service_file(&mut self) {
+        self.service_file.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_file(&mut self, v: ::protobuf::RepeatedField<ServiceFile>) {
+        self.service_file = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_service_file(&mut self) -> &mut ::protobuf::RepeatedField<ServiceFile> {
+        &mut self.service_file
+    }
+
+    // Take field
+    pub fn take_service_file(&mut self) -> ::protobuf::RepeatedField<ServiceFile> {
+        ::std::mem::replace(&mut self.service_file, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_service_file(&self) -> &[ServiceFile] {
+        &self.service_file
+    }
+
+    // optional uint32 next_offset = 8;
+
+    pub fn clear_next_offset(&mut self) {
+        self.next_offset = ::std::option::Option::None;
+    }
+
+    pub fn has_next_offset(&self) -> bool {
+        self.next_offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_offset(&mut self, v: u32) {
+        self.next_offset = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_next_offset(&self) -> u32 {
+        self.next_offset.unwrap_or(0)
+    }
+
+    // optional uint32 total = 9;
+
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u32) {
+        self.total = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_total(&self) -> u32 {
+        self.total.unwrap_or(0)
+    }
+
+    // optional uint32 offset = 10;
+
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u32) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+
+    // repeated .ServiceConfig config_history = 11;
+
+    pub fn clear_config_history(&mut self) {
+        self.config_history.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_config_history(&mut self, v: ::protobuf::RepeatedField<ServiceConfig>) {
+        self.config_history = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_config_history(&mut self) -> &mut ::protobuf::RepeatedField<ServiceConfig> {
+        &mut self.config_history
+    }
+
+    // Take field
+    pub fn take_config_history(&mut self) -> ::protobuf::RepeatedField<ServiceConfig> {
+        ::std::mem::replace(&mut self.config_history, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_config_history(&self) -> &[ServiceConfig] {
+        &self.config_history
+    }
+
+    // repeated uint64 configs_too_large = 12;
+
+    pub fn clear_configs_too_large(&mut self) {
+        self.configs_too_large.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_configs_too_large(&mut self, v: ::std::vec::Vec<u64>) {
+        self.configs_too_large = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_configs_too_large(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.configs_too_large
+    }
+
+    // Take field
+    pub fn take_configs_too_large(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.configs_too_large, ::std::vec::Vec::new())
+    }
+
+    pub fn get_configs_too_large(&self) -> &[u64] {
+        &self.configs_too_large
+    }
+
+    // optional string next_after = 13;
+
+    pub fn clear_next_after(&mut self) {
+        self.next_after.clear();
+    }
+
+    pub fn has_next_after(&self) -> bool {
+        self.next_after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_after(&mut self, v: ::std::string::String) {
+        self.next_after = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_after(&mut self) -> &mut ::std::string::String {
+        if self.next_after.is_none() {
+            self.next_after.set_default();
+        };
+        self.next_after.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_next_after(&mut self) -> ::std::string::String {
+        self.next_after.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_next_after(&self) -> &str {
+        match self.next_after.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string after = 14;
+
+    pub fn clear_after(&mut self) {
+        self.after.clear();
+    }
+
+    pub fn has_after(&self) -> bool {
+        self.after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after(&mut self, v: ::std::string::String) {
+        self.after = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_after(&mut self) -> &mut ::std::string::String {
+        if self.after.is_none() {
+            self.after.set_default();
+        };
+        self.after.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_after(&mut self) -> ::std::string::String {
+        self.after.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_after(&self) -> &str {
+        match self.after.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool bodies_withheld = 15;
+
+    pub fn clear_bodies_withheld(&mut self) {
+        self.bodies_withheld = ::std::option::Option::None;
+    }
+
+    pub fn has_bodies_withheld(&self) -> bool {
+        self.bodies_withheld.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bodies_withheld(&mut self, v: bool) {
+        self.bodies_withheld = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_bodies_withheld(&self) -> bool {
+        self.bodies_withheld.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for QueryResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.service));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.leader_id));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.service_config));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.config_too_large = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.service_file));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.next_offset = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.offset = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.config_history));
+                },
+                12 => {
+                    try!(::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.configs_too_large));
+                },
+                13 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.next_after));
+                },
+                14 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.after));
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.bodies_withheld = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.member_id {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.membership {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.service {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.leader_id {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.service_config {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.config_too_large.is_some() {
+            my_size += 2;
+        };
+        for value in &self.service_file {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.next_offset {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.total {
+            my_size += ::protobuf::rt::value_size(9, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.offset {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.config_history {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.configs_too_large {
+            my_size += ::protobuf::rt::value_size(12, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.next_after {
+            my_size += ::protobuf::rt::string_size(13, &value);
+        };
+        for value in &self.after {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
+        if self.bodies_withheld.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.member_id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        for v in &self.membership {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        for v in &self.service {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.leader_id.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.service_config.as_ref() {
+            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.config_too_large {
+            try!(os.write_bool(6, v));
+        };
+        for v in &self.service_file {
+            try!(os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.next_offset {
+            try!(os.write_uint32(8, v));
         };
-        if let Some(v) = self.encrypted {
-            try!(os.write_bool(3, v));
+        if let Some(v) = self.total {
+            try!(os.write_uint32(9, v));
         };
-        if let Some(v) = self.filename.as_ref() {
-            try!(os.write_string(4, &v));
+        if let Some(v) = self.offset {
+            try!(os.write_uint32(10, v));
         };
-        if let Some(v) = self.body.as_ref() {
-            try!(os.write_bytes(5, &v));
+        for v in &self.config_history {
+            try!(os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        for v in &self.configs_too_large {
+            try!(os.write_uint64(12, *v));
+        };
+        if let Some(v) = self.next_after.as_ref() {
+            try!(os.write_string(13, &v));
+        };
+        if let Some(v) = self.after.as_ref() {
+            try!(os.write_string(14, &v));
+        };
+        if let Some(v) = self.bodies_withheld {
+            try!(os.write_bool(15, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3018,7 +6305,7 @@ impl ::protobuf::Message for ServiceFile {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<ServiceFile>()
+        ::std::any::TypeId::of::<QueryResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3030,12 +6317,12 @@ impl ::protobuf::Message for ServiceFile {
     }
 }
 
-impl ::protobuf::MessageStatic for ServiceFile {
-    fn new() -> ServiceFile {
-        ServiceFile::new()
+impl ::protobuf::MessageStatic for QueryResponse {
+    fn new() -> QueryResponse {
+        QueryResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ServiceFile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<QueryResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3044,32 +6331,77 @@ impl ::protobuf::MessageStatic for ServiceFile {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "service_group",
-                    ServiceFile::has_service_group,
-                    ServiceFile::get_service_group,
+                    "member_id",
+                    QueryResponse::has_member_id,
+                    QueryResponse::get_member_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "incarnation",
-                    ServiceFile::has_incarnation,
-                    ServiceFile::get_incarnation,
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "membership",
+                    QueryResponse::get_membership,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "service",
+                    QueryResponse::get_service,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "leader_id",
+                    QueryResponse::has_leader_id,
+                    QueryResponse::get_leader_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "service_config",
+                    QueryResponse::has_service_config,
+                    QueryResponse::get_service_config,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "encrypted",
-                    ServiceFile::has_encrypted,
-                    ServiceFile::get_encrypted,
+                    "config_too_large",
+                    QueryResponse::has_config_too_large,
+                    QueryResponse::get_config_too_large,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "service_file",
+                    QueryResponse::get_service_file,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "next_offset",
+                    QueryResponse::has_next_offset,
+                    QueryResponse::get_next_offset,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "total",
+                    QueryResponse::has_total,
+                    QueryResponse::get_total,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "offset",
+                    QueryResponse::has_offset,
+                    QueryResponse::get_offset,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "config_history",
+                    QueryResponse::get_config_history,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_u64_accessor(
+                    "configs_too_large",
+                    QueryResponse::get_configs_too_large,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "filename",
-                    ServiceFile::has_filename,
-                    ServiceFile::get_filename,
+                    "next_after",
+                    QueryResponse::has_next_after,
+                    QueryResponse::get_next_after,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "body",
-                    ServiceFile::has_body,
-                    ServiceFile::get_body,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "after",
+                    QueryResponse::has_after,
+                    QueryResponse::get_after,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
-                    "ServiceFile",
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "bodies_withheld",
+                    QueryResponse::has_bodies_withheld,
+                    QueryResponse::get_bodies_withheld,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryResponse>(
+                    "QueryResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3078,29 +6410,49 @@ impl ::protobuf::MessageStatic for ServiceFile {
     }
 }
 
-impl ::protobuf::Clear for ServiceFile {
+impl ::protobuf::Clear for QueryResponse {
     fn clear(&mut self) {
-        self.clear_service_group();
-        self.clear_incarnation();
-        self.clear_encrypted();
-        self.clear_filename();
-        self.clear_body();
+        self.clear_member_id();
+        self.clear_membership();
+        self.clear_service();
+        self.clear_leader_id();
+        self.clear_service_config();
+        self.clear_config_too_large();
+        self.clear_service_file();
+        self.clear_next_offset();
+        self.clear_total();
+        self.clear_offset();
+        self.clear_config_history();
+        self.clear_configs_too_large();
+        self.clear_next_after();
+        self.clear_after();
+        self.clear_bodies_withheld();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for ServiceFile {
-    fn eq(&self, other: &ServiceFile) -> bool {
-        self.service_group == other.service_group &&
-        self.incarnation == other.incarnation &&
-        self.encrypted == other.encrypted &&
-        self.filename == other.filename &&
-        self.body == other.body &&
+impl ::std::cmp::PartialEq for QueryResponse {
+    fn eq(&self, other: &QueryResponse) -> bool {
+        self.member_id == other.member_id &&
+        self.membership == other.membership &&
+        self.service == other.service &&
+        self.leader_id == other.leader_id &&
+        self.service_config == other.service_config &&
+        self.config_too_large == other.config_too_large &&
+        self.service_file == other.service_file &&
+        self.next_offset == other.next_offset &&
+        self.total == other.total &&
+        self.offset == other.offset &&
+        self.config_history == other.config_history &&
+        self.configs_too_large == other.configs_too_large &&
+        self.next_after == other.next_after &&
+        self.after == other.after &&
+        self.bodies_withheld == other.bodies_withheld &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for ServiceFile {
+impl ::std::fmt::Debug for QueryResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -3126,6 +6478,8 @@ pub enum Swim_oneof_payload {
     ping(Ping),
     ack(Ack),
     pingreq(PingReq),
+    query(Query),
+    query_response(QueryResponse),
 }
 
 impl Swim {
@@ -3320,6 +6674,106 @@ impl Swim {
         }
     }
 
+    // optional .Query query = 6;
+
+    pub fn clear_query(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_query(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: Query) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::query(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut Query {
+        if let ::std::option::Option::Some(Swim_oneof_payload::query(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::query(Query::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> Query {
+        if self.has_query() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::query(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Query::new()
+        }
+    }
+
+    pub fn get_query(&self) -> &Query {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query(ref v)) => v,
+            _ => Query::default_instance(),
+        }
+    }
+
+    // optional .QueryResponse query_response = 7;
+
+    pub fn clear_query_response(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_query_response(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_response(&mut self, v: QueryResponse) {
+        self.payload = ::std::option::Option::Some(Swim_oneof_payload::query_response(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query_response(&mut self) -> &mut QueryResponse {
+        if let ::std::option::Option::Some(Swim_oneof_payload::query_response(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Swim_oneof_payload::query_response(QueryResponse::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_query_response(&mut self) -> QueryResponse {
+        if self.has_query_response() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Swim_oneof_payload::query_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryResponse::new()
+        }
+    }
+
+    pub fn get_query_response(&self) -> &QueryResponse {
+        match self.payload {
+            ::std::option::Option::Some(Swim_oneof_payload::query_response(ref v)) => v,
+            _ => QueryResponse::default_instance(),
+        }
+    }
+
     // repeated .Membership membership = 5;
 
     pub fn clear_membership(&mut self) {
@@ -3383,6 +6837,18 @@ impl ::protobuf::Message for Swim {
                     };
                     self.payload = ::std::option::Option::Some(Swim_oneof_payload::pingreq(try!(is.read_message())));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::query(try!(is.read_message())));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Swim_oneof_payload::query_response(try!(is.read_message())));
+                },
                 5 => {
                     try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.membership));
                 },
@@ -3419,6 +6885,14 @@ impl ::protobuf::Message for Swim {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Swim_oneof_payload::query(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Swim_oneof_payload::query_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3452,6 +6926,16 @@ impl ::protobuf::Message for Swim {
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
+                &Swim_oneof_payload::query(ref v) => {
+                    try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &Swim_oneof_payload::query_response(ref v) => {
+                    try!(os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
             };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -3516,6 +7000,16 @@ impl ::protobuf::MessageStatic for Swim {
                     Swim::has_pingreq,
                     Swim::get_pingreq,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "query",
+                    Swim::has_query,
+                    Swim::get_query,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "query_response",
+                    Swim::has_query_response,
+                    Swim::get_query_response,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
                     "membership",
                     Swim::get_membership,
@@ -3536,6 +7030,8 @@ impl ::protobuf::Clear for Swim {
         self.clear_ping();
         self.clear_ack();
         self.clear_pingreq();
+        self.clear_query();
+        self.clear_query_response();
         self.clear_membership();
         self.unknown_fields.clear();
     }
@@ -3561,6 +7057,8 @@ pub enum Swim_Type {
     PING = 1,
     ACK = 2,
     PINGREQ = 3,
+    QUERY = 4,
+    QUERY_RESPONSE = 5,
 }
 
 impl ::protobuf::ProtobufEnum for Swim_Type {
@@ -3573,6 +7071,8 @@ impl ::protobuf::ProtobufEnum for Swim_Type {
             1 => ::std::option::Option::Some(Swim_Type::PING),
             2 => ::std::option::Option::Some(Swim_Type::ACK),
             3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
+            4 => ::std::option::Option::Some(Swim_Type::QUERY),
+            5 => ::std::option::Option::Some(Swim_Type::QUERY_RESPONSE),
             _ => ::std::option::Option::None
         }
     }
@@ -3582,6 +7082,8 @@ impl ::protobuf::ProtobufEnum for Swim_Type {
             Swim_Type::PING,
             Swim_Type::ACK,
             Swim_Type::PINGREQ,
+            Swim_Type::QUERY,
+            Swim_Type::QUERY_RESPONSE,
         ];
         values
     }
@@ -3625,6 +7127,8 @@ pub enum Rumor_oneof_payload {
     service_config(ServiceConfig),
     service_file(ServiceFile),
     election(Election),
+    file_chunk(FileChunk),
+    file_chunk_request(FileChunkRequest),
 }
 
 impl Rumor {
@@ -3980,6 +7484,106 @@ impl Rumor {
             _ => Election::default_instance(),
         }
     }
+
+    // optional .FileChunk file_chunk = 9;
+
+    pub fn clear_file_chunk(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_file_chunk(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_chunk(&mut self, v: FileChunk) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_chunk(&mut self) -> &mut FileChunk {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(FileChunk::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_chunk(&mut self) -> FileChunk {
+        if self.has_file_chunk() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileChunk::new()
+        }
+    }
+
+    pub fn get_file_chunk(&self) -> &FileChunk {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(ref v)) => v,
+            _ => FileChunk::default_instance(),
+        }
+    }
+
+    // optional .FileChunkRequest file_chunk_request = 10;
+
+    pub fn clear_file_chunk_request(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_file_chunk_request(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_chunk_request(&mut self, v: FileChunkRequest) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_chunk_request(&mut self) -> &mut FileChunkRequest {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(FileChunkRequest::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_chunk_request(&mut self) -> FileChunkRequest {
+        if self.has_file_chunk_request() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileChunkRequest::new()
+        }
+    }
+
+    pub fn get_file_chunk_request(&self) -> &FileChunkRequest {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(ref v)) => v,
+            _ => FileChunkRequest::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for Rumor {
@@ -4023,19 +7627,31 @@ impl ::protobuf::Message for Rumor {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(try!(is.read_message())));
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_config(try!(is.read_message())));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(try!(is.read_message())));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(try!(is.read_message())));
                 },
-                7 => {
+                9 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::service_file(try!(is.read_message())));
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk(try!(is.read_message())));
                 },
-                8 => {
+                10 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(try!(is.read_message())));
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::file_chunk_request(try!(is.read_message())));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4080,6 +7696,14 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Rumor_oneof_payload::file_chunk(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::file_chunk_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -4124,6 +7748,16 @@ impl ::protobuf::Message for Rumor {
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
+                &Rumor_oneof_payload::file_chunk(ref v) => {
+                    try!(os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &Rumor_oneof_payload::file_chunk_request(ref v) => {
+                    try!(os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
             };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -4207,6 +7841,16 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_election,
                     Rumor::get_election,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "file_chunk",
+                    Rumor::has_file_chunk,
+                    Rumor::get_file_chunk,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "file_chunk_request",
+                    Rumor::has_file_chunk_request,
+                    Rumor::get_file_chunk_request,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -4227,6 +7871,8 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_config();
         self.clear_service_file();
         self.clear_election();
+        self.clear_file_chunk();
+        self.clear_file_chunk_request();
         self.unknown_fields.clear();
     }
 }
@@ -4257,6 +7903,8 @@ pub enum Rumor_Type {
     Fake = 6,
     Fake2 = 7,
     ElectionUpdate = 8,
+    FileChunk = 9,
+    FileChunkRequest = 10,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -4274,6 +7922,8 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             6 => ::std::option::Option::Some(Rumor_Type::Fake),
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::FileChunk),
+            10 => ::std::option::Option::Some(Rumor_Type::FileChunkRequest),
             _ => ::std::option::Option::None
         }
     }
@@ -4288,6 +7938,8 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             Rumor_Type::Fake,
             Rumor_Type::Fake2,
             Rumor_Type::ElectionUpdate,
+            Rumor_Type::FileChunk,
+            Rumor_Type::FileChunkRequest,
         ];
         values
     }
@@ -4314,6 +7966,9 @@ pub struct Wire {
     encrypted: ::std::option::Option<bool>,
     nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    key_rev: ::protobuf::SingularField<::std::string::String>,
+    compression: ::std::option::Option<Wire_Compression>,
+    batched: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -4338,6 +7993,9 @@ impl Wire {
                     encrypted: ::std::option::Option::None,
                     nonce: ::protobuf::SingularField::none(),
                     payload: ::protobuf::SingularField::none(),
+                    key_rev: ::protobuf::SingularField::none(),
+                    compression: ::std::option::Option::None,
+                    batched: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4435,6 +8093,80 @@ impl Wire {
             None => &[],
         }
     }
+
+    // optional string key_rev = 4;
+
+    pub fn clear_key_rev(&mut self) {
+        self.key_rev.clear();
+    }
+
+    pub fn has_key_rev(&self) -> bool {
+        self.key_rev.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_rev(&mut self, v: ::std::string::String) {
+        self.key_rev = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_rev(&mut self) -> &mut ::std::string::String {
+        if self.key_rev.is_none() {
+            self.key_rev.set_default();
+        };
+        self.key_rev.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_rev(&mut self) -> ::std::string::String {
+        self.key_rev.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_rev(&self) -> &str {
+        match self.key_rev.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .Wire.Compression compression = 5;
+
+    pub fn clear_compression(&mut self) {
+        self.compression = ::std::option::Option::None;
+    }
+
+    pub fn has_compression(&self) -> bool {
+        self.compression.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compression(&mut self, v: Wire_Compression) {
+        self.compression = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_compression(&self) -> Wire_Compression {
+        self.compression.unwrap_or(Wire_Compression::NONE)
+    }
+
+    // optional bool batched = 6;
+
+    pub fn clear_batched(&mut self) {
+        self.batched = ::std::option::Option::None;
+    }
+
+    pub fn has_batched(&self) -> bool {
+        self.batched.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_batched(&mut self, v: bool) {
+        self.batched = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_batched(&self) -> bool {
+        self.batched.unwrap_or(false)
+    }
 }
 
 impl ::protobuf::Message for Wire {
@@ -4459,6 +8191,23 @@ impl ::protobuf::Message for Wire {
                 3 => {
                     try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
                 },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_rev));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.compression = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.batched = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -4480,6 +8229,15 @@ impl ::protobuf::Message for Wire {
         for value in &self.payload {
             my_size += ::protobuf::rt::bytes_size(3, &value);
         };
+        for value in &self.key_rev {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.compression {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
+        if self.batched.is_some() {
+            my_size += 2;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4495,6 +8253,15 @@ impl ::protobuf::Message for Wire {
         if let Some(v) = self.payload.as_ref() {
             try!(os.write_bytes(3, &v));
         };
+        if let Some(v) = self.key_rev.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.compression {
+            try!(os.write_enum(5, v.value()));
+        };
+        if let Some(v) = self.batched {
+            try!(os.write_bool(6, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -4552,6 +8319,21 @@ impl ::protobuf::MessageStatic for Wire {
                     Wire::has_payload,
                     Wire::get_payload,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_rev",
+                    Wire::has_key_rev,
+                    Wire::get_key_rev,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "compression",
+                    Wire::has_compression,
+                    Wire::get_compression,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "batched",
+                    Wire::has_batched,
+                    Wire::get_batched,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wire>(
                     "Wire",
                     fields,
@@ -4567,6 +8349,9 @@ impl ::protobuf::Clear for Wire {
         self.clear_encrypted();
         self.clear_nonce();
         self.clear_payload();
+        self.clear_key_rev();
+        self.clear_compression();
+        self.clear_batched();
         self.unknown_fields.clear();
     }
 }
@@ -4576,6 +8361,9 @@ impl ::std::cmp::PartialEq for Wire {
         self.encrypted == other.encrypted &&
         self.nonce == other.nonce &&
         self.payload == other.payload &&
+        self.key_rev == other.key_rev &&
+        self.compression == other.compression &&
+        self.batched == other.batched &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -4586,9 +8374,223 @@ impl ::std::fmt::Debug for Wire {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Wire_Compression {
+    NONE = 1,
+    DEFLATE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Wire_Compression {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Wire_Compression> {
+        match value {
+            1 => ::std::option::Option::Some(Wire_Compression::NONE),
+            2 => ::std::option::Option::Some(Wire_Compression::DEFLATE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Wire_Compression] = &[
+            Wire_Compression::NONE,
+            Wire_Compression::DEFLATE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Wire_Compression>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Wire_Compression", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Wire_Compression {
+}
+
+#[derive(Clone,Default)]
+pub struct RumorBatch {
+    // message fields
+    rumor: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RumorBatch {}
+
+impl RumorBatch {
+    pub fn new() -> RumorBatch {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RumorBatch {
+        static mut instance: ::protobuf::lazy::Lazy<RumorBatch> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RumorBatch,
+        };
+        unsafe {
+            instance.get(|| {
+                RumorBatch {
+                    rumor: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated bytes rumor = 1;
+
+    pub fn clear_rumor(&mut self) {
+        self.rumor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rumor(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.rumor = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rumor(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.rumor
+    }
+
+    // Take field
+    pub fn take_rumor(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.rumor, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_rumor(&self) -> &[::std::vec::Vec<u8>] {
+        &self.rumor
+    }
+}
+
+impl ::protobuf::Message for RumorBatch {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.rumor));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.rumor {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.rumor {
+            try!(os.write_bytes(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<RumorBatch>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RumorBatch {
+    fn new() -> RumorBatch {
+        RumorBatch::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RumorBatch>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_bytes_accessor(
+                    "rumor",
+                    RumorBatch::get_rumor,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RumorBatch>(
+                    "RumorBatch",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RumorBatch {
+    fn clear(&mut self) {
+        self.clear_rumor();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for RumorBatch {
+    fn eq(&self, other: &RumorBatch) -> bool {
+        self.rumor == other.rumor &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for RumorBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x14, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x73, 0x77, 0x69, 0x6d,
-    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xb9, 0x01, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xd3, 0x02, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65,
     0x72, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69,
     0x64, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
     0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74,
@@ -4600,82 +8602,219 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x50, 0x6f, 0x72, 0x74, 0x12, 0x25, 0x0a, 0x0a, 0x70,
     0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x3a,
     0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x0a, 0x70, 0x65, 0x72, 0x73, 0x69, 0x73, 0x74, 0x65,
-    0x6e, 0x74, 0x22, 0x4b, 0x0a, 0x04, 0x50, 0x69, 0x6e, 0x67, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72,
-    0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61,
-    0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x52, 0x09, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22,
-    0x4a, 0x0a, 0x03, 0x41, 0x63, 0x6b, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66,
-    0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74,
-    0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x52, 0x09, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22, 0x47, 0x0a, 0x07, 0x50,
-    0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66,
-    0x72, 0x6f, 0x6d, 0x12, 0x1f, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x74, 0x61,
-    0x72, 0x67, 0x65, 0x74, 0x22, 0x8a, 0x01, 0x0a, 0x0a, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73,
-    0x68, 0x69, 0x70, 0x12, 0x1f, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x6d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x12, 0x2a, 0x0a, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x0e, 0x32, 0x12, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69,
-    0x70, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x52, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68,
-    0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c,
-    0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54,
-    0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10,
-    0x03, 0x22, 0xf5, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b,
-    0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70,
-    0x12, 0x12, 0x0a, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04,
-    0x74, 0x65, 0x72, 0x6d, 0x12, 0x20, 0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c,
-    0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61,
-    0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x28, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
-    0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52,
-    0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x12, 0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a,
-    0x08, 0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46,
-    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10, 0x03, 0x22, 0x8e, 0x02, 0x0a, 0x07, 0x53, 0x65,
-    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f,
-    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72,
-    0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72,
-    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e,
-    0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x70, 0x18,
-    0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x70, 0x12, 0x1a, 0x0a, 0x08, 0x68, 0x6f, 0x73,
-    0x74, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x68, 0x6f, 0x73,
-    0x74, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x06, 0x20,
-    0x01, 0x28, 0x0d, 0x52, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x65, 0x78, 0x70,
-    0x6f, 0x73, 0x65, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0d, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f,
-    0x73, 0x65, 0x73, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a,
-    0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61,
-    0x6c, 0x69, 0x7a, 0x65, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
-    0x5f, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x70, 0x61,
-    0x63, 0x6b, 0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x22, 0x8c, 0x01, 0x0a, 0x0d, 0x53,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d,
-    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20,
+    0x6e, 0x74, 0x12, 0x36, 0x0a, 0x13, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x5f, 0x63, 0x6f,
+    0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x3a,
+    0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x12, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x73, 0x43,
+    0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x31, 0x0a, 0x14, 0x61, 0x64,
+    0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x65, 0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x09, 0x52, 0x13, 0x61, 0x64, 0x64, 0x69, 0x74, 0x69,
+    0x6f, 0x6e, 0x61, 0x6c, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x12, 0x2d, 0x0a,
+    0x12, 0x61, 0x64, 0x76, 0x65, 0x72, 0x74, 0x69, 0x73, 0x65, 0x64, 0x5f, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x61, 0x64, 0x76, 0x65, 0x72,
+    0x74, 0x69, 0x73, 0x65, 0x64, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x4b, 0x0a, 0x04,
+    0x50, 0x69, 0x6e, 0x67, 0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f,
+    0x6d, 0x12, 0x26, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x09,
+    0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x54, 0x6f, 0x22, 0x4a, 0x0a, 0x03, 0x41, 0x63, 0x6b,
+    0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07,
+    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x26, 0x0a,
+    0x0a, 0x66, 0x6f, 0x72, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x09, 0x66, 0x6f, 0x72, 0x77,
+    0x61, 0x72, 0x64, 0x54, 0x6f, 0x22, 0x47, 0x0a, 0x07, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71,
+    0x12, 0x1b, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07,
+    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x1f, 0x0a,
+    0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e,
+    0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x22, 0x8a,
+    0x01, 0x0a, 0x0a, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x1f, 0x0a,
+    0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e,
+    0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x2a,
+    0x0a, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x12,
+    0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2e, 0x48, 0x65, 0x61, 0x6c,
+    0x74, 0x68, 0x52, 0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65,
+    0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x49, 0x56, 0x45, 0x10, 0x01, 0x12,
+    0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09,
+    0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10, 0x03, 0x22, 0xc6, 0x02, 0x0a, 0x08,
+    0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x65,
+    0x72, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x12, 0x20,
+    0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79,
+    0x12, 0x28, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x10, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74,
+    0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f,
+    0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73,
+    0x12, 0x14, 0x0a, 0x05, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x05, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x12, 0x1d, 0x0a, 0x0a, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x5f, 0x61, 0x63, 0x6b, 0x73,
+    0x18, 0x09, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x41, 0x63, 0x6b,
+    0x73, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a, 0x07, 0x52,
+    0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x6f, 0x51, 0x75,
+    0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x69, 0x6e, 0x69, 0x73, 0x68,
+    0x65, 0x64, 0x10, 0x03, 0x22, 0x95, 0x04, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a,
+    0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f,
+    0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x70, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x02, 0x69, 0x70, 0x12, 0x1a, 0x0a, 0x08, 0x68, 0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x68, 0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65,
+    0x12, 0x12, 0x0a, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x04,
+    0x70, 0x6f, 0x72, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x73, 0x18,
+    0x07, 0x20, 0x03, 0x28, 0x0d, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x73, 0x12, 0x20,
+    0x0a, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x0b, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64,
+    0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x65, 0x6e,
+    0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
+    0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x69, 0x70, 0x76, 0x36, 0x18, 0x0a, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x04, 0x69, 0x70, 0x76, 0x36, 0x12, 0x2d, 0x0a, 0x12, 0x63, 0x6f, 0x6e,
+    0x66, 0x69, 0x67, 0x5f, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x0b, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x49, 0x6e, 0x63,
+    0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x34, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x66,
+    0x69, 0x67, 0x5f, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x0f, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68,
+    0x52, 0x0c, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x36,
+    0x0a, 0x0e, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68,
+    0x18, 0x0d, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x52, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
+    0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x61, 0x64, 0x5f, 0x72, 0x65,
+    0x6c, 0x65, 0x61, 0x73, 0x65, 0x73, 0x18, 0x0e, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x62, 0x61,
+    0x64, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x73, 0x22, 0x31, 0x0a, 0x06, 0x48, 0x65, 0x61,
+    0x6c, 0x74, 0x68, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x01,
+    0x12, 0x0b, 0x0a, 0x07, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x79, 0x10, 0x02, 0x12, 0x0d, 0x0a,
+    0x09, 0x55, 0x6e, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x79, 0x10, 0x03, 0x22, 0xb4, 0x01, 0x0a,
+    0x0d, 0x52, 0x6f, 0x6c, 0x6c, 0x6f, 0x75, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1d,
+    0x0a, 0x0a, 0x62, 0x61, 0x74, 0x63, 0x68, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0d, 0x52, 0x09, 0x62, 0x61, 0x74, 0x63, 0x68, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x23, 0x0a,
+    0x0d, 0x62, 0x61, 0x74, 0x63, 0x68, 0x5f, 0x70, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0d, 0x52, 0x0c, 0x62, 0x61, 0x74, 0x63, 0x68, 0x50, 0x65, 0x72, 0x63, 0x65,
+    0x6e, 0x74, 0x12, 0x26, 0x0a, 0x0b, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x5f, 0x67, 0x61, 0x74,
+    0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x3a, 0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x0a,
+    0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x47, 0x61, 0x74, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61,
+    0x75, 0x73, 0x65, 0x5f, 0x73, 0x65, 0x63, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09,
+    0x70, 0x61, 0x75, 0x73, 0x65, 0x53, 0x65, 0x63, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x73, 0x22, 0xa0, 0x03, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e,
+    0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09,
+    0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52,
+    0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f,
+    0x6e, 0x66, 0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x6f, 0x6e, 0x66,
+    0x69, 0x67, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x06, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69,
+    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73,
+    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x61, 0x70, 0x70, 0x6c,
+    0x69, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x61, 0x70,
+    0x70, 0x6c, 0x69, 0x65, 0x64, 0x42, 0x79, 0x12, 0x27, 0x0a, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x2e, 0x4d, 0x6f, 0x64, 0x65, 0x52, 0x04, 0x6d, 0x6f, 0x64, 0x65,
+    0x12, 0x1f, 0x0a, 0x0b, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x5f, 0x6b, 0x65, 0x79, 0x73, 0x18,
+    0x09, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0a, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4b, 0x65, 0x79,
+    0x73, 0x12, 0x28, 0x0a, 0x07, 0x72, 0x6f, 0x6c, 0x6c, 0x6f, 0x75, 0x74, 0x18, 0x0a, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x52, 0x6f, 0x6c, 0x6c, 0x6f, 0x75, 0x74, 0x50, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x52, 0x07, 0x72, 0x6f, 0x6c, 0x6c, 0x6f, 0x75, 0x74, 0x12, 0x29, 0x0a, 0x10, 0x62,
+    0x61, 0x73, 0x65, 0x5f, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x0b, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0f, 0x62, 0x61, 0x73, 0x65, 0x49, 0x6e, 0x63, 0x61, 0x72,
+    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x1e, 0x0a, 0x04, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x0b,
+    0x0a, 0x07, 0x52, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x50,
+    0x61, 0x74, 0x63, 0x68, 0x10, 0x02, 0x22, 0xf0, 0x02, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69,
+    0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a,
+    0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08,
+    0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x66,
+    0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x66,
+    0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18,
+    0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x12, 0x16, 0x0a, 0x06, 0x73,
+    0x69, 0x67, 0x6e, 0x65, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x69, 0x67,
+    0x6e, 0x65, 0x72, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+    0x65, 0x12, 0x21, 0x0a, 0x0c, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x5f, 0x68, 0x61, 0x73,
+    0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
+    0x48, 0x61, 0x73, 0x68, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x09, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x12, 0x1f, 0x0a, 0x0b, 0x63, 0x68, 0x75, 0x6e,
+    0x6b, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x63,
+    0x68, 0x75, 0x6e, 0x6b, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1f, 0x0a, 0x07, 0x64, 0x65, 0x6c,
+    0x65, 0x74, 0x65, 0x64, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x08, 0x3a, 0x05, 0x66, 0x61, 0x6c, 0x73,
+    0x65, 0x52, 0x07, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x64, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x03, 0x52, 0x09,
+    0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x41, 0x74, 0x22, 0x79, 0x0a, 0x09, 0x46, 0x69, 0x6c,
+    0x65, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x12, 0x21, 0x0a, 0x0c, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64,
+    0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x12,
+    0x1f, 0x0a, 0x0b, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x43, 0x6f, 0x75, 0x6e, 0x74,
+    0x12, 0x12, 0x0a, 0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
+    0x64, 0x61, 0x74, 0x61, 0x22, 0x4b, 0x0a, 0x10, 0x46, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e,
+    0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
+    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x69,
+    0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0d, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65,
+    0x78, 0x22, 0xed, 0x01, 0x0a, 0x05, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x1f, 0x0a, 0x04, 0x74,
+    0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x51, 0x75, 0x65, 0x72,
+    0x79, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x23, 0x0a, 0x0d,
+    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20,
     0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75,
-    0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64,
-    0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65,
-    0x64, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28,
-    0x0c, 0x52, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x22, 0xa2, 0x01, 0x0a, 0x0b, 0x53, 0x65,
-    0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72,
-    0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20,
-    0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x1a,
-    0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x62, 0x6f,
-    0x64, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x22, 0xe3,
-    0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18,
+    0x70, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x0d, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63,
+    0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b,
+    0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x61,
+    0x66, 0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x61, 0x66, 0x74, 0x65,
+    0x72, 0x22, 0x4e, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x4d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x73, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x43, 0x65, 0x6e, 0x73, 0x75, 0x73,
+    0x10, 0x02, 0x12, 0x0a, 0x0a, 0x06, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x10, 0x03, 0x12, 0x11,
+    0x0a, 0x0d, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x10,
+    0x04, 0x12, 0x0e, 0x0a, 0x0a, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x42, 0x6f, 0x64, 0x79, 0x10,
+    0x05, 0x22, 0xca, 0x04, 0x0a, 0x0d, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64,
+    0x12, 0x2b, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x18, 0x02,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69,
+    0x70, 0x52, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x22, 0x0a,
+    0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x08,
+    0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x49, 0x64, 0x12, 0x35,
+    0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x2f, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x5f,
+    0x74, 0x6f, 0x6f, 0x5f, 0x6c, 0x61, 0x72, 0x67, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x3a,
+    0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x0e, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x54, 0x6f,
+    0x6f, 0x4c, 0x61, 0x72, 0x67, 0x65, 0x12, 0x2f, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x52, 0x0b, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x1f, 0x0a, 0x0b, 0x6e, 0x65, 0x78, 0x74, 0x5f,
+    0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x6e, 0x65,
+    0x78, 0x74, 0x4f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61,
+    0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x12, 0x16,
+    0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06,
+    0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x35, 0x0a, 0x0e, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x5f, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e,
+    0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x0d,
+    0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x12, 0x2a, 0x0a,
+    0x11, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x73, 0x5f, 0x74, 0x6f, 0x6f, 0x5f, 0x6c, 0x61, 0x72,
+    0x67, 0x65, 0x18, 0x0c, 0x20, 0x03, 0x28, 0x04, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x73, 0x54, 0x6f, 0x6f, 0x4c, 0x61, 0x72, 0x67, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x78,
+    0x74, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e,
+    0x65, 0x78, 0x74, 0x41, 0x66, 0x74, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x61, 0x66, 0x74, 0x65,
+    0x72, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x61, 0x66, 0x74, 0x65, 0x72, 0x12, 0x2e,
+    0x0a, 0x0f, 0x62, 0x6f, 0x64, 0x69, 0x65, 0x73, 0x5f, 0x77, 0x69, 0x74, 0x68, 0x68, 0x65, 0x6c,
+    0x64, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x08, 0x3a, 0x05, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x52, 0x0e,
+    0x62, 0x6f, 0x64, 0x69, 0x65, 0x73, 0x57, 0x69, 0x74, 0x68, 0x68, 0x65, 0x6c, 0x64, 0x22, 0xdb,
+    0x02, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18,
     0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53, 0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70,
     0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1b, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18,
     0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x48, 0x00, 0x52, 0x04,
@@ -4683,356 +8822,745 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x0b, 0x32, 0x04, 0x2e, 0x41, 0x63, 0x6b, 0x48, 0x00, 0x52, 0x03, 0x61, 0x63, 0x6b, 0x12, 0x24,
     0x0a, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32,
     0x08, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x48, 0x00, 0x52, 0x07, 0x70, 0x69, 0x6e,
-    0x67, 0x72, 0x65, 0x71, 0x12, 0x2b, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68,
-    0x69, 0x70, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x73, 0x68, 0x69, 0x70, 0x52, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69,
-    0x70, 0x22, 0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e,
-    0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07,
-    0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79,
-    0x6c, 0x6f, 0x61, 0x64, 0x22, 0xbc, 0x03, 0x0a, 0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f,
-    0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52,
-    0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12,
-    0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61,
-    0x67, 0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49, 0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d,
-    0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x12, 0x24, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01,
-    0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07,
-    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x37, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32,
-    0x0e, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48,
-    0x00, 0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
-    0x12, 0x31, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65,
-    0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x46, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46,
-    0x69, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18,
-    0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x48, 0x00, 0x52, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x7a, 0x0a, 0x04,
-    0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01,
-    0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a,
-    0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f,
-    0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12,
-    0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65, 0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b,
-    0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a, 0x0e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x08, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c,
-    0x6f, 0x61, 0x64, 0x22, 0x54, 0x0a, 0x04, 0x57, 0x69, 0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65,
-    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09,
-    0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e,
-    0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12,
-    0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
-    0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x4a, 0xd8, 0x26, 0x0a, 0x06, 0x12, 0x04,
-    0x00, 0x00, 0x70, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
-    0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04,
-    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x20, 0x21,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06,
-    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04,
-    0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x11,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12,
-    0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x08, 0x12, 0x03, 0x08,
-    0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x07, 0x12, 0x03, 0x08, 0x2a, 0x2f,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
-    0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d,
-    0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04,
-    0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x12, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
-    0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x15, 0x00, 0x18, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x19,
-    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1a, 0x00,
-    0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x02, 0x12, 0x03, 0x1b, 0x25, 0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02,
-    0x02, 0x12, 0x03, 0x1b, 0x28, 0x36, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x1b, 0x28, 0x31, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02,
-    0x02, 0x12, 0x03, 0x1b, 0x34, 0x35, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03,
-    0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e,
-    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b, 0x1c,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x21, 0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x05, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00,
-    0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x22,
-    0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22,
-    0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22,
-    0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1d,
-    0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1d,
-    0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x28,
-    0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x22, 0x2b, 0x38,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x2b, 0x33,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x22, 0x36, 0x37,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x25,
-    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
-    0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04,
-    0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03,
-    0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x26, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x26, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x03, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
-    0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12,
-    0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02,
-    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x06, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
-    0x05, 0x12, 0x03, 0x29, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12,
-    0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x17,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2c, 0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
-    0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
-    0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x06, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e,
-    0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x22, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x30,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x30, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
-    0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04,
-    0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x31, 0x1d, 0x1e, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x32, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x05, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x32, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12,
-    0x03, 0x32, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02,
-    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x33, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x33, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
-    0x07, 0x12, 0x03, 0x34, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x04, 0x12,
-    0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x34,
-    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x34, 0x10, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x03, 0x12, 0x03, 0x34, 0x1e, 0x1f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x06, 0x02, 0x08, 0x12, 0x03, 0x35, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x08, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x08, 0x05, 0x12, 0x03, 0x35, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01,
-    0x12, 0x03, 0x35, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03,
-    0x35, 0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x38, 0x00, 0x3d, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x38, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x07, 0x02, 0x00, 0x12, 0x03, 0x39, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39,
-    0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x22, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x3a, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x3a, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x3a, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x3b,
-    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3b, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
-    0x02, 0x03, 0x12, 0x03, 0x3c, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04,
-    0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03,
-    0x3c, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3c, 0x11,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3c, 0x1a, 0x1b, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x3f, 0x00, 0x45, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x08, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12,
-    0x03, 0x40, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x40,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x40, 0x12, 0x1f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x40, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x41, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x01, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x41, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x20,
-    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x42, 0x02, 0x1e, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x42, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x42, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03,
-    0x43, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x43, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x43, 0x12, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x43, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x08, 0x02, 0x04, 0x12, 0x03, 0x44, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x44, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x44, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x44,
-    0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x44, 0x18, 0x19,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x47, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x09, 0x01, 0x12, 0x03, 0x47, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00,
-    0x12, 0x03, 0x48, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x48, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x48,
-    0x0e, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48,
-    0x0e, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x48,
-    0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x48, 0x18,
-    0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x48, 0x18,
-    0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x48, 0x1e,
-    0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x48, 0x21, 0x2d,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x48, 0x21, 0x28,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x48, 0x2b, 0x2c,
-    0x0a, 0x33, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x4b, 0x02, 0x19, 0x1a, 0x26, 0x20,
-    0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
-    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64,
-    0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x4b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4b, 0x0b,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4b, 0x10, 0x14, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4b, 0x17, 0x18, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x4c, 0x02, 0x50, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
-    0x01, 0x12, 0x03, 0x4d, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x4d, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d,
-    0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4d, 0x10, 0x11,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x4e, 0x04, 0x10, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x02, 0x06, 0x12, 0x03, 0x4e, 0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x4e, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12,
-    0x03, 0x4f, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4f,
-    0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4f, 0x0c, 0x13,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4f, 0x16, 0x17, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x03, 0x51, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x04, 0x06, 0x12, 0x03, 0x51, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01,
-    0x12, 0x03, 0x51, 0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03,
-    0x51, 0x23, 0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x54, 0x00, 0x6a, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x54, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0a, 0x04, 0x00, 0x12, 0x04, 0x55, 0x02, 0x5e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04,
-    0x00, 0x01, 0x12, 0x03, 0x55, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x56, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x56, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x56, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x57, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x57, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02,
-    0x12, 0x03, 0x57, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x58, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x58, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
-    0x03, 0x58, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
-    0x59, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x59, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
-    0x59, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x5a,
-    0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5a,
-    0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x5a,
-    0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x5b, 0x04,
-    0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5b, 0x04,
-    0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x5b, 0x0b,
-    0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x5c, 0x04, 0x0e,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5c, 0x04, 0x09,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5c, 0x0c, 0x0d,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x5d, 0x04, 0x17, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x04, 0x12, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x5d, 0x15, 0x16, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x60, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x60, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x60, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x60, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x61, 0x02,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x61, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x61, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02,
-    0x02, 0x12, 0x03, 0x62, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12,
-    0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x62,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x62, 0x12, 0x19,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x62, 0x1c, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x0a, 0x08, 0x00, 0x12, 0x04, 0x63, 0x02, 0x69, 0x03, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x08, 0x00, 0x01, 0x12, 0x03, 0x63, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
-    0x02, 0x03, 0x12, 0x03, 0x64, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06,
-    0x12, 0x03, 0x64, 0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x64, 0x0f, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x64, 0x18,
-    0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x65, 0x04, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x65, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x65, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x04, 0x03, 0x12, 0x03, 0x65, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05,
-    0x12, 0x03, 0x66, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03,
-    0x66, 0x04, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x66, 0x12,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x66, 0x23, 0x24, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12, 0x03, 0x67, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x06, 0x06, 0x12, 0x03, 0x67, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x67, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06,
-    0x03, 0x12, 0x03, 0x67, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03,
-    0x68, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x03, 0x68, 0x04,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x03, 0x68, 0x0d, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x03, 0x68, 0x18, 0x19, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x0b, 0x12, 0x04, 0x6c, 0x00, 0x70, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01,
-    0x12, 0x03, 0x6c, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6d,
-    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6d, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6d, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b,
-    0x02, 0x01, 0x12, 0x03, 0x6e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x6e, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x11,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x6f, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x6f, 0x11, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x6f, 0x1b, 0x1c,
+    0x67, 0x72, 0x65, 0x71, 0x12, 0x1e, 0x0a, 0x05, 0x71, 0x75, 0x65, 0x72, 0x79, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x48, 0x00, 0x52, 0x05, 0x71,
+    0x75, 0x65, 0x72, 0x79, 0x12, 0x37, 0x0a, 0x0e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x72, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x51,
+    0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x0d,
+    0x71, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a,
+    0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x18, 0x05, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x52, 0x0a,
+    0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x22, 0x45, 0x0a, 0x04, 0x54, 0x79,
+    0x70, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03,
+    0x41, 0x43, 0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51,
+    0x10, 0x03, 0x12, 0x09, 0x0a, 0x05, 0x51, 0x55, 0x45, 0x52, 0x59, 0x10, 0x04, 0x12, 0x12, 0x0a,
+    0x0e, 0x51, 0x55, 0x45, 0x52, 0x59, 0x5f, 0x52, 0x45, 0x53, 0x50, 0x4f, 0x4e, 0x53, 0x45, 0x10,
+    0x05, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0xd2, 0x04, 0x0a,
+    0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70,
+    0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x02,
+    0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67, 0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f,
+    0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d,
+    0x49, 0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48,
+    0x00, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x24, 0x0a, 0x07, 0x73, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12,
+    0x37, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69,
+    0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x31, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c,
+    0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b,
+    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x08, 0x65,
+    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e,
+    0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x08, 0x65, 0x6c, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2b, 0x0a, 0x0a, 0x66, 0x69, 0x6c, 0x65, 0x5f, 0x63, 0x68, 0x75,
+    0x6e, 0x6b, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x46, 0x69, 0x6c, 0x65, 0x43,
+    0x68, 0x75, 0x6e, 0x6b, 0x48, 0x00, 0x52, 0x09, 0x66, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e,
+    0x6b, 0x12, 0x41, 0x0a, 0x12, 0x66, 0x69, 0x6c, 0x65, 0x5f, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x5f,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e,
+    0x46, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x48, 0x00, 0x52, 0x10, 0x66, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x22, 0x9f, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a,
+    0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65,
+    0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a,
+    0x0e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10,
+    0x08, 0x12, 0x0d, 0x0a, 0x09, 0x46, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x10, 0x09,
+    0x12, 0x14, 0x0a, 0x10, 0x46, 0x69, 0x6c, 0x65, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x10, 0x0a, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61,
+    0x64, 0x22, 0xe2, 0x01, 0x0a, 0x04, 0x57, 0x69, 0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e,
+    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65,
+    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x18,
+    0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x6b, 0x65, 0x79, 0x5f,
+    0x72, 0x65, 0x76, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x6b, 0x65, 0x79, 0x52, 0x65,
+    0x76, 0x12, 0x33, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x57, 0x69, 0x72, 0x65, 0x2e, 0x43, 0x6f,
+    0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x72,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x74, 0x63, 0x68, 0x65,
+    0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x62, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64,
+    0x22, 0x24, 0x0a, 0x0b, 0x43, 0x6f, 0x6d, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12,
+    0x08, 0x0a, 0x04, 0x4e, 0x4f, 0x4e, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x44, 0x45, 0x46,
+    0x4c, 0x41, 0x54, 0x45, 0x10, 0x02, 0x22, 0x22, 0x0a, 0x0a, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x42,
+    0x61, 0x74, 0x63, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0c, 0x52, 0x05, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x4a, 0xe4, 0x53, 0x0a, 0x07, 0x12,
+    0x05, 0x00, 0x00, 0xcc, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x0c, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x17, 0x18, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04,
+    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12,
+    0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x11, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x04, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05,
+    0x12, 0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x07, 0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x07, 0x1f,
+    0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x31, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x08, 0x12,
+    0x03, 0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x07, 0x12, 0x03, 0x08,
+    0x2a, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x09, 0x02, 0x3a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x09, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x09, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x09, 0x10, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x06, 0x03, 0x12, 0x03, 0x09, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x08,
+    0x12, 0x03, 0x09, 0x28, 0x39, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x07, 0x12, 0x03,
+    0x09, 0x33, 0x38, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x0a, 0x02, 0x2b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x05, 0x12, 0x03, 0x0a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x0a, 0x12, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x0a, 0x29, 0x2a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08,
+    0x12, 0x03, 0x0b, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12, 0x03,
+    0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x05, 0x12, 0x03, 0x0b, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x0b, 0x27, 0x28, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x0e, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
+    0x12, 0x03, 0x0e, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0f,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0f, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0f, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03,
+    0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x12,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x1f, 0x20, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x13, 0x00, 0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x02, 0x01, 0x12, 0x03, 0x13, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12,
+    0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x14, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x15, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x15, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x15, 0x1f,
+    0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x18, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x18, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x00, 0x12, 0x03, 0x19, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x19,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x19, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x19, 0x19, 0x1a, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x06, 0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x1a, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x1a, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1d, 0x00, 0x22, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x04, 0x04, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x1e, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x1e, 0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x1e, 0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x03, 0x1e, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12,
+    0x03, 0x1e, 0x1b, 0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x1e, 0x1b, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12,
+    0x03, 0x1e, 0x25, 0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x1e, 0x28, 0x36, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x1e, 0x28, 0x31, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x1e, 0x34, 0x35, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x20, 0x02, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x20, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x20, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01,
+    0x12, 0x03, 0x21, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x21, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x12, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x21, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0x24, 0x00, 0x30, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
+    0x12, 0x03, 0x24, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x03, 0x25,
+    0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x25, 0x07, 0x0d,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x25, 0x10, 0x1c, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x10, 0x17, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x25, 0x1a, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x25, 0x1d, 0x2a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x1d, 0x25, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x25, 0x28, 0x29, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x25, 0x2b, 0x38, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x25, 0x2b, 0x33, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x25, 0x36, 0x37, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x27, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x27, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x27,
+    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x28, 0x02, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x28, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x28, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12,
+    0x03, 0x29, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x29,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x29, 0x12, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x29, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x2a, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x2a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x2a, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2a, 0x20,
+    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x04, 0x06, 0x12, 0x03, 0x2b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x2b, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x2b, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03,
+    0x2c, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12, 0x03, 0x2c, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2c, 0x12, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x2c, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x06, 0x12, 0x03, 0x2d, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06,
+    0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x01, 0x12, 0x03, 0x2d,
+    0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x03, 0x12, 0x03, 0x2d, 0x1a, 0x1b,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x07, 0x12, 0x03, 0x2e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x07, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x07, 0x01, 0x12, 0x03, 0x2e, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x03,
+    0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x08, 0x12, 0x03, 0x2f,
+    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2f, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06,
+    0x12, 0x04, 0x32, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x32,
+    0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x04, 0x00, 0x12, 0x03, 0x33, 0x02, 0x3a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x04, 0x00, 0x01, 0x12, 0x03, 0x33, 0x07, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x33, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x33, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x33, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x33, 0x1d, 0x29, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x33, 0x1d, 0x24, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x33, 0x27, 0x28, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x33, 0x2a, 0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x33, 0x2a, 0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x33, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x00, 0x12, 0x03, 0x35, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x35, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x35, 0x12,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x35, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x36, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x36, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x36, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x36, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x37, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x37, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x37, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x37, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x37, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x03, 0x12, 0x03, 0x38, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12,
+    0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x38,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x38, 0x12, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x38, 0x17, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x39, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x04, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x39, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x39, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x3a, 0x02, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x3a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x3a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06,
+    0x12, 0x03, 0x3b, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x04, 0x12, 0x03,
+    0x3b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12, 0x03, 0x3b, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x3b, 0x12, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x3b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x3c, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x07, 0x04, 0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07,
+    0x05, 0x12, 0x03, 0x3c, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x01, 0x12,
+    0x03, 0x3c, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x03, 0x12, 0x03, 0x3c,
+    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x08, 0x12, 0x03, 0x3d, 0x02, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x08, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x08, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x08, 0x03, 0x12, 0x03, 0x3d, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x09, 0x12,
+    0x03, 0x3e, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x04, 0x12, 0x03, 0x3e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x03, 0x12, 0x03, 0x3e, 0x19, 0x1b, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x0a, 0x12, 0x03, 0x3f, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x0a, 0x04, 0x12, 0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a, 0x05,
+    0x12, 0x03, 0x3f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a, 0x01, 0x12, 0x03,
+    0x3f, 0x12, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x3f, 0x27,
+    0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x0b, 0x12, 0x03, 0x40, 0x02, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x0b, 0x06, 0x12, 0x03, 0x40, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x0b, 0x01, 0x12, 0x03, 0x40, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0b,
+    0x03, 0x12, 0x03, 0x40, 0x22, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x0c, 0x12, 0x03,
+    0x41, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0c, 0x04, 0x12, 0x03, 0x41, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0c, 0x06, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x41, 0x12, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x41, 0x23, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x06, 0x02, 0x0d, 0x12, 0x03, 0x42, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0d,
+    0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0d, 0x05, 0x12,
+    0x03, 0x42, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x42,
+    0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x42, 0x21, 0x23,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x45, 0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x07, 0x01, 0x12, 0x03, 0x45, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00,
+    0x12, 0x03, 0x46, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x46, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x12, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x1f, 0x20, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x47, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x47, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x47,
+    0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x48, 0x02, 0x32, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x48, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x48, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x48, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x48, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x08,
+    0x12, 0x03, 0x48, 0x20, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x07, 0x12, 0x03,
+    0x48, 0x2b, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x49, 0x02, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x49, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x49, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x49, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x04,
+    0x12, 0x03, 0x4a, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x04, 0x12, 0x03,
+    0x4a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x03, 0x4a, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4a, 0x12, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4a, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x08, 0x12, 0x04, 0x4d, 0x00, 0x5b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01,
+    0x12, 0x03, 0x4d, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x04, 0x00, 0x12, 0x03, 0x4e,
+    0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x04, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x07, 0x0b,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x4e, 0x0e, 0x1a, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x0e, 0x15, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x4e, 0x18, 0x19, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x1b, 0x25, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4e, 0x1b, 0x20, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x4e, 0x23, 0x24, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x50, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x50, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x50, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x51, 0x02, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x51, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x51, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x51, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02,
+    0x12, 0x03, 0x52, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x52, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x52, 0x0b,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x52, 0x10, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x52, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x53, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x03, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x53, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x53, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x53,
+    0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x54, 0x02, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03, 0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x54, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x54, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x54, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12,
+    0x03, 0x55, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x04, 0x12, 0x03, 0x55,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x55, 0x11, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x55, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x56, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x06, 0x04, 0x12, 0x03, 0x56, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x05,
+    0x12, 0x03, 0x56, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12, 0x03,
+    0x56, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x03, 0x56, 0x1f,
+    0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x03, 0x57, 0x02, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x04, 0x12, 0x03, 0x57, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x07, 0x06, 0x12, 0x03, 0x57, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x07, 0x01, 0x12, 0x03, 0x57, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07,
+    0x03, 0x12, 0x03, 0x57, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x08, 0x12, 0x03,
+    0x58, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x04, 0x12, 0x03, 0x58, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x05, 0x12, 0x03, 0x58, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x01, 0x12, 0x03, 0x58, 0x12, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x08, 0x03, 0x12, 0x03, 0x58, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x09, 0x12, 0x03, 0x59, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09,
+    0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09, 0x06, 0x12,
+    0x03, 0x59, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09, 0x01, 0x12, 0x03, 0x59,
+    0x19, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09, 0x03, 0x12, 0x03, 0x59, 0x23, 0x25,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x0a, 0x12, 0x03, 0x5a, 0x02, 0x28, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x0a, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x0a, 0x03,
+    0x12, 0x03, 0x5a, 0x25, 0x27, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x5d, 0x00, 0x6a,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x13, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x5e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e,
+    0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x22, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x5f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12,
+    0x03, 0x60, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x60,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x60, 0x10, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x60, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x61, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x1d,
+    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x04, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x62, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x62, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x05, 0x12, 0x03,
+    0x63, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x04, 0x12, 0x03, 0x63, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x05, 0x12, 0x03, 0x63, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x01, 0x12, 0x03, 0x63, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x05, 0x03, 0x12, 0x03, 0x63, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x06, 0x12, 0x03, 0x64, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06,
+    0x04, 0x12, 0x03, 0x64, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x64, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x01, 0x12, 0x03, 0x64,
+    0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x03, 0x12, 0x03, 0x64, 0x1d, 0x1e,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x07, 0x12, 0x03, 0x65, 0x02, 0x23, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x07, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x07, 0x05, 0x12, 0x03, 0x65, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x07, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x07, 0x03,
+    0x12, 0x03, 0x65, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x08, 0x12, 0x03, 0x66,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x08, 0x04, 0x12, 0x03, 0x66, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x08, 0x05, 0x12, 0x03, 0x66, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x08, 0x01, 0x12, 0x03, 0x66, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x08, 0x03, 0x12, 0x03, 0x66, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x09, 0x12, 0x03, 0x67, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x09, 0x04,
+    0x12, 0x03, 0x67, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x09, 0x05, 0x12, 0x03,
+    0x67, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x09, 0x01, 0x12, 0x03, 0x67, 0x12,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x09, 0x03, 0x12, 0x03, 0x67, 0x20, 0x22, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x0a, 0x12, 0x03, 0x68, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x0a, 0x05, 0x12, 0x03, 0x68, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x0a,
+    0x01, 0x12, 0x03, 0x68, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x0a, 0x03, 0x12,
+    0x03, 0x68, 0x1a, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x0a, 0x08, 0x12, 0x03, 0x68,
+    0x1d, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x0a, 0x07, 0x12, 0x03, 0x68, 0x28, 0x2d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x0b, 0x12, 0x03, 0x69, 0x02, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x69, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x69, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x0b, 0x01, 0x12, 0x03, 0x69, 0x11, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x0b, 0x03,
+    0x12, 0x03, 0x69, 0x1e, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x6c, 0x00, 0x71,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x6c, 0x08, 0x11, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x6d, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x6d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x6d, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6d,
+    0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x6e, 0x02, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x6e, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12,
+    0x03, 0x6f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0a, 0x02, 0x03, 0x12, 0x03, 0x70, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x70, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x70, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x70, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x70, 0x18,
+    0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x73, 0x00, 0x76, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x73, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x00, 0x12, 0x03, 0x74, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x74, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x74,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x74, 0x12, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x74, 0x21, 0x22, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x75, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x75, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x75, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x75, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x75, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x05, 0x78, 0x00, 0x80, 0x01, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x78, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0c, 0x04, 0x00, 0x12, 0x03, 0x79, 0x02, 0x57, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x79, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0c, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x79, 0x0e, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x79, 0x0e, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x79, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x79, 0x1b, 0x26, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x79, 0x1b, 0x21, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x79, 0x24, 0x25, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x79, 0x27, 0x32, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x79, 0x27, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x79, 0x30, 0x31, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x79, 0x33, 0x45, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x79, 0x33, 0x40, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x79, 0x43, 0x44, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x79,
+    0x46, 0x55, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x79,
+    0x46, 0x50, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0c, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x79,
+    0x53, 0x54, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03, 0x7b, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x06, 0x12, 0x03, 0x7b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x7b, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12,
+    0x03, 0x7c, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x7c,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x03, 0x7c, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7c, 0x12, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7c, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0c, 0x02, 0x02, 0x12, 0x03, 0x7d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x7d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x7d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7d, 0x1b,
+    0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x03, 0x12, 0x03, 0x7e, 0x02, 0x22, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x04, 0x12, 0x03, 0x7e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x03, 0x05, 0x12, 0x03, 0x7e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x7e, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x7e, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x04, 0x12, 0x03,
+    0x7f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x04, 0x12, 0x03, 0x7f, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x05, 0x12, 0x03, 0x7f, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7f, 0x12, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x04, 0x03, 0x12, 0x03, 0x7f, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0d, 0x12, 0x06, 0x82, 0x01, 0x00, 0x92, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01,
+    0x12, 0x04, 0x82, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x04,
+    0x83, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x83,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x12,
+    0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x01, 0x1e, 0x1f,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x02, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x01, 0x06, 0x12, 0x04, 0x84, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d,
+    0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0x85, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x06,
+    0x12, 0x04, 0x85, 0x01, 0x0b, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x85, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0x85, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01,
+    0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x04, 0x12, 0x04, 0x86, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x05, 0x12, 0x04, 0x86, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x04, 0x86, 0x01, 0x12, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x04, 0x86, 0x01, 0x1e, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04, 0x12, 0x04, 0x87, 0x01, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x04, 0x04, 0x12, 0x04, 0x87, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x04, 0x06, 0x12, 0x04, 0x87, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x87, 0x01, 0x19, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0x87, 0x01, 0x2a, 0x2b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x05,
+    0x12, 0x04, 0x88, 0x01, 0x02, 0x37, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x04, 0x12,
+    0x04, 0x88, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x05, 0x12, 0x04,
+    0x88, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x01, 0x12, 0x04, 0x88,
+    0x01, 0x10, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x03, 0x12, 0x04, 0x88, 0x01,
+    0x23, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x08, 0x12, 0x04, 0x88, 0x01, 0x25,
+    0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x07, 0x12, 0x04, 0x88, 0x01, 0x30, 0x35,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x06, 0x12, 0x04, 0x89, 0x01, 0x02, 0x28, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x06, 0x04, 0x12, 0x04, 0x89, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x06, 0x06, 0x12, 0x04, 0x89, 0x01, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x06, 0x01, 0x12, 0x04, 0x89, 0x01, 0x17, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x06, 0x03, 0x12, 0x04, 0x89, 0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d,
+    0x02, 0x07, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07,
+    0x04, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x05,
+    0x12, 0x04, 0x8a, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0x8a, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x03, 0x12, 0x04,
+    0x8a, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x08, 0x12, 0x04, 0x8b, 0x01,
+    0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x05, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x12, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x1a, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0d, 0x02, 0x09, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x09, 0x04, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x09, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x09, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x09, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x1b, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x0a,
+    0x12, 0x04, 0x8d, 0x01, 0x02, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0a, 0x04, 0x12,
+    0x04, 0x8d, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0a, 0x06, 0x12, 0x04,
+    0x8d, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x8d,
+    0x01, 0x19, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0a, 0x03, 0x12, 0x04, 0x8d, 0x01,
+    0x2a, 0x2c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x0b, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x29,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0b, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0b, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0b, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x12, 0x23, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x0b, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x26, 0x28, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0d, 0x02, 0x0c, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x0c, 0x04, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x0c, 0x05, 0x12, 0x04, 0x8f, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0c,
+    0x01, 0x12, 0x04, 0x8f, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0c, 0x03,
+    0x12, 0x04, 0x8f, 0x01, 0x1f, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x0d, 0x12, 0x04,
+    0x90, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0d, 0x04, 0x12, 0x04, 0x90,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0d, 0x05, 0x12, 0x04, 0x90, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0d, 0x01, 0x12, 0x04, 0x90, 0x01, 0x12,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0d, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1a, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x0e, 0x12, 0x04, 0x91, 0x01, 0x02, 0x37, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x0e, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x0e, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x0e, 0x01, 0x12, 0x04, 0x91, 0x01, 0x10, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x0e, 0x03, 0x12, 0x04, 0x91, 0x01, 0x22, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x0e, 0x08, 0x12, 0x04, 0x91, 0x01, 0x25, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x0e, 0x07, 0x12, 0x04, 0x91, 0x01, 0x30, 0x35, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06,
+    0x94, 0x01, 0x00, 0xa1, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x94,
+    0x01, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x04, 0x00, 0x12, 0x04, 0x95, 0x01, 0x02,
+    0x4e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x04, 0x00, 0x01, 0x12, 0x04, 0x95, 0x01, 0x07, 0x0b,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x95, 0x01, 0x0e, 0x17,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x95, 0x01, 0x0e,
+    0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x95, 0x01,
+    0x15, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x95, 0x01,
+    0x18, 0x20, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95,
+    0x01, 0x18, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04,
+    0x95, 0x01, 0x1e, 0x1f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04,
+    0x95, 0x01, 0x21, 0x2d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x95, 0x01, 0x21, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x04, 0x95, 0x01, 0x2b, 0x2c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03,
+    0x12, 0x04, 0x95, 0x01, 0x2e, 0x38, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0x95, 0x01, 0x2e, 0x33, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02,
+    0x03, 0x02, 0x12, 0x04, 0x95, 0x01, 0x36, 0x37, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00,
+    0x02, 0x04, 0x12, 0x04, 0x95, 0x01, 0x39, 0x4c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x95, 0x01, 0x39, 0x47, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04,
+    0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x95, 0x01, 0x4a, 0x4b, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x0e,
+    0x02, 0x00, 0x12, 0x04, 0x98, 0x01, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74,
+    0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x98, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x06, 0x12, 0x04, 0x98, 0x01, 0x0b, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x98, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x98, 0x01, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x04,
+    0x04, 0x0e, 0x08, 0x00, 0x12, 0x06, 0x99, 0x01, 0x02, 0x9f, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x08, 0x00, 0x01, 0x12, 0x04, 0x99, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x01, 0x06, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0x9a, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0x9a, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04,
+    0x9b, 0x01, 0x04, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x06, 0x12, 0x04, 0x9b,
+    0x01, 0x04, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9b, 0x01,
+    0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9b, 0x01, 0x0e,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x06, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x0b, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x0c, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0x9c, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x04, 0x06, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x09, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x0a, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0x9d, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12,
+    0x04, 0x9e, 0x01, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0x9e, 0x01, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04, 0x9e,
+    0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0x9e, 0x01,
+    0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xa0, 0x01, 0x02, 0x25,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x04, 0x12, 0x04, 0xa0, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x06, 0x12, 0x04, 0xa0, 0x01, 0x0b, 0x15, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x16, 0x20, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0xa0, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x0f, 0x12, 0x06, 0xa3, 0x01, 0x00, 0xbd, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f,
+    0x01, 0x12, 0x04, 0xa3, 0x01, 0x08, 0x0d, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0f, 0x04, 0x00, 0x12,
+    0x06, 0xa4, 0x01, 0x02, 0xaf, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x04, 0x00, 0x01,
+    0x12, 0x04, 0xa4, 0x01, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x04, 0xa5, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xa5, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x04, 0xa5, 0x01, 0x0d, 0x0e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00,
+    0x02, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xa6, 0x01, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f,
+    0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0f, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa7, 0x01, 0x0f, 0x10, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0f, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xa8, 0x01, 0x14, 0x15, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x14, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x0f, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xa9, 0x01, 0x12, 0x13,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x0d,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x04,
+    0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xaa, 0x01,
+    0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0xab, 0x01,
+    0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0xab,
+    0x01, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04,
+    0xab, 0x01, 0x0c, 0x0d, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04,
+    0xac, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0xac, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x07, 0x02,
+    0x12, 0x04, 0xac, 0x01, 0x15, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x08,
+    0x12, 0x04, 0xad, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02, 0x08,
+    0x01, 0x12, 0x04, 0xad, 0x01, 0x04, 0x0d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00, 0x02,
+    0x08, 0x02, 0x12, 0x04, 0xad, 0x01, 0x10, 0x11, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0f, 0x04, 0x00,
+    0x02, 0x09, 0x12, 0x04, 0xae, 0x01, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04, 0x00,
+    0x02, 0x09, 0x01, 0x12, 0x04, 0xae, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0f, 0x04,
+    0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0xae, 0x01, 0x17, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f,
+    0x02, 0x00, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xb1, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xb1, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xb1, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0xb2, 0x01,
+    0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x04, 0xb2, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x12, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb2, 0x01, 0x18, 0x19, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x1c, 0x1d, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0f, 0x08, 0x00,
+    0x12, 0x06, 0xb4, 0x01, 0x02, 0xbc, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x08, 0x00,
+    0x01, 0x12, 0x04, 0xb4, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12,
+    0x04, 0xb5, 0x01, 0x04, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x06, 0x12, 0x04,
+    0xb5, 0x01, 0x04, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb5,
+    0x01, 0x0f, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb5, 0x01,
+    0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x01, 0x04, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x06, 0x12, 0x04, 0xb6, 0x01, 0x04, 0x0b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x0c, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb6, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0f, 0x02, 0x05, 0x12, 0x04, 0xb7, 0x01, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x05, 0x06, 0x12, 0x04, 0xb7, 0x01, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x05, 0x03, 0x12, 0x04, 0xb7, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x06,
+    0x12, 0x04, 0xb8, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x06, 0x12,
+    0x04, 0xb8, 0x01, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x01, 0x12, 0x04,
+    0xb8, 0x01, 0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x06, 0x03, 0x12, 0x04, 0xb8,
+    0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x07, 0x12, 0x04, 0xb9, 0x01, 0x04,
+    0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x07, 0x06, 0x12, 0x04, 0xb9, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x07, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x0d, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x07, 0x03, 0x12, 0x04, 0xb9, 0x01, 0x18, 0x19, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x08, 0x12, 0x04, 0xba, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x08, 0x06, 0x12, 0x04, 0xba, 0x01, 0x04, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x08, 0x01, 0x12, 0x04, 0xba, 0x01, 0x0e, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x08, 0x03, 0x12, 0x04, 0xba, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02,
+    0x09, 0x12, 0x04, 0xbb, 0x01, 0x04, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x06,
+    0x12, 0x04, 0xbb, 0x01, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x01, 0x12,
+    0x04, 0xbb, 0x01, 0x15, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x09, 0x03, 0x12, 0x04,
+    0xbb, 0x01, 0x2a, 0x2c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xbf, 0x01, 0x00, 0xc8,
+    0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x08, 0x0c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x10, 0x04, 0x00, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x2d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x04, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x07, 0x12, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xc0, 0x01, 0x15, 0x1e, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x15, 0x19, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xc0, 0x01, 0x1c, 0x1d, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x1f, 0x2b, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x1f, 0x26, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xc0, 0x01, 0x29, 0x2a,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x1e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc2, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc2, 0x01, 0x10, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10,
+    0x02, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xc3, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xc3, 0x01, 0x11, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xc3, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xc4, 0x01,
+    0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc4, 0x01, 0x0b, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x11, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x1b, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04,
+    0x12, 0x04, 0xc6, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x04, 0x12,
+    0x04, 0xc6, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0xc6, 0x01, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc6,
+    0x01, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc6, 0x01,
+    0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x1c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x04, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x0b, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x10, 0x17, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x05, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x11, 0x12, 0x06, 0xca, 0x01, 0x00, 0xcc, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11,
+    0x01, 0x12, 0x04, 0xca, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12,
+    0x04, 0xcb, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xcb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xcb,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xcb, 0x01,
+    0x11, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x19,
+    0x1a,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -21,11 +21,24 @@
 //! It uses a particular variant I think of as the "highlander" model. A given election will
 //! devolve to a single, universal rumor, which when it is received by the winner will result in
 //! the election finishing. There can, in the end, be only one.
-
+//!
+//! Winning an election only grants a leader a lease on leadership. The leader asks to renew the
+//! lease by bumping the `lease` counter of the finished election and gossiping it again. Every
+//! member of the group that hears about the renewal acknowledges it in `lease_acks`, and the
+//! leader only extends its own lease once a quorum has; a leader cut off from the rest of the
+//! group can't renew, however healthy the group still looks from its side. Every member keeps its
+//! own clock for when the lease it last heard about runs out; when it does, the election is
+//! restarted with a new term. The leader counts its renewed lease from when it asked for it,
+//! before anyone could have heard, so its lease always runs out first. The term of a finished
+//! election only ever increases, so it doubles as a fencing token for the leader.
+
+use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, RwLock};
 
 use habitat_core::service::ServiceGroup;
 use protobuf::{Message, RepeatedField};
+use time::{Duration, SteadyTime};
 
 pub use message::swim::Election_Status;
 pub use types::rumor_election::*;
@@ -33,6 +46,15 @@ use error::Result;
 use message::swim::{Election as ProtoElection, Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
 
+/// How long a leader may lead without renewing its lease.
+pub const LEADER_LEASE_MS: i64 = 15000;
+
+/// A leader renews its lease once less than this much of it is left.
+pub const LEADER_LEASE_RENEW_MS: i64 = LEADER_LEASE_MS * 2 / 3;
+
+/// How long a leader waits for a quorum to acknowledge a renewal before it asks again.
+pub const LEADER_LEASE_RETRY_MS: i64 = 2000;
+
 impl From<ProtoRumor> for Election {
     fn from(pr: ProtoRumor) -> Election {
         Election(pr)
@@ -85,6 +107,20 @@ impl Election {
         Election(rumor)
     }
 
+    /// Create a request to hand leadership of the service group over to `member_id`, or, if it is
+    /// `None`, to any member but the current leader. It is not an election itself; whichever
+    /// member receives it starts a new term with the nominee as the candidate.
+    pub fn transfer_request<S1: Into<String>>(from_id: S1,
+                                              service_group: ServiceGroup,
+                                              member_id: Option<&str>)
+                                              -> Election {
+        let mut election = Election::new(from_id, service_group, 0);
+        election.set_member_id(String::from(member_id.unwrap_or("")));
+        election.mut_votes().clear();
+        election.set_transfer(true);
+        election
+    }
+
     /// Insert a vote for the election.
     pub fn insert_vote(&mut self, member_id: &str) {
         if !self.get_votes().contains(&String::from(member_id)) {
@@ -118,6 +154,119 @@ impl Election {
     pub fn is_finished(&self) -> bool {
         self.get_status() == Election_Status::Finished
     }
+
+    /// Ask to renew the winners lease on leadership for this term. The winner acknowledges its
+    /// own renewal; the rest of the group has yet to.
+    pub fn renew_lease(&mut self) {
+        let lease = self.get_lease();
+        self.set_lease(lease + 1);
+        let member_id = String::from(self.get_member_id());
+        self.mut_lease_acks().clear();
+        self.mut_lease_acks().push(member_id);
+    }
+
+    /// Acknowledge the current lease. Returns true if we had not already.
+    pub fn insert_lease_ack(&mut self, member_id: &str) -> bool {
+        if self.get_lease_acks().contains(&String::from(member_id)) {
+            false
+        } else {
+            self.mut_lease_acks().push(String::from(member_id));
+            true
+        }
+    }
+}
+
+/// When the leader lease of each service group runs out, from this members point of view. Is
+/// thread-safe.
+#[derive(Debug, Clone, Default)]
+pub struct LeaderLeases {
+    expires: Arc<RwLock<HashMap<String, SteadyTime>>>,
+    renewals: Arc<RwLock<HashMap<String, (u64, SteadyTime)>>>,
+}
+
+impl LeaderLeases {
+    pub fn new() -> LeaderLeases {
+        LeaderLeases::default()
+    }
+
+    /// Start a full lease for the service group.
+    pub fn grant(&self, service_group: &str) {
+        let mut expires = self.expires.write().expect("Leader lease lock is poisoned");
+        expires.insert(String::from(service_group),
+                       SteadyTime::now() + Duration::milliseconds(LEADER_LEASE_MS));
+    }
+
+    /// Forget the lease for the service group.
+    pub fn revoke(&self, service_group: &str) {
+        let mut expires = self.expires.write().expect("Leader lease lock is poisoned");
+        expires.remove(service_group);
+        let mut renewals = self.renewals.write().expect("Leader lease lock is poisoned");
+        renewals.remove(service_group);
+    }
+
+    /// Note that we, as the leader, have asked to renew our lease for the service group as
+    /// `lease`. It only counts once a quorum has acknowledged it.
+    pub fn renewing(&self, service_group: &str, lease: u64) {
+        let mut renewals = self.renewals.write().expect("Leader lease lock is poisoned");
+        renewals.insert(String::from(service_group), (lease, SteadyTime::now()));
+    }
+
+    /// Returns true if we asked to renew our lease for the service group recently enough that
+    /// the acknowledgements may still come.
+    pub fn is_renewing(&self, service_group: &str) -> bool {
+        let renewals = self.renewals.read().expect("Leader lease lock is poisoned");
+        match renewals.get(service_group) {
+            Some(&(_, asked)) => {
+                (SteadyTime::now() - asked).num_milliseconds() < LEADER_LEASE_RETRY_MS
+            }
+            None => false,
+        }
+    }
+
+    /// A quorum has acknowledged `lease` for the service group. If it is the renewal we asked
+    /// for, extend our lease, counting from when we asked. Returns true if it did.
+    pub fn confirm(&self, service_group: &str, lease: u64) -> bool {
+        let mut renewals = self.renewals.write().expect("Leader lease lock is poisoned");
+        let asked = match renewals.get(service_group) {
+            Some(&(renewal, asked)) if renewal == lease => asked,
+            _ => return false,
+        };
+        renewals.remove(service_group);
+        let mut expires = self.expires.write().expect("Leader lease lock is poisoned");
+        expires.insert(String::from(service_group),
+                       asked + Duration::milliseconds(LEADER_LEASE_MS));
+        true
+    }
+
+    /// Every service group with a lease that has not run out, in order.
+    pub fn held(&self) -> Vec<String> {
+        let expires = self.expires.read().expect("Leader lease lock is poisoned");
+        let now = SteadyTime::now();
+        let mut held: Vec<String> = expires.iter()
+            .filter(|&(_, expiry)| *expiry > now)
+            .map(|(service_group, _)| service_group.clone())
+            .collect();
+        held.sort();
+        held
+    }
+
+    /// Returns true if the service group has a lease that has not run out.
+    pub fn is_held(&self, service_group: &str) -> bool {
+        self.remaining_ms(service_group) > 0
+    }
+
+    /// Returns true if the lease for the service group is due to be renewed.
+    pub fn needs_renewal(&self, service_group: &str) -> bool {
+        self.remaining_ms(service_group) < LEADER_LEASE_RENEW_MS
+    }
+
+    fn remaining_ms(&self, service_group: &str) -> i64 {
+        let expires = self.expires.read().expect("Leader lease lock is poisoned");
+        match expires.get(service_group) {
+            Some(expiry) => (*expiry - SteadyTime::now()).num_milliseconds(),
+            None => 0,
+        }
+    }
 }
 
 impl PartialEq for Election {
@@ -127,7 +276,8 @@ impl PartialEq for Election {
         self.get_member_id() == other.get_member_id() &&
         self.get_suitability() == other.get_suitability() &&
         self.get_votes() == other.get_votes() && self.get_status() == other.get_status() &&
-        self.get_term() == other.get_term()
+        self.get_term() == other.get_term() && self.get_lease() == other.get_lease() &&
+        self.get_lease_acks() == other.get_lease_acks()
     }
 }
 
@@ -138,6 +288,20 @@ impl Rumor for Election {
             // If we are the same object, just return false
             // println!("Equal: {:?} {:?}", self, other);
             false
+        } else if other.get_term() == self.get_term() && self.is_finished() &&
+                  other.is_finished() && other.get_lease() == self.get_lease() {
+            // If we both have the same lease, put together who has acknowledged it, and keep
+            // sharing if either side was missing any.
+            let mut changed = false;
+            for member_id in other.get_lease_acks().iter() {
+                changed = self.insert_lease_ack(member_id) || changed;
+            }
+            changed || self.get_lease_acks().len() > other.get_lease_acks().len()
+        } else if other.get_term() == self.get_term() && self.is_finished() &&
+                  other.is_finished() && other.get_lease() < self.get_lease() {
+            // If we both have the same leader, but ours has a newer lease, the other side is
+            // stale; keep sharing ours.
+            true
         } else if other.get_term() >= self.get_term() &&
                   other.get_status() == Election_Status::Finished {
             // If the new rumors term is bigger or equal to ours, and it has a leader, we take it as
@@ -269,7 +433,7 @@ impl Rumor for ElectionUpdate {
 #[cfg(test)]
 mod tests {
     use rumor::Rumor;
-    use rumor::election::Election;
+    use rumor::election::{Election, LeaderLeases};
     use habitat_core::service::ServiceGroup;
 
     fn create_election(member_id: &str, suitability: u64) -> Election {
@@ -298,6 +462,82 @@ mod tests {
         assert_eq!(e1.get_votes().len(), 4);
     }
 
+    #[test]
+    fn merge_takes_renewed_lease() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        let mut e2 = e1.clone();
+        e2.renew_lease();
+        assert_eq!(e1.merge(e2), true);
+        assert_eq!(e1.get_lease(), 1);
+    }
+
+    #[test]
+    fn merge_keeps_newer_lease() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        let e2 = e1.clone();
+        e1.renew_lease();
+        assert_eq!(e1.merge(e2), true);
+        assert_eq!(e1.get_lease(), 1);
+    }
+
+    #[test]
+    fn leases_run_out() {
+        let leases = LeaderLeases::new();
+        assert!(!leases.is_held("tdep.prod"));
+        assert!(leases.needs_renewal("tdep.prod"));
+        leases.grant("tdep.prod");
+        assert!(leases.is_held("tdep.prod"));
+        assert!(!leases.needs_renewal("tdep.prod"));
+        leases.revoke("tdep.prod");
+        assert!(!leases.is_held("tdep.prod"));
+    }
+
+    #[test]
+    fn merge_puts_lease_acks_together() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        e1.renew_lease();
+        let mut e2 = e1.clone();
+        e2.insert_lease_ack("b");
+        let mut e3 = e1.clone();
+        e3.insert_lease_ack("c");
+        assert_eq!(e1.merge(e2), true);
+        assert_eq!(e1.merge(e3.clone()), true);
+        assert_eq!(e1.get_lease_acks(), &["a", "b", "c"]);
+        // The other side lacks "b", so ours is still worth sharing
+        assert_eq!(e3.merge(e1.clone()), true);
+        assert_eq!(e3.get_lease_acks().len(), 3);
+        assert_eq!(e1.merge(e3), false);
+    }
+
+    #[test]
+    fn renewing_a_lease_clears_its_acks() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        e1.renew_lease();
+        e1.insert_lease_ack("b");
+        e1.renew_lease();
+        assert_eq!(e1.get_lease(), 2);
+        assert_eq!(e1.get_lease_acks(), &["a"]);
+    }
+
+    #[test]
+    fn renewals_only_count_once_confirmed() {
+        let leases = LeaderLeases::new();
+        leases.renewing("tdep.prod", 1);
+        assert!(leases.is_renewing("tdep.prod"));
+        assert!(!leases.is_held("tdep.prod"));
+        assert!(!leases.confirm("tdep.prod", 2));
+        assert!(!leases.is_held("tdep.prod"));
+        assert!(leases.confirm("tdep.prod", 1));
+        assert!(leases.is_held("tdep.prod"));
+        assert!(!leases.is_renewing("tdep.prod"));
+        assert_eq!(leases.held(), vec![String::from("tdep.prod")]);
+        assert!(!leases.confirm("tdep.prod", 1));
+    }
+
     #[test]
     fn merge_four() {
         let mut e1 = create_election("a", 0);
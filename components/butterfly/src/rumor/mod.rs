@@ -18,7 +18,10 @@
 //! represents what "kind" of rumor it is (for example, a "member").
 //!
 //! These keys are added to a RumorList, which tracks each rumors spread to each member it sends
-//! to. Each rumor is shared with every member `RUMOR_MAX` times.
+//! to. Each rumor is shared with every member at most `RUMOR_MAX` times, and with no more members
+//! in total than the retransmit limit, which the push thread scales to the size of the ring. A
+//! member that joins after a rumor has gone cold would never hear it that way, so we still share
+//! the rumor with it once.
 //!
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
@@ -28,14 +31,16 @@ pub mod service;
 pub mod service_config;
 pub mod service_file;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::collections::hash_map::Entry;
 use std::default::Default;
 use std::result;
 use std::sync::{Arc, RwLock};
+use std::usize;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use serde::{Serialize, Serializer};
+use time::SteadyTime;
 
 use message::swim::Rumor_Type;
 use error::{Result, Error};
@@ -157,6 +162,16 @@ impl<T: Rumor + Clone> RumorStore<T> {
         result
     }
 
+    /// Returns true if inserting `rumor` would change the store; it would not if we already have
+    /// it, or something that supersedes it.
+    pub fn is_news(&self, rumor: &T) -> bool {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        match list.get(rumor.key()).and_then(|rumors| rumors.get(rumor.id())) {
+            Some(current) => current.clone().merge(rumor.clone()),
+            None => true,
+        }
+    }
+
     pub fn remove(&self, key: &str, id: &str) {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         list.get_mut(key).and_then(|mut r| r.remove(id));
@@ -215,42 +230,191 @@ pub const RUMOR_MAX: usize = 2;
 ///
 /// When a rumor changes, we re-insert it into the `RumorList` - this automatically sets all the
 /// counters for every member, and starts the sharing cycle over again.
+///
+/// It also remembers when we first saw the current version of each rumor, and how many times
+/// rumors of each kind have been new or changed, so we can tell how fast they spread; and when
+/// each rumor reached the retransmit limit, and when each member joined, so a member that joined
+/// later still hears it.
 #[derive(Debug, Clone)]
 pub struct RumorList {
     rumor_list: Arc<RwLock<HashMap<RumorKey, HashMap<String, usize>>>>,
+    first_seen: Arc<RwLock<HashMap<RumorKey, SteadyTime>>>,
+    inserts: Arc<RwLock<HashMap<Rumor_Type, u64>>>,
+    retransmit_limit: Arc<AtomicUsize>,
+    cold_at: Arc<RwLock<HashMap<RumorKey, SteadyTime>>>,
+    joined_at: Arc<RwLock<HashMap<String, SteadyTime>>>,
 }
 
 impl Default for RumorList {
     fn default() -> RumorList {
-        RumorList { rumor_list: Arc::new(RwLock::new(HashMap::new())) }
+        RumorList {
+            rumor_list: Arc::new(RwLock::new(HashMap::new())),
+            first_seen: Arc::new(RwLock::new(HashMap::new())),
+            inserts: Arc::new(RwLock::new(HashMap::new())),
+            retransmit_limit: Arc::new(AtomicUsize::new(usize::MAX)),
+            cold_at: Arc::new(RwLock::new(HashMap::new())),
+            joined_at: Arc::new(RwLock::new(HashMap::new())),
+        }
     }
 }
 
 pub type RumorVec = Vec<(RumorKey, usize)>;
 
+/// A rumor along with how many times we have shared it with each member, for reporting.
+struct RumorHeat<'a> {
+    key: &'a RumorKey,
+    heat: &'a HashMap<String, usize>,
+}
+
+impl<'a> Serialize for RumorHeat<'a> {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("rumor_heat", 3));
+        try!(serializer.serialize_struct_elt(&mut state, "id", &self.key.id));
+        try!(serializer.serialize_struct_elt(&mut state, "key", &self.key.key));
+        try!(serializer.serialize_struct_elt(&mut state, "heat", self.heat));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+/// Serializes as a map of rumor kind to every rumor of that kind, with its heat per member.
+impl Serialize for RumorList {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let rumors = self.rumor_list.read().expect("Rumor map lock poisoned");
+        let mut by_kind: BTreeMap<String, Vec<RumorHeat>> = BTreeMap::new();
+        for (rk, heat) in rumors.iter() {
+            by_kind.entry(format!("{:?}", rk.kind))
+                .or_insert_with(Vec::new)
+                .push(RumorHeat {
+                    key: rk,
+                    heat: heat,
+                });
+        }
+        by_kind.serialize(serializer)
+    }
+}
+
 impl RumorList {
     /// Add/Update a rumor to the list.
     pub fn insert<T: Into<RumorKey>>(&self, rumor: T) {
         let rk: RumorKey = rumor.into();
+        *self.inserts
+            .write()
+            .expect("Rumor inserts lock poisoned")
+            .entry(rk.kind.clone())
+            .or_insert(0) += 1;
+        self.first_seen
+            .write()
+            .expect("Rumor first seen lock poisoned")
+            .insert(rk.clone(), SteadyTime::now());
         let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        self.cold_at.write().expect("Rumor cold lock poisoned").remove(&rk);
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Stop sharing a rumor we no longer have.
+    pub fn remove(&self, rk: &RumorKey) {
+        self.first_seen.write().expect("Rumor first seen lock poisoned").remove(rk);
+        let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        self.cold_at.write().expect("Rumor cold lock poisoned").remove(rk);
+        rumors.remove(rk);
+    }
+
+    /// Remember that we have just heard of a member, so that rumors that have already gone cold
+    /// are still shared with it.
+    pub fn member_joined(&self, id: &str) {
+        self.joined_at
+            .write()
+            .expect("Rumor joined lock poisoned")
+            .insert(String::from(id), SteadyTime::now());
+    }
+
+    /// When we first saw the current version of a rumor.
+    pub fn first_seen(&self, rk: &RumorKey) -> Option<SteadyTime> {
+        self.first_seen.read().expect("Rumor first seen lock poisoned").get(rk).cloned()
+    }
+
+    /// How many times rumors of each kind have been new or changed.
+    pub fn inserts(&self) -> HashMap<Rumor_Type, u64> {
+        self.inserts.read().expect("Rumor inserts lock poisoned").clone()
+    }
+
+    /// Call the closure with every rumor, how many times we have shared it with each member, and
+    /// when we first saw it.
+    pub fn with_heat<F>(&self, mut with_closure: F)
+        where F: FnMut(&RumorKey, &HashMap<String, usize>, Option<SteadyTime>)
+    {
+        let rumors = self.rumor_list.read().expect("Rumor map lock poisoned");
+        let first_seen = self.first_seen.read().expect("Rumor first seen lock poisoned");
+        for (rk, heat) in rumors.iter() {
+            with_closure(rk, heat, first_seen.get(rk).cloned());
+        }
+    }
+
+    /// How many times, in total, a rumor is shared before it goes cold for every member.
+    pub fn retransmit_limit(&self) -> usize {
+        self.retransmit_limit.load(Ordering::Relaxed)
+    }
+
+    /// Set how many times, in total, a rumor is shared before it goes cold for every member.
+    pub fn set_retransmit_limit(&self, limit: usize) {
+        self.retransmit_limit.store(limit, Ordering::Relaxed);
+    }
+
     /// Return a list of rumors, along with their current heat, sorted by heat. Lowest to highest.
-    /// So all the "0" rumors sort higher than the "2" rumors.
+    /// So all the "0" rumors sort before the "1" rumors, and the least transmitted rumors go out
+    /// first when there isn't room for them all. Ties go to the rumor we have shared least in
+    /// total.
+    ///
+    /// Rumors that have already been shared `retransmit_limit` times with anyone are left out,
+    /// unless the member joined after they went cold and we have not shared them with it yet.
     pub fn rumors(&self, id: &str) -> RumorVec {
+        self.rumors_where(id, |_| true)
+    }
+
+    /// Like `rumors`, but only the ones for which `keep` returns true.
+    fn rumors_where<F>(&self, id: &str, keep: F) -> RumorVec
+        where F: Fn(&RumorKey) -> bool
+    {
+        let limit = self.retransmit_limit();
         let rumors = self.rumor_list.read().expect("Rumor map lock poisoned");
-        let mut rumor_vec: RumorVec = rumors.iter()
+        let cold_at = self.cold_at.read().expect("Rumor cold lock poisoned");
+        let joined_at = self.joined_at.read().expect("Rumor joined lock poisoned");
+        let joined = joined_at.get(id);
+        let joined_since = |rk: &RumorKey| match (joined, cold_at.get(rk)) {
+            (Some(joined), Some(cold)) => joined >= cold,
+            _ => false,
+        };
+        let mut rumor_vec: Vec<(RumorKey, usize, usize)> = rumors.iter()
+            .filter(|&(rk, _)| keep(rk))
             .map(|(rk, heat_map)| {
+                let total = heat_map.values().fold(0, |total, h| total + h);
                 match heat_map.get(id) {
-                    Some(h) => (rk.clone(), h.clone()),
-                    None => (rk.clone(), 0),
+                    Some(h) => (rk.clone(), h.clone(), total),
+                    None => (rk.clone(), 0, total),
                 }
             })
-            .filter(|&(ref _rk, heat)| heat < RUMOR_MAX)
+            .filter(|&(ref rk, heat, total)| {
+                heat < RUMOR_MAX && (total < limit || (heat == 0 && joined_since(rk)))
+            })
             .collect();
-        rumor_vec.sort_by(|&(ref _a_rk, ref a_heat), &(ref _b_rk, ref b_heat)| b_heat.cmp(&a_heat));
-        rumor_vec
+        rumor_vec.sort_by(|&(ref _a_rk, a_heat, a_total), &(ref _b_rk, b_heat, b_total)| {
+            (a_heat, a_total).cmp(&(b_heat, b_total))
+        });
+        rumor_vec.into_iter().map(|(rk, heat, _total)| (rk, heat)).collect()
+    }
+
+    /// Every rumor we know, whatever its heat.
+    pub fn all_rumors(&self) -> RumorVec {
+        self.rumor_list
+            .read()
+            .expect("Rumor map lock poisoned")
+            .keys()
+            .map(|rk| (rk.clone(), 0))
+            .collect()
     }
 
     /// Take a certain amount of rumors.
@@ -260,16 +424,13 @@ impl RumorList {
 
     /// Take a certain amount of rumors of a given kind.
     pub fn take_by_kind(&self, id: &str, amount: usize, kind: Rumor_Type) -> RumorVec {
-        self.rumors(id)
-            .into_iter()
-            .filter(|&(ref rk, ref _heat)| rk.kind == kind)
-            .take(amount)
-            .collect()
+        self.rumors_where(id, |rk| rk.kind == kind).into_iter().take(amount).collect()
     }
 
     /// Increment the heat for a given member for the list of rumors given.
     pub fn update_heat(&self, id: &str, rumors: &RumorVec) {
         if rumors.len() > 0 {
+            let limit = self.retransmit_limit();
             let mut rumor_map = self.rumor_list.write().expect("Rumor map lock poisoned");
             for &(ref rk, ref _heat) in rumors {
                 if rumor_map.contains_key(&rk) {
@@ -280,6 +441,13 @@ impl RumorList {
                     } else {
                         heat_map.insert(String::from(id), 1);
                     }
+                    if heat_map.values().fold(0, |acc, heat| acc + heat) >= limit {
+                        self.cold_at
+                            .write()
+                            .expect("Rumor cold lock poisoned")
+                            .entry(rk.clone())
+                            .or_insert_with(SteadyTime::now);
+                    }
                 } else {
                     debug!("Rumor does not exist in map; was probably deleted between retrieval \
                             and sending");
@@ -444,6 +612,16 @@ mod tests {
             assert_eq!(rs.insert(f2), false);
         }
 
+        #[test]
+        fn a_rumor_we_already_have_is_not_news() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            assert!(rs.is_news(&f1));
+            rs.insert(f1.clone());
+            assert!(!rs.is_news(&f1));
+            assert!(rs.is_news(&FakeRumor::default()));
+        }
+
         #[test]
         fn with_rumor_calls_closure_with_rumor() {
             let rs = create_rumor_store();
@@ -462,6 +640,8 @@ mod tests {
     }
 
     mod rumor_list {
+        use serde_json::{self, Value};
+
         use super::{FakeRumor, TrumpRumor};
         use message::swim::Rumor_Type;
         use rumor::{RumorList, RUMOR_MAX};
@@ -551,6 +731,24 @@ mod tests {
                        5);
         }
 
+        #[test]
+        fn rumors_least_transmitted_first() {
+            let rl = RumorList::default();
+            for _ in 0..10 {
+                let rumor = FakeRumor::default();
+                rl.insert(&rumor);
+            }
+            let sent = rl.take("a", 5);
+            rl.update_heat("a", &sent);
+            // Cold for "a" first
+            let rumors = rl.rumors("a");
+            assert!(rumors[..5].iter().all(|&(ref _rk, heat)| heat == 0));
+            assert!(rumors[5..].iter().all(|&(ref _rk, heat)| heat == 1));
+            // For "b", the ones nobody has heard yet come first
+            let rumors = rl.take("b", 5);
+            assert!(rumors.iter().all(|&(ref rk, _heat)| !sent.iter().any(|&(ref s, _)| s == rk)));
+        }
+
         #[test]
         fn rumor_list_obeys_max_heat() {
             let rl = RumorList::default();
@@ -565,5 +763,60 @@ mod tests {
             assert_eq!(rl.rumors(&String::from("fake")).len(), 5);
         }
 
+        #[test]
+        fn rumor_list_obeys_retransmit_limit() {
+            let rl = RumorList::default();
+            for _ in 0..10 {
+                let rumor = FakeRumor::default();
+                rl.insert(&rumor);
+            }
+            rl.set_retransmit_limit(2);
+            let rumors = rl.rumors("a");
+            rl.update_heat("a", &rumors);
+            assert_eq!(rl.rumors("c").len(), 10);
+            let rumors = rl.take("b", 5);
+            rl.update_heat("b", &rumors);
+            assert_eq!(rl.rumors("c").len(), 5);
+            assert_eq!(rl.rumors("a").len(), 5);
+            assert_eq!(rl.all_rumors().len(), 10);
+        }
+
+        #[test]
+        fn serializes_rumors_by_kind_with_their_heat_per_member() {
+            let rl = RumorList::default();
+            let fake = FakeRumor::default();
+            rl.insert(&fake);
+            rl.insert(&TrumpRumor::default());
+            let rumors = rl.rumors("a");
+            rl.update_heat("a", &rumors);
+            let value: Value = serde_json::from_str(&serde_json::to_string(&rl).unwrap()).unwrap();
+            let by_kind = value.as_object().expect("Rumor list is not an object");
+            assert_eq!(by_kind.len(), 2);
+            assert_eq!(by_kind.get("Fake2").and_then(|v| v.as_array()).map(|v| v.len()),
+                       Some(1));
+            let fakes = by_kind.get("Fake").and_then(|v| v.as_array()).unwrap();
+            assert_eq!(fakes.len(), 1);
+            let rumor = fakes[0].as_object().unwrap();
+            assert_eq!(rumor.get("id").and_then(|v| v.as_str()), Some(&fake.id[..]));
+            assert_eq!(rumor.get("key").and_then(|v| v.as_str()), Some("fakerton"));
+            let heat = rumor.get("heat").and_then(|v| v.as_object()).unwrap();
+            assert_eq!(heat.get("a").and_then(|v| v.as_u64()), Some(1));
+        }
+
+        #[test]
+        fn a_member_that_joins_after_a_rumor_went_cold_still_hears_it_once() {
+            let rl = RumorList::default();
+            rl.insert(&FakeRumor::default());
+            rl.set_retransmit_limit(1);
+            let rumors = rl.rumors("a");
+            rl.update_heat("a", &rumors);
+            assert_eq!(rl.rumors("b").len(), 0);
+            rl.member_joined("b");
+            let rumors = rl.rumors("b");
+            assert_eq!(rumors.len(), 1);
+            rl.update_heat("b", &rumors);
+            assert_eq!(rl.rumors("b").len(), 0);
+            assert_eq!(rl.rumors("c").len(), 0);
+        }
     }
 }
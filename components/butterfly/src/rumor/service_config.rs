@@ -17,18 +17,21 @@
 //! Holds the toml configuration injected for a service.
 
 use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, RwLock};
 
-use habitat_core::crypto::{BoxKeyPair, default_cache_key_path};
+use habitat_core::crypto::{BoxKeyPair, SigKeyPair, default_cache_key_path};
 use habitat_core::service::ServiceGroup;
 use protobuf::Message;
 
 pub use types::rumor_service_config::*;
 use error::{Error, Result};
-use message::swim::{ServiceConfig as ProtoServiceConfig, Rumor as ProtoRumor,
+use message::swim::{ServiceConfig as ProtoServiceConfig, ServiceConfig_Mode, Rumor as ProtoRumor,
                     Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
+use server::signer_policy::SignerKeys;
 
 impl PartialOrd for ServiceConfig {
     fn partial_cmp(&self, other: &ServiceConfig) -> Option<Ordering> {
@@ -45,7 +48,10 @@ impl PartialEq for ServiceConfig {
         self.get_service_group() == other.get_service_group() &&
         self.get_incarnation() == other.get_incarnation() &&
         self.get_encrypted() == other.get_encrypted() &&
-        self.get_config() == other.get_config()
+        self.get_config() == other.get_config() && self.get_mode() == other.get_mode() &&
+        self.get_delete_keys() == other.get_delete_keys() &&
+        self.get_base_incarnation() == other.get_base_incarnation() &&
+        self.get_rollout() == other.get_rollout()
     }
 }
 
@@ -113,17 +119,113 @@ impl ServiceConfig {
             Ok(config)
         }
     }
+
+    /// Sign the rumor with a user's signing key, recording the signer alongside it. The signature
+    /// covers the service group, incarnation and config as they are when this is called, so
+    /// sign after encrypting.
+    pub fn sign(&mut self, signer: &SigKeyPair) -> Result<()> {
+        let signature = try!(signer.sign(&self.signed_bytes()));
+        self.set_signer(signer.name_with_rev());
+        self.set_signature(signature);
+        Ok(())
+    }
+
+    /// Verify the signature with the signer's public key from `keys`, returning the name of the
+    /// key that signed this rumor.
+    pub fn verify(&self, keys: &SignerKeys) -> Result<String> {
+        if !self.has_signer() {
+            return Err(Error::UnsignedRumor(String::from(self.get_service_group())));
+        }
+        let signer = try!(keys.get(self.get_signer()));
+        try!(signer.verify(self.get_signature(), &self.signed_bytes()));
+        Ok(signer.name)
+    }
+
+    fn signed_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!("{}\n{}\n{}\n",
+                                self.get_service_group(),
+                                self.get_incarnation(),
+                                self.get_encrypted())
+            .into_bytes();
+        // Replacements are signed as they were before there were patches, so their signatures
+        // still check out
+        if self.get_mode() == ServiceConfig_Mode::Patch {
+            bytes.extend_from_slice(format!("patch\n{}\n{}\n",
+                                            self.get_base_incarnation(),
+                                            self.get_delete_keys().len())
+                .as_bytes());
+            // Each key is prefixed with its length, so no list of keys reads the same as another
+            for key in self.get_delete_keys() {
+                bytes.extend_from_slice(format!("{}:{}\n", key.len(), key).as_bytes());
+            }
+        }
+        if self.has_rollout() {
+            let rollout = self.get_rollout();
+            bytes.extend_from_slice(format!("rollout\n{}\n{}\n{}\n{}\n",
+                                            rollout.get_batch_size(),
+                                            rollout.get_batch_percent(),
+                                            rollout.get_health_gate(),
+                                            rollout.get_pause_secs())
+                .as_bytes());
+            // Policies without a roster are signed as they were before they had one
+            if !rollout.get_members().is_empty() {
+                bytes.extend_from_slice(format!("members\n{}\n", rollout.get_members().len())
+                    .as_bytes());
+                for id in rollout.get_members() {
+                    bytes.extend_from_slice(format!("{}:{}\n", id.len(), id).as_bytes());
+                }
+            }
+        }
+        bytes.extend_from_slice(self.get_config());
+        bytes
+    }
+
+    /// Returns true if this config should take the place of `other`: it is a later incarnation,
+    /// or the same incarnation with different contents that win a tie-break every member makes
+    /// the same way, so two configs sent as the same incarnation at once settle on one.
+    pub fn supersedes(&self, other: &ServiceConfig) -> bool {
+        match self.get_incarnation().cmp(&other.get_incarnation()) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => self.tie_break_key() > other.tie_break_key(),
+        }
+    }
+
+    fn tie_break_key(&self) -> (&[u8], &[String], u64, &str) {
+        (self.get_config(), self.get_delete_keys(), self.get_base_incarnation(), self.get_signer())
+    }
+
+    /// What applying this config does to the gossip layer of the service group's config.
+    pub fn change(&self) -> Result<ConfigChange> {
+        let config = try!(self.config());
+        match self.get_mode() {
+            ServiceConfig_Mode::Replace => Ok(ConfigChange::Replace(config)),
+            ServiceConfig_Mode::Patch => {
+                Ok(ConfigChange::Patch(config, self.get_delete_keys().to_vec()))
+            }
+        }
+    }
+}
+
+/// A change to the gossip layer of a service group's config.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigChange {
+    /// The TOML to use in place of the whole layer
+    Replace(String),
+    /// TOML to merge into the layer, and the dotted paths of keys to remove from it afterwards
+    Patch(String, Vec<String>),
 }
 
 impl Rumor for ServiceConfig {
     /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
-    /// new one wins. So far, these never change.
+    /// new one wins. Two different configs with the same incarnation are settled by
+    /// `supersedes`, so every member keeps the same one.
     fn merge(&mut self, mut other: ServiceConfig) -> bool {
-        if *self >= other {
-            false
-        } else {
+        if other.supersedes(self) {
             mem::swap(self, &mut other);
             true
+        } else {
+            false
         }
     }
 
@@ -144,14 +246,150 @@ impl Rumor for ServiceConfig {
     }
 }
 
+/// How many service configs we keep for each service group, the current one included.
+pub const CONFIG_HISTORY_MAX: usize = 10;
+
+/// The service configs we have seen for each service group, so an operator can see what changed
+/// and go back to an earlier one. Is thread-safe.
+#[derive(Debug, Clone)]
+pub struct ConfigHistory {
+    list: Arc<RwLock<HashMap<String, VecDeque<ServiceConfig>>>>,
+}
+
+impl Default for ConfigHistory {
+    fn default() -> ConfigHistory {
+        ConfigHistory { list: Arc::new(RwLock::new(HashMap::new())) }
+    }
+}
+
+impl ConfigHistory {
+    /// Record a service config we have received, in incarnation order, forgetting the oldest
+    /// once there are more than `CONFIG_HISTORY_MAX`. Configs older than the current one are
+    /// kept too, since a patch may need them; of two with the same incarnation, the one that
+    /// `supersedes` the other is kept.
+    pub fn insert(&self, service_config: ServiceConfig) {
+        let mut list = self.list.write().expect("Config history lock poisoned");
+        let configs = list.entry(String::from(service_config.get_service_group()))
+            .or_insert(VecDeque::new());
+        let incarnation = service_config.get_incarnation();
+        match configs.iter().position(|sc| sc.get_incarnation() >= incarnation) {
+            Some(index) if configs[index].get_incarnation() == incarnation => {
+                if service_config.supersedes(&configs[index]) {
+                    configs[index] = service_config;
+                }
+            }
+            Some(index) => configs.insert(index, service_config),
+            None => configs.push_back(service_config),
+        }
+        while configs.len() > CONFIG_HISTORY_MAX {
+            configs.pop_front();
+        }
+    }
+
+    /// Returns true if inserting `service_config` would change the history.
+    pub fn is_news(&self, service_config: &ServiceConfig) -> bool {
+        let list = self.list.read().expect("Config history lock poisoned");
+        let configs = match list.get(service_config.get_service_group()) {
+            Some(configs) => configs,
+            None => return true,
+        };
+        let incarnation = service_config.get_incarnation();
+        match configs.iter().find(|sc| sc.get_incarnation() == incarnation) {
+            Some(sc) => service_config.supersedes(sc),
+            None => {
+                configs.len() < CONFIG_HISTORY_MAX ||
+                configs.front().map_or(true, |oldest| incarnation > oldest.get_incarnation())
+            }
+        }
+    }
+
+    /// Forget every config for which `keep` returns false.
+    pub fn retain<F>(&self, mut keep: F)
+        where F: FnMut(&ServiceConfig) -> bool
+    {
+        let mut list = self.list.write().expect("Config history lock poisoned");
+        for configs in list.values_mut() {
+            configs.retain(|sc| keep(sc));
+        }
+    }
+
+    /// The changes that take a service group's config to `latest`, oldest first and ending with
+    /// `latest` itself. A replacement stands alone; a patch comes after the config it patches,
+    /// back to a replacement, to the first config the service group had, or to `applied`, the
+    /// incarnation we already have. Fails if we never received one of the configs in between,
+    /// rather than leave this member with a different config to the rest of the ring.
+    pub fn chain(&self,
+                 latest: &ServiceConfig,
+                 applied: Option<u64>)
+                 -> Result<Vec<ServiceConfig>> {
+        let history = self.for_service_group(latest.get_service_group());
+        let mut chain = vec![latest.clone()];
+        loop {
+            let (base, incarnation) = {
+                let oldest = chain.last().expect("Config chain is never empty");
+                if oldest.get_mode() == ServiceConfig_Mode::Replace {
+                    break;
+                }
+                (oldest.get_base_incarnation(), oldest.get_incarnation())
+            };
+            if base == 0 || applied == Some(base) {
+                break;
+            }
+            // A patch only ever builds on an earlier incarnation; anything else would send us
+            // round in circles
+            let found = if base < incarnation {
+                history.iter().find(|sc| sc.get_incarnation() == base)
+            } else {
+                None
+            };
+            match found {
+                Some(sc) => chain.push(sc.clone()),
+                None => {
+                    return Err(Error::ConfigChainGap(String::from(latest.get_service_group()),
+                                                     latest.get_incarnation(),
+                                                     base))
+                }
+            }
+        }
+        chain.reverse();
+        Ok(chain)
+    }
+
+    /// Every service config we remember for a service group, newest first.
+    pub fn for_service_group(&self, service_group: &str) -> Vec<ServiceConfig> {
+        let list = self.list.read().expect("Config history lock poisoned");
+        match list.get(service_group) {
+            Some(configs) => configs.iter().rev().cloned().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Call the closure with every service config we remember, oldest first within each service
+    /// group.
+    pub fn with_configs<F>(&self, mut with_closure: F)
+        where F: FnMut(&ServiceConfig)
+    {
+        let list = self.list.read().expect("Config history lock poisoned");
+        for configs in list.values() {
+            for service_config in configs.iter() {
+                with_closure(service_config);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp::Ordering;
 
+    use habitat_core::crypto::SigKeyPair;
     use habitat_core::service::ServiceGroup;
 
-    use super::ServiceConfig;
+    use super::{ConfigChange, ConfigHistory, ServiceConfig, CONFIG_HISTORY_MAX};
+    use error::Error;
+    use message::swim::ServiceConfig_Mode;
     use rumor::Rumor;
+    use server::signer_policy::SignerKeys;
 
     fn create_service_config(member_id: &str, config: &str) -> ServiceConfig {
         let config_bytes: Vec<u8> = Vec::from(config);
@@ -228,4 +466,193 @@ mod tests {
         let s1 = create_service_config("adam", "yep");
         assert_eq!(s1.config().unwrap(), String::from("yep"));
     }
+
+    // Signing
+    #[test]
+    fn signing_records_the_signer() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_config("adam", "yep");
+        s1.sign(&signer).unwrap();
+        assert_eq!(s1.get_signer(), signer.name_with_rev());
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_does_not_cover_a_changed_config() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_config("adam", "yep");
+        s1.sign(&signer).unwrap();
+        s1.set_config(Vec::from("nope"));
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_does_not_cover_a_patch_turned_into_a_replacement() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_config("adam", "yep");
+        s1.set_mode(ServiceConfig_Mode::Patch);
+        s1.sign(&signer).unwrap();
+        s1.set_mode(ServiceConfig_Mode::Replace);
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    fn signature_tells_delete_keys_apart_from_keys_with_commas() {
+        let mut s1 = create_service_config("adam", "");
+        s1.set_mode(ServiceConfig_Mode::Patch);
+        let mut s2 = s1.clone();
+        s1.mut_delete_keys().push(String::from("a,b"));
+        s2.mut_delete_keys().push(String::from("a"));
+        s2.mut_delete_keys().push(String::from("b"));
+        assert!(s1.signed_bytes() != s2.signed_bytes());
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_covers_the_rollout_policy() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_config("adam", "yep");
+        s1.mut_rollout().set_batch_size(1);
+        s1.sign(&signer).unwrap();
+        s1.mut_rollout().set_batch_size(100);
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_covers_the_rollout_roster() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_config("adam", "yep");
+        s1.mut_rollout().mut_members().push(String::from("a"));
+        s1.mut_rollout().mut_members().push(String::from("b"));
+        s1.sign(&signer).unwrap();
+        s1.mut_rollout().mut_members().swap(0, 1);
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    fn a_patch_changes_the_layer_by_merging_and_deleting() {
+        let mut s1 = create_service_config("adam", "yep");
+        assert_eq!(s1.change().unwrap(), ConfigChange::Replace(String::from("yep")));
+        s1.set_mode(ServiceConfig_Mode::Patch);
+        s1.mut_delete_keys().push(String::from("nope"));
+        assert_eq!(s1.change().unwrap(),
+                   ConfigChange::Patch(String::from("yep"), vec![String::from("nope")]));
+    }
+
+    #[test]
+    fn verify_rejects_unsigned_rumors() {
+        let s1 = create_service_config("adam", "yep");
+        match s1.verify(&SignerKeys::default()) {
+            Err(Error::UnsignedRumor(_)) => {}
+            _ => panic!("Unsigned service config passed verification"),
+        }
+    }
+
+    #[test]
+    fn config_history_is_newest_first_and_bounded() {
+        let history = ConfigHistory::default();
+        for incarnation in 0..(CONFIG_HISTORY_MAX as u64 + 3) {
+            let mut sc = create_service_config("adam", "yep");
+            sc.set_incarnation(incarnation);
+            history.insert(sc);
+        }
+        let configs = history.for_service_group("neurosis.production");
+        assert_eq!(configs.len(), CONFIG_HISTORY_MAX);
+        assert_eq!(configs[0].get_incarnation(), CONFIG_HISTORY_MAX as u64 + 2);
+        assert_eq!(configs[CONFIG_HISTORY_MAX - 1].get_incarnation(), 3);
+        assert!(history.for_service_group("redis.default").is_empty());
+    }
+
+    #[test]
+    fn config_history_knows_what_is_news() {
+        let history = ConfigHistory::default();
+        let mut s1 = create_service_config("adam", "a = 1");
+        s1.set_incarnation(1);
+        assert!(history.is_news(&s1));
+        history.insert(s1.clone());
+        assert!(!history.is_news(&s1));
+        let mut s2 = s1.clone();
+        s2.set_incarnation(2);
+        assert!(history.is_news(&s2));
+        for incarnation in 3..(CONFIG_HISTORY_MAX as u64 + 3) {
+            s2.set_incarnation(incarnation);
+            history.insert(s2.clone());
+        }
+        let mut old = s1.clone();
+        old.set_incarnation(0);
+        assert!(!history.is_news(&old));
+    }
+
+    #[test]
+    fn configs_with_the_same_incarnation_settle_on_one() {
+        let mut s1 = create_service_config("adam", "a = 1");
+        s1.set_incarnation(2);
+        let mut s2 = create_service_config("adam", "b = 2");
+        s2.set_incarnation(2);
+        let mut kept_by_one = s1.clone();
+        kept_by_one.merge(s2.clone());
+        let mut kept_by_other = s2.clone();
+        kept_by_other.merge(s1.clone());
+        assert_eq!(kept_by_one, kept_by_other);
+        assert!(s2.supersedes(&s1));
+        assert!(!s1.supersedes(&s2));
+    }
+
+    #[test]
+    fn config_history_keeps_incarnation_order_and_one_config_per_incarnation() {
+        let history = ConfigHistory::default();
+        for &(incarnation, config) in [(3, "a = 1"), (1, "a = 1"), (3, "b = 2"), (2, "a = 1")]
+            .iter() {
+            let mut sc = create_service_config("adam", config);
+            sc.set_incarnation(incarnation);
+            history.insert(sc);
+        }
+        let configs = history.for_service_group("neurosis.production");
+        let incarnations: Vec<u64> = configs.iter().map(|sc| sc.get_incarnation()).collect();
+        assert_eq!(incarnations, vec![3, 2, 1]);
+        assert_eq!(configs[0].config().unwrap(), "b = 2");
+    }
+
+    fn patch(incarnation: u64, base: u64) -> ServiceConfig {
+        let mut sc = create_service_config("adam", "yep");
+        sc.set_incarnation(incarnation);
+        sc.set_mode(ServiceConfig_Mode::Patch);
+        sc.set_base_incarnation(base);
+        sc
+    }
+
+    #[test]
+    fn config_chain_follows_patches_back_to_what_we_have() {
+        let history = ConfigHistory::default();
+        let mut replacement = create_service_config("adam", "yep");
+        replacement.set_incarnation(1);
+        history.insert(replacement);
+        history.insert(patch(2, 1));
+        history.insert(patch(4, 2));
+        let incarnations = |chain: Vec<ServiceConfig>| -> Vec<u64> {
+            chain.iter().map(|sc| sc.get_incarnation()).collect()
+        };
+        assert_eq!(incarnations(history.chain(&patch(5, 4), None).unwrap()),
+                   vec![1, 2, 4, 5]);
+        assert_eq!(incarnations(history.chain(&patch(5, 4), Some(2)).unwrap()),
+                   vec![4, 5]);
+        assert_eq!(incarnations(history.chain(&patch(1, 0), None).unwrap()), vec![1]);
+    }
+
+    #[test]
+    fn config_chain_refuses_gaps_and_loops() {
+        let history = ConfigHistory::default();
+        history.insert(patch(3, 3));
+        match history.chain(&patch(5, 4), Some(1)) {
+            Err(Error::ConfigChainGap(_, 5, 4)) => {}
+            other => panic!("Expected a gap at incarnation 4, got {:?}", other),
+        }
+        match history.chain(&patch(3, 3), None) {
+            Err(Error::ConfigChainGap(_, 3, 3)) => {}
+            other => panic!("Expected a patch of itself to be refused, got {:?}", other),
+        }
+    }
 }
@@ -19,16 +19,31 @@
 use std::cmp::Ordering;
 use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::path::Path;
 
-use habitat_core::crypto::{BoxKeyPair, default_cache_key_path};
+use habitat_core::crypto::{BoxKeyPair, SigKeyPair, default_cache_key_path, hash};
 use habitat_core::service::ServiceGroup;
 use protobuf::Message;
+use time;
 
 pub use types::rumor_service_file::*;
-use error::Result;
+use chunk::{self, CHUNK_SIZE};
+use error::{Error, Result};
 use message::swim::{ServiceFile as ProtoServiceFile, Rumor as ProtoRumor,
                     Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
+use server::signer_policy::SignerKeys;
+
+/// How long, in seconds, a tombstone stays in the ring before every member forgets it.
+pub const TOMBSTONE_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Whether `filename` names a file inside a service's files directory, rather than somewhere
+/// else on disk: it must not be empty, `.` or absolute, and may not contain a path separator or
+/// `..`.
+pub fn is_safe_filename(filename: &str) -> bool {
+    !filename.is_empty() && filename != "." && !filename.contains('/') &&
+    !filename.contains('\\') && !filename.contains("..") && !Path::new(filename).is_absolute()
+}
 
 impl PartialOrd for ServiceFile {
     fn partial_cmp(&self, other: &ServiceFile) -> Option<Ordering> {
@@ -45,7 +60,9 @@ impl PartialEq for ServiceFile {
         self.get_service_group() == other.get_service_group() &&
         self.get_incarnation() == other.get_incarnation() &&
         self.get_encrypted() == other.get_encrypted() &&
-        self.get_filename() == other.get_filename() && self.get_body() == other.get_body()
+        self.get_filename() == other.get_filename() && self.get_body() == other.get_body() &&
+        self.get_content_hash() == other.get_content_hash() &&
+        self.get_deleted() == other.get_deleted()
     }
 }
 
@@ -100,6 +117,26 @@ impl ServiceFile {
         ServiceFile(rumor)
     }
 
+    /// Creates a tombstone, which deletes a file from every member of the service group. It stays
+    /// in the ring like any other service file, so that members who join later delete the file
+    /// too, until a file of the same name is uploaded with a higher incarnation, or until
+    /// `TOMBSTONE_TTL_SECS` after it was made.
+    pub fn tombstone<S1, S2>(member_id: S1, service_group: ServiceGroup, filename: S2) -> Self
+        where S1: Into<String>,
+              S2: Into<String>
+    {
+        let mut service_file = ServiceFile::new(member_id, service_group, filename, Vec::new());
+        service_file.set_deleted(true);
+        service_file.set_deleted_at(time::get_time().sec);
+        service_file
+    }
+
+    /// Whether this is a tombstone that has outlived `TOMBSTONE_TTL_SECS`, as of `now` in
+    /// seconds since the epoch.
+    pub fn is_expired_tombstone(&self, now: i64) -> bool {
+        self.get_deleted() && self.get_deleted_at() + TOMBSTONE_TTL_SECS <= now
+    }
+
     /// Encrypt the contents of the service file
     pub fn encrypt(&mut self, user_pair: &BoxKeyPair, service_pair: &BoxKeyPair) -> Result<()> {
         let body = self.take_body();
@@ -110,14 +147,93 @@ impl ServiceFile {
     }
 
     /// Return the body of the service file as a stream of bytes. Always returns a new copy, due to
-    /// the fact that we might be encrypted.
+    /// the fact that we might be encrypted. Chunked service files carry no body; assemble it from
+    /// the chunks and use `decrypt_body` instead.
     pub fn body(&self) -> Result<Vec<u8>> {
+        self.decrypt_body(self.get_body())
+    }
+
+    /// Decrypt a body that belongs to this service file, if it is encrypted.
+    pub fn decrypt_body(&self, body: &[u8]) -> Result<Vec<u8>> {
         if self.get_encrypted() {
-            let bytes = try!(BoxKeyPair::decrypt(self.get_body(), &default_cache_key_path(None)));
+            let bytes = try!(BoxKeyPair::decrypt(body, &default_cache_key_path(None)));
             Ok(bytes)
         } else {
-            Ok(self.get_body().to_vec())
+            Ok(body.to_vec())
+        }
+    }
+
+    /// Returns true if the body is distributed in chunks rather than carried in the rumor.
+    pub fn is_chunked(&self) -> bool {
+        self.get_chunk_count() > 0
+    }
+
+    /// Returns true if the chunk count of a chunked body is the one its size calls for, and no
+    /// more than we are willing to hold.
+    pub fn chunking_is_valid(&self) -> bool {
+        let max_size = chunk::MAX_CHUNK_COUNT as u64 * CHUNK_SIZE as u64;
+        !self.is_chunked() ||
+        (self.get_size() <= max_size &&
+         self.get_chunk_count() == chunk::chunk_count(self.get_size() as usize))
+    }
+
+    /// If the body is larger than a single chunk, move it out of the rumor, recording its content
+    /// hash, size and chunk count instead. Returns the body that was moved out, if any. Chunk
+    /// after encrypting and before signing.
+    pub fn chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        if self.get_body().len() <= CHUNK_SIZE {
+            return Ok(None);
+        }
+        if chunk::chunk_count(self.get_body().len()) > chunk::MAX_CHUNK_COUNT {
+            return Err(Error::FileTooLarge(String::from(self.get_filename()),
+                                           self.get_body().len() as u64));
+        }
+        let body = self.take_body();
+        let content_hash = try!(hash::hash_bytes(&body));
+        self.set_content_hash(content_hash);
+        self.set_size(body.len() as u64);
+        self.set_chunk_count(chunk::chunk_count(body.len()));
+        Ok(Some(body))
+    }
+
+    /// Sign the rumor with a user's signing key, recording the signer alongside it. The signature
+    /// covers the service group, incarnation, filename, content hash and body as they are when
+    /// this is called, so sign after encrypting and chunking.
+    pub fn sign(&mut self, signer: &SigKeyPair) -> Result<()> {
+        let signature = try!(signer.sign(&self.signed_bytes()));
+        self.set_signer(signer.name_with_rev());
+        self.set_signature(signature);
+        Ok(())
+    }
+
+    /// Verify the signature with the signer's public key from `keys`, returning the name of the
+    /// key that signed this rumor.
+    pub fn verify(&self, keys: &SignerKeys) -> Result<String> {
+        if !self.has_signer() {
+            return Err(Error::UnsignedRumor(format!("{} {}",
+                                                    self.get_service_group(),
+                                                    self.get_filename())));
         }
+        let signer = try!(keys.get(self.get_signer()));
+        try!(signer.verify(self.get_signature(), &self.signed_bytes()));
+        Ok(signer.name)
+    }
+
+    fn signed_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!("{}\n{}\n{}\n{}\n{}\n",
+                                self.get_service_group(),
+                                self.get_incarnation(),
+                                self.get_encrypted(),
+                                self.get_filename(),
+                                self.get_content_hash())
+            .into_bytes();
+        // Files are signed as they were before there were tombstones, so their signatures still
+        // check out
+        if self.get_deleted() {
+            bytes.extend_from_slice(format!("deleted\n{}\n", self.get_deleted_at()).as_bytes());
+        }
+        bytes.extend_from_slice(self.get_body());
+        bytes
     }
 }
 
@@ -154,10 +270,14 @@ impl Rumor for ServiceFile {
 mod tests {
     use std::cmp::Ordering;
 
+    use habitat_core::crypto::{SigKeyPair, hash};
     use habitat_core::service::ServiceGroup;
 
-    use super::ServiceFile;
+    use super::{is_safe_filename, ServiceFile, TOMBSTONE_TTL_SECS};
+    use chunk::CHUNK_SIZE;
+    use error::Error;
     use rumor::Rumor;
+    use server::signer_policy::SignerKeys;
 
     fn create_service_file(member_id: &str, filename: &str, body: &str) -> ServiceFile {
         let body_bytes: Vec<u8> = Vec::from(body);
@@ -230,6 +350,68 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn a_tombstone_replaces_the_file_it_deletes() {
+        let mut s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        let mut s2 = ServiceFile::tombstone("adam",
+                                            ServiceGroup::new("neurosis", "production", None),
+                                            "yep");
+        s2.set_incarnation(1);
+        assert!(s1.merge(s2));
+        assert!(s1.get_deleted());
+        assert!(s1.get_body().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_does_not_cover_a_tombstone_turned_into_a_file() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = ServiceFile::tombstone("adam",
+                                            ServiceGroup::new("neurosis", "production", None),
+                                            "yep");
+        s1.sign(&signer).unwrap();
+        s1.set_deleted(false);
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    fn signature_covers_when_a_tombstone_was_made() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = ServiceFile::tombstone("adam",
+                                            ServiceGroup::new("neurosis", "production", None),
+                                            "yep");
+        s1.sign(&signer).unwrap();
+        let deleted_at = s1.get_deleted_at();
+        s1.set_deleted_at(deleted_at + TOMBSTONE_TTL_SECS);
+        assert!(signer.verify(s1.get_signature(), &s1.signed_bytes()).is_err());
+    }
+
+    #[test]
+    fn tombstones_expire() {
+        let mut s1 = ServiceFile::tombstone("adam",
+                                            ServiceGroup::new("neurosis", "production", None),
+                                            "yep");
+        s1.set_deleted_at(1000);
+        assert!(!s1.is_expired_tombstone(1000 + TOMBSTONE_TTL_SECS - 1));
+        assert!(s1.is_expired_tombstone(1000 + TOMBSTONE_TTL_SECS));
+        let s2 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        assert!(!s2.is_expired_tombstone(1000 + TOMBSTONE_TTL_SECS));
+    }
+
+    #[test]
+    fn filenames_stay_inside_the_files_directory() {
+        assert!(is_safe_filename("redis.conf"));
+        assert!(is_safe_filename(".hidden"));
+        assert!(!is_safe_filename(""));
+        assert!(!is_safe_filename("."));
+        assert!(!is_safe_filename(".."));
+        assert!(!is_safe_filename("../../x"));
+        assert!(!is_safe_filename("/etc/passwd"));
+        assert!(!is_safe_filename("dir/redis.conf"));
+        assert!(!is_safe_filename("..\\x"));
+        assert!(!is_safe_filename("C:\\x"));
+    }
+
     #[test]
     fn config_comes_back_as_a_string() {
         let s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
@@ -237,4 +419,72 @@ mod tests {
                        .expect("cannot get a utf-8 string for the body"),
                    String::from("tcp-backlog = 128"));
     }
+
+    // Chunking
+    #[test]
+    fn small_bodies_are_not_chunked() {
+        let mut s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        assert_eq!(s1.chunk().unwrap(), None);
+        assert!(!s1.is_chunked());
+        assert_eq!(s1.get_body(), b"tcp-backlog = 128");
+    }
+
+    #[test]
+    fn large_bodies_are_moved_out_of_the_rumor() {
+        let body = vec![7; CHUNK_SIZE * 2 + 1];
+        let mut s1 = ServiceFile::new("adam",
+                                      ServiceGroup::new("neurosis", "production", None),
+                                      "yep",
+                                      body.clone());
+        assert_eq!(s1.chunk().unwrap(), Some(body.clone()));
+        assert!(s1.is_chunked());
+        assert!(s1.get_body().is_empty());
+        assert_eq!(s1.get_size(), body.len() as u64);
+        assert_eq!(s1.get_chunk_count(), 3);
+        assert_eq!(s1.get_content_hash(), hash::hash_bytes(&body).unwrap());
+    }
+
+    #[test]
+    fn chunking_is_valid_when_the_count_matches_the_size() {
+        let mut s1 = ServiceFile::new("adam",
+                                      ServiceGroup::new("neurosis", "production", None),
+                                      "yep",
+                                      vec![7; CHUNK_SIZE * 2 + 1]);
+        s1.chunk().unwrap();
+        assert!(s1.chunking_is_valid());
+        s1.set_chunk_count(1000);
+        assert!(!s1.chunking_is_valid());
+        s1.set_size(u64::max_value());
+        s1.set_chunk_count(u32::max_value());
+        assert!(!s1.chunking_is_valid());
+    }
+
+    // Signing
+    #[test]
+    fn signing_records_the_signer() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        s1.sign(&signer).unwrap();
+        assert_eq!(s1.get_signer(), signer.name_with_rev());
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_does_not_cover_a_changed_filename() {
+        let signer = SigKeyPair::generate_in_memory("adam").unwrap();
+        let mut s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        s1.sign(&signer).unwrap();
+        s1.set_filename(String::from("nope"));
+        signer.verify(s1.get_signature(), &s1.signed_bytes()).unwrap();
+    }
+
+    #[test]
+    fn verify_rejects_unsigned_rumors() {
+        let s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        match s1.verify(&SignerKeys::default()) {
+            Err(Error::UnsignedRumor(_)) => {}
+            _ => panic!("Unsigned service file passed verification"),
+        }
+    }
 }
@@ -76,3 +76,46 @@ pub mod rumor_service {
     #[derive(Debug, Clone, Serialize)]
     pub struct Service(pub ProtoRumor);
 }
+
+pub mod server_metrics {
+    // JW TODO: After updating to Rust 1.15, move the types contained in this module back into
+    // `server/metrics.rs`
+
+    use std::collections::BTreeMap;
+
+    /// Bytes moved by the SWIM or gossip protocol, in total and in the last complete round.
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct TrafficReport {
+        pub bytes_sent: u64,
+        pub bytes_received: u64,
+        pub last_round_bytes_sent: u64,
+        pub last_round_bytes_received: u64,
+    }
+
+    /// How the rumors of one kind are spreading.
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct RumorKindReport {
+        /// How many times a rumor of this kind was new or changed.
+        pub inserts: u64,
+        /// How many rumors of this kind we know.
+        pub rumors: usize,
+        /// How many (rumor, member) pairs have each heat; `heat[n]` is the count for heat `n`.
+        pub heat: Vec<usize>,
+        /// How many rumors we have finished sharing.
+        pub converged: usize,
+        pub mean_convergence_ms: Option<i64>,
+        pub max_convergence_ms: Option<i64>,
+        /// How long the oldest rumor we are still sharing has been spreading.
+        pub oldest_unconverged_ms: Option<i64>,
+    }
+
+    /// A snapshot of how rumors are moving through the ring, as this member sees it.
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct MetricsReport {
+        pub rumors: BTreeMap<String, RumorKindReport>,
+        pub swim: TrafficReport,
+        pub gossip: TrafficReport,
+        /// The fraction of rumors we have finished sharing, from 0 to 1.
+        pub convergence: f64,
+    }
+}
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Expire suspected members.
+//! Expire suspected members and old tombstones.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. It also forgets service file tombstones once they have expired.
 
 use std::thread;
 use std::time::Duration;
@@ -63,6 +63,7 @@ impl<'a> Expire<'a> {
                 self.server.member_list.expire(mid);
                 self.server.rumor_list.insert(RumorKey::new(Rumor_Type::Member, mid.clone(), ""));
             }
+            self.server.expire_tombstones();
             thread::sleep(Duration::from_millis(500));
         }
     }
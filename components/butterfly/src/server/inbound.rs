@@ -24,11 +24,14 @@ use std::time::Duration;
 
 use protobuf;
 
-use member::{Member, Health};
+use member::{canonical_ip, Member, Health};
 use message::swim::{Swim, Swim_Type};
-use server::{Server, outbound};
+use server::{Server, outbound, query};
 use trace::TraceKind;
 
+/// The largest payload a UDP datagram can carry.
+pub const MAX_RECV_BYTES: usize = 65507;
+
 /// Takes the Server and a channel to send received Acks to the outbound thread.
 pub struct Inbound<'a> {
     pub server: &'a Server,
@@ -49,9 +52,10 @@ impl<'a> Inbound<'a> {
         }
     }
 
-    /// Run the thread. Listens for messages up to 1k in size, and then processes them accordingly.
+    /// Run the thread. Listens for messages up to the largest UDP datagram in size, whatever
+    /// datagram size the sender is configured for, and then processes them accordingly.
     pub fn run(&self) {
-        let mut recv_buffer: Vec<u8> = vec![0; 1024];
+        let mut recv_buffer: Vec<u8> = vec![0; MAX_RECV_BYTES];
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
                 thread::sleep(Duration::from_millis(100));
@@ -59,6 +63,7 @@ impl<'a> Inbound<'a> {
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
                 Ok((length, addr)) => {
+                    self.server.metrics.swim_received(length);
                     let swim_payload = match self.server.unwrap_wire(&recv_buffer[0..length]) {
                         Ok(swim_payload) => swim_payload,
                         Err(e) => {
@@ -105,6 +110,10 @@ impl<'a> Inbound<'a> {
                             }
                             self.process_pingreq(addr, msg);
                         }
+                        Swim_Type::QUERY => self.process_query(addr, msg),
+                        Swim_Type::QUERY_RESPONSE => {
+                            debug!("Ignoring query response from {}; we never ask", addr);
+                        }
                     }
                 }
                 Err(e) => {
@@ -141,7 +150,7 @@ impl<'a> Inbound<'a> {
             };
             // Set the route-back address to the one we received the pingreq from
             let mut from = msg.mut_pingreq().take_from();
-            from.set_address(format!("{}", addr.ip()));
+            from.set_address(canonical_ip(addr.ip()).to_string());
             outbound::ping(self.server,
                            &self.socket,
                            target,
@@ -156,15 +165,12 @@ impl<'a> Inbound<'a> {
         info!("Ack from {}@{}", msg.get_ack().get_from().get_id(), addr);
         if msg.get_ack().has_forward_to() {
             if self.server.member_id() != msg.get_ack().get_forward_to().get_id() {
-                let forward_addr_str = format!("{}:{}",
-                                               msg.get_ack().get_forward_to().get_address(),
-                                               msg.get_ack().get_forward_to().get_swim_port());
-                let forward_to_addr = match forward_addr_str.parse() {
-                    Ok(addr) => addr,
-                    Err(e) => {
-                        error!("Abandoning Ack forward: cannot parse member address: {}, {}",
-                               msg.get_ack().get_forward_to().get_address(),
-                               e);
+                let forward_to = Member::from(msg.get_ack().get_forward_to());
+                let forward_to_addr = match forward_to.ip() {
+                    Some(ip) => SocketAddr::new(ip, forward_to.get_swim_port() as u16),
+                    None => {
+                        error!("Abandoning Ack forward: cannot parse member address: {}",
+                               forward_to.get_address());
                         return;
                     }
                 };
@@ -174,7 +180,7 @@ impl<'a> Inbound<'a> {
                       msg.get_ack().get_forward_to().get_id(),
                       msg.get_ack().get_forward_to().get_address(),
                       );
-                msg.mut_ack().mut_from().set_address(format!("{}", addr.ip()));
+                msg.mut_ack().mut_from().set_address(canonical_ip(addr.ip()).to_string());
                 outbound::forward_ack(self.server, &self.socket, forward_to_addr, msg);
                 return;
             }
@@ -193,6 +199,19 @@ impl<'a> Inbound<'a> {
         self.server.insert_member_from_rumors(membership);
     }
 
+    /// Process query messages. Queries come from clients outside the ring, so there is no member
+    /// to check against the blacklist; holding the ring key is what lets them ask. Without a ring
+    /// key, we only answer if we were told to.
+    fn process_query(&self, addr: SocketAddr, msg: Swim) {
+        if !self.server.is_encrypted() && !self.server.plaintext_queries() {
+            debug!("Ignoring query from {}; the ring has no key", addr);
+            return;
+        }
+        debug!("Query from {}: {:?}", addr, msg.get_query());
+        let response = query::answer(self.server, msg.get_query());
+        outbound::query_response(self.server, &self.socket, addr, response);
+    }
+
     /// Process ping messages.
     fn process_ping(&self, addr: SocketAddr, mut msg: Swim) {
         trace_it!(SWIM: &self.server, TraceKind::RecvPing, msg.get_ping().get_from().get_id(), addr, &msg);
@@ -210,11 +229,18 @@ impl<'a> Inbound<'a> {
         let from = {
             let mut ping = msg.mut_ping();
             let mut from = ping.take_from();
-            from.set_address(format!("{}", addr.ip()));
+            from.set_address(canonical_ip(addr.ip()).to_string());
             from
         };
         info!("Ping from {}@{}", from.get_id(), addr);
-        self.server.insert_member(from.into(), Health::Alive);
+        // A member we have never heard of is probably joining; catch it up on everything we know
+        // over gossip, instead of a few membership rumors at a time over SWIM.
+        let joining = !self.server.member_list.contains_member(from.get_id());
+        let from: Member = from.into();
+        if joining {
+            self.server.send_full_state(from.clone());
+        }
+        self.server.insert_member(from, Health::Alive);
         let membership: Vec<(Member, Health)> = msg.take_membership()
             .iter()
             .map(|m| (Member::from(m.get_member()), Health::from(m.get_health())))
@@ -0,0 +1,304 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measures how rumors move through the ring.
+//!
+//! We count the bytes the SWIM and gossip protocols send and receive, and watch the heat of every
+//! rumor. A rumor has converged once we have shared it `RUMOR_MAX` times with every member we
+//! think is alive, or have used up its retransmit limit; the time from when we first saw it until
+//! then is its convergence time.
+//!
+//! All of this is from our own point of view. We can't see what other members have shared with
+//! each other, so a rumor may well have reached everyone before we count it as converged.
+
+use std::cmp;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use time::SteadyTime;
+
+use rumor::{RumorKey, RumorList, RUMOR_MAX};
+
+pub use types::server_metrics::*;
+
+#[derive(Debug, Clone, Default)]
+struct Traffic {
+    sent: u64,
+    received: u64,
+    round_sent: u64,
+    round_received: u64,
+    last_round_sent: u64,
+    last_round_received: u64,
+}
+
+impl Traffic {
+    fn sent(&mut self, bytes: usize) {
+        self.sent += bytes as u64;
+        self.round_sent += bytes as u64;
+    }
+
+    fn received(&mut self, bytes: usize) {
+        self.received += bytes as u64;
+        self.round_received += bytes as u64;
+    }
+
+    fn end_round(&mut self) {
+        self.last_round_sent = self.round_sent;
+        self.last_round_received = self.round_received;
+        self.round_sent = 0;
+        self.round_received = 0;
+    }
+
+    fn report(&self) -> TrafficReport {
+        TrafficReport {
+            bytes_sent: self.sent,
+            bytes_received: self.received,
+            last_round_bytes_sent: self.last_round_sent,
+            last_round_bytes_received: self.last_round_received,
+        }
+    }
+}
+
+/// Traffic counters and convergence times. Is thread-safe.
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    swim: Arc<Mutex<Traffic>>,
+    gossip: Arc<Mutex<Traffic>>,
+    // When we first saw the version of the rumor that converged, and how long it took
+    converged: Arc<RwLock<HashMap<RumorKey, (SteadyTime, i64)>>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Metrics {
+        Metrics {
+            swim: Arc::new(Mutex::new(Traffic::default())),
+            gossip: Arc::new(Mutex::new(Traffic::default())),
+            converged: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Metrics {
+    pub fn swim_sent(&self, bytes: usize) {
+        self.swim.lock().expect("Swim metrics lock poisoned").sent(bytes);
+    }
+
+    pub fn swim_received(&self, bytes: usize) {
+        self.swim.lock().expect("Swim metrics lock poisoned").received(bytes);
+    }
+
+    pub fn gossip_sent(&self, bytes: usize) {
+        self.gossip.lock().expect("Gossip metrics lock poisoned").sent(bytes);
+    }
+
+    pub fn gossip_received(&self, bytes: usize) {
+        self.gossip.lock().expect("Gossip metrics lock poisoned").received(bytes);
+    }
+
+    /// Start counting the bytes of a new SWIM round.
+    pub fn end_swim_round(&self) {
+        self.swim.lock().expect("Swim metrics lock poisoned").end_round();
+    }
+
+    /// Start counting the bytes of a new gossip round.
+    pub fn end_gossip_round(&self) {
+        self.gossip.lock().expect("Gossip metrics lock poisoned").end_round();
+    }
+
+    /// Record the convergence time of every rumor that has newly converged. `alive` is the id of
+    /// every other member we think is alive.
+    pub fn update_convergence(&self, rumor_list: &RumorList, alive: &[String]) {
+        if alive.is_empty() {
+            return;
+        }
+        let now = SteadyTime::now();
+        let limit = rumor_list.retransmit_limit();
+        let mut converged = self.converged.write().expect("Convergence lock poisoned");
+        rumor_list.with_heat(|rk, heat, first_seen| {
+            let first_seen = match first_seen {
+                Some(first_seen) => first_seen,
+                None => return,
+            };
+            if let Some(&(seen, _)) = converged.get(rk) {
+                if seen == first_seen {
+                    return;
+                }
+            }
+            if is_cold(heat, alive, limit) {
+                converged.insert(rk.clone(),
+                                 (first_seen, (now - first_seen).num_milliseconds()));
+            } else {
+                // A newer version of the rumor is still spreading
+                converged.remove(rk);
+            }
+        });
+    }
+
+    /// Report on the traffic so far and the rumors in `rumor_list`, as shared with the `alive`
+    /// members.
+    pub fn report(&self, rumor_list: &RumorList, alive: &[String]) -> MetricsReport {
+        self.update_convergence(rumor_list, alive);
+        let now = SteadyTime::now();
+        let converged = self.converged.read().expect("Convergence lock poisoned");
+        let mut report = MetricsReport::default();
+        let mut convergence_totals: HashMap<String, i64> = HashMap::new();
+        let limit = rumor_list.retransmit_limit();
+        let mut total = 0;
+        let mut done = 0;
+
+        rumor_list.with_heat(|rk, heat, first_seen| {
+            let kind = format!("{:?}", rk.kind);
+            let entry = report.rumors.entry(kind.clone()).or_insert(RumorKindReport::default());
+            if entry.heat.is_empty() {
+                entry.heat = vec![0; RUMOR_MAX + 1];
+            }
+            entry.rumors += 1;
+            for id in alive.iter() {
+                let h = cmp::min(heat.get(id).cloned().unwrap_or(0), RUMOR_MAX);
+                entry.heat[h] += 1;
+            }
+            if is_cold(heat, alive, limit) {
+                done += 1;
+            }
+            total += 1;
+            match (converged.get(rk), first_seen) {
+                (Some(&(seen, ms)), Some(first_seen)) if seen == first_seen => {
+                    entry.converged += 1;
+                    *convergence_totals.entry(kind).or_insert(0) += ms;
+                    if entry.max_convergence_ms.map(|max| ms > max).unwrap_or(true) {
+                        entry.max_convergence_ms = Some(ms);
+                    }
+                }
+                (_, Some(first_seen)) => {
+                    let age = (now - first_seen).num_milliseconds();
+                    if entry.oldest_unconverged_ms.map(|oldest| age > oldest).unwrap_or(true) {
+                        entry.oldest_unconverged_ms = Some(age);
+                    }
+                }
+                (_, None) => (),
+            }
+        });
+
+        for (kind, inserts) in rumor_list.inserts() {
+            let entry = report.rumors
+                .entry(format!("{:?}", kind))
+                .or_insert(RumorKindReport::default());
+            entry.inserts = inserts;
+        }
+        for (kind, sum) in convergence_totals {
+            if let Some(entry) = report.rumors.get_mut(&kind) {
+                if entry.converged > 0 {
+                    entry.mean_convergence_ms = Some(sum / entry.converged as i64);
+                }
+            }
+        }
+
+        report.swim = self.swim.lock().expect("Swim metrics lock poisoned").report();
+        report.gossip = self.gossip.lock().expect("Gossip metrics lock poisoned").report();
+        report.convergence = if total == 0 || alive.is_empty() {
+            1.0
+        } else {
+            done as f64 / total as f64
+        };
+        report
+    }
+}
+
+/// Whether we are done sharing a rumor: every alive member has heard it `RUMOR_MAX` times, or we
+/// have shared it as many times as the retransmit limit allows.
+fn is_cold(heat: &HashMap<String, usize>, alive: &[String], limit: usize) -> bool {
+    heat.values().fold(0, |total, h| total + h) >= limit ||
+    alive.iter().all(|id| heat.get(id).map(|h| *h >= RUMOR_MAX).unwrap_or(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metrics;
+    use message::swim::Rumor_Type;
+    use rumor::{RumorKey, RumorList};
+
+    fn rumor_list() -> RumorList {
+        let rumor_list = RumorList::default();
+        rumor_list.insert(RumorKey::new(Rumor_Type::Member, "a", ""));
+        rumor_list.insert(RumorKey::new(Rumor_Type::Service, "a", "redis.default"));
+        rumor_list
+    }
+
+    #[test]
+    fn traffic_is_counted_per_round() {
+        let metrics = Metrics::default();
+        metrics.swim_sent(100);
+        metrics.swim_received(40);
+        metrics.end_swim_round();
+        metrics.swim_sent(10);
+        let report = metrics.report(&RumorList::default(), &[]);
+        assert_eq!(report.swim.bytes_sent, 110);
+        assert_eq!(report.swim.bytes_received, 40);
+        assert_eq!(report.swim.last_round_bytes_sent, 100);
+        assert_eq!(report.swim.last_round_bytes_received, 40);
+        assert_eq!(report.gossip.bytes_sent, 0);
+    }
+
+    #[test]
+    fn heat_and_inserts_are_reported_by_kind() {
+        let rumor_list = rumor_list();
+        let alive = vec![String::from("b"), String::from("c")];
+        rumor_list.update_heat("b", &rumor_list.rumors("b"));
+        let report = Metrics::default().report(&rumor_list, &alive);
+        let service = report.rumors.get("Service").unwrap();
+        assert_eq!(service.inserts, 1);
+        assert_eq!(service.rumors, 1);
+        assert_eq!(service.heat, vec![1, 1, 0]);
+        assert_eq!(service.converged, 0);
+        assert!(service.oldest_unconverged_ms.is_some());
+        assert_eq!(report.convergence, 0.0);
+    }
+
+    #[test]
+    fn rumors_converge_once_every_alive_member_is_hot() {
+        let rumor_list = rumor_list();
+        let alive = vec![String::from("b"), String::from("c")];
+        let metrics = Metrics::default();
+        for id in alive.iter() {
+            rumor_list.update_heat(id, &rumor_list.rumors(id));
+            rumor_list.update_heat(id, &rumor_list.rumors(id));
+        }
+        let report = metrics.report(&rumor_list, &alive);
+        assert_eq!(report.convergence, 1.0);
+        let member = report.rumors.get("Member").unwrap();
+        assert_eq!(member.converged, 1);
+        assert!(member.mean_convergence_ms.is_some());
+        assert!(member.oldest_unconverged_ms.is_none());
+
+        // A new version of the rumor has to spread all over again
+        rumor_list.insert(RumorKey::new(Rumor_Type::Member, "a", ""));
+        let report = metrics.report(&rumor_list, &alive);
+        let member = report.rumors.get("Member").unwrap();
+        assert_eq!(member.inserts, 2);
+        assert_eq!(member.converged, 0);
+        assert_eq!(report.convergence, 0.5);
+    }
+
+    #[test]
+    fn rumors_converge_once_the_retransmit_limit_is_used_up() {
+        let rumor_list = rumor_list();
+        let alive = vec![String::from("b"), String::from("c"), String::from("d")];
+        rumor_list.set_retransmit_limit(2);
+        rumor_list.update_heat("b", &rumor_list.rumors("b"));
+        rumor_list.update_heat("c", &rumor_list.rumors("c"));
+        let report = Metrics::default().report(&rumor_list, &alive);
+        assert_eq!(report.convergence, 1.0);
+        assert_eq!(report.rumors.get("Service").unwrap().heat, vec![1, 2, 0]);
+    }
+}
@@ -17,23 +17,31 @@
 //! Creates `Server` structs, that hold everything we need to run the SWIM and Gossip protocol.
 //! Winds up with 5 separate threads - inbound (incoming connections), outbound (the Probe
 //! protocol), expire (turning Suspect members into Confirmed members), push (the fan-out rumors),
-//! and pull (the inbound receipt of rumors.).
+//! and pull (the inbound receipt of rumors.). A server with a data path runs a sixth, persist,
+//! which saves its state for the next time it starts.
 
 pub mod expire;
 pub mod inbound;
+pub mod metrics;
 pub mod outbound;
+pub mod persist;
 pub mod pull;
 pub mod push;
+pub mod query;
+pub mod quorum;
+pub mod signer_policy;
 pub mod timing;
 
 use std::collections::{HashSet, HashMap};
 use std::fmt;
+use std::fs;
 use std::io;
 use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
+use std::path::PathBuf;
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
 use std::time::Duration;
 use std::thread;
@@ -41,16 +49,24 @@ use std::thread;
 use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
+use time::{self, Duration as TimeDuration, SteadyTime};
 
+use chunk::{self, ChunkStore, MAX_CHUNKS_PER_REQUEST, MAX_CHUNK_WORKERS};
 use error::{Result, Error};
+use keyring::KeyRing;
 use member::{Member, Health, MemberList};
 use trace::{Trace, TraceKind};
+use server::metrics::{Metrics, MetricsReport};
+use server::quorum::QuorumPolicy;
+use server::signer_policy::{SignerKeys, SignerPolicy};
 use rumor::{Rumor, RumorStore, RumorList, RumorKey};
 use rumor::service::Service;
-use rumor::service_config::ServiceConfig;
-use rumor::service_file::ServiceFile;
-use rumor::election::{Election, ElectionUpdate};
+use rumor::service_config::{ConfigHistory, ServiceConfig};
+use rumor::service_file::{is_safe_filename, ServiceFile};
+use rumor::election::{Election, ElectionUpdate, LeaderLeases};
 use message;
+use message::swim::{FileChunk, FileChunkRequest, RolloutPolicy, Rumor as ProtoRumor, Rumor_Type,
+                    Wire_Compression};
 
 /// The server struct. Is thread-safe.
 #[derive(Debug, Clone)]
@@ -59,13 +75,19 @@ pub struct Server {
     pub member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    pub ring_key: Arc<Option<SymKey>>,
+    pub ring_keys: Arc<RwLock<KeyRing>>,
     pub rumor_list: RumorList,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
+    pub config_history: ConfigHistory,
     pub service_file_store: RumorStore<ServiceFile>,
+    pub file_chunks: ChunkStore,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub leader_leases: LeaderLeases,
+    pub quorum_policies: Arc<RwLock<HashMap<String, QuorumPolicy>>>,
+    pub signer_policy: Arc<RwLock<Option<SignerPolicy>>>,
+    pub signer_keys: SignerKeys,
     pub swim_addr: Arc<RwLock<SocketAddr>>,
     pub gossip_addr: Arc<RwLock<SocketAddr>>,
     // These are all here for testing support
@@ -74,6 +96,14 @@ pub struct Server {
     pub swim_rounds: Arc<AtomicIsize>,
     pub gossip_rounds: Arc<AtomicIsize>,
     pub blacklist: Arc<RwLock<HashSet<String>>>,
+    pub metrics: Metrics,
+    pub max_datagram_bytes: Arc<AtomicUsize>,
+    pub plaintext_queries: Arc<AtomicBool>,
+    envelope_bytes: Arc<RwLock<Option<(Option<String>, usize)>>>,
+    pub chunk_workers: Arc<AtomicUsize>,
+    pub full_state_sent: Arc<RwLock<HashMap<String, SteadyTime>>>,
+    pub full_state_workers: Arc<AtomicUsize>,
+    pub data_path: Option<PathBuf>,
 }
 
 impl Serialize for Server {
@@ -91,42 +121,112 @@ impl Serialize for Server {
 impl Server {
     /// Create a new server, bound to the `addr`, hosting a particular `member`, and with a
     /// `Trace` struct, a ring_key if you want encryption on the wire, and an optional server name.
+    ///
+    /// With a `data_path`, the server keeps its member id, incarnation and the rumors it knows
+    /// about in that directory. If they are already there from an earlier run, we take on that
+    /// member id in place of the one `member` came with, and pick up the rumors where we left off.
     pub fn new<A: ToSocketAddrs>(swim_addr: A,
                                  gossip_addr: A,
-                                 member: Member,
+                                 mut member: Member,
                                  trace: Trace,
                                  ring_key: Option<SymKey>,
-                                 name: Option<String>)
+                                 name: Option<String>,
+                                 data_path: Option<PathBuf>)
                                  -> Result<Server> {
+        // Let the rest of the ring know it can send us compressed gossip.
+        member.set_accepts_compression(true);
+        let snapshot = match data_path {
+            Some(ref path) => {
+                match persist::read(&path.join(persist::DATA_FILE)) {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => {
+                        error!("Starting without our saved state in {}: {}",
+                               path.display(),
+                               e);
+                        persist::Snapshot::default()
+                    }
+                }
+            }
+            None => persist::Snapshot::default(),
+        };
+        if let Some(ref saved) = snapshot.member {
+            // Anyone who still remembers us as suspect or confirmed has to hear that we are back.
+            member.set_id(String::from(saved.get_id()));
+            member.set_incarnation(saved.get_incarnation() + 1);
+        }
         let maybe_swim_socket_addr = swim_addr.to_socket_addrs().map(|mut iter| iter.next());
         let maybe_gossip_socket_addr = gossip_addr.to_socket_addrs().map(|mut iter| iter.next());
 
         match (maybe_swim_socket_addr, maybe_gossip_socket_addr) {
-            (Ok(Some(swim_socket_addr)), Ok(Some(gossip_socket_addr))) => Ok(Server {
-                name: Arc::new(name.unwrap_or(String::from(member.get_id()))),
-                member_id: Arc::new(String::from(member.get_id())),
-                member: Arc::new(RwLock::new(member)),
-                member_list: MemberList::new(),
-                ring_key: Arc::new(ring_key),
-                rumor_list: RumorList::default(),
-                service_store: RumorStore::default(),
-                service_config_store: RumorStore::default(),
-                service_file_store: RumorStore::default(),
-                election_store: RumorStore::default(),
-                update_store: RumorStore::default(),
-                swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
-                gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
-                pause: Arc::new(AtomicBool::new(false)),
-                trace: Arc::new(RwLock::new(trace)),
-                swim_rounds: Arc::new(AtomicIsize::new(0)),
-                gossip_rounds: Arc::new(AtomicIsize::new(0)),
-                blacklist: Arc::new(RwLock::new(HashSet::new())),
-            }),
+            (Ok(Some(swim_socket_addr)), Ok(Some(gossip_socket_addr))) => {
+                let server = Server {
+                    name: Arc::new(name.unwrap_or(String::from(member.get_id()))),
+                    member_id: Arc::new(String::from(member.get_id())),
+                    member: Arc::new(RwLock::new(member)),
+                    member_list: MemberList::new(),
+                    ring_keys: Arc::new(RwLock::new(KeyRing::new(ring_key))),
+                    rumor_list: RumorList::default(),
+                    service_store: RumorStore::default(),
+                    service_config_store: RumorStore::default(),
+                    config_history: ConfigHistory::default(),
+                    service_file_store: RumorStore::default(),
+                    file_chunks: ChunkStore::new(),
+                    election_store: RumorStore::default(),
+                    update_store: RumorStore::default(),
+                    leader_leases: LeaderLeases::new(),
+                    quorum_policies: Arc::new(RwLock::new(HashMap::new())),
+                    signer_policy: Arc::new(RwLock::new(None)),
+                    signer_keys: SignerKeys::default(),
+                    swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
+                    gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
+                    pause: Arc::new(AtomicBool::new(false)),
+                    trace: Arc::new(RwLock::new(trace)),
+                    swim_rounds: Arc::new(AtomicIsize::new(0)),
+                    gossip_rounds: Arc::new(AtomicIsize::new(0)),
+                    blacklist: Arc::new(RwLock::new(HashSet::new())),
+                    metrics: Metrics::default(),
+                    max_datagram_bytes: Arc::new(AtomicUsize::new(outbound::MAX_DATAGRAM_BYTES_DEFAULT)),
+                    plaintext_queries: Arc::new(AtomicBool::new(false)),
+                    envelope_bytes: Arc::new(RwLock::new(None)),
+                    chunk_workers: Arc::new(AtomicUsize::new(0)),
+                    full_state_sent: Arc::new(RwLock::new(HashMap::new())),
+                    full_state_workers: Arc::new(AtomicUsize::new(0)),
+                    data_path: data_path,
+                };
+                server.restore(snapshot.rumors);
+                Ok(server)
+            }
             (Err(e), _) | (_, Err(e)) => Err(Error::CannotBind(e)),
             (Ok(None), _) | (_, Ok(None)) => Err(Error::CannotBind(io::Error::new(io::ErrorKind::AddrNotAvailable, "No address discovered.")))
         }
     }
 
+    /// Put rumors saved by an earlier run back into their stores, and spread them again. Elections
+    /// go straight into the store; they are only picked up again once our services rejoin them.
+    fn restore(&self, rumors: Vec<ProtoRumor>) {
+        for proto in rumors.into_iter() {
+            match proto.get_field_type() {
+                Rumor_Type::ServiceConfig => self.insert_service_config(proto.into()),
+                Rumor_Type::ServiceFile => self.insert_service_file(proto.into()),
+                Rumor_Type::Election => {
+                    let election = Election::from(proto);
+                    let rk = RumorKey::from(&election);
+                    if self.election_store.insert(election) {
+                        self.rumor_list.insert(rk);
+                    }
+                }
+                Rumor_Type::ElectionUpdate => {
+                    let election = ElectionUpdate::from(proto);
+                    let rk = RumorKey::from(&election);
+                    if self.update_store.insert(election) {
+                        self.rumor_list.insert(rk);
+                    }
+                }
+                kind => debug!("Not restoring a saved {:?} rumor", kind),
+            }
+        }
+    }
+
     /// Every iteration of the outbound protocol (which means every member has been pinged if they
     /// are available) increments the round. If we exceed an isize in rounds, we reset to 0.
     ///
@@ -138,6 +238,7 @@ impl Server {
 
     /// Adds 1 to the current round, atomically.
     pub fn update_swim_round(&self) {
+        self.metrics.end_swim_round();
         let current_round = self.swim_rounds.load(Ordering::SeqCst);
         match current_round.checked_add(1) {
             Some(_number) => {
@@ -162,6 +263,7 @@ impl Server {
 
     /// Adds 1 to the current round, atomically.
     pub fn update_gossip_round(&self) {
+        self.metrics.end_gossip_round();
         let current_round = self.gossip_rounds.load(Ordering::SeqCst);
         match current_round.checked_add(1) {
             Some(_number) => {
@@ -175,6 +277,28 @@ impl Server {
         }
     }
 
+    /// The id of every other member we think is alive.
+    fn alive_member_ids(&self) -> Vec<String> {
+        let mut alive = Vec::new();
+        self.member_list.with_members(|member| {
+            if member.get_id() != self.member_id() &&
+               self.member_list.check_health_of(member, Health::Alive) {
+                alive.push(String::from(member.get_id()));
+            }
+        });
+        alive
+    }
+
+    /// Record how long newly converged rumors took to spread. Called every gossip round.
+    pub fn update_convergence(&self) {
+        self.metrics.update_convergence(&self.rumor_list, &self.alive_member_ids());
+    }
+
+    /// How rumors are spreading through the ring, and how much traffic it takes.
+    pub fn metrics(&self) -> MetricsReport {
+        self.metrics.report(&self.rumor_list, &self.alive_member_ids())
+    }
+
     /// Start the server, along with a `Timing` for outbound connections. Spawns the `inbound`,
     /// `outbound`, and `expire` threads.
     ///
@@ -183,6 +307,7 @@ impl Server {
     /// * Returns `Error::CannotBind` if the socket cannot be bound
     /// * Returns `Error::SocketSetReadTimeout` if the socket read timeout cannot be set
     /// * Returns `Error::SocketSetWriteTimeout` if the socket write timeout cannot be set
+    /// * Returns `Error::DataFile` if the data directory cannot be created
     pub fn start(&self, timing: timing::Timing) -> Result<()> {
         let (tx_outbound, rx_inbound) = channel();
 
@@ -237,6 +362,16 @@ impl Server {
             panic!("You should never, ever get here, liu");
         });
 
+        if let Some(ref path) = self.data_path {
+            try!(fs::create_dir_all(path).map_err(|e| Error::DataFile(e)));
+            let server_f = self.clone();
+            let path_f = path.join(persist::DATA_FILE);
+            let _ = thread::Builder::new().name(format!("persist-{}", self.name())).spawn(move || {
+                persist::Persist::new(&server_f, path_f).run();
+                panic!("You should never, ever get here, mo");
+            });
+        }
+
         Ok(())
     }
 
@@ -252,19 +387,69 @@ impl Server {
         blacklist.remove(member_id);
     }
 
+    /// Every member ID on the blacklist.
+    pub fn blacklist(&self) -> Vec<String> {
+        let blacklist = self.blacklist.read().expect("Read lock for blacklist is poisoned");
+        let mut member_ids: Vec<String> = blacklist.iter().cloned().collect();
+        member_ids.sort();
+        member_ids
+    }
+
     /// Check that a given address is on the blacklist.
     pub fn check_blacklist(&self, member_id: &str) -> bool {
         let blacklist = self.blacklist.write().expect("Write lock for blacklist is poisoned");
         blacklist.contains(member_id)
     }
 
+    /// Trust an additional ring key revision, so we can decrypt traffic encrypted with it. We keep
+    /// encrypting outbound traffic with the current key until told to `use_ring_key`. Returns
+    /// false if the revision was already trusted.
+    pub fn add_ring_key(&self, ring_key: SymKey) -> bool {
+        let mut ring_keys = self.ring_keys.write().expect("Ring key lock is poisoned");
+        ring_keys.insert(ring_key)
+    }
+
+    /// Start encrypting outbound traffic with a trusted ring key revision, given as `name-rev`.
+    /// Only do this once every member trusts it, or they will reject what we send.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::UnknownRingKey` if the revision was never trusted
+    pub fn use_ring_key(&self, name_with_rev: &str) -> Result<()> {
+        let mut ring_keys = self.ring_keys.write().expect("Ring key lock is poisoned");
+        ring_keys.switch_to(name_with_rev)
+    }
+
+    /// The `name-rev` of the ring key revision we encrypt with, if any.
+    pub fn current_ring_key_revision(&self) -> Option<String> {
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        ring_keys.current().map(|k| k.name_with_rev())
+    }
+
+    /// Stop trusting a ring key revision, given as `name-rev`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::RingKeyInUse` if the revision is the one we encrypt with
+    /// * Returns `Error::UnknownRingKey` if the revision was never trusted
+    pub fn retire_ring_key(&self, name_with_rev: &str) -> Result<()> {
+        let mut ring_keys = self.ring_keys.write().expect("Ring key lock is poisoned");
+        try!(ring_keys.retire(name_with_rev));
+        Ok(())
+    }
+
+    /// The `name-rev` of every ring key revision we trust, newest first.
+    pub fn ring_key_revisions(&self) -> Vec<String> {
+        self.ring_keys.read().expect("Ring key lock is poisoned").revisions()
+    }
+
     /// Stop the outbound and inbound threads from processing work.
-    pub fn pause(&mut self) {
+    pub fn pause(&self) {
         self.pause.compare_and_swap(false, true, Ordering::Relaxed);
     }
 
     /// Allow the outbound and inbound threads to process work.
-    pub fn unpause(&mut self) {
+    pub fn unpause(&self) {
         self.pause.compare_and_swap(true, false, Ordering::Relaxed);
     }
 
@@ -273,6 +458,103 @@ impl Server {
         self.pause.load(Ordering::Relaxed)
     }
 
+    /// Return the largest SWIM datagram we will send, in bytes.
+    pub fn max_datagram_bytes(&self) -> usize {
+        self.max_datagram_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Set the largest SWIM datagram we will send, in bytes. Membership rumors that don't fit are
+    /// left for a later message, or for gossip.
+    pub fn set_max_datagram_bytes(&self, bytes: usize) {
+        self.max_datagram_bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    /// How many bytes the wire envelope around a SWIM message takes up, on top of the message
+    /// itself. It only changes with the ring key, so it is worked out once for each.
+    pub fn envelope_bytes(&self) -> usize {
+        let revision = self.current_ring_key_revision();
+        if let Some((ref cached_revision, bytes)) =
+               *self.envelope_bytes.read().expect("Envelope size lock is poisoned") {
+            if *cached_revision == revision {
+                return bytes;
+            }
+        }
+        let bytes = match self.generate_wire(Vec::new()) {
+            Ok(wire) => wire.len(),
+            Err(_) => 0,
+        };
+        *self.envelope_bytes.write().expect("Envelope size lock is poisoned") =
+            Some((revision, bytes));
+        bytes
+    }
+
+    /// Returns true if the wire is encrypted with a ring key.
+    pub fn is_encrypted(&self) -> bool {
+        !self.ring_keys.read().expect("Ring key lock is poisoned").is_empty()
+    }
+
+    /// Whether we answer queries on a ring without a key.
+    pub fn plaintext_queries(&self) -> bool {
+        self.plaintext_queries.load(Ordering::Relaxed)
+    }
+
+    /// Answer queries even though the ring has no key. Anyone who can reach the SWIM port can
+    /// ask, and a forged source address turns the answer on someone else, so this is off unless
+    /// asked for; config bodies are never sent in the clear.
+    pub fn set_plaintext_queries(&self, answer: bool) {
+        self.plaintext_queries.store(answer, Ordering::Relaxed);
+    }
+
+    /// Send every rumor we know to a member that has just joined the ring. Anyone can claim to be
+    /// a new member, so each member gets it at most once every `push::FULL_STATE_INTERVAL_MS`, and
+    /// only `push::MAX_FULL_STATE_WORKERS` get it at once.
+    pub fn send_full_state(&self, member: Member) {
+        if self.full_state_workers.fetch_add(1, Ordering::SeqCst) >= push::MAX_FULL_STATE_WORKERS {
+            self.full_state_workers.fetch_sub(1, Ordering::SeqCst);
+            debug!("Too busy to send full state to {}", member.get_id());
+            return;
+        }
+        if !self.full_state_due(member.get_id()) {
+            self.full_state_workers.fetch_sub(1, Ordering::SeqCst);
+            debug!("Sent full state to {} too recently to send it again",
+                   member.get_id());
+            return;
+        }
+        let server = self.clone();
+        let workers = self.full_state_workers.clone();
+        let _ = thread::Builder::new()
+            .name(String::from("full-state-worker"))
+            .spawn(move || {
+                push::PushWorker::new(server).send_full_state(member);
+                workers.fetch_sub(1, Ordering::SeqCst);
+            })
+            .map_err(|e| {
+                self.full_state_workers.fetch_sub(1, Ordering::SeqCst);
+                error!("Could not spawn thread: {}", e)
+            });
+    }
+
+    /// Returns true, and notes that we are sending it, if we have not sent a member our full
+    /// state within `push::FULL_STATE_INTERVAL_MS`. Members we sent it to longer ago than that
+    /// are forgotten, so made-up member ids can't pile up.
+    fn full_state_due(&self, member_id: &str) -> bool {
+        let mut sent = self.full_state_sent.write().expect("Full state lock is poisoned");
+        let now = SteadyTime::now();
+        let interval = TimeDuration::milliseconds(push::FULL_STATE_INTERVAL_MS);
+        let expired: Vec<String> = sent.iter()
+            .filter(|&(_, at)| now - *at >= interval)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in expired.iter() {
+            sent.remove(id);
+        }
+        if sent.contains_key(member_id) {
+            return false;
+        }
+        sent.insert(String::from(member_id), now);
+        true
+    }
+
     /// Return the swim address we are bound to
     pub fn swim_addr(&self) -> SocketAddr {
         let sa = self.swim_addr.read().expect("Swim Address lock poisoned");
@@ -315,12 +597,16 @@ impl Server {
         let trace_member_id = String::from(member.get_id());
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
+        let joined = !self.member_list.contains_member(&trace_member_id);
         if self.member_list.insert(member, health) {
             trace_it!(MEMBERSHIP: self,
                       TraceKind::MemberUpdate,
                       trace_member_id,
                       trace_incarnation,
                       trace_health);
+            if joined {
+                self.rumor_list.member_joined(&rk.id);
+            }
             self.rumor_list.insert(rk);
         }
     }
@@ -364,6 +650,8 @@ impl Server {
         let trace_member_id = String::from(member.get_id());
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
+        let joined = !incremented_incarnation &&
+                     !self.member_list.contains_member(&trace_member_id);
 
         if self.member_list.insert(member, health) || incremented_incarnation {
             trace_it!(MEMBERSHIP: self,
@@ -371,6 +659,9 @@ impl Server {
                       trace_member_id,
                       trace_incarnation,
                       trace_health);
+            if joined {
+                self.rumor_list.member_joined(&rk.id);
+            }
             self.rumor_list.insert(rk);
         }
     }
@@ -392,20 +683,229 @@ impl Server {
 
     /// Insert a service file rumor into the service store.
     pub fn insert_service_config(&self, service_config: ServiceConfig) {
+        // Checking the signature is the expensive part, so only do it for a config we would keep
+        if !self.config_history.is_news(&service_config) &&
+           !self.service_config_store.is_news(&service_config) {
+            return;
+        }
+        if let Some(reason) = self.refuse_service_config(&service_config) {
+            warn!("Refusing service config {} for {}: {}",
+                  service_config.get_incarnation(),
+                  service_config.get_service_group(),
+                  reason);
+            return;
+        }
         let rk = RumorKey::from(&service_config);
+        // Keep configs that arrive after a later one, too; the later one may be a patch to them
+        self.config_history.insert(service_config.clone());
         if self.service_config_store.insert(service_config) {
             self.rumor_list.insert(rk);
         }
     }
 
-    /// Insert a service file rumor into the service store.
+    /// Insert a service file rumor into the service store. A chunked file whose chunk count does
+    /// not match its size, or that has more chunks than we will hold, is refused, as is one the
+    /// signer policy refuses.
     pub fn insert_service_file(&self, service_file: ServiceFile) {
+        if !self.service_file_store.is_news(&service_file) {
+            return;
+        }
+        if let Some(reason) = self.refuse_service_file(&service_file) {
+            warn!("Refusing service file {} {} for {}: {}",
+                  service_file.get_filename(),
+                  service_file.get_incarnation(),
+                  service_file.get_service_group(),
+                  reason);
+            return;
+        }
+        if !service_file.chunking_is_valid() {
+            warn!("Refusing service file {} for {}; it claims {} chunks for {} bytes",
+                  service_file.get_filename(),
+                  service_file.get_service_group(),
+                  service_file.get_chunk_count(),
+                  service_file.get_size());
+            return;
+        }
         let rk = RumorKey::from(&service_file);
         if self.service_file_store.insert(service_file) {
             self.rumor_list.insert(rk);
+            self.prune_file_chunks();
         }
     }
 
+    /// Insert a service file along with its body, chunking the body if it is too large to gossip.
+    /// The body must already be encrypted, if it is going to be.
+    pub fn insert_service_file_with_body(&self, mut service_file: ServiceFile) -> Result<()> {
+        if let Some(body) = try!(service_file.chunk()) {
+            // A file the signer policy refuses never makes it into the store, so neither should
+            // its body
+            if self.refuse_service_file(&service_file).is_none() {
+                self.file_chunks.insert_body(&body);
+            }
+        }
+        self.insert_service_file(service_file);
+        Ok(())
+    }
+
+    /// Store a chunk of a service file body, as long as some service file we know about refers
+    /// to it. The chunk count and chunk length come from that service file, not the peer; a chunk
+    /// that disagrees with them is ignored.
+    pub fn insert_file_chunk(&self, file_chunk: &FileChunk) {
+        let content_hash = file_chunk.get_content_hash();
+        let (chunk_count, size) = match self.chunking_for(content_hash) {
+            Some(chunking) => chunking,
+            None => {
+                debug!("Ignoring chunk of unknown body {}", content_hash);
+                return;
+            }
+        };
+        let index = file_chunk.get_index();
+        if file_chunk.get_chunk_count() != chunk_count ||
+           file_chunk.get_data().len() as u64 != chunk::chunk_len(size, index) {
+            debug!("Ignoring chunk {} of body {}; it does not match the service file",
+                   index,
+                   content_hash);
+            return;
+        }
+        self.file_chunks.insert(content_hash, index, chunk_count, file_chunk.get_data().to_vec());
+    }
+
+    /// Answer a request for file chunks with every requested chunk we have. At most
+    /// `MAX_CHUNK_WORKERS` requests are served at once; the rest are dropped, and the member will
+    /// ask again on a later gossip round.
+    pub fn serve_file_chunks(&self, member_id: &str, request: &FileChunkRequest) {
+        let mut member = None;
+        self.member_list.with_member(member_id, |m| member = m.cloned());
+        let member = match member {
+            Some(member) => member,
+            None => {
+                debug!("Not serving file chunks to unknown member {}", member_id);
+                return;
+            }
+        };
+        let content_hash = request.get_content_hash();
+        let chunk_count = match self.chunking_for(content_hash) {
+            Some((chunk_count, _)) => chunk_count,
+            None => return,
+        };
+        let chunks: Vec<_> = request.get_index()
+            .iter()
+            .take(MAX_CHUNKS_PER_REQUEST)
+            .filter_map(|&index| {
+                self.file_chunks.get(content_hash, index).map(|data| {
+                    chunk::file_chunk(self.member_id(), content_hash, index, chunk_count, data)
+                })
+            })
+            .collect();
+        if chunks.is_empty() {
+            return;
+        }
+        if self.chunk_workers.fetch_add(1, Ordering::SeqCst) >= MAX_CHUNK_WORKERS {
+            self.chunk_workers.fetch_sub(1, Ordering::SeqCst);
+            debug!("Too busy to serve file chunks to {}", member_id);
+            return;
+        }
+        let server = self.clone();
+        let workers = self.chunk_workers.clone();
+        let _ = thread::Builder::new()
+            .name(String::from("file-chunk-worker"))
+            .spawn(move || {
+                push::PushWorker::new(server).send_messages(member, chunks);
+                workers.fetch_sub(1, Ordering::SeqCst);
+            })
+            .map_err(|e| {
+                self.chunk_workers.fetch_sub(1, Ordering::SeqCst);
+                error!("Could not spawn thread: {}", e)
+            });
+    }
+
+    /// Ask up to `fanout` of the given members for any service file chunks we are missing. The
+    /// missing chunks are spread across the members, so no one member serves a whole body.
+    pub fn request_missing_file_chunks(&self, members: &[Member], fanout: usize) {
+        let peers: Vec<&Member> = members.iter()
+            .filter(|m| {
+                !self.check_blacklist(m.get_id()) && self.member_list.pingable(m) &&
+                !self.member_list.persistent_and_confirmed(m)
+            })
+            .take(fanout)
+            .collect();
+        if peers.is_empty() {
+            return;
+        }
+        let mut requests: Vec<Vec<_>> = vec![Vec::new(); peers.len()];
+        for (content_hash, chunk_count) in self.wanted_file_bodies().into_iter() {
+            let missing = self.file_chunks.missing(&content_hash, chunk_count);
+            let mut indexes: Vec<Vec<u32>> = vec![Vec::new(); peers.len()];
+            for (n, index) in missing.into_iter().enumerate() {
+                let slot = &mut indexes[n % peers.len()];
+                if slot.len() < MAX_CHUNKS_PER_REQUEST {
+                    slot.push(index);
+                }
+            }
+            for (n, indexes) in indexes.into_iter().enumerate() {
+                if !indexes.is_empty() {
+                    requests[n].push(chunk::file_chunk_request(self.member_id(),
+                                                               &content_hash,
+                                                               indexes));
+                }
+            }
+        }
+        for (member, messages) in peers.into_iter().zip(requests.into_iter()) {
+            if messages.is_empty() {
+                continue;
+            }
+            let server = self.clone();
+            let member = member.clone();
+            let _ = thread::Builder::new()
+                .name(String::from("file-chunk-worker"))
+                .spawn(move || push::PushWorker::new(server).send_messages(member, messages))
+                .map_err(|e| error!("Could not spawn thread: {}", e));
+        }
+    }
+
+    /// The content hash and chunk count of every chunked service file whose body we have not
+    /// finished assembling.
+    fn wanted_file_bodies(&self) -> Vec<(String, u32)> {
+        let mut wanted = Vec::new();
+        self.service_file_store.with_keys(|(_, files)| {
+            for sf in files.values() {
+                if sf.is_chunked() &&
+                   !self.file_chunks.missing(sf.get_content_hash(), sf.get_chunk_count())
+                    .is_empty() {
+                    wanted.push((String::from(sf.get_content_hash()), sf.get_chunk_count()));
+                }
+            }
+        });
+        wanted
+    }
+
+    /// The chunk count and size of the body with the given content hash, if some service file we
+    /// know about refers to it.
+    fn chunking_for(&self, content_hash: &str) -> Option<(u32, u64)> {
+        let mut chunking = None;
+        self.service_file_store.with_keys(|(_, files)| {
+            for sf in files.values() {
+                if sf.is_chunked() && sf.get_content_hash() == content_hash {
+                    chunking = Some((sf.get_chunk_count(), sf.get_size()));
+                }
+            }
+        });
+        chunking
+    }
+
+    /// Drop the chunks of any body that no service file refers to anymore.
+    fn prune_file_chunks(&self) {
+        let mut referenced = HashSet::new();
+        self.service_file_store.with_keys(|(_, files)| {
+            for sf in files.values() {
+                if sf.is_chunked() {
+                    referenced.insert(String::from(sf.get_content_hash()));
+                }
+            }
+        });
+        self.file_chunks.retain(|content_hash| referenced.contains(content_hash));
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     pub fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -418,23 +918,140 @@ impl Server {
         electorate
     }
 
+    /// Only take service configs and files that `policy` allows from now on, and forget the ones
+    /// we already have that it refuses, such as those restored from an earlier run.
+    pub fn set_signer_policy(&self, policy: SignerPolicy) {
+        *self.signer_policy.write().expect("Signer policy lock is poisoned") = Some(policy);
+
+        let mut refused_configs = Vec::new();
+        self.service_config_store.with_keys(|(service_group, rumors)| {
+            for sc in rumors.values() {
+                if self.refuse_service_config(sc).is_some() {
+                    refused_configs.push(service_group.clone());
+                }
+            }
+        });
+        for service_group in refused_configs.iter() {
+            warn!("Forgetting the service config for {}; the signer policy refuses it",
+                  service_group);
+            self.service_config_store.remove(service_group, "service_config");
+        }
+        self.config_history.retain(|sc| self.refuse_service_config(sc).is_none());
+
+        let mut refused_files = Vec::new();
+        self.service_file_store.with_keys(|(service_group, rumors)| {
+            for (filename, sf) in rumors.iter() {
+                if self.refuse_service_file(sf).is_some() {
+                    refused_files.push((service_group.clone(), filename.clone()));
+                }
+            }
+        });
+        for &(ref service_group, ref filename) in refused_files.iter() {
+            warn!("Forgetting service file {} for {}; it is refused", filename, service_group);
+            self.service_file_store.remove(service_group, filename);
+        }
+        if !refused_files.is_empty() {
+            self.prune_file_chunks();
+        }
+    }
+
+    /// Forget the tombstones older than `TOMBSTONE_TTL_SECS`. The time a file was deleted is part
+    /// of its tombstone, so every member forgets it at about the same time, and none will take it
+    /// back afterwards.
+    pub fn expire_tombstones(&self) {
+        let now = time::get_time().sec;
+        let mut expired = Vec::new();
+        self.service_file_store.with_keys(|(service_group, rumors)| {
+            for (filename, sf) in rumors.iter() {
+                if sf.is_expired_tombstone(now) {
+                    expired.push((service_group.clone(), filename.clone()));
+                }
+            }
+        });
+        for (service_group, filename) in expired.into_iter() {
+            debug!("Forgetting the tombstone of service file {} for {}",
+                   filename,
+                   service_group);
+            self.service_file_store.remove(&service_group, &filename);
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::ServiceFile, filename, service_group));
+        }
+    }
+
+    /// The reason the signer policy refuses a service config, if there is a policy and it does.
+    fn refuse_service_config(&self, service_config: &ServiceConfig) -> Option<String> {
+        let policy = self.signer_policy.read().expect("Signer policy lock is poisoned");
+        policy.as_ref()
+            .and_then(|policy| policy.check_service_config(service_config, &self.signer_keys).err())
+    }
+
+    /// The reason to refuse a service file: its name would escape the service's files directory,
+    /// it is a tombstone that has expired, or the signer policy refuses it.
+    fn refuse_service_file(&self, service_file: &ServiceFile) -> Option<String> {
+        if !is_safe_filename(service_file.get_filename()) {
+            return Some(String::from("its name leads outside the files directory"));
+        }
+        if service_file.is_expired_tombstone(time::get_time().sec) {
+            return Some(String::from("it is a tombstone that has already expired"));
+        }
+        let policy = self.signer_policy.read().expect("Signer policy lock is poisoned");
+        policy.as_ref()
+            .and_then(|policy| policy.check_service_file(service_file, &self.signer_keys).err())
+    }
+
+    /// Set the quorum policy for a service group.
+    pub fn set_quorum_policy(&self, sg: &ServiceGroup, policy: QuorumPolicy) {
+        let mut policies = self.quorum_policies
+            .write()
+            .expect("Quorum policy lock is poisoned");
+        policies.insert(format!("{}", sg), policy);
+    }
+
+    /// Return the quorum policy for a service group, or the default policy if none was set.
+    pub fn quorum_policy(&self, key: &str) -> QuorumPolicy {
+        let policies = self.quorum_policies
+            .read()
+            .expect("Quorum policy lock is poisoned");
+        policies.get(key).cloned().unwrap_or_else(QuorumPolicy::default)
+    }
+
     /// Check if a given service group has quorum to run an election.
     ///
     /// A given group has quorum if, from this servers perspective, it has an alive population that
-    /// is over 50%, and at least 3 members.
+    /// is over 50% of its total population, and the total population is at least as large as the
+    /// minimum size in its quorum policy.
     pub fn check_quorum(&self, key: &str) -> bool {
-        let electorate = self.get_electorate(key);
+        let alive_population = self.get_electorate(key).len();
+        self.quorum_policy(key).has_quorum(alive_population, self.total_population(key))
+    }
 
-        let total_population = self.service_store.len_for_key(key);
-        let alive_population = electorate.len();
+    /// The population of a service group that quorum is counted against, under its policy.
+    fn total_population(&self, key: &str) -> usize {
+        let mut not_departed = 0;
+        self.service_store.with_rumors(key, |s| {
+            if !self.member_list.check_health_of_by_id(s.get_member_id(), Health::Confirmed) {
+                not_departed += 1;
+            }
+        });
+        self.quorum_policy(key).population(self.service_store.len_for_key(key), not_departed)
+    }
 
-        if total_population < 3 {
-            info!("Quorum size: {}/3 - election cannot complete",
-                  total_population);
-            return false;
-        }
+    /// Returns true if enough of the live members of a service group have acknowledged the
+    /// current lease of its election to make a quorum.
+    fn lease_has_quorum(&self, election: &Election) -> bool {
+        let electorate = self.get_electorate(election.key());
+        let acks = election.get_lease_acks()
+            .iter()
+            .filter(|member_id| electorate.contains(member_id))
+            .count();
+        self.quorum_policy(election.key()).has_quorum(acks, self.total_population(election.key()))
+    }
 
-        alive_population >= ((total_population / 2) + 1)
+    /// Returns true if we are the only member of the electorate, which a quorum policy that leaves
+    /// out departed members allows. Nobody else will ever send our election back to us, so we win
+    /// it as soon as we start it.
+    fn is_sole_voter(&self, key: &str) -> bool {
+        self.get_electorate(key) == vec![String::from(self.member_id())]
     }
 
     /// Start an election for the given service group, declaring this members suitability and the
@@ -445,6 +1062,9 @@ impl Server {
         let ek = RumorKey::from(&e);
         if !self.check_quorum(e.key()) {
             e.no_quorum();
+        } else if self.is_sole_voter(e.key()) {
+            e.finish();
+            self.leader_leases.grant(e.key());
         }
         self.election_store
             .insert(e);
@@ -457,6 +1077,8 @@ impl Server {
         let ek = RumorKey::from(&e);
         if !self.check_quorum(e.key()) {
             e.no_quorum();
+        } else if self.is_sole_voter(e.key()) {
+            e.finish();
         }
         self.update_store
             .insert(e);
@@ -465,11 +1087,16 @@ impl Server {
 
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
-    /// a) We are the leader, and we have lost quorum with the rest of the group.
-    /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    /// a) We are the leader, and we have lost quorum with the rest of the group, or could not
+    ///    renew our lease.
+    /// b) We are not the leader, and we have detected that the leader is confirmed dead, or its
+    ///    lease has run out.
+    ///
+    /// A leader that still has quorum renews its lease here, too.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
+        let mut leases_to_renew = vec![];
 
         self.election_store.with_keys(|(service_group, rumors)| {
             if self.service_store.contains_rumor(&service_group, self.member_id()) {
@@ -483,17 +1110,30 @@ impl Server {
                         warn!("Restarting election with a new term as the leader has lost quorum: {:?}", election);
                         elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
 
+                    } else if !self.leader_leases.is_held(&service_group) {
+                        warn!("Restarting election with a new term as the leader lease has run out: {:?}", election);
+                        elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
+                    } else if self.leader_leases.needs_renewal(&service_group) &&
+                              !self.leader_leases.is_renewing(&service_group) {
+                        leases_to_renew.push(String::from(&service_group[..]));
                     }
                 } else if election.is_finished() {
                     if self.member_list
                         .check_health_of_by_id(election.get_member_id(), Health::Confirmed) {
                             warn!("Restarting election with a new term as the leader is dead {}: {:?}", self.member_id(), election);
                             elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
+                    } else if !self.leader_leases.is_held(&service_group) {
+                            warn!("Restarting election with a new term as the leader lease has run out {}: {:?}", self.member_id(), election);
+                            elections_to_restart.push((String::from(&service_group[..]), election.get_term()));
                     }
                 }
             }
         });
 
+        for service_group in leases_to_renew {
+            self.renew_leader_lease(&service_group);
+        }
+
         self.update_store.with_keys(|(service_group, rumors)| {
             if self.service_store.contains_rumor(&service_group, self.member_id()) {
                 // This is safe; there is only one id for an election, and it is "election"
@@ -529,6 +1169,7 @@ impl Server {
             };
             let term = old_term + 1;
             warn!("Starting a new election for {} {}", sg, term);
+            self.leader_leases.revoke(&service_group);
             self.election_store.remove(&service_group, "election");
             self.start_election(sg, 0, term);
         }
@@ -550,10 +1191,163 @@ impl Server {
         }
     }
 
+    /// Ask the ring to renew our lease as the leader of a service group. Our lease is only
+    /// extended once a quorum of the group has acknowledged the renewal; see `confirm_lease`.
+    fn renew_leader_lease(&self, service_group: &str) {
+        let mut renewed = None;
+        self.election_store.with_rumor(service_group, "election", |e| {
+            if let Some(election) = e {
+                let mut election = election.clone();
+                election.renew_lease();
+                renewed = Some(election);
+            }
+        });
+        if let Some(election) = renewed {
+            debug!("Asking to renew leader lease for {} in term {}",
+                   service_group,
+                   election.get_term());
+            let rk = RumorKey::from(&election);
+            self.leader_leases.renewing(service_group, election.get_lease());
+            if self.election_store.insert(election) {
+                self.rumor_list.insert(rk);
+            }
+            self.confirm_lease(service_group);
+        }
+    }
+
+    /// Acknowledge the lease of the leader of a service group we are a member of, or, if we are
+    /// the leader, extend our lease once a quorum has acknowledged it.
+    fn confirm_lease(&self, service_group: &str) {
+        if !self.service_store.contains_rumor(service_group, self.member_id()) {
+            return;
+        }
+        let mut acked = None;
+        let mut confirmed = None;
+        self.election_store.with_rumor(service_group, "election", |e| {
+            if let Some(election) = e {
+                if !election.is_finished() {
+                    return;
+                }
+                if election.get_member_id() == self.member_id() {
+                    if self.lease_has_quorum(election) {
+                        confirmed = Some(election.get_lease());
+                    }
+                } else if !election.get_lease_acks().contains(&String::from(self.member_id())) {
+                    let mut election = election.clone();
+                    election.insert_lease_ack(self.member_id());
+                    acked = Some(election);
+                }
+            }
+        });
+        if let Some(lease) = confirmed {
+            if self.leader_leases.confirm(service_group, lease) {
+                debug!("Renewed leader lease for {} as {}", service_group, lease);
+            }
+        }
+        if let Some(election) = acked {
+            let rk = RumorKey::from(&election);
+            if self.election_store.insert(election) {
+                self.rumor_list.insert(rk);
+            }
+        }
+    }
+
+    /// The term and lease of the finished election for a service group, if there is one.
+    fn finished_election_lease(&self, service_group: &str) -> Option<(u64, u64)> {
+        let mut lease = None;
+        self.election_store.with_rumor(service_group, "election", |e| {
+            lease = e.and_then(|e| if e.is_finished() {
+                Some((e.get_term(), e.get_lease()))
+            } else {
+                None
+            });
+        });
+        lease
+    }
+
+    /// The fencing token for the current leader of a service group: the term of the election it
+    /// won. Returns `None` if there is no leader, or its lease has run out.
+    pub fn leader_term(&self, service_group: &str) -> Option<u64> {
+        if !self.leader_leases.is_held(service_group) {
+            return None;
+        }
+        self.finished_election_lease(service_group).map(|(term, _)| term)
+    }
+
+    /// Act on a request to hand leadership of a service group to another member, by starting a
+    /// new term in which the nominee is more suitable than anyone else. Members that receive the
+    /// new term vote for the nominee, and it wins as soon as it has every vote.
+    fn transfer_leadership(&self, request: &Election) {
+        let key = request.get_service_group();
+        let mut current: Option<Election> = None;
+        self.election_store.with_rumor(key, "election", |e| current = e.cloned());
+        let current = match current {
+            Some(current) => current,
+            None => {
+                warn!("Ignoring leader transfer for {}; there is no election for it",
+                      key);
+                return;
+            }
+        };
+        let electorate = self.get_electorate(key);
+        let nominee = if request.get_member_id().is_empty() {
+            // Anyone but the current leader. We only know our own suitability, so pick the one
+            // an election between equally suitable members would: the greatest member id.
+            match electorate.iter().filter(|m| *m != current.get_member_id()).max() {
+                Some(member_id) => member_id.clone(),
+                None => {
+                    warn!("Ignoring leader transfer for {}; there is nobody to hand it to",
+                          key);
+                    return;
+                }
+            }
+        } else {
+            String::from(request.get_member_id())
+        };
+        if !electorate.contains(&nominee) {
+            warn!("Ignoring leader transfer for {}; {} is not an alive member of it",
+                  key,
+                  nominee);
+            return;
+        }
+        if current.is_finished() && current.get_member_id() == nominee {
+            info!("Ignoring leader transfer for {}; {} is already the leader",
+                  key,
+                  nominee);
+            return;
+        }
+        let sg = match ServiceGroup::from_str(key) {
+            Ok(sg) => sg,
+            Err(e) => {
+                error!("Leader transfer malformed; cannot parse service group: {}", e);
+                return;
+            }
+        };
+        let term = current.get_term() + 1;
+        warn!("Transferring leadership of {} to {} in term {}",
+              key,
+              nominee,
+              term);
+        let mut e = Election::new(nominee, sg, u64::max_value());
+        e.set_term(term);
+        if self.service_store.contains_rumor(key, self.member_id()) {
+            e.insert_vote(self.member_id());
+        }
+        let rk = RumorKey::from(&e);
+        self.leader_leases.revoke(key);
+        self.election_store.remove(key, "election");
+        self.election_store.insert(e);
+        self.rumor_list.insert(rk);
+    }
+
     /// Insert an election into the election store. Handles creating a new election rumor for this
     /// member on receipt of an election rumor for a service this server cares about. Also handles
     /// stopping the election if we are the winner and we have enough votes.
     pub fn insert_election(&self, mut election: Election) {
+        if election.get_transfer() {
+            self.transfer_leadership(&election);
+            return;
+        }
         let rk = RumorKey::from(&election);
 
         // If this is an election for a service group we care about
@@ -620,9 +1414,18 @@ impl Server {
                 }
             }
         }
+        let service_group = String::from(election.key());
+        let lease_before = self.finished_election_lease(&service_group);
         if self.election_store.insert(election) {
             self.rumor_list.insert(rk);
         }
+        // Hearing about a newly finished election, or a renewed lease, starts the lease clock
+        // over.
+        match self.finished_election_lease(&service_group) {
+            Some(lease) if Some(lease) != lease_before => self.leader_leases.grant(&service_group),
+            _ => {}
+        }
+        self.confirm_lease(&service_group);
     }
 
     pub fn insert_update_election(&self, mut election: ElectionUpdate) {
@@ -697,10 +1500,13 @@ impl Server {
         }
     }
 
+    /// Returns (incarnation, filename, body) for every service file of a service group that is
+    /// newer than the incarnation in `current_service_files`. The body is `None` for a file that
+    /// has been deleted.
     pub fn service_files_for(&self,
                              service_group: &str,
                              current_service_files: &HashMap<String, u64>)
-                             -> Vec<(u64, String, Vec<u8>)> {
+                             -> Vec<(u64, String, Option<Vec<u8>>)> {
         let mut service_files = Vec::new();
 
         self.service_file_store
@@ -708,9 +1514,24 @@ impl Server {
                 let current_incarnation = current_service_files.get(sf.get_filename());
                 if current_incarnation.is_none() ||
                    sf.get_incarnation() > *current_incarnation.unwrap() {
-                    match sf.body() {
+                    if sf.get_deleted() {
+                        service_files.push((sf.get_incarnation(),
+                                            String::from(sf.get_filename()),
+                                            None));
+                        return;
+                    }
+                    let body = if sf.is_chunked() {
+                        // Wait until every chunk has arrived
+                        match self.file_chunks.assemble(sf.get_content_hash()) {
+                            Some(body) => sf.decrypt_body(&body),
+                            None => return,
+                        }
+                    } else {
+                        sf.body()
+                    };
+                    match body {
                         Ok(body) => {
-                            service_files.push((sf.get_incarnation(), String::from(sf.get_filename()), body))
+                            service_files.push((sf.get_incarnation(), String::from(sf.get_filename()), Some(body)))
                         }
                         Err(e) => {
                             warn!("Cannot decrypt service file for {} {} {}: {}", service_group, sf.get_filename(), sf.get_incarnation(), e)
@@ -721,34 +1542,76 @@ impl Server {
         service_files
     }
 
-    /// Returns (incarnation, config) if the service group has a configuration.
+    /// Returns the latest incarnation of a service group's configuration, if it is newer than
+    /// `incarnation`, along with the configs that take the gossip layer there, oldest first; see
+    /// `ConfigHistory::chain`.
     pub fn service_config_for(&self,
                               service_group: &str,
                               incarnation: Option<u64>)
-                              -> Option<(u64, String)> {
-        let mut result = None;
+                              -> Option<(u64, Result<Vec<ServiceConfig>>)> {
+        let mut latest = None;
         self.service_config_store
             .with_rumor(service_group, "service_config", |maybe_sc| {
                 if let Some(sc) = maybe_sc {
                     if incarnation.is_none() || sc.get_incarnation() > incarnation.unwrap() {
-                        match sc.config() {
-                            Ok(config) => result = Some((sc.get_incarnation(), config)),
-                            Err(e) => {
-                                warn!("Cannot decrypt service config for {}: {}", service_group, e)
-                            }
-                        }
+                        latest = Some(sc.clone());
+                    }
+                }
+            });
+        latest.map(|latest| {
+            (latest.get_incarnation(), self.config_history.chain(&latest, incarnation))
+        })
+    }
+
+    /// Returns the rollout policy of an incarnation of a service group's configuration, if it is
+    /// the latest one and is to be rolled out in batches rather than everywhere at once.
+    pub fn service_config_rollout(&self,
+                                  service_group: &str,
+                                  incarnation: u64)
+                                  -> Option<RolloutPolicy> {
+        let mut rollout = None;
+        self.service_config_store
+            .with_rumor(service_group, "service_config", |maybe_sc| {
+                if let Some(sc) = maybe_sc {
+                    if sc.get_incarnation() == incarnation && sc.has_rollout() {
+                        rollout = Some(sc.get_rollout().clone());
                     }
                 }
             });
-        result
+        rollout
     }
 
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, &self.ring_key)
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        message::generate_wire(payload, ring_keys.current(), Wire_Compression::NONE)
+    }
+
+    /// Like `generate_wire`, but compresses the payload if `member` has told us it accepts
+    /// compressed gossip. Members that predate compression never set that flag, so they keep
+    /// receiving plain payloads.
+    fn generate_wire_for(&self, payload: Vec<u8>, member: &Member) -> Result<Vec<u8>> {
+        let compression = if member.get_accepts_compression() {
+            Wire_Compression::DEFLATE
+        } else {
+            Wire_Compression::NONE
+        };
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        message::generate_wire(payload, ring_keys.current(), compression)
+    }
+
+    fn generate_batch_wire(&self, payloads: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        message::generate_batch_wire(payloads, ring_keys.current(), Wire_Compression::DEFLATE)
+    }
+
+    fn unwrap_wire_rumors(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>> {
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        message::unwrap_wire_rumors(payload, &ring_keys)
     }
 
     fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, &self.ring_key)
+        let ring_keys = self.ring_keys.read().expect("Ring key lock is poisoned");
+        message::unwrap_wire(payload, &ring_keys)
     }
 }
 
@@ -765,9 +1628,28 @@ impl fmt::Display for Server {
 #[cfg(test)]
 mod tests {
     mod server {
-        use server::Server;
+        use std::collections::HashMap;
+        use std::str::FromStr;
+
+        use tempdir::TempDir;
+        use time::{self, Duration as TimeDuration, SteadyTime};
+
+        use habitat_core::crypto::SymKey;
+        use habitat_core::package::PackageIdent;
+        use habitat_core::service::ServiceGroup;
+        use chunk::{self, CHUNK_SIZE, MAX_CHUNK_COUNT};
+        use error::Error;
+        use server::{push, Server};
+        use server::persist::{Persist, DATA_FILE};
+        use server::signer_policy::SignerPolicy;
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use message::swim::ServiceConfig_Mode;
+        use rumor::election::Election;
+        use rumor::service::Service;
+        use rumor::service_config::{ConfigChange, ServiceConfig};
+        use rumor::RumorKey;
+        use rumor::service_file::{ServiceFile, TOMBSTONE_TTL_SECS};
         use trace::Trace;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
@@ -789,6 +1671,7 @@ mod tests {
                         member,
                         Trace::default(),
                         None,
+                        None,
                         None)
                 .unwrap()
         }
@@ -808,6 +1691,7 @@ mod tests {
                 member,
                 Trace::default(),
                 None,
+                None,
                 None).is_err())
         }
 
@@ -816,5 +1700,298 @@ mod tests {
             let server = start_server();
             server.start(Timing::default()).expect("Server failed to start");
         }
+
+        #[test]
+        fn restarts_as_the_same_member() {
+            let dir = TempDir::new("butterfly").unwrap();
+            let new_server = || {
+                Server::new("127.0.0.1:0",
+                            "127.0.0.1:0",
+                            Member::new(),
+                            Trace::default(),
+                            None,
+                            None,
+                            Some(dir.path().to_path_buf()))
+                    .unwrap()
+            };
+            let server = new_server();
+            let sg = ServiceGroup::new("redis", "default", None);
+            server.insert_service_config(ServiceConfig::new(server.member_id(),
+                                                            sg.clone(),
+                                                            Vec::from("port = 6379")));
+            Persist::new(&server, dir.path().join(DATA_FILE)).save().unwrap();
+
+            let restarted = new_server();
+            assert_eq!(restarted.member_id(), server.member_id());
+            assert_eq!(restarted.member.read().unwrap().get_incarnation(), 1);
+            assert_eq!(chain_for(&restarted, &sg, None), Some(vec![0]));
+        }
+
+        fn insert_config(server: &Server,
+                         sg: &ServiceGroup,
+                         incarnation: u64,
+                         mode: ServiceConfig_Mode,
+                         base: u64) {
+            let mut sc = ServiceConfig::new(server.member_id(),
+                                            sg.clone(),
+                                            Vec::from(format!("n = {}", incarnation)));
+            sc.set_incarnation(incarnation);
+            sc.set_mode(mode);
+            sc.set_base_incarnation(base);
+            server.insert_service_config(sc);
+        }
+
+        fn chain_for(server: &Server,
+                     sg: &ServiceGroup,
+                     applied: Option<u64>)
+                     -> Option<Vec<u64>> {
+            server.service_config_for(&sg.to_string(), applied)
+                .map(|(_, chain)| chain.unwrap().iter().map(|sc| sc.get_incarnation()).collect())
+        }
+
+        #[test]
+        fn patches_come_with_the_ones_since_the_last_replacement() {
+            let server = start_server();
+            let sg = ServiceGroup::new("redis", "default", None);
+            insert_config(&server, &sg, 1, ServiceConfig_Mode::Patch, 0);
+            insert_config(&server, &sg, 2, ServiceConfig_Mode::Replace, 0);
+            insert_config(&server, &sg, 3, ServiceConfig_Mode::Patch, 2);
+            insert_config(&server, &sg, 4, ServiceConfig_Mode::Patch, 3);
+            assert_eq!(chain_for(&server, &sg, None), Some(vec![2, 3, 4]));
+            assert_eq!(chain_for(&server, &sg, Some(3)), Some(vec![4]));
+            assert_eq!(chain_for(&server, &sg, Some(4)), None);
+            let changes: Vec<ConfigChange> = server.service_config_for(&sg.to_string(), Some(3))
+                .unwrap()
+                .1
+                .unwrap()
+                .iter()
+                .map(|sc| sc.change().unwrap())
+                .collect();
+            assert_eq!(changes,
+                       vec![ConfigChange::Patch(String::from("n = 4"), Vec::new())]);
+        }
+
+        #[test]
+        fn patches_received_out_of_order_still_chain() {
+            let server = start_server();
+            let sg = ServiceGroup::new("redis", "default", None);
+            insert_config(&server, &sg, 1, ServiceConfig_Mode::Replace, 0);
+            insert_config(&server, &sg, 3, ServiceConfig_Mode::Patch, 2);
+            insert_config(&server, &sg, 2, ServiceConfig_Mode::Patch, 1);
+            assert_eq!(chain_for(&server, &sg, None), Some(vec![1, 2, 3]));
+        }
+
+        #[test]
+        fn a_missing_patch_is_a_gap() {
+            let server = start_server();
+            let sg = ServiceGroup::new("redis", "default", None);
+            insert_config(&server, &sg, 1, ServiceConfig_Mode::Replace, 0);
+            insert_config(&server, &sg, 3, ServiceConfig_Mode::Patch, 2);
+            match server.service_config_for(&sg.to_string(), Some(1)) {
+                Some((3, Err(Error::ConfigChainGap(_, 3, 2)))) => {}
+                other => panic!("Expected a gap at incarnation 2, got {:?}", other),
+            }
+        }
+
+        fn chunked_file(server: &Server, size: usize) -> ServiceFile {
+            let mut file = ServiceFile::new(server.member_id(),
+                                            ServiceGroup::new("redis", "default", None),
+                                            "redis.conf",
+                                            vec![7; size]);
+            file.chunk().unwrap();
+            file
+        }
+
+        #[test]
+        fn chunks_that_disagree_with_the_service_file_are_ignored() {
+            let server = start_server();
+            let file = chunked_file(&server, CHUNK_SIZE + 5);
+            let hash = String::from(file.get_content_hash());
+            server.insert_service_file(file);
+            let chunk = |index, count, len| {
+                let rumor = chunk::file_chunk("peer", &hash, index, count, vec![7; len]);
+                rumor.get_file_chunk().clone()
+            };
+            server.insert_file_chunk(&chunk(1, 1024, 5));
+            server.insert_file_chunk(&chunk(1, 2, CHUNK_SIZE));
+            assert_eq!(server.file_chunks.missing(&hash, 2), vec![0, 1]);
+            server.insert_file_chunk(&chunk(1, 2, 5));
+            assert_eq!(server.file_chunks.missing(&hash, 2), vec![0]);
+        }
+
+        #[test]
+        fn service_files_with_bad_chunk_counts_are_refused() {
+            let server = start_server();
+            let mut file = chunked_file(&server, CHUNK_SIZE + 5);
+            file.set_chunk_count(MAX_CHUNK_COUNT);
+            server.insert_service_file(file);
+            assert!(!server.service_file_store.contains_rumor("redis.default", "redis.conf"));
+        }
+
+        /// Three alive members of witcher.prod, with the finished election won by `leader`, one
+        /// of the ids returned; the first is the server itself.
+        fn witcher_group(server: &Server, leader: usize) -> Vec<String> {
+            let ident = PackageIdent::from_str("core/witcher").unwrap();
+            let mut ids = vec![String::from(server.member_id())];
+            for _ in 0..2 {
+                let member = Member::new();
+                ids.push(String::from(member.get_id()));
+                server.insert_member(member, Health::Alive);
+            }
+            for id in ids.iter() {
+                server.insert_service(Service::new(&id[..],
+                                                   &ident,
+                                                   "prod",
+                                                   None,
+                                                   "witcher",
+                                                   "10.0.0.1",
+                                                   vec![]));
+            }
+            let mut election =
+                Election::new(&ids[leader][..], ServiceGroup::new("witcher", "prod", None), 0);
+            election.finish();
+            server.insert_election(election);
+            ids
+        }
+
+        fn election(server: &Server) -> Election {
+            let mut election = None;
+            server.election_store.with_rumor("witcher.prod", "election", |e| {
+                election = e.cloned();
+            });
+            election.unwrap()
+        }
+
+        #[test]
+        fn a_leader_cut_off_from_its_group_cannot_renew_its_lease() {
+            let server = start_server();
+            let ids = witcher_group(&server, 0);
+            assert_eq!(server.leader_term("witcher.prod"), Some(0));
+
+            // The rest of the group still looks alive, but none of it hears the renewal
+            server.leader_leases.revoke("witcher.prod");
+            server.renew_leader_lease("witcher.prod");
+            assert!(server.check_quorum("witcher.prod"));
+            assert_eq!(server.leader_term("witcher.prod"), None);
+
+            // Once a quorum acknowledges it, the lease is ours again
+            let mut acked = election(&server);
+            assert_eq!(acked.get_lease(), 1);
+            acked.insert_lease_ack(&ids[1]);
+            server.insert_election(acked);
+            assert_eq!(server.leader_term("witcher.prod"), Some(0));
+        }
+
+        #[test]
+        fn followers_acknowledge_the_leaders_lease() {
+            let server = start_server();
+            let ids = witcher_group(&server, 1);
+            let mut renewed = election(&server);
+            renewed.renew_lease();
+            server.insert_election(renewed);
+            let election = election(&server);
+            assert_eq!(election.get_lease(), 1);
+            assert!(election.get_lease_acks().contains(&ids[0]));
+            assert_eq!(server.leader_term("witcher.prod"), Some(0));
+        }
+
+        fn ops_only() -> SignerPolicy {
+            let mut signers = HashMap::new();
+            signers.insert(String::from("*"), vec![String::from("ops")]);
+            SignerPolicy::new(signers)
+        }
+
+        #[test]
+        fn a_signer_policy_refuses_unsigned_rumors() {
+            let server = start_server();
+            server.set_signer_policy(ops_only());
+            let sg = ServiceGroup::new("witcher", "prod", None);
+            server.insert_service_config(ServiceConfig::new("adam", sg.clone(), Vec::from("a")));
+            server.insert_service_file(ServiceFile::new("adam", sg, "witcher.conf", vec![1]));
+            assert!(!server.service_config_store.contains_rumor("witcher.prod", "service_config"));
+            assert!(!server.service_file_store.contains_rumor("witcher.prod", "witcher.conf"));
+            assert!(server.config_history.for_service_group("witcher.prod").is_empty());
+        }
+
+        #[test]
+        fn setting_a_signer_policy_forgets_the_rumors_it_refuses() {
+            let server = start_server();
+            let sg = ServiceGroup::new("witcher", "prod", None);
+            server.insert_service_config(ServiceConfig::new("adam", sg.clone(), Vec::from("a")));
+            server.insert_service_file(ServiceFile::new("adam", sg, "witcher.conf", vec![1]));
+            assert!(server.service_config_store.contains_rumor("witcher.prod", "service_config"));
+            server.set_signer_policy(ops_only());
+            assert!(!server.service_config_store.contains_rumor("witcher.prod", "service_config"));
+            assert!(!server.service_file_store.contains_rumor("witcher.prod", "witcher.conf"));
+            assert!(server.config_history.for_service_group("witcher.prod").is_empty());
+        }
+
+        #[test]
+        fn service_files_named_outside_the_files_directory_are_refused() {
+            let server = start_server();
+            let sg = ServiceGroup::new("witcher", "prod", None);
+            let filename = "../../witcher.conf";
+            server.insert_service_file(ServiceFile::tombstone("adam", sg, filename));
+            assert!(!server.service_file_store.contains_rumor("witcher.prod", filename));
+        }
+
+        #[test]
+        fn expired_tombstones_are_forgotten() {
+            let server = start_server();
+            let sg = ServiceGroup::new("witcher", "prod", None);
+            let mut tombstone = ServiceFile::tombstone("adam", sg, "witcher.conf");
+            server.insert_service_file(tombstone.clone());
+            server.expire_tombstones();
+            assert!(server.service_file_store.contains_rumor("witcher.prod", "witcher.conf"));
+
+            let incarnation = tombstone.get_incarnation();
+            let deleted_at = tombstone.get_deleted_at();
+            tombstone.set_incarnation(incarnation + 1);
+            tombstone.set_deleted_at(deleted_at - TOMBSTONE_TTL_SECS);
+            server.service_file_store.insert(tombstone.clone());
+            server.expire_tombstones();
+            assert!(!server.service_file_store.contains_rumor("witcher.prod", "witcher.conf"));
+            assert!(server.rumor_list.first_seen(&RumorKey::from(&tombstone)).is_none());
+
+            server.insert_service_file(tombstone);
+            assert!(!server.service_file_store.contains_rumor("witcher.prod", "witcher.conf"));
+        }
+
+        #[test]
+        fn the_envelope_is_measured_again_for_a_new_ring_key() {
+            let server = start_server();
+            let plain = server.envelope_bytes();
+            let ring_key = SymKey::generate_in_memory("ring").unwrap();
+            server.add_ring_key(ring_key.clone());
+            server.use_ring_key(&ring_key.name_with_rev()).unwrap();
+            assert!(server.envelope_bytes() > plain);
+            assert_eq!(server.envelope_bytes(),
+                       server.generate_wire(Vec::new()).unwrap().len());
+        }
+
+        #[test]
+        fn full_state_goes_to_each_member_at_most_once_an_interval() {
+            let server = start_server();
+            assert!(server.full_state_due("geralt"));
+            assert!(!server.full_state_due("geralt"));
+            assert!(server.full_state_due("yennefer"));
+            server.full_state_sent
+                .write()
+                .unwrap()
+                .insert(String::from("geralt"),
+                        SteadyTime::now() -
+                        TimeDuration::milliseconds(push::FULL_STATE_INTERVAL_MS));
+            assert!(server.full_state_due("geralt"));
+        }
+
+        #[test]
+        fn full_state_is_not_sent_when_too_busy() {
+            let server = start_server();
+            server.full_state_workers.store(push::MAX_FULL_STATE_WORKERS, Ordering::SeqCst);
+            server.send_full_state(Member::new());
+            assert!(server.full_state_sent.read().unwrap().is_empty());
+            assert_eq!(server.full_state_workers.load(Ordering::SeqCst),
+                       push::MAX_FULL_STATE_WORKERS);
+        }
     }
 }
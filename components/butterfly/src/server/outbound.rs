@@ -18,18 +18,19 @@
 
 use std::sync::atomic::Ordering;
 use std::sync::mpsc;
-use std::net::{SocketAddr, UdpSocket};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
 use std::thread;
 use std::time::Duration;
 use std::fmt;
+use std::io;
 
 use time::SteadyTime;
 use protobuf::{Message, RepeatedField};
 
-use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
+use message::swim::{Ack, Ping, PingReq, QueryResponse, Swim, Swim_Type, Rumor_Type};
 use server::Server;
 use server::timing::Timing;
-use member::{Member, Health};
+use member::{canonical_ip, Member, Health};
 use trace::TraceKind;
 
 /// How long to sleep between calls to `recv`.
@@ -51,6 +52,19 @@ impl fmt::Display for AckFrom {
     }
 }
 
+/// The largest SWIM datagram we send, unless told otherwise. Older members read no more than this
+/// much of a datagram, so we can't send more without losing them; it also fits in the smallest
+/// MTU an IPv6 network may have.
+pub const MAX_DATAGRAM_BYTES_DEFAULT: usize = 1024;
+
+/// Room for the field tag and length of a membership entry, and for the length of the wire
+/// message to grow with it.
+pub const ENTRY_OVERHEAD_BYTES: usize = 4;
+
+/// No membership entry is smaller than this, overhead included: a member id alone, as we make
+/// them, is 32 bytes.
+const MIN_MEMBERSHIP_ENTRY_BYTES: usize = 40;
+
 /// The outbound thread
 pub struct Outbound<'a> {
     pub server: &'a Server,
@@ -81,8 +95,9 @@ impl<'a> Outbound<'a> {
     /// period to finish before starting the next probe.
     pub fn run(&mut self) {
         let mut have_members = false;
-        let num_initial = self.server.member_list.len_initial_members();
         loop {
+            // Peers may be discovered after we start, so check for them every time around.
+            let num_initial = self.server.member_list.len_initial_members();
             if !have_members && num_initial != 0 {
                 // The minimum that's strictly more than half
                 let min_to_start = num_initial / 2 + 1;
@@ -212,7 +227,7 @@ impl<'a> Outbound<'a> {
                     // If this was forwarded to us, we want to retain the address of the member who
                     // sent the ack, not the one we received on the socket.
                     if !swim.get_ack().has_forward_to() {
-                        ack_from.set_address(format!("{}", real_addr.ip()));
+                        ack_from.set_address(canonical_ip(real_addr.ip()).to_string());
                     }
                     let ack_from_member: Member = ack_from.into();
                     self.server.insert_member(ack_from_member, Health::Alive);
@@ -236,19 +251,59 @@ impl<'a> Outbound<'a> {
     }
 }
 
-/// Populate a SWIM message with rumors.
+/// Member addresses are kept in plain IPv4 when they can be, but a socket bound to an IPv6
+/// address can only reach an IPv4 member through its IPv4-mapped address.
+fn route(socket: &UdpSocket, addr: SocketAddr) -> SocketAddr {
+    match (socket.local_addr(), addr) {
+        (Ok(SocketAddr::V6(_)), SocketAddr::V4(v4)) => {
+            SocketAddr::new(IpAddr::V6(v4.ip().to_ipv6_mapped()), v4.port())
+        }
+        _ => addr,
+    }
+}
+
+/// Send a SWIM payload, counting its bytes.
+fn send_to(server: &Server,
+           socket: &UdpSocket,
+           payload: &[u8],
+           addr: SocketAddr)
+           -> io::Result<usize> {
+    let sent = try!(socket.send_to(payload, route(socket, addr)));
+    server.metrics.swim_sent(sent);
+    Ok(sent)
+}
+
+/// Populate a SWIM message with as many membership rumors as fit in `Server::max_datagram_bytes`,
+/// least transmitted first. Call it once the rest of the message is filled in, so we know how
+/// much room is left.
 pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut Swim) {
+    // The encrypted wire envelope, and the rest of the message, come out of the same datagram
+    let max_bytes = server.max_datagram_bytes();
+    let mut size = server.envelope_bytes() + ENTRY_OVERHEAD_BYTES + swim.compute_size() as usize;
     let mut membership_entries = RepeatedField::new();
     // If this isn't the first time we are communicating with this target, we want to include this
     // targets current status. This ensures that members always get a "Confirmed" rumor, before we
     // have the chance to flip it to "Alive", which helps make sure we heal from a partition.
     if server.member_list.contains_member(target.get_id()) {
         let always_target = server.member_list.membership_for(target.get_id());
+        size += always_target.compute_size() as usize + ENTRY_OVERHEAD_BYTES;
         membership_entries.push(always_target);
     }
-    let rumors = server.rumor_list.take_by_kind(target.get_id(), 5, Rumor_Type::Member);
-    for &(ref rkey, _heat) in rumors.iter() {
-        membership_entries.push(server.member_list.membership_for(&rkey.key()));
+    // No more membership rumors than could possibly fit
+    let room = max_bytes.saturating_sub(size) / MIN_MEMBERSHIP_ENTRY_BYTES;
+    let mut rumors = Vec::new();
+    for (rkey, heat) in server.rumor_list
+        .take_by_kind(target.get_id(), room, Rumor_Type::Member)
+        .into_iter() {
+        let membership = server.member_list.membership_for(&rkey.key());
+        let entry_size = membership.compute_size() as usize + ENTRY_OVERHEAD_BYTES;
+        if size + entry_size > max_bytes {
+            // The rest wait for the next message, or for gossip
+            break;
+        }
+        size += entry_size;
+        membership_entries.push(membership);
+        rumors.push((rkey, heat));
     }
     // We don't want to update the heat for rumors that we know we are sending to a target that is
     // confirmed dead; the odds are, they won't receive them. Lets spam them a little harder with
@@ -286,7 +341,7 @@ pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, tar
             return;
         }
     };
-    match socket.send_to(&payload, addr) {
+    match send_to(server, socket, &payload, addr) {
         Ok(_s) => {
             info!("Sent PingReq to {}@{} for {}@{}",
                   pingreq_target.get_id(),
@@ -345,7 +400,7 @@ pub fn ping(server: &Server,
         }
     };
 
-    match socket.send_to(&payload, addr) {
+    match send_to(server, socket, &payload, addr) {
         Ok(_s) => {
             if forward_to.is_some() {
                 info!("Sent Ping to {} on behalf of {}@{}",
@@ -388,7 +443,7 @@ pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim:
         }
     };
 
-    match socket.send_to(&payload, addr) {
+    match send_to(server, socket, &payload, addr) {
         Ok(_s) => {
             info!("Forwarded ack to {}@{}",
                   swim.get_ack().get_from().get_id(),
@@ -438,7 +493,7 @@ pub fn ack(server: &Server,
         }
     };
 
-    match socket.send_to(&payload, addr) {
+    match send_to(server, socket, &payload, addr) {
         Ok(_s) => {
             info!("Sent ack to {}@{}",
                   swim.get_ack().get_from().get_id(),
@@ -457,3 +512,102 @@ pub fn ack(server: &Server,
               addr,
               &swim);
 }
+
+/// Send the answer to a query back to whoever asked.
+pub fn query_response(server: &Server,
+                      socket: &UdpSocket,
+                      addr: SocketAddr,
+                      response: QueryResponse) {
+    let mut swim = Swim::new();
+    swim.set_field_type(Swim_Type::QUERY_RESPONSE);
+    swim.set_query_response(response);
+    let bytes = match swim.write_to_bytes() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!("Generating protobuf failed: {}", e);
+            return;
+        }
+    };
+    let payload = match server.generate_wire(bytes) {
+        Ok(payload) => payload,
+        Err(e) => {
+            error!("Generating protobuf failed: {}", e);
+            return;
+        }
+    };
+    match send_to(server, socket, &payload, addr) {
+        Ok(_s) => debug!("Sent query response to {}", addr),
+        Err(e) => error!("Failed query response to {}: {}", addr, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::Message;
+
+    use member::{Health, Member};
+    use message::swim::{Ping, Swim, Swim_Type};
+    use server::Server;
+    use trace::Trace;
+    use super::populate_membership_rumors;
+
+    fn server_with_members(count: usize) -> Server {
+        let server = Server::new("127.0.0.1:0",
+                                 "127.0.0.1:0",
+                                 Member::new(),
+                                 Trace::default(),
+                                 None,
+                                 None,
+                                 None)
+            .unwrap();
+        for _ in 0..count {
+            let mut member = Member::new();
+            member.set_address(String::from("10.0.0.1"));
+            server.insert_member(member, Health::Alive);
+        }
+        server
+    }
+
+    fn populated_ping(server: &Server, target: &Member) -> Swim {
+        let mut swim = Swim::new();
+        swim.set_field_type(Swim_Type::PING);
+        let mut ping = Ping::new();
+        ping.set_from(server.member.read().unwrap().proto.clone());
+        swim.set_ping(ping);
+        populate_membership_rumors(server, target, &mut swim);
+        swim
+    }
+
+    #[test]
+    fn membership_fits_in_a_datagram() {
+        let server = server_with_members(200);
+        let target = Member::new();
+        let swim = populated_ping(&server, &target);
+        let membership = swim.get_membership().len();
+        assert!(membership > 0);
+        assert!(membership < 200);
+        let wire = server.generate_wire(swim.write_to_bytes().unwrap()).unwrap();
+        assert!(wire.len() <= server.max_datagram_bytes(),
+                "{} byte datagram",
+                wire.len());
+    }
+
+    #[test]
+    fn least_transmitted_membership_goes_first() {
+        let server = server_with_members(200);
+        let target = Member::new();
+        let first: Vec<String> = populated_ping(&server, &target)
+            .get_membership()
+            .iter()
+            .map(|m| String::from(m.get_member().get_id()))
+            .collect();
+        let second = populated_ping(&server, &target);
+        assert!(second.get_membership()
+            .iter()
+            .all(|m| !first.iter().any(|id| id == m.get_member().get_id())));
+
+        server.set_max_datagram_bytes(65507);
+        let everything = populated_ping(&server, &target);
+        assert_eq!(everything.get_membership().len(), 200);
+    }
+}
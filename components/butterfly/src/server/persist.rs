@@ -0,0 +1,242 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Saves what a member needs to rejoin the ring as itself after a restart.
+//!
+//! The data file holds our own member record, with its id and incarnation, followed by every
+//! service config in our history, and every current service config, service file, election and
+//! election update rumor we know. Each is a `Rumor`
+//! protobuf, preceded by its length as a big-endian `u32`. Service rumors and the rest of the
+//! membership are left out; they describe the ring as it is now, and the ring tells us that again
+//! soon enough.
+//!
+//! The file is written whenever any of it changes, to a temporary file that is then renamed over
+//! the old one, so a crash leaves either the old snapshot or the new one.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use protobuf::{self, Message};
+
+use error::{Error, Result};
+use message::swim::{Member as ProtoMember, Membership as ProtoMembership, Rumor as ProtoRumor,
+                    Rumor_Type, Membership_Health};
+use rumor::{Rumor, RumorStore};
+use server::Server;
+
+/// The name of the data file in the data directory.
+pub const DATA_FILE: &'static str = "butterfly.dat";
+
+/// How often to check whether anything needs saving.
+const PERSIST_PERIOD_MS: u64 = 1000;
+
+/// What we read back from a data file.
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    /// Our own member record, when we last ran
+    pub member: Option<ProtoMember>,
+    /// Every other rumor in the file
+    pub rumors: Vec<ProtoRumor>,
+}
+
+/// Read a data file. A missing file is an empty snapshot; a damaged one is an error.
+pub fn read(path: &Path) -> Result<Snapshot> {
+    let mut snapshot = Snapshot::default();
+    let mut bytes = Vec::new();
+    match File::open(path) {
+        Ok(mut file) => {
+            try!(file.read_to_end(&mut bytes).map_err(|e| Error::DataFile(e)));
+        }
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(snapshot),
+        Err(e) => return Err(Error::DataFile(e)),
+    }
+    let mut offset = 0;
+    while offset < bytes.len() {
+        if offset + 4 > bytes.len() {
+            return Err(truncated());
+        }
+        let length = ((bytes[offset] as usize) << 24) | ((bytes[offset + 1] as usize) << 16) |
+                     ((bytes[offset + 2] as usize) << 8) |
+                     bytes[offset + 3] as usize;
+        offset += 4;
+        if offset + length > bytes.len() {
+            return Err(truncated());
+        }
+        let mut rumor: ProtoRumor = try!(protobuf::parse_from_bytes(&bytes[offset..offset +
+                                                                             length]));
+        offset += length;
+        if rumor.get_field_type() == Rumor_Type::Member && snapshot.member.is_none() {
+            snapshot.member = Some(rumor.mut_member().take_member());
+        } else {
+            snapshot.rumors.push(rumor);
+        }
+    }
+    Ok(snapshot)
+}
+
+fn truncated() -> Error {
+    Error::DataFile(io::Error::new(io::ErrorKind::InvalidData, "data file is truncated"))
+}
+
+/// Write a data file holding `member` and `rumors`, which are already written to bytes.
+pub fn write(path: &Path, member: &ProtoMember, rumors: &[Vec<u8>]) -> Result<()> {
+    let mut membership = ProtoMembership::new();
+    membership.set_member(member.clone());
+    membership.set_health(Membership_Health::ALIVE);
+    let mut proto = ProtoRumor::new();
+    proto.set_field_type(Rumor_Type::Member);
+    proto.set_from_id(String::from(member.get_id()));
+    proto.set_member(membership);
+    let member_bytes = try!(proto.write_to_bytes());
+
+    let tmp_path = path.with_extension("dat.tmp");
+    {
+        let mut file = try!(File::create(&tmp_path).map_err(|e| Error::DataFile(e)));
+        for bytes in Some(&member_bytes).into_iter().chain(rumors.iter()) {
+            let length = bytes.len();
+            let header = [(length >> 24) as u8, (length >> 16) as u8, (length >> 8) as u8,
+                          length as u8];
+            try!(file.write_all(&header).map_err(|e| Error::DataFile(e)));
+            try!(file.write_all(bytes).map_err(|e| Error::DataFile(e)));
+        }
+        try!(file.sync_all().map_err(|e| Error::DataFile(e)));
+    }
+    try!(fs::rename(&tmp_path, path).map_err(|e| Error::DataFile(e)));
+    Ok(())
+}
+
+fn store_bytes<T: Rumor + Clone>(store: &RumorStore<T>, rumors: &mut Vec<Vec<u8>>) {
+    store.with_keys(|(_key, by_member)| {
+        for rumor in by_member.values() {
+            match rumor.write_to_bytes() {
+                Ok(bytes) => rumors.push(bytes),
+                Err(e) => error!("Cannot save rumor {}: {}", rumor.key(), e),
+            }
+        }
+    });
+}
+
+/// Saves the server's state to its data file whenever it changes.
+pub struct Persist<'a> {
+    pub server: &'a Server,
+    pub path: PathBuf,
+}
+
+impl<'a> Persist<'a> {
+    pub fn new(server: &'a Server, path: PathBuf) -> Persist {
+        Persist {
+            server: server,
+            path: path,
+        }
+    }
+
+    /// Everything that, if it changes, means the data file needs writing again.
+    fn version(&self) -> (u64, usize, usize, usize, usize) {
+        (self.server.member.read().expect("Member lock is poisoned").get_incarnation(),
+         self.server.service_config_store.get_update_counter(),
+         self.server.service_file_store.get_update_counter(),
+         self.server.election_store.get_update_counter(),
+         self.server.update_store.get_update_counter())
+    }
+
+    /// Write the data file now.
+    pub fn save(&self) -> Result<()> {
+        let mut rumors = Vec::new();
+        // Oldest first, so that restoring them builds the same history again
+        self.server.config_history.with_configs(|service_config| {
+            match service_config.write_to_bytes() {
+                Ok(bytes) => rumors.push(bytes),
+                Err(e) => error!("Cannot save rumor {}: {}", service_config.key(), e),
+            }
+        });
+        store_bytes(&self.server.service_config_store, &mut rumors);
+        store_bytes(&self.server.service_file_store, &mut rumors);
+        store_bytes(&self.server.election_store, &mut rumors);
+        store_bytes(&self.server.update_store, &mut rumors);
+        let member = self.server.member.read().expect("Member lock is poisoned").proto.clone();
+        write(&self.path, &member, &rumors)
+    }
+
+    /// Run the persist thread.
+    pub fn run(&self) {
+        let mut saved = None;
+        loop {
+            let version = self.version();
+            if saved != Some(version) {
+                match self.save() {
+                    Ok(()) => saved = Some(version),
+                    Err(e) => error!("Cannot save butterfly state to {}: {}", self.path.display(), e),
+                }
+            }
+            thread::sleep(Duration::from_millis(PERSIST_PERIOD_MS));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use habitat_core::service::ServiceGroup;
+    use member::Member;
+    use message::swim::Rumor_Type;
+    use rumor::Rumor;
+    use rumor::service_config::ServiceConfig;
+    use super::{read, write, DATA_FILE};
+
+    #[test]
+    fn a_missing_file_is_empty() {
+        let dir = TempDir::new("persist").unwrap();
+        let snapshot = read(&dir.path().join(DATA_FILE)).unwrap();
+        assert!(snapshot.member.is_none());
+        assert!(snapshot.rumors.is_empty());
+    }
+
+    #[test]
+    fn round_trips_the_member_and_rumors() {
+        let dir = TempDir::new("persist").unwrap();
+        let path = dir.path().join(DATA_FILE);
+        let mut member = Member::new();
+        member.set_incarnation(7);
+        let config = ServiceConfig::new(member.get_id(),
+                                        ServiceGroup::new("redis", "default", None),
+                                        Vec::from("port = 6379"));
+        write(&path, &member.proto, &[config.write_to_bytes().unwrap()]).unwrap();
+
+        let snapshot = read(&path).unwrap();
+        let saved = snapshot.member.unwrap();
+        assert_eq!(saved.get_id(), member.get_id());
+        assert_eq!(saved.get_incarnation(), 7);
+        assert_eq!(snapshot.rumors.len(), 1);
+        assert_eq!(snapshot.rumors[0].get_field_type(), Rumor_Type::ServiceConfig);
+        assert!(!path.with_extension("dat.tmp").exists());
+    }
+
+    #[test]
+    fn a_truncated_file_is_an_error() {
+        let dir = TempDir::new("persist").unwrap();
+        let path = dir.path().join(DATA_FILE);
+        write(&path, &Member::new().proto, &[]).unwrap();
+        let length = fs::metadata(&path).unwrap().len();
+        let mut file = File::create(&path).unwrap();
+        file.write_all(&[0, 0, 0, length as u8]).unwrap();
+        assert!(read(&path).is_err());
+    }
+}
@@ -49,6 +49,7 @@ impl<'a> Pull<'a> {
         socket.set_linger(0).expect("Failure to set the ZMQ Pull socket to not linger");
         socket.set_tcp_keepalive(0)
             .expect("Failure to set the ZMQ Pull socket to not use keepalive");
+        socket.set_ipv6(true).expect("Failure to set the ZMQ Pull socket to use IPv6");
         socket.bind(&format!("tcp://{}", self.server.gossip_addr()))
             .expect("Failure to bind the ZMQ Pull socket to the port");
         'recv: loop {
@@ -63,8 +64,9 @@ impl<'a> Pull<'a> {
                     continue 'recv;
                 }
             };
-            let payload = match self.server.unwrap_wire(&msg) {
-                Ok(payload) => payload,
+            self.server.metrics.gossip_received(msg.len());
+            let payloads = match self.server.unwrap_wire_rumors(&msg) {
+                Ok(payloads) => payloads,
                 Err(e) => {
                     // NOTE: In the future, we might want to blacklist people who send us
                     // garbage all the time.
@@ -72,43 +74,57 @@ impl<'a> Pull<'a> {
                     continue;
                 }
             };
-            let mut proto: Rumor = match protobuf::parse_from_bytes(&payload) {
-                Ok(proto) => proto,
-                Err(e) => {
-                    error!("Error parsing protobuf: {:?}", e);
-                    continue 'recv;
-                }
-            };
-            if self.server.check_blacklist(proto.get_from_id()) {
-                warn!("Not processing message from {} - it is blacklisted",
-                      proto.get_from_id());
-                continue 'recv;
+            for payload in payloads.iter() {
+                self.process_rumor(payload);
             }
-            trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
-            match proto.get_field_type() {
-                Rumor_Type::Member => {
-                    let member = proto.mut_member().take_member().into();
-                    let health = proto.mut_member().get_health().into();
-                    self.server.insert_member_from_rumor(member, health);
-                }
-                Rumor_Type::Service => {
-                    self.server.insert_service(proto.into());
-                }
-                Rumor_Type::ServiceConfig => {
-                    self.server.insert_service_config(proto.into());
-                }
-                Rumor_Type::ServiceFile => {
-                    self.server.insert_service_file(proto.into());
-                }
-                Rumor_Type::Election => {
-                    self.server.insert_election(proto.into());
-                }
-                Rumor_Type::ElectionUpdate => {
-                    self.server.insert_update_election(proto.into());
-                }
-                Rumor_Type::Fake |
-                Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
+        }
+    }
+
+    /// Apply a single rumor that has been unwrapped from the wire.
+    fn process_rumor(&self, payload: &[u8]) {
+        let mut proto: Rumor = match protobuf::parse_from_bytes(payload) {
+            Ok(proto) => proto,
+            Err(e) => {
+                error!("Error parsing protobuf: {:?}", e);
+                return;
+            }
+        };
+        if self.server.check_blacklist(proto.get_from_id()) {
+            warn!("Not processing message from {} - it is blacklisted",
+                  proto.get_from_id());
+            return;
+        }
+        trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
+        match proto.get_field_type() {
+            Rumor_Type::Member => {
+                let member = proto.mut_member().take_member().into();
+                let health = proto.mut_member().get_health().into();
+                self.server.insert_member_from_rumor(member, health);
+            }
+            Rumor_Type::Service => {
+                self.server.insert_service(proto.into());
+            }
+            Rumor_Type::ServiceConfig => {
+                self.server.insert_service_config(proto.into());
+            }
+            Rumor_Type::ServiceFile => {
+                self.server.insert_service_file(proto.into());
+            }
+            Rumor_Type::Election => {
+                self.server.insert_election(proto.into());
+            }
+            Rumor_Type::ElectionUpdate => {
+                self.server.insert_update_election(proto.into());
+            }
+            Rumor_Type::FileChunk => {
+                self.server.insert_file_chunk(proto.get_file_chunk());
+            }
+            Rumor_Type::FileChunkRequest => {
+                self.server.serve_file_chunks(proto.get_from_id(),
+                                              proto.get_file_chunk_request());
             }
+            Rumor_Type::Fake |
+            Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
         }
     }
 }
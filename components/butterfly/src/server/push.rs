@@ -14,9 +14,13 @@
 
 //! The push thread.
 //!
-//! This is the thread for distributing rumors to members. It distributes to `FANOUT` members, no
-//! more often than `Timing::GOSSIP_PERIOD_DEFAULT_MS`.
+//! This is the thread for distributing rumors to members. It distributes to a fanout of members
+//! at a time, no more often than `Timing::GOSSIP_PERIOD_DEFAULT_MS`. Both the fanout and the number
+//! of times each rumor is pushed grow with the log of the number of members, so large rings
+//! neither flood nor converge slowly; see `Timing::gossip_fanout` and `Timing::retransmit_limit`.
 
+use std::mem;
+use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
@@ -34,7 +38,14 @@ use server::Server;
 use server::timing::Timing;
 use trace::TraceKind;
 
-const FANOUT: usize = 5;
+/// Batches of rumors are sent once they reach this many bytes, before compression.
+pub const MAX_BATCH_BYTES: usize = 256 * 1024;
+
+/// How long before we will send the same member everything we know again.
+pub const FULL_STATE_INTERVAL_MS: i64 = 30000;
+
+/// The most members we send everything we know to at once.
+pub const MAX_FULL_STATE_WORKERS: usize = 4;
 
 /// The Push server
 #[derive(Debug)]
@@ -53,9 +64,9 @@ impl<'a> Push<'a> {
     }
 
     /// Executes the Push thread. Gets a list of members to talk to that are not Confirmed; then
-    /// proceeds to process the list in `FANOUT` sized chunks. If we finish sending the messages to
-    /// all FANOUT targets faster than `Timing::GOSSIP_PERIOD_DEFAULT_MS`, we will block until we
-    /// exceed that time.
+    /// proceeds to process the list in fanout sized chunks. If we finish sending the messages to
+    /// all the fanout targets faster than `Timing::GOSSIP_PERIOD_DEFAULT_MS`, we will block until
+    /// we exceed that time.
     pub fn run(&mut self) {
         'send: loop {
             if self.server.pause.load(Ordering::Relaxed) {
@@ -64,17 +75,24 @@ impl<'a> Push<'a> {
             }
 
             self.server.update_gossip_round();
+            self.server.update_convergence();
 
             let mut check_list = self.server.member_list.check_list(self.server.member_id());
+            let members = check_list.iter()
+                .filter(|m| self.server.member_list.pingable(m))
+                .count();
+            let fanout = self.timing.gossip_fanout(members);
+            self.server.rumor_list.set_retransmit_limit(self.timing.retransmit_limit(members));
+            self.server.request_missing_file_chunks(&check_list, fanout);
             let long_wait = self.timing.gossip_timeout();
 
             'fanout: loop {
-                let mut thread_list = Vec::with_capacity(FANOUT);
+                let mut thread_list = Vec::with_capacity(fanout);
                 if check_list.len() == 0 {
                     break 'fanout;
                 }
-                let drain_length = if check_list.len() >= FANOUT {
-                    FANOUT
+                let drain_length = if check_list.len() >= fanout {
+                    fanout
                 } else {
                     check_list.len()
                 };
@@ -131,7 +149,7 @@ impl<'a> Push<'a> {
 }
 
 /// A worker thread for pushing messages to a target
-struct PushWorker {
+pub struct PushWorker {
     pub server: Server,
 }
 
@@ -141,30 +159,56 @@ impl PushWorker {
         PushWorker { server: server }
     }
 
-    /// Send the list of rumors to a given member. This method creates an outbound socket and then
-    /// closes the connection as soon as we are done sending rumors. ZeroMQ may choose to keep the
-    /// connection and socket open for 1 second longer - so it is possible, but unlikely, that this
-    /// method can loose messages.
+    /// Send the list of rumors to a given member, and update their heat.
     fn send_rumors(&self, member: Member, rumors: RumorVec) {
-        let socket = (**ZMQ_CONTEXT)
-            .as_mut()
-            .socket(zmq::PUSH)
-            .expect("Failure to create the ZMQ push socket");
-        socket.set_linger(1000)
-            .expect("Failure to set the ZMQ push socket to not linger");
-        socket.set_tcp_keepalive(0)
-            .expect("Failure to set the ZMQ push socket to not use keepalive");
-        socket.set_immediate(true).expect("Failure to set the ZMQ push socket to immediate");
-        socket.set_sndhwm(1000).expect("Failure to set the ZMQ push socket hwm");
-        socket.set_sndtimeo(500).expect("Failure to set the ZMQ send timeout");
-        let to_addr = format!("{}:{}", member.get_address(), member.get_gossip_port());
-        match socket.connect(&format!("tcp://{}", to_addr)) {
-            Ok(()) => debug!("Connected push socket to {:?}", member),
-            Err(e) => {
-                println!("Cannot connect push socket to {:?}: {:?}", member, e);
-                return;
+        if self.push_rumors(&member, &rumors) {
+            self.server.rumor_list.update_heat(member.get_id(), &rumors);
+        }
+    }
+
+    /// Send every rumor we know to a member that has just joined, rather than leave it to learn
+    /// the state of the ring a few rumors at a time. The heat of the rumors is left alone; the
+    /// rest of the ring still needs to hear them.
+    pub fn send_full_state(&self, member: Member) {
+        // The configs each current config patch builds on go too, since a patch is no use
+        // without them; the current configs themselves are among the rumors
+        let mut latest = Vec::new();
+        self.server.service_config_store.with_keys(|(_, rumors)| {
+            latest.extend(rumors.values().cloned());
+        });
+        let mut configs = Vec::new();
+        for sc in latest.iter() {
+            if let Ok(mut chain) = self.server.config_history.chain(sc, None) {
+                chain.pop();
+                configs.extend(chain.into_iter().map(ProtoRumor::from));
             }
         }
+        if !configs.is_empty() {
+            self.send_messages(member.clone(), configs);
+        }
+        let rumors = self.server.rumor_list.all_rumors();
+        if rumors.is_empty() {
+            return;
+        }
+        if self.push_rumors(&member, &rumors) {
+            debug!("Sent all {} rumors to {:?}", rumors.len(), member);
+        }
+    }
+
+    /// Push the list of rumors to a given member. This method creates an outbound socket and then
+    /// closes the connection as soon as we are done sending rumors. ZeroMQ may choose to keep the
+    /// connection and socket open for 1 second longer - so it is possible, but unlikely, that this
+    /// method can loose messages. Returns false if we could not connect to the member at all.
+    fn push_rumors(&self, member: &Member, rumors: &RumorVec) -> bool {
+        let mut socket = match MemberSocket::connect(member) {
+            Some(socket) => socket,
+            None => return false,
+        };
+        // Members that accept compression also accept batches, which is where compression pays
+        // off; everyone else gets one rumor per message.
+        let batch_rumors = member.get_accepts_compression();
+        let mut batch = Vec::new();
+        let mut batch_size = 0;
         'rumorlist: for &(ref rumor_key, ref _heat) in rumors.iter() {
             let rumor_as_bytes = match rumor_key.kind {
                 ProtoRumor_Type::Member => {
@@ -254,20 +298,89 @@ impl PushWorker {
                     debug!("You have fake rumors; how odd!");
                     continue 'rumorlist;
                 }
+                ProtoRumor_Type::FileChunk |
+                ProtoRumor_Type::FileChunkRequest => {
+                    debug!("File chunks are sent directly, never gossiped");
+                    continue 'rumorlist;
+                }
             };
-            let payload = match self.server.generate_wire(rumor_as_bytes) {
+            if batch_rumors {
+                batch_size += rumor_as_bytes.len();
+                batch.push(rumor_as_bytes);
+                if batch_size >= MAX_BATCH_BYTES {
+                    self.send_batch(&mut socket, member, mem::replace(&mut batch, Vec::new()));
+                    batch_size = 0;
+                }
+                continue 'rumorlist;
+            }
+            let payload = match self.server.generate_wire_for(rumor_as_bytes, member) {
                 Ok(payload) => payload,
                 Err(e) => {
                     error!("Generating protobuf failed: {}", e);
                     continue 'rumorlist;
                 }
             };
-            match socket.send(&payload, 0) {
-                Ok(()) => debug!("Sent rumor {:?} to {:?}", rumor_key, member),
+            match socket.send(&payload) {
+                Ok(()) => {
+                    self.server.metrics.gossip_sent(payload.len());
+                    debug!("Sent rumor {:?} to {:?}", rumor_key, member)
+                }
                 Err(e) => println!("Could not send rumor to {:?}; ZMQ said: {:?}", member, e),
             }
         }
-        self.server.rumor_list.update_heat(member.get_id(), &rumors);
+        if !batch.is_empty() {
+            self.send_batch(&mut socket, member, batch);
+        }
+        true
+    }
+
+    /// Send a batch of rumors, compressed, in a single message.
+    fn send_batch(&self, socket: &mut MemberSocket, member: &Member, batch: Vec<Vec<u8>>) {
+        let count = batch.len();
+        let payload = match self.server.generate_batch_wire(batch) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Generating protobuf failed: {}", e);
+                return;
+            }
+        };
+        match socket.send(&payload) {
+            Ok(()) => {
+                self.server.metrics.gossip_sent(payload.len());
+                debug!("Sent a batch of {} rumors to {:?}", count, member)
+            }
+            Err(e) => println!("Could not send rumors to {:?}; ZMQ said: {:?}", member, e),
+        }
+    }
+
+    /// Send messages that are not gossiped rumors, such as file chunks, straight to a member.
+    pub fn send_messages(&self, member: Member, messages: Vec<ProtoRumor>) {
+        let mut socket = match MemberSocket::connect(&member) {
+            Some(socket) => socket,
+            None => return,
+        };
+        for message in messages.into_iter() {
+            trace_it!(GOSSIP: &self.server, TraceKind::SendRumor, member.get_id(), &message);
+            let bytes = match message.write_to_bytes() {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    println!("Could not write message to bytes; abandoning sending it: {:?}",
+                             e);
+                    continue;
+                }
+            };
+            let payload = match self.server.generate_wire_for(bytes, &member) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    error!("Generating protobuf failed: {}", e);
+                    continue;
+                }
+            };
+            match socket.send(&payload) {
+                Ok(()) => self.server.metrics.gossip_sent(payload.len()),
+                Err(e) => println!("Could not send message to {:?}; ZMQ said: {:?}", member, e),
+            }
+        }
     }
 
     /// Given a rumorkey, creates a protobuf rumor for sharing.
@@ -287,3 +400,257 @@ impl PushWorker {
         rumor
     }
 }
+
+/// A push socket to a member that may be reached on more than one address.
+///
+/// It connects to one address at a time, in the order `Member::addresses` prefers them. As the
+/// socket only queues messages on a connection that is up, a send that times out means that
+/// address is not answering, so we disconnect from it, connect to the next and send again, until
+/// we run out of addresses.
+struct MemberSocket {
+    socket: zmq::Socket,
+    endpoints: Vec<String>,
+    current: usize,
+}
+
+impl MemberSocket {
+    /// Create an outbound socket connected to the member's gossip port, on the first of its
+    /// addresses we can connect to; `send` falls back to the others.
+    fn connect(member: &Member) -> Option<MemberSocket> {
+        let socket = (**ZMQ_CONTEXT)
+            .as_mut()
+            .socket(zmq::PUSH)
+            .expect("Failure to create the ZMQ push socket");
+        socket.set_linger(1000)
+            .expect("Failure to set the ZMQ push socket to not linger");
+        socket.set_tcp_keepalive(0)
+            .expect("Failure to set the ZMQ push socket to not use keepalive");
+        socket.set_immediate(true).expect("Failure to set the ZMQ push socket to immediate");
+        socket.set_sndhwm(1000).expect("Failure to set the ZMQ push socket hwm");
+        socket.set_sndtimeo(500).expect("Failure to set the ZMQ send timeout");
+        socket.set_ipv6(true).expect("Failure to set the ZMQ push socket to use IPv6");
+        let mut socket = MemberSocket {
+            socket: socket,
+            endpoints: gossip_endpoints(member),
+            current: 0,
+        };
+        if socket.endpoints.is_empty() {
+            println!("Cannot connect push socket to {:?}: no usable address", member);
+            return None;
+        }
+        if socket.connect_from(0) {
+            debug!("Connected push socket to {:?}", member);
+            Some(socket)
+        } else {
+            println!("Cannot connect push socket to {:?} on any of its addresses", member);
+            None
+        }
+    }
+
+    /// Send the payload, moving on to the member's next address whenever this one times out.
+    fn send(&mut self, payload: &[u8]) -> zmq::Result<()> {
+        loop {
+            match self.socket.send(payload, 0) {
+                Err(zmq::Error::EAGAIN) if self.current + 1 < self.endpoints.len() => {
+                    debug!("No connection to {} in time; trying the next address",
+                           self.endpoints[self.current]);
+                    let _ = self.socket.disconnect(&self.endpoints[self.current]);
+                    let next = self.current + 1;
+                    if !self.connect_from(next) {
+                        return Err(zmq::Error::EAGAIN);
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Connect to the first endpoint, starting at `start`, that we can; false if there is none.
+    fn connect_from(&mut self, start: usize) -> bool {
+        for index in start..self.endpoints.len() {
+            match self.socket.connect(&self.endpoints[index]) {
+                Ok(()) => {
+                    self.current = index;
+                    return true;
+                }
+                Err(e) => {
+                    println!("Cannot connect push socket to {}: {:?}",
+                             self.endpoints[index],
+                             e)
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Every endpoint a member's gossip port can be reached on, in the order we try them.
+fn gossip_endpoints(member: &Member) -> Vec<String> {
+    member.addresses()
+        .into_iter()
+        .map(|ip| format!("tcp://{}", SocketAddr::new(ip, member.get_gossip_port() as u16)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng, XorShiftRng};
+    use zmq;
+
+    use ZMQ_CONTEXT;
+    use member::Member;
+    use message::swim::Rumor_Type;
+    use rumor::{RumorKey, RumorList, RUMOR_MAX};
+    use server::timing::Timing;
+    use super::{gossip_endpoints, MemberSocket};
+
+    /// How one rumor spread through a virtual ring.
+    struct Spread {
+        /// How many gossip periods it took to reach every member, if it did
+        converged_in: Option<usize>,
+        /// How many times it was pushed, in total, before every member stopped pushing it
+        sends: usize,
+    }
+
+    /// Spread one rumor from member 0 through a virtual ring of `size` members, the way the push
+    /// thread does: every gossip period, each member pushes its hot rumors to the next fanout of
+    /// members in its shuffled list, and members that hear a new rumor push it on in turn.
+    fn spread(size: usize, timing: &Timing, seed: u32) -> Spread {
+        let mut rng = XorShiftRng::from_seed([seed, 0x9e37, 0x79b9, 0x7f4a]);
+        let ids: Vec<String> = (0..size).map(|n| n.to_string()).collect();
+        let lists: Vec<RumorList> = (0..size).map(|_| RumorList::default()).collect();
+        let fanout = timing.gossip_fanout(size - 1);
+        for list in lists.iter() {
+            list.set_retransmit_limit(timing.retransmit_limit(size - 1));
+        }
+        let rk = RumorKey::new(Rumor_Type::Service, "0", "redis.default");
+        let mut informed = vec![false; size];
+        informed[0] = true;
+        lists[0].insert(rk.clone());
+
+        let mut check_lists: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut converged_in = None;
+        let mut sends = 0;
+        for period in 1..1000 {
+            let mut heard = Vec::new();
+            for member in 0..size {
+                for _ in 0..fanout {
+                    if check_lists[member].is_empty() {
+                        let mut others: Vec<usize> = (0..size).filter(|&o| o != member).collect();
+                        rng.shuffle(&mut others);
+                        check_lists[member] = others;
+                    }
+                    let target = check_lists[member].remove(0);
+                    let rumors = lists[member].rumors(&ids[target]);
+                    if !rumors.is_empty() {
+                        lists[member].update_heat(&ids[target], &rumors);
+                        sends += 1;
+                        heard.push(target);
+                    }
+                }
+            }
+            if heard.is_empty() {
+                break;
+            }
+            // Rumors arrive in time for the next period
+            for target in heard {
+                if !informed[target] {
+                    informed[target] = true;
+                    lists[target].insert(rk.clone());
+                }
+            }
+            if converged_in.is_none() && informed.iter().all(|i| *i) {
+                converged_in = Some(period);
+            }
+        }
+        Spread {
+            converged_in: converged_in,
+            sends: sends,
+        }
+    }
+
+    fn assert_spreads(size: usize, max_periods: usize) {
+        let timing = Timing::default();
+        let limit = timing.retransmit_limit(size - 1);
+        for seed in 1..6 {
+            let spread = spread(size, &timing, seed);
+            match spread.converged_in {
+                Some(periods) => {
+                    assert!(periods <= max_periods,
+                            "{} members took {} periods to converge with seed {}",
+                            size,
+                            periods,
+                            seed)
+                }
+                None => panic!("{} members never converged with seed {}", size, seed),
+            }
+            assert!(spread.sends <= size * limit,
+                    "{} members pushed one rumor {} times",
+                    size,
+                    spread.sends);
+            // Without the limit every member would push it to every other member RUMOR_MAX times
+            assert!(spread.sends < size * (size - 1) * RUMOR_MAX);
+        }
+    }
+
+    #[test]
+    fn small_rings_converge() {
+        assert_spreads(6, 4);
+    }
+
+    #[test]
+    fn rings_of_a_hundred_converge() {
+        assert_spreads(100, 8);
+    }
+
+    #[test]
+    fn rings_of_a_thousand_converge() {
+        assert_spreads(1000, 10);
+    }
+
+    #[test]
+    fn pushes_grow_with_the_log_of_the_ring() {
+        let timing = Timing::default();
+        let hundred = spread(100, &timing, 1).sends / 100;
+        let thousand = spread(1000, &timing, 1).sends / 1000;
+        assert!(thousand <= hundred * 2,
+                "pushes per member grew from {} to {}",
+                hundred,
+                thousand);
+    }
+
+    #[test]
+    fn gossip_endpoints_follow_the_preferred_addresses() {
+        let mut member = Member::new();
+        member.set_gossip_port(9638);
+        member.set_advertised_address(String::from("203.0.113.7"));
+        member.set_address(String::from("10.0.0.1"));
+        member.mut_additional_addresses().push(String::from("::1"));
+        assert_eq!(gossip_endpoints(&member),
+                   vec![String::from("tcp://203.0.113.7:9638"),
+                        String::from("tcp://10.0.0.1:9638"),
+                        String::from("tcp://[::1]:9638")]);
+    }
+
+    #[test]
+    fn a_member_is_reached_on_its_next_address_when_the_first_does_not_answer() {
+        let pull = (**ZMQ_CONTEXT)
+            .as_mut()
+            .socket(zmq::PULL)
+            .expect("Failure to create the ZMQ pull socket");
+        pull.set_rcvtimeo(5000).expect("Failure to set the ZMQ receive timeout");
+        pull.bind("tcp://127.0.0.1:*").expect("Failure to bind the ZMQ pull socket");
+        let endpoint = pull.get_last_endpoint().unwrap().unwrap();
+        let port = endpoint.rsplit(':').next().unwrap().parse::<i32>().unwrap();
+
+        let mut member = Member::new();
+        member.set_gossip_port(port);
+        // Nothing answers on the documentation-only address we prefer
+        member.set_advertised_address(String::from("192.0.2.1"));
+        member.mut_additional_addresses().push(String::from("127.0.0.1"));
+        let mut socket = MemberSocket::connect(&member).expect("No usable address");
+        socket.send(b"rumor").expect("Could not reach the member on any address");
+        assert_eq!(socket.current, 1);
+        assert_eq!(pull.recv_bytes(0).unwrap(), b"rumor".to_vec());
+    }
+}
@@ -0,0 +1,604 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Answers questions about the ring from clients that are not part of it.
+//!
+//! A client sends a `Query` to our SWIM port, and we send a `QueryResponse` back to wherever it
+//! came from. No response is bigger than `Server::max_datagram_bytes`, so a small query never
+//! brings back more than one small datagram. Members, census entries, a service group's files
+//! and its config history, newest first, come a page at a time; the client asks again for what
+//! comes `after` the key of the last entry it has, until `next_after` comes back empty. Keys are
+//! member ids, filenames and incarnations, so an entry that comes or goes between pages moves no
+//! other. A config body too large to fit is left out, and the client fetches it with a
+//! `ConfigBody` query, a slice at a time from the byte offset.
+//!
+//! Only a query that came encrypted with the ring key is answered in full. On a ring without a
+//! key, queries are ignored unless the server was told to answer them, and even then config
+//! bodies are withheld, since anyone could be asking; the response says so.
+
+use std::cmp;
+
+use habitat_core::crypto::hash;
+use protobuf::Message;
+
+use message::swim::{Election_Status, Membership as ProtoMembership, Membership_Health, Query,
+                    QueryResponse, Query_Type, Service as ProtoService,
+                    ServiceFile as ProtoServiceFile};
+use rumor::service_config::ServiceConfig;
+use server::{outbound, Server};
+
+/// Room for the fields of the `Swim` message the response is wrapped in, and for `next_offset`,
+/// which we only fill in once the page is full.
+const RESPONSE_OVERHEAD_BYTES: usize = 16;
+
+/// Room for the tag and length of `next_after`, on top of the key itself.
+const CURSOR_OVERHEAD_BYTES: usize = 4;
+
+/// Room for an incarnation in `configs_too_large`.
+const INCARNATION_BYTES: usize = 11;
+
+/// The least of a config body we send in one response, so the client gets somewhere however
+/// small the datagrams are.
+const MIN_BODY_SLICE_BYTES: usize = 64;
+
+/// Answer a query.
+pub fn answer(server: &Server, query: &Query) -> QueryResponse {
+    let mut response = QueryResponse::new();
+    response.set_member_id(String::from(server.member_id()));
+    // So the client can tell this page from an answer to a query it had to send again
+    response.set_offset(query.get_offset());
+    response.set_after(String::from(query.get_after()));
+    let withhold_bodies = !server.is_encrypted();
+    if withhold_bodies {
+        response.set_bodies_withheld(true);
+    }
+    match query.get_field_type() {
+        Query_Type::Members => {
+            let members = members(server);
+            response.set_total(members.len() as u32);
+            page(server,
+                 after(query.get_after(), members, |m| String::from(m.get_member().get_id())),
+                 &mut response,
+                 |membership| membership.compute_size() as usize,
+                 |response, membership| response.mut_membership().push(membership));
+        }
+        Query_Type::Census => {
+            if let Some(leader_id) = leader_of(server, query.get_service_group()) {
+                response.set_leader_id(leader_id);
+            }
+            let census = census(server, query.get_service_group());
+            response.set_total(census.len() as u32);
+            page(server,
+                 after(query.get_after(),
+                       census,
+                       |&(ref service, _)| String::from(service.get_member_id())),
+                 &mut response,
+                 |&(ref service, ref membership)| {
+                     service.compute_size() as usize +
+                     membership.as_ref().map(|m| m.compute_size() as usize).unwrap_or(0)
+                 },
+                 |response, (service, membership)| {
+                     response.mut_service().push(service);
+                     if let Some(membership) = membership {
+                         response.mut_membership().push(membership);
+                     }
+                 });
+        }
+        Query_Type::Config => {
+            config(server,
+                   query.get_service_group(),
+                   query.get_after(),
+                   withhold_bodies,
+                   &mut response)
+        }
+        Query_Type::ConfigHistory => {
+            let history = server.config_history.for_service_group(query.get_service_group());
+            response.set_total(history.len() as u32);
+            // Newest first, so the next page holds the incarnations below the last one we sent
+            let before = query.get_after().parse::<u64>().ok();
+            let max_bytes = server.max_datagram_bytes();
+            let alone = server.envelope_bytes() + RESPONSE_OVERHEAD_BYTES + INCARNATION_BYTES +
+                        response.compute_size() as usize;
+            let entries: Vec<(String, (ServiceConfig, bool))> = history.into_iter()
+                .filter(|sc| before.map_or(true, |before| sc.get_incarnation() < before))
+                .map(|mut sc| {
+                    let too_large = alone + sc.compute_size() as usize +
+                                    outbound::ENTRY_OVERHEAD_BYTES >
+                                    max_bytes;
+                    if too_large || withhold_bodies {
+                        sc.clear_config();
+                    }
+                    (sc.get_incarnation().to_string(), (sc, too_large))
+                })
+                .collect();
+            page(server,
+                 entries,
+                 &mut response,
+                 |&(ref sc, too_large)| {
+                     sc.compute_size() as usize + if too_large { INCARNATION_BYTES } else { 0 }
+                 },
+                 |response, (sc, too_large)| {
+                     if too_large {
+                         response.mut_configs_too_large().push(sc.get_incarnation());
+                     }
+                     response.mut_config_history().push((*sc).clone());
+                 });
+        }
+        Query_Type::ConfigBody if withhold_bodies => {}
+        Query_Type::ConfigBody => {
+            config_body(server,
+                        query.get_service_group(),
+                        query.get_incarnation(),
+                        query.get_offset(),
+                        &mut response)
+        }
+    }
+    response
+}
+
+/// The `entries`, ordered by key, that come after the key `after`, each paired with its key. All
+/// of them come after an empty key.
+fn after<T, K>(after: &str, entries: Vec<T>, key_of: K) -> Vec<(String, T)>
+    where K: Fn(&T) -> String
+{
+    entries.into_iter()
+        .map(|entry| (key_of(&entry), entry))
+        .filter(|&(ref key, _)| after.is_empty() || &key[..] > after)
+        .collect()
+}
+
+/// Fill `response` with as many `entries` as fit in a datagram, in order. If some are left over,
+/// `next_after` is the key of the last one that fit. There is always at least one, so the client
+/// gets somewhere however small the datagrams are.
+fn page<T, S, A>(server: &Server,
+                 entries: Vec<(String, T)>,
+                 response: &mut QueryResponse,
+                 size_of: S,
+                 mut add: A)
+    where S: Fn(&T) -> usize,
+          A: FnMut(&mut QueryResponse, T)
+{
+    let max_bytes = server.max_datagram_bytes();
+    let mut size = server.envelope_bytes() + RESPONSE_OVERHEAD_BYTES +
+                   response.compute_size() as usize;
+    let mut last_key = None;
+    for (key, entry) in entries.into_iter() {
+        // Leave room to send this entry's key as `next_after`, in case it is the last to fit
+        let entry_size = size_of(&entry) + outbound::ENTRY_OVERHEAD_BYTES;
+        let cursor_size = key.len() + CURSOR_OVERHEAD_BYTES;
+        if last_key.is_some() && size + entry_size + cursor_size > max_bytes {
+            response.set_next_after(last_key.unwrap());
+            return;
+        }
+        size += entry_size;
+        add(response, entry);
+        last_key = Some(key);
+    }
+}
+
+/// Every member we know about, ourselves included, ordered by id so that pages line up.
+fn members(server: &Server) -> Vec<ProtoMembership> {
+    let mut ids = Vec::new();
+    server.member_list.with_members(|member| {
+        if member.get_id() != server.member_id() &&
+           server.member_list.health_of(member).is_some() {
+            ids.push(String::from(member.get_id()));
+        }
+    });
+    let mut us = ProtoMembership::new();
+    us.set_member(server.member.read().expect("Member lock is poisoned").proto.clone());
+    us.set_health(Membership_Health::ALIVE);
+    let mut members: Vec<ProtoMembership> =
+        ids.iter().map(|id| server.member_list.membership_for(id)).collect();
+    members.push(us);
+    members.sort_by(|a, b| a.get_member().get_id().cmp(b.get_member().get_id()));
+    members
+}
+
+/// Every member of a service group, along with how healthy we think it is.
+fn census(server: &Server,
+          service_group: &str)
+          -> Vec<(ProtoService, Option<ProtoMembership>)> {
+    let mut services = Vec::new();
+    server.service_store.with_rumors(service_group, |service| {
+        let membership = if service.get_member_id() == server.member_id() {
+            None
+        } else if server.member_list.health_of_by_id(service.get_member_id()).is_some() {
+            Some(server.member_list.membership_for(service.get_member_id()))
+        } else {
+            None
+        };
+        services.push((ProtoService::clone(service), membership));
+    });
+    services.sort_by(|a, b| a.0.get_member_id().cmp(b.0.get_member_id()));
+    services
+}
+
+/// The member that won the latest election for a service group, if it is over.
+fn leader_of(server: &Server, service_group: &str) -> Option<String> {
+    let mut leader = None;
+    server.election_store.with_rumor(service_group, "election", |election| {
+        if let Some(election) = election {
+            if election.get_status() == Election_Status::Finished {
+                leader = Some(String::from(election.get_member_id()));
+            }
+        }
+    });
+    leader
+}
+
+/// The latest config for a service group, and every file we know it has, without their bodies
+/// but with a hash of each, so a client can tell whether its own copy is the same. Files that
+/// have been deleted are there too, marked deleted, so a client sending the file again knows
+/// which incarnation it has to beat. The config comes on the first page, without its body if
+/// that would not fit or is withheld, and the files a page at a time.
+fn config(server: &Server,
+          service_group: &str,
+          after_filename: &str,
+          withhold_body: bool,
+          response: &mut QueryResponse) {
+    if after_filename.is_empty() {
+        server.service_config_store.with_rumor(service_group, "service_config", |sc| {
+            if let Some(sc) = sc {
+                response.set_service_config((**sc).clone());
+            }
+        });
+        if withhold_body && response.has_service_config() {
+            response.mut_service_config().clear_config();
+        }
+        let size = server.envelope_bytes() + RESPONSE_OVERHEAD_BYTES +
+                   response.compute_size() as usize;
+        if size > server.max_datagram_bytes() {
+            response.mut_service_config().clear_config();
+            response.set_config_too_large(true);
+        }
+    }
+    let mut files = Vec::new();
+    server.service_file_store.with_rumors(service_group, |sf| {
+        let mut file = ProtoServiceFile::clone(sf);
+        // Only chunked files already carry their size and hash, and tombstones have neither
+        if !file.get_deleted() && !file.has_size() {
+            let size = file.get_body().len() as u64;
+            file.set_size(size);
+        }
+        if !file.get_deleted() && !file.has_content_hash() {
+            match hash::hash_bytes(file.get_body()) {
+                Ok(content_hash) => file.set_content_hash(content_hash),
+                Err(e) => warn!("Cannot hash service file {}: {}", file.get_filename(), e),
+            }
+        }
+        file.clear_body();
+        file.clear_signature();
+        files.push(file);
+    });
+    response.set_total(files.len() as u32);
+    files.sort_by(|a, b| a.get_filename().cmp(b.get_filename()));
+    page(server,
+         after(after_filename, files, |file| String::from(file.get_filename())),
+         response,
+         |file| file.compute_size() as usize,
+         |response, file| response.mut_service_file().push(file));
+}
+
+/// As much of the body of an incarnation of a service group's config as fits, from the byte
+/// `offset`. `total` is the length of the whole body, and `next_offset` where the rest starts.
+fn config_body(server: &Server,
+               service_group: &str,
+               incarnation: u64,
+               offset: u32,
+               response: &mut QueryResponse) {
+    let mut sc = match server.config_history
+        .for_service_group(service_group)
+        .into_iter()
+        .find(|sc| sc.get_incarnation() == incarnation) {
+        Some(sc) => sc,
+        None => return,
+    };
+    let body = sc.take_config();
+    let start = cmp::min(offset as usize, body.len());
+    response.set_total(body.len() as u32);
+    response.set_service_config((*sc).clone());
+    // Count `next_offset` at its largest, and the length of the body field
+    response.set_next_offset(body.len() as u32);
+    let size = server.envelope_bytes() + RESPONSE_OVERHEAD_BYTES + outbound::ENTRY_OVERHEAD_BYTES +
+               response.compute_size() as usize;
+    let room = cmp::max(server.max_datagram_bytes().saturating_sub(size), MIN_BODY_SLICE_BYTES);
+    let end = cmp::min(start + room, body.len());
+    response.mut_service_config().set_config(body[start..end].to_vec());
+    if end < body.len() {
+        response.set_next_offset(end as u32);
+    } else {
+        response.clear_next_offset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use habitat_core::crypto::{hash, SymKey};
+    use habitat_core::package::PackageIdent;
+    use habitat_core::service::ServiceGroup;
+    use protobuf::Message;
+
+    use member::{Health, Member};
+    use message::swim::{Query, Query_Type};
+    use rumor::service::Service;
+    use rumor::service_config::ServiceConfig;
+    use rumor::service_file::ServiceFile;
+    use server::Server;
+    use trace::Trace;
+    use super::answer;
+
+    fn server_with(ring_key: Option<SymKey>) -> Server {
+        Server::new("127.0.0.1:0",
+                    "127.0.0.1:0",
+                    Member::new(),
+                    Trace::default(),
+                    ring_key,
+                    None,
+                    None)
+            .unwrap()
+    }
+
+    fn server() -> Server {
+        server_with(Some(SymKey::generate_in_memory("ring").unwrap()))
+    }
+
+    fn query(kind: Query_Type, after: &str) -> Query {
+        let mut query = Query::new();
+        query.set_field_type(kind);
+        query.set_service_group(String::from("redis.default"));
+        query.set_after(String::from(after));
+        query
+    }
+
+    fn config_body_query(incarnation: u64, offset: u32) -> Query {
+        let mut query = query(Query_Type::ConfigBody, "");
+        query.set_incarnation(incarnation);
+        query.set_offset(offset);
+        query
+    }
+
+    /// The filenames of every page of a `Config` query, asked for one page at a time, calling
+    /// `between_pages` after each.
+    fn filenames<F>(server: &Server, mut between_pages: F) -> Vec<String>
+        where F: FnMut()
+    {
+        let mut names = Vec::new();
+        let mut after = String::new();
+        loop {
+            let response = answer(server, &query(Query_Type::Config, &after));
+            assert!(response.compute_size() <= 512);
+            names.extend(response.get_service_file()
+                .iter()
+                .map(|file| String::from(file.get_filename())));
+            after = String::from(response.get_next_after());
+            if after.is_empty() {
+                return names;
+            }
+            between_pages();
+        }
+    }
+
+    #[test]
+    fn members_come_a_page_at_a_time() {
+        let server = server();
+        for _ in 0..50 {
+            server.insert_member(Member::new(), Health::Alive);
+        }
+        let mut ids = Vec::new();
+        let mut after = String::new();
+        let mut pages = 0;
+        loop {
+            let response = answer(&server, &query(Query_Type::Members, &after));
+            assert_eq!(response.get_total(), 51);
+            ids.extend(response.get_membership().iter().map(|m| {
+                String::from(m.get_member().get_id())
+            }));
+            pages += 1;
+            after = String::from(response.get_next_after());
+            if after.is_empty() {
+                break;
+            }
+        }
+        assert!(pages > 1);
+        assert_eq!(ids.len(), 51);
+        let mut sorted = ids.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, ids);
+        assert!(ids.iter().any(|id| id == server.member_id()));
+    }
+
+    #[test]
+    fn census_lists_the_members_of_a_service_group() {
+        let server = server();
+        let member = Member::new();
+        server.insert_member(member.clone(), Health::Suspect);
+        server.insert_service(Service::new(member.get_id(),
+                                           &PackageIdent::from_str("core/redis").unwrap(),
+                                           "default",
+                                           None,
+                                           "redis-1",
+                                           "10.0.0.1",
+                                           vec![6379]));
+        let response = answer(&server, &query(Query_Type::Census, ""));
+        assert_eq!(response.get_total(), 1);
+        assert_eq!(response.get_service()[0].get_member_id(), member.get_id());
+        assert_eq!(response.get_membership()[0].get_member().get_id(), member.get_id());
+        assert!(!response.has_leader_id());
+    }
+
+    #[test]
+    fn config_is_the_latest_incarnation() {
+        let server = server();
+        let mut sc = ServiceConfig::new(server.member_id(),
+                                        ServiceGroup::new("redis", "default", None),
+                                        Vec::from("port = 6379"));
+        sc.set_incarnation(4);
+        server.insert_service_config(sc);
+        let response = answer(&server, &query(Query_Type::Config, ""));
+        assert_eq!(response.get_service_config().get_incarnation(), 4);
+        assert_eq!(response.get_service_config().get_config(), b"port = 6379");
+        assert!(!response.get_config_too_large());
+    }
+
+    #[test]
+    fn config_marks_deleted_files() {
+        let server = server();
+        let sg = ServiceGroup::new("redis", "default", None);
+        let file = ServiceFile::new(server.member_id(), sg.clone(), "redis.conf", Vec::from("x"));
+        server.insert_service_file(file);
+        let mut tombstone = ServiceFile::tombstone(server.member_id(), sg.clone(), "old.conf");
+        tombstone.set_incarnation(3);
+        server.insert_service_file(tombstone);
+        let response = answer(&server, &query(Query_Type::Config, ""));
+        assert_eq!(response.get_service_file().len(), 2);
+        let deleted = &response.get_service_file()[0];
+        assert_eq!(deleted.get_filename(), "old.conf");
+        assert!(deleted.get_deleted());
+        assert_eq!(deleted.get_incarnation(), 3);
+        let file = &response.get_service_file()[1];
+        assert_eq!(file.get_filename(), "redis.conf");
+        assert!(!file.get_deleted());
+        assert_eq!(file.get_size(), 1);
+        assert_eq!(file.get_content_hash(), hash::hash_bytes(b"x").unwrap());
+        assert!(file.get_body().is_empty());
+    }
+
+    #[test]
+    fn config_history_is_newest_first() {
+        let server = server();
+        for incarnation in 1..4 {
+            let mut sc = ServiceConfig::new(server.member_id(),
+                                            ServiceGroup::new("redis", "default", None),
+                                            Vec::from(format!("port = {}", 6378 + incarnation)));
+            sc.set_incarnation(incarnation);
+            server.insert_service_config(sc);
+        }
+        let response = answer(&server, &query(Query_Type::ConfigHistory, ""));
+        let incarnations: Vec<u64> =
+            response.get_config_history().iter().map(|sc| sc.get_incarnation()).collect();
+        assert_eq!(incarnations, vec![3, 2, 1]);
+    }
+
+    fn large_config(server: &Server, incarnation: u64) -> Vec<u8> {
+        let body: Vec<u8> = (0..3000).map(|i| b'a' + (i % 26) as u8).collect();
+        let mut sc = ServiceConfig::new(server.member_id(),
+                                        ServiceGroup::new("redis", "default", None),
+                                        body.clone());
+        sc.set_incarnation(incarnation);
+        server.insert_service_config(sc);
+        body
+    }
+
+    #[test]
+    fn a_large_config_is_fetched_a_slice_at_a_time() {
+        let server = server();
+        server.set_max_datagram_bytes(512);
+        let body = large_config(&server, 2);
+        let response = answer(&server, &query(Query_Type::Config, ""));
+        assert!(response.get_config_too_large());
+        assert!(response.get_service_config().get_config().is_empty());
+        assert!(response.compute_size() <= 512);
+
+        let mut fetched = Vec::new();
+        let mut offset = 0;
+        loop {
+            let response = answer(&server, &config_body_query(2, offset));
+            assert!(response.compute_size() <= 512);
+            assert_eq!(response.get_total(), body.len() as u32);
+            fetched.extend_from_slice(response.get_service_config().get_config());
+            offset = response.get_next_offset();
+            if offset == 0 {
+                break;
+            }
+        }
+        assert_eq!(fetched, body);
+    }
+
+    #[test]
+    fn config_body_of_an_unknown_incarnation_is_empty() {
+        let server = server();
+        large_config(&server, 2);
+        assert!(!answer(&server, &config_body_query(3, 0)).has_service_config());
+    }
+
+    #[test]
+    fn files_come_a_page_at_a_time() {
+        let server = server();
+        server.set_max_datagram_bytes(512);
+        let sg = ServiceGroup::new("redis", "default", None);
+        for n in 0..20 {
+            server.insert_service_file(ServiceFile::new(server.member_id(),
+                                                        sg.clone(),
+                                                        format!("file-{:02}.conf", n),
+                                                        Vec::from("x")));
+        }
+        let names = filenames(&server, || {});
+        assert_eq!(names.len(), 20);
+        assert_eq!(names[0], "file-00.conf");
+    }
+
+    #[test]
+    fn a_file_that_goes_between_pages_moves_no_other() {
+        let server = server();
+        server.set_max_datagram_bytes(512);
+        let sg = ServiceGroup::new("redis", "default", None);
+        for n in 0..20 {
+            server.insert_service_file(ServiceFile::new(server.member_id(),
+                                                        sg.clone(),
+                                                        format!("file-{:02}.conf", n),
+                                                        Vec::from("x")));
+        }
+        let mut removed = false;
+        let names = filenames(&server, || {
+            if !removed {
+                server.service_file_store.remove("redis.default", "file-00.conf");
+                removed = true;
+            }
+        });
+        let expected: Vec<String> = (0..20).map(|n| format!("file-{:02}.conf", n)).collect();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn config_history_leaves_out_bodies_too_large_to_fit() {
+        let server = server();
+        server.set_max_datagram_bytes(512);
+        large_config(&server, 2);
+        let response = answer(&server, &query(Query_Type::ConfigHistory, ""));
+        assert!(response.compute_size() <= 512);
+        assert_eq!(response.get_configs_too_large(), &[2]);
+        assert!(response.get_config_history()[0].get_config().is_empty());
+    }
+
+    #[test]
+    fn a_ring_without_a_key_withholds_config_bodies() {
+        let server = server_with(None);
+        let mut sc = ServiceConfig::new(server.member_id(),
+                                        ServiceGroup::new("redis", "default", None),
+                                        Vec::from("password = \"hunter2\""));
+        sc.set_incarnation(1);
+        server.insert_service_config(sc);
+        let response = answer(&server, &query(Query_Type::Config, ""));
+        assert!(response.get_bodies_withheld());
+        assert_eq!(response.get_service_config().get_incarnation(), 1);
+        assert!(response.get_service_config().get_config().is_empty());
+        let response = answer(&server, &query(Query_Type::ConfigHistory, ""));
+        assert!(response.get_config_history()[0].get_config().is_empty());
+        assert!(!answer(&server, &config_body_query(1, 0)).has_service_config());
+    }
+}
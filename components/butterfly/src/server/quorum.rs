@@ -0,0 +1,94 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Quorum policies for elections.
+//!
+//! A service group has quorum when more than half of its population is alive, and the population
+//! is at least `min_size`. By default the population is every member that has ever announced the
+//! service, and it takes at least 3 of them to hold an election. A policy can instead leave out
+//! members that are confirmed dead, or declare the size the group is expected to be.
+
+/// The smallest population that can hold an election, unless the policy says otherwise.
+pub const MIN_SIZE_DEFAULT: usize = 3;
+
+/// How a service group decides whether it has quorum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumPolicy {
+    /// The fewest members the population may have for an election to complete.
+    pub min_size: usize,
+    /// Whether members that are confirmed dead still count toward the population.
+    pub count_departed: bool,
+    /// If set, the population is always this size, no matter how many members we have heard of.
+    pub expected_size: Option<usize>,
+}
+
+impl Default for QuorumPolicy {
+    fn default() -> QuorumPolicy {
+        QuorumPolicy {
+            min_size: MIN_SIZE_DEFAULT,
+            count_departed: true,
+            expected_size: None,
+        }
+    }
+}
+
+impl QuorumPolicy {
+    /// The population to measure quorum against, given every member we have heard of and the
+    /// members that are not confirmed dead.
+    pub fn population(&self, known: usize, not_departed: usize) -> usize {
+        match self.expected_size {
+            Some(expected_size) => expected_size,
+            None if self.count_departed => known,
+            None => not_departed,
+        }
+    }
+
+    /// Returns true if `alive` members are a quorum of `population`.
+    pub fn has_quorum(&self, alive: usize, population: usize) -> bool {
+        if population < self.min_size {
+            info!("Quorum size: {}/{} - election cannot complete",
+                  population,
+                  self.min_size);
+            return false;
+        }
+        alive >= ((population / 2) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuorumPolicy;
+
+    #[test]
+    fn default_needs_three_members_and_a_majority() {
+        let policy = QuorumPolicy::default();
+        assert!(!policy.has_quorum(2, 2));
+        assert!(policy.has_quorum(2, 3));
+        assert!(!policy.has_quorum(1, 3));
+        assert_eq!(policy.population(5, 3), 5);
+    }
+
+    #[test]
+    fn departed_members_can_be_left_out() {
+        let policy = QuorumPolicy { count_departed: false, ..QuorumPolicy::default() };
+        assert_eq!(policy.population(5, 3), 3);
+    }
+
+    #[test]
+    fn expected_size_overrides_the_population() {
+        let policy = QuorumPolicy { expected_size: Some(4), ..QuorumPolicy::default() };
+        assert_eq!(policy.population(5, 3), 4);
+        assert_eq!(policy.population(2, 2), 4);
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signer policies for service configs and files.
+//!
+//! A policy maps a service group to the names of the signing keys allowed to change its
+//! configuration and files; the special `"*"` entry applies to every service group. A server with
+//! a policy refuses any service config or service file that is unsigned, whose signature does not
+//! verify against the public key in the key cache, or whose signer is not listed for the service
+//! group, so a refused rumor is neither kept nor gossiped on.
+//!
+//! Public keys are read from the key cache the first time a signer is seen, and kept in
+//! `SignerKeys` after that, so checking a rumor does not go to disk.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::result;
+use std::sync::{Arc, RwLock};
+
+use habitat_core::crypto::{SigKeyPair, default_cache_key_path};
+
+use error::Result;
+use rumor::service_config::ServiceConfig;
+use rumor::service_file::ServiceFile;
+
+/// The entry that applies to every service group.
+pub const ANY_SERVICE_GROUP: &'static str = "*";
+
+/// Which signing keys may change which service groups.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignerPolicy {
+    signers: HashMap<String, Vec<String>>,
+}
+
+impl SignerPolicy {
+    /// Create a policy from the names of the signing keys allowed for each service group.
+    pub fn new(signers: HashMap<String, Vec<String>>) -> SignerPolicy {
+        SignerPolicy { signers: signers }
+    }
+
+    /// Returns true if `signer` may change the configuration and files of `service_group`.
+    pub fn allows(&self, service_group: &str, signer: &str) -> bool {
+        [service_group, ANY_SERVICE_GROUP].iter().any(|sg| {
+            self.signers
+                .get(*sg)
+                .map_or(false, |allowed| allowed.iter().any(|name| name == signer))
+        })
+    }
+
+    /// Check a service config against the policy, returning the reason it is refused.
+    pub fn check_service_config(&self,
+                                service_config: &ServiceConfig,
+                                keys: &SignerKeys)
+                                -> result::Result<(), String> {
+        match service_config.verify(keys) {
+            Ok(signer) => self.check_signer(service_config.get_service_group(), &signer),
+            Err(e) => Err(format!("{}", e)),
+        }
+    }
+
+    /// Check a service file against the policy, returning the reason it is refused.
+    pub fn check_service_file(&self,
+                              service_file: &ServiceFile,
+                              keys: &SignerKeys)
+                              -> result::Result<(), String> {
+        match service_file.verify(keys) {
+            Ok(signer) => self.check_signer(service_file.get_service_group(), &signer),
+            Err(e) => Err(format!("{}", e)),
+        }
+    }
+
+    fn check_signer(&self, service_group: &str, signer: &str) -> result::Result<(), String> {
+        if self.allows(service_group, signer) {
+            Ok(())
+        } else {
+            Err(format!("{} is not allowed to change {}", signer, service_group))
+        }
+    }
+}
+
+/// The public keys of the signers we have seen, by name and revision.
+#[derive(Clone)]
+pub struct SignerKeys {
+    cache_key_path: Arc<PathBuf>,
+    keys: Arc<RwLock<HashMap<String, SigKeyPair>>>,
+}
+
+impl Default for SignerKeys {
+    fn default() -> SignerKeys {
+        SignerKeys::new(default_cache_key_path(None))
+    }
+}
+
+impl fmt::Debug for SignerKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys = self.keys.read().expect("Signer keys lock poisoned");
+        write!(f, "SignerKeys {{ {:?} }}", keys.keys().collect::<Vec<&String>>())
+    }
+}
+
+impl SignerKeys {
+    /// Read keys from the key cache at `cache_key_path`.
+    pub fn new<P: Into<PathBuf>>(cache_key_path: P) -> SignerKeys {
+        SignerKeys {
+            cache_key_path: Arc::new(cache_key_path.into()),
+            keys: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// The key named `name_with_rev`. A key that is not in the key cache yet is looked for again
+    /// the next time it is asked for.
+    pub fn get(&self, name_with_rev: &str) -> Result<SigKeyPair> {
+        if let Some(key) = self.keys.read().expect("Signer keys lock poisoned").get(name_with_rev) {
+            return Ok(key.clone());
+        }
+        let key = try!(SigKeyPair::get_pair_for(name_with_rev, &*self.cache_key_path));
+        self.keys
+            .write()
+            .expect("Signer keys lock poisoned")
+            .insert(String::from(name_with_rev), key.clone());
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+
+    use habitat_core::crypto::SigKeyPair;
+    use habitat_core::service::ServiceGroup;
+    use tempdir::TempDir;
+
+    use rumor::service_config::ServiceConfig;
+    use rumor::service_file::ServiceFile;
+    use super::{SignerKeys, SignerPolicy};
+
+    fn policy(service_group: &str, signers: &[&str]) -> SignerPolicy {
+        let mut map = HashMap::new();
+        map.insert(String::from(service_group),
+                   signers.iter().map(|s| String::from(*s)).collect());
+        SignerPolicy::new(map)
+    }
+
+    #[test]
+    fn allows_listed_signers() {
+        let policy = policy("redis.default", &["adam", "jamie"]);
+        assert!(policy.allows("redis.default", "adam"));
+        assert!(!policy.allows("redis.default", "fletcher"));
+        assert!(!policy.allows("redis.production", "adam"));
+    }
+
+    #[test]
+    fn refuses_unsigned_service_config() {
+        let sc = ServiceConfig::new("adam",
+                                    ServiceGroup::new("redis", "default", None),
+                                    Vec::from("port = 6379"));
+        assert!(policy("*", &["ops"]).check_service_config(&sc, &SignerKeys::default()).is_err());
+    }
+
+    #[test]
+    fn refuses_unsigned_service_file() {
+        let sf = ServiceFile::new("adam",
+                                  ServiceGroup::new("redis", "default", None),
+                                  "redis.conf",
+                                  Vec::from("port 6379"));
+        assert!(policy("*", &["ops"]).check_service_file(&sf, &SignerKeys::default()).is_err());
+    }
+
+    #[test]
+    fn signer_keys_are_read_from_the_key_cache_once() {
+        let cache = TempDir::new("signer-keys").expect("Could not create a key cache");
+        let pair = SigKeyPair::generate_pair_for_origin("ops", cache.path())
+            .expect("Could not generate a signing key");
+        let keys = SignerKeys::new(cache.path());
+        assert!(keys.get(&pair.name_with_rev()).is_ok());
+        for entry in fs::read_dir(cache.path()).expect("Could not read the key cache") {
+            fs::remove_file(entry.expect("Could not read the key cache").path())
+                .expect("Could not remove a key");
+        }
+        let key = keys.get(&pair.name_with_rev()).expect("The key was not kept");
+        assert_eq!(key.name_with_rev(), pair.name_with_rev());
+        assert!(keys.get("ops-20170101000000").is_err());
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+
 use time::{SteadyTime, Duration as TimeDuration};
 
 /// How long to wait for an Ack after we ping
@@ -22,6 +24,10 @@ const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
+/// How many members to gossip with each gossip period, per power of ten members in the ring
+const GOSSIP_FANOUT_MULT_DEFAULT: usize = 3;
+/// How many times a rumor is pushed, per power of ten members in the ring
+const RETRANSMIT_MULT_DEFAULT: usize = 6;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -30,6 +36,8 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    pub gossip_fanout_mult: usize,
+    pub retransmit_mult: usize,
 }
 
 impl Default for Timing {
@@ -39,6 +47,8 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            gossip_fanout_mult: GOSSIP_FANOUT_MULT_DEFAULT,
+            retransmit_mult: RETRANSMIT_MULT_DEFAULT,
         }
     }
 }
@@ -55,9 +65,26 @@ impl Timing {
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            gossip_fanout_mult: GOSSIP_FANOUT_MULT_DEFAULT,
+            retransmit_mult: RETRANSMIT_MULT_DEFAULT,
         }
     }
 
+    /// How many members to gossip with at once in a ring of `members` other members; grows with
+    /// the log of the ring size, and is never more than the ring.
+    pub fn gossip_fanout(&self, members: usize) -> usize {
+        cmp::max(1, cmp::min(members, self.gossip_fanout_mult * log_scale(members)))
+    }
+
+    /// How many times, in total, we push a rumor to the members of a ring of `members` other
+    /// members; grows with the log of the ring size.
+    ///
+    /// Members we push to push new rumors on in turn, so this reaches the whole ring with high
+    /// probability without every member sending every rumor to everyone.
+    pub fn retransmit_limit(&self, members: usize) -> usize {
+        cmp::max(1, self.retransmit_mult * log_scale(members))
+    }
+
     /// When should this gossip period expire
     pub fn gossip_timeout(&self) -> SteadyTime {
         SteadyTime::now() + TimeDuration::milliseconds(self.gossip_period_ms)
@@ -89,3 +116,44 @@ impl Timing {
                                    self.suspicion_timeout_protocol_periods)
     }
 }
+
+/// `ceil(log10(members + 1))`, but never less than 1: the number of decimal digits in `members`.
+fn log_scale(members: usize) -> usize {
+    let mut scale = 1;
+    let mut remaining = members / 10;
+    while remaining > 0 {
+        scale += 1;
+        remaining /= 10;
+    }
+    scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timing;
+
+    #[test]
+    fn fanout_grows_with_the_log_of_the_ring() {
+        let timing = Timing::default();
+        assert_eq!(timing.gossip_fanout(0), 1);
+        assert_eq!(timing.gossip_fanout(2), 2);
+        assert_eq!(timing.gossip_fanout(9), 3);
+        assert_eq!(timing.gossip_fanout(10), 6);
+        assert_eq!(timing.gossip_fanout(999), 9);
+        assert_eq!(timing.gossip_fanout(1000), 12);
+    }
+
+    #[test]
+    fn retransmit_limit_grows_with_the_log_of_the_ring() {
+        let timing = Timing::default();
+        assert_eq!(timing.retransmit_limit(5), 6);
+        assert_eq!(timing.retransmit_limit(50), 12);
+        assert_eq!(timing.retransmit_limit(1000), 24);
+
+        let mut timing = Timing::default();
+        timing.retransmit_mult = 4;
+        assert_eq!(timing.retransmit_limit(50), 8);
+        timing.retransmit_mult = 0;
+        assert_eq!(timing.retransmit_limit(50), 1);
+    }
+}
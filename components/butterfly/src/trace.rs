@@ -14,18 +14,58 @@
 
 //! This module handles the writing of swim trace files, which can later be post-processed to see
 //! whats happening in a network.
+//!
+//! Each event is written as one line of JSON, with the fields:
+//!
+//! * `schema` - the version of this format; currently 1
+//! * `timestamp` - when the event happened, in UTC, as RFC 3339 with nanoseconds
+//! * `kind` - the `TraceKind` of the event
+//! * `server` - the name of the server that wrote the event
+//! * `from` and `to` - the member ids on either end of a message; for `Recv` events, `to` is the
+//!   server that wrote the event, and for every other event it is `from`
+//! * `listening` and `to_addr` - the addresses on either end of a message
+//! * `member_id` and `incarnation` - the member an event is about, such as the subject of a
+//!   membership rumor
+//! * `rumor_kind`, `rumor_key` and `rumor_id` - the rumor an event is about, which together are
+//!   the rumor's `RumorKey`
+//! * `detail` - anything else worth knowing, as free-form text
+//! * `thread`, `module` and `line` - where the event was written from
+//!
+//! Fields that do not apply to an event are `null`. Trace files are written to
+//! `/tmp/habitat-swim-trace`, or the directory in `TRACE_SWIM_DIR`, and a new file is started
+//! whenever one grows past `TRACE_SWIM_MAX_BYTES`. Failing to write a trace never stops the
+//! server; the event is dropped, and we try a new file for the next one.
 
 use time;
 
+use std::collections::VecDeque;
 use std::default::Default;
 use std::env;
 use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::io::Write;
+use std::result;
 
+use serde::{Serialize, Serializer};
+use serde_json;
+
+use message::swim::{Rumor as ProtoRumor, Rumor_Type};
 use server::Server;
 
+/// The version of the trace file format.
+pub const TRACE_SCHEMA_VERSION: u32 = 1;
+
+/// Where trace files go, unless `TRACE_SWIM_DIR` says otherwise.
+const TRACE_DIRECTORY_DEFAULT: &'static str = "/tmp/habitat-swim-trace";
+
+/// How large a trace file grows before we start another, unless `TRACE_SWIM_MAX_BYTES` says
+/// otherwise.
+const TRACE_MAX_FILE_BYTES_DEFAULT: u64 = 64 * 1024 * 1024;
+
+/// How many trace files we keep for each server before removing the oldest.
+const TRACE_MAX_FILES_DEFAULT: usize = 10;
+
 #[derive(Debug, Clone, Copy)]
 pub enum TraceKind {
     MemberUpdate,
@@ -47,6 +87,19 @@ pub enum TraceKind {
     TestEvent,
 }
 
+impl TraceKind {
+    /// Returns true if the event is this server receiving a message from another member.
+    pub fn is_receive(&self) -> bool {
+        match *self {
+            TraceKind::RecvAck |
+            TraceKind::RecvPing |
+            TraceKind::RecvPingReq |
+            TraceKind::RecvRumor => true,
+            _ => false,
+        }
+    }
+}
+
 impl fmt::Display for TraceKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -83,6 +136,11 @@ pub struct TraceWrite<'a> {
     pub to_member_id: Option<&'a str>,
     pub listening: Option<&'a str>,
     pub to_addr: Option<&'a str>,
+    pub subject_id: Option<&'a str>,
+    pub incarnation: Option<u64>,
+    pub rumor_kind: Option<&'a str>,
+    pub rumor_key: Option<&'a str>,
+    pub rumor_id: Option<&'a str>,
     pub swim: Option<&'a str>,
     pub rumor: Option<&'a str>,
 }
@@ -94,14 +152,8 @@ impl<'a> TraceWrite<'a> {
                thread_name: &'a str)
                -> TraceWrite<'a> {
         let now = time::now_utc();
-        let time_string = format!("{}-{}-{}-{}-{}-{}-{}",
-                                  1900 + now.tm_year,
-                                  now.tm_mon + 1,
-                                  now.tm_mday,
-                                  now.tm_hour,
-                                  now.tm_min,
-                                  now.tm_sec,
-                                  now.tm_nsec);
+        let seconds = format!("{}", now.rfc3339());
+        let time_string = format!("{}.{:09}Z", seconds.trim_right_matches('Z'), now.tm_nsec);
         TraceWrite {
             kind: kind,
             time: time_string,
@@ -113,27 +165,146 @@ impl<'a> TraceWrite<'a> {
             to_member_id: None,
             listening: None,
             to_addr: None,
+            subject_id: None,
+            incarnation: None,
+            rumor_kind: None,
+            rumor_key: None,
+            rumor_id: None,
             swim: None,
             rumor: None,
         }
     }
+
+    /// Fill in the rumor fields from a description of a rumor.
+    pub fn set_rumor(&mut self, description: &'a RumorTrace) {
+        self.subject_id = description.subject_id.as_ref().map(|s| &s[..]);
+        self.incarnation = description.incarnation;
+        self.rumor_kind = Some(&description.kind);
+        self.rumor_key = Some(&description.key);
+        self.rumor_id = Some(&description.id);
+        self.rumor = Some(&description.detail);
+    }
 }
 
-impl<'a> fmt::Display for TraceWrite<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        try!(write!(f, "{}", self.time));
-        try!(write!(f, "^{}", self.kind));
-        try!(write!(f, "^{}", self.thread_name));
-        try!(write!(f, "^{}", self.module_path));
-        try!(write!(f, "^{}", self.line));
-        try!(write!(f, "^{}", self.server_name.unwrap_or("")));
-        try!(write!(f, "^{}", self.member_id.unwrap_or("")));
-        try!(write!(f, "^{}", self.to_member_id.unwrap_or("")));
-        try!(write!(f, "^{}", self.listening.unwrap_or("")));
-        try!(write!(f, "^{}", self.to_addr.unwrap_or("")));
-        try!(write!(f, "^{}", self.swim.unwrap_or("")));
-        try!(write!(f, "^{}", self.rumor.unwrap_or("")));
-        write!(f, "\n")
+impl<'a> Serialize for TraceWrite<'a> {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let (from, to) = if self.kind.is_receive() {
+            (self.to_member_id, self.member_id)
+        } else {
+            (self.member_id, self.to_member_id)
+        };
+        let kind = self.kind.to_string();
+        let mut state = try!(serializer.serialize_struct("trace", 18));
+        try!(serializer.serialize_struct_elt(&mut state, "schema", TRACE_SCHEMA_VERSION));
+        try!(serializer.serialize_struct_elt(&mut state, "timestamp", &self.time));
+        try!(serializer.serialize_struct_elt(&mut state, "kind", &kind));
+        try!(serializer.serialize_struct_elt(&mut state, "server", self.server_name));
+        try!(serializer.serialize_struct_elt(&mut state, "from", from));
+        try!(serializer.serialize_struct_elt(&mut state, "to", to));
+        try!(serializer.serialize_struct_elt(&mut state, "listening", self.listening));
+        try!(serializer.serialize_struct_elt(&mut state, "to_addr", self.to_addr));
+        try!(serializer.serialize_struct_elt(&mut state, "member_id", self.subject_id));
+        try!(serializer.serialize_struct_elt(&mut state, "incarnation", self.incarnation));
+        try!(serializer.serialize_struct_elt(&mut state, "rumor_kind", self.rumor_kind));
+        try!(serializer.serialize_struct_elt(&mut state, "rumor_key", self.rumor_key));
+        try!(serializer.serialize_struct_elt(&mut state, "rumor_id", self.rumor_id));
+        try!(serializer.serialize_struct_elt(&mut state, "detail", self.swim.or(self.rumor)));
+        try!(serializer.serialize_struct_elt(&mut state, "thread", self.thread_name));
+        try!(serializer.serialize_struct_elt(&mut state, "module", self.module_path));
+        try!(serializer.serialize_struct_elt(&mut state, "line", self.line));
+        serializer.serialize_struct_end(state)
+    }
+}
+
+/// What the trace records about a rumor.
+#[derive(Debug)]
+pub struct RumorTrace {
+    pub kind: String,
+    pub key: String,
+    pub id: String,
+    pub subject_id: Option<String>,
+    pub incarnation: Option<u64>,
+    pub detail: String,
+}
+
+impl<'a> From<&'a ProtoRumor> for RumorTrace {
+    fn from(payload: &'a ProtoRumor) -> RumorTrace {
+        let kind = format!("{:?}", payload.get_field_type());
+        let (key, id, subject_id, incarnation, detail) = match payload.get_field_type() {
+            Rumor_Type::Member => {
+                let member = payload.get_member().get_member();
+                (String::from(""),
+                 String::from(member.get_id()),
+                 Some(String::from(member.get_id())),
+                 Some(member.get_incarnation()),
+                 format!("{:?}", payload.get_member().get_health()))
+            }
+            Rumor_Type::Service => {
+                let service = payload.get_service();
+                (String::from(service.get_service_group()),
+                 String::from(service.get_member_id()),
+                 Some(String::from(service.get_member_id())),
+                 Some(service.get_incarnation()),
+                 String::from(service.get_package_ident()))
+            }
+            Rumor_Type::ServiceConfig => {
+                let sc = payload.get_service_config();
+                (String::from(sc.get_service_group()),
+                 String::from("service_config"),
+                 None,
+                 Some(sc.get_incarnation()),
+                 format!("encrypted={}", sc.get_encrypted()))
+            }
+            Rumor_Type::ServiceFile => {
+                let sf = payload.get_service_file();
+                (String::from(sf.get_service_group()),
+                 String::from(sf.get_filename()),
+                 None,
+                 Some(sf.get_incarnation()),
+                 format!("encrypted={}", sf.get_encrypted()))
+            }
+            Rumor_Type::Election |
+            Rumor_Type::ElectionUpdate => {
+                let election = payload.get_election();
+                (String::from(election.get_service_group()),
+                 String::from("election"),
+                 Some(String::from(election.get_member_id())),
+                 Some(election.get_term()),
+                 format!("suitability={} status={:?} votes={:?}",
+                         election.get_suitability(),
+                         election.get_status(),
+                         election.get_votes()))
+            }
+            Rumor_Type::FileChunk => {
+                let chunk = payload.get_file_chunk();
+                (String::from(chunk.get_content_hash()),
+                 format!("{}", chunk.get_index()),
+                 None,
+                 None,
+                 format!("{} of {}", chunk.get_index(), chunk.get_chunk_count()))
+            }
+            Rumor_Type::FileChunkRequest => {
+                let request = payload.get_file_chunk_request();
+                (String::from(request.get_content_hash()),
+                 String::from(""),
+                 None,
+                 None,
+                 format!("{:?}", request.get_index()))
+            }
+            Rumor_Type::Fake | Rumor_Type::Fake2 => {
+                (String::from(""), String::from(""), None, None, String::from("nothing-to-see"))
+            }
+        };
+        RumorTrace {
+            kind: kind,
+            key: key,
+            id: id,
+            subject_id: subject_id,
+            incarnation: incarnation,
+            detail: detail,
+        }
     }
 }
 
@@ -141,16 +312,30 @@ impl<'a> fmt::Display for TraceWrite<'a> {
 #[derive(Debug)]
 pub struct Trace {
     pub directory: PathBuf,
+    pub max_file_bytes: u64,
+    pub max_files: usize,
     pub file: Option<fs::File>,
     pub on: bool,
+    name: Option<String>,
+    written: u64,
+    files: VecDeque<PathBuf>,
+    sequence: u64,
+    failing: bool,
 }
 
 impl Default for Trace {
     fn default() -> Trace {
         Trace {
-            directory: PathBuf::from("/tmp/habitat-swim-trace"),
+            directory: PathBuf::from(TRACE_DIRECTORY_DEFAULT),
+            max_file_bytes: TRACE_MAX_FILE_BYTES_DEFAULT,
+            max_files: TRACE_MAX_FILES_DEFAULT,
             file: None,
             on: false,
+            name: None,
+            written: 0,
+            files: VecDeque::new(),
+            sequence: 0,
+            failing: false,
         }
     }
 }
@@ -158,17 +343,19 @@ impl Default for Trace {
 impl Trace {
     /// Initialize the trace object; only happens once.
     pub fn init(&mut self, server: &Server) {
+        if self.name.is_none() {
+            self.name = Some(String::from(server.name()));
+        }
         if self.file.is_none() {
-            let now = time::now_utc();
-            let filename = format!("{}-{}.swimtrace", server.name(), now.rfc3339());
-            match fs::File::create(self.directory.join(&filename)) {
-                Ok(f) => self.file = Some(f),
-                Err(e) => {
-                    panic!("Trace requested, but cannot create file {:?}: {}",
-                           self.directory.join(&filename),
-                           e)
-                }
+            if let Ok(directory) = env::var("TRACE_SWIM_DIR") {
+                self.directory = PathBuf::from(directory);
+            }
+            if let Some(bytes) = env::var("TRACE_SWIM_MAX_BYTES")
+                .ok()
+                .and_then(|b| b.parse::<u64>().ok()) {
+                self.max_file_bytes = bytes;
             }
+            self.open_file();
         }
     }
 
@@ -180,23 +367,80 @@ impl Trace {
         }
     }
 
-    /// Write a line to the trace file.
+    /// Write a line to the trace file. If the line cannot be written, it is dropped, and the next
+    /// line goes to a new file.
     pub fn write(&mut self, trace_write: TraceWrite) {
-        let dump = format!("{:#?}", self);
-        match self.file.as_mut() {
-            Some(mut file) => {
-                match write!(file, "{}", trace_write) {
-                    Ok(_) => {}
-                    Err(e) => panic!("Trace requested, but failed to write {:?}", e),
+        if self.file.is_none() {
+            self.open_file();
+        }
+        let mut line = match serde_json::to_string(&trace_write) {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Cannot serialize trace event {:?}: {}", trace_write, e);
+                return;
+            }
+        };
+        line.push('\n');
+        let result = match self.file.as_mut() {
+            Some(file) => file.write_all(line.as_bytes()),
+            None => return,
+        };
+        match result {
+            Ok(()) => {
+                self.written += line.len() as u64;
+                if self.written >= self.max_file_bytes {
+                    // Rotate; the next line starts a new file
+                    self.file = None;
+                }
+            }
+            Err(e) => {
+                self.warn_once(format!("Cannot write to trace file: {}", e));
+                self.file = None;
+            }
+        }
+    }
+
+    fn open_file(&mut self) {
+        let name = match self.name {
+            Some(ref name) => name.clone(),
+            None => return,
+        };
+        if let Err(e) = fs::create_dir_all(&self.directory) {
+            self.warn_once(format!("Cannot create trace directory {}: {}",
+                                   self.directory.display(),
+                                   e));
+            return;
+        }
+        let now = time::now_utc();
+        let filename = format!("{}-{}-{}.swimtrace", name, now.rfc3339(), self.sequence);
+        let path = self.directory.join(&filename);
+        match fs::File::create(&path) {
+            Ok(f) => {
+                self.file = Some(f);
+                self.written = 0;
+                self.sequence += 1;
+                self.failing = false;
+                self.files.push_back(path);
+                while self.files.len() > self.max_files {
+                    if let Some(oldest) = self.files.pop_front() {
+                        let _ = fs::remove_file(oldest);
+                    }
                 }
             }
-            None => {
-                panic!("Trace requested, but init was never called; use the trace! macro \
-                        instead: {:#?}",
-                       dump);
+            Err(e) => {
+                self.warn_once(format!("Cannot create trace file {}: {}", path.display(), e))
             }
         }
     }
+
+    /// Log a trace failure, once until tracing works again, so a broken disk does not flood the
+    /// log.
+    fn warn_once(&mut self, message: String) {
+        if !self.failing {
+            warn!("{}; dropping trace events until it succeeds", message);
+            self.failing = true;
+        }
+    }
 }
 
 #[macro_export]
@@ -262,12 +506,17 @@ macro_rules! trace_it {
                 let thread_name = thread.name().unwrap_or("undefined");
                 let member_id = $server.member_id();
                 let server_name = $server.name();
-                let rumor_text = format!("{}-{}-{}", $member_id, $member_incarnation, $health);
+                let subject_id = format!("{}", $member_id);
+                let health = format!("{}", $health);
 
                 let mut tw = TraceWrite::new($msg_type, module_path!(), line!(), thread_name);
                 tw.server_name = Some(&server_name);
                 tw.member_id = Some(member_id);
-                tw.rumor = Some(&rumor_text);
+                tw.subject_id = Some(&subject_id);
+                tw.incarnation = Some($member_incarnation);
+                tw.rumor_kind = Some("Member");
+                tw.rumor_id = Some(&subject_id);
+                tw.rumor = Some(&health);
                 trace.write(tw);
             }
         }
@@ -333,37 +582,21 @@ macro_rules! trace_it {
             let trace_on = $server.trace.read().expect("Trace lock is poisoned").on();
             if trace_on {
                 let mut trace = $server.trace.write().expect("Trace lock is poisoned");
-                use trace::TraceWrite;
-                use message::swim::Rumor_Type;
+                use trace::{RumorTrace, TraceWrite};
                 trace.init($server);
                 let thread = thread::current();
                 let thread_name = thread.name().unwrap_or("undefined");
                 let listening = format!("{}", $server.gossip_addr());
                 let member_id = $server.member_id();
                 let server_name = $server.name();
-                let rp = match $payload.get_field_type() {
-                    Rumor_Type::Member => format!("{}-{}-{:?}", $payload.get_member().get_member().get_id(), $payload.get_member().get_member().get_incarnation(), $payload.get_member().get_health()),
-                    Rumor_Type::Service => format!("{}-{}-{}", $payload.get_service().get_member_id(), $payload.get_service().get_service_group(), $payload.get_service().get_incarnation()),
-                    Rumor_Type::ServiceConfig => format!("{}-{}-{}",
-                                                         $payload.get_service_config().get_service_group(),
-                                                         $payload.get_service_config().get_incarnation(),
-                                                         $payload.get_service_config().get_encrypted()),
-                    Rumor_Type::ServiceFile => format!("{}-{}-{}-{}",
-                                                         $payload.get_service_file().get_service_group(),
-                                                         $payload.get_service_file().get_incarnation(),
-                                                         $payload.get_service_file().get_encrypted(),
-                                                         $payload.get_service_file().get_filename()),
-                    Rumor_Type::Election | Rumor_Type::ElectionUpdate => format!("{}-{}-{}-{}-{:?}-{:?}", $payload.get_election().get_member_id(), $payload.get_election().get_service_group(), $payload.get_election().get_term(), $payload.get_election().get_suitability(), $payload.get_election().get_status(), $payload.get_election().get_votes()),
-                    Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
-                };
+                let rumor_trace = RumorTrace::from($payload);
 
                 let mut tw = TraceWrite::new($msg_type, module_path!(), line!(), thread_name);
                 tw.server_name = Some(&server_name);
                 tw.member_id = Some(member_id);
                 tw.to_member_id = Some($to_member_id);
                 tw.listening = Some(&listening);
-                tw.swim = None;
-                tw.rumor = Some(&rp);
+                tw.set_rumor(&rumor_trace);
                 trace.write(tw);
             }
         }
@@ -373,13 +606,82 @@ macro_rules! trace_it {
 #[cfg(test)]
 mod tests {
     mod trace {
-        use trace::Trace;
+        use trace::{Trace, TraceKind, TraceWrite};
+        use std::env;
+        use std::fs;
+        use std::io::Read;
         use std::path::Path;
 
+        use serde_json::{self, Value};
+
+        fn trace_in(name: &str) -> Trace {
+            let directory = env::temp_dir().join(format!("habitat-swim-trace-test-{}", name));
+            let _ = fs::remove_dir_all(&directory);
+            let mut trace = Trace::default();
+            trace.directory = directory;
+            trace.name = Some(String::from(name));
+            trace
+        }
+
+        fn write_event(trace: &mut Trace) {
+            let mut tw = TraceWrite::new(TraceKind::RecvAck, module_path!(), line!(), "main");
+            tw.server_name = Some("kenny");
+            tw.member_id = Some("me");
+            tw.to_member_id = Some("them");
+            tw.subject_id = Some("them");
+            tw.incarnation = Some(3);
+            trace.write(tw);
+        }
+
         #[test]
         fn default() {
             let trace = Trace::default();
             assert_eq!(trace.directory, Path::new("/tmp/habitat-swim-trace"));
         }
+
+        #[test]
+        fn writes_one_json_object_per_line() {
+            let mut trace = trace_in("schema");
+            write_event(&mut trace);
+            write_event(&mut trace);
+            trace.file = None;
+            let mut contents = String::new();
+            let path = trace.files.front().expect("No trace file was written").clone();
+            fs::File::open(&path).unwrap().read_to_string(&mut contents).unwrap();
+            let lines: Vec<&str> = contents.lines().collect();
+            assert_eq!(lines.len(), 2);
+            let event: Value = serde_json::from_str(lines[0]).unwrap();
+            let event = event.as_object().expect("Trace event is not an object");
+            assert_eq!(event.get("schema").and_then(|v| v.as_u64()), Some(1));
+            assert_eq!(event.get("kind").and_then(|v| v.as_str()), Some("RecvAck"));
+            assert_eq!(event.get("from").and_then(|v| v.as_str()), Some("them"));
+            assert_eq!(event.get("to").and_then(|v| v.as_str()), Some("me"));
+            assert_eq!(event.get("member_id").and_then(|v| v.as_str()), Some("them"));
+            assert_eq!(event.get("incarnation").and_then(|v| v.as_u64()), Some(3));
+            assert!(event.get("rumor_key").map(|v| v.is_null()).unwrap_or(false));
+            let _ = fs::remove_dir_all(&trace.directory);
+        }
+
+        #[test]
+        fn rotates_and_removes_old_files() {
+            let mut trace = trace_in("rotate");
+            trace.max_file_bytes = 1;
+            trace.max_files = 2;
+            for _ in 0..5 {
+                write_event(&mut trace);
+            }
+            let written = fs::read_dir(&trace.directory).unwrap().count();
+            assert_eq!(written, 2);
+            assert_eq!(trace.files.len(), 2);
+            let _ = fs::remove_dir_all(&trace.directory);
+        }
+
+        #[test]
+        fn does_not_panic_when_the_directory_is_unwritable() {
+            let mut trace = trace_in("unwritable");
+            trace.directory = Path::new("/dev/null/habitat-swim-trace").to_path_buf();
+            write_event(&mut trace);
+            assert!(trace.file.is_none());
+        }
     }
 }
@@ -28,3 +28,60 @@ fn symmetric_encryption_of_wire_payloads() {
     net.wait_for_gossip_rounds(2);
     net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
 }
+
+#[test]
+fn ring_key_rotation_without_downtime() {
+    let old_key = SymKey::generate_in_memory("wolverine")
+        .expect("Failed to generate an in memory symkey");
+    let mut new_key = SymKey::generate_in_memory("wolverine")
+        .expect("Failed to generate an in memory symkey");
+    new_key.rev = format!("{}1", old_key.rev);
+    let mut net = btest::SwimNet::new_ring_encryption(2, Some(old_key.clone()));
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+
+    // Member 0 has been given the new key before member 1. It trusts the new key, but keeps
+    // encrypting with the old one, so member 1 can still hear it.
+    assert!(net[0].add_ring_key(new_key.clone()));
+    assert_eq!(net[0].current_ring_key_revision(),
+               Some(old_key.name_with_rev()));
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+
+    // Once both trust it, member 0 switches to the new key while member 1 is still on the old
+    // one; each hears the other.
+    assert!(net[1].add_ring_key(new_key.clone()));
+    net[0].use_ring_key(&new_key.name_with_rev()).unwrap();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/geralt/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1].service_store.with_rumor("witcher.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    net[0].service_store.with_rumor("geralt.prod", net[1].member_id(), |u| assert!(u.is_some()));
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+
+    // Then member 1 switches, and both retire the old key.
+    net[1].use_ring_key(&new_key.name_with_rev()).unwrap();
+    net[0].retire_ring_key(&old_key.name_with_rev()).unwrap();
+    net[1].retire_ring_key(&old_key.name_with_rev()).unwrap();
+    assert_eq!(net[0].ring_key_revisions(), vec![new_key.name_with_rev()]);
+
+    net.add_service(1, "core/ciri/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[0].service_store.with_rumor("ciri.prod", net[1].member_id(), |u| assert!(u.is_some()));
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+}
+
+#[test]
+fn a_member_cannot_retire_the_ring_key_it_encrypts_with() {
+    let old_key = SymKey::generate_in_memory("wolverine")
+        .expect("Failed to generate an in memory symkey");
+    let mut new_key = SymKey::generate_in_memory("wolverine")
+        .expect("Failed to generate an in memory symkey");
+    new_key.rev = format!("{}1", old_key.rev);
+    let net = btest::SwimNet::new_ring_encryption(1, Some(old_key.clone()));
+    assert!(net[0].add_ring_key(new_key.clone()));
+    assert!(net[0].retire_ring_key(&old_key.name_with_rev()).is_err());
+    assert!(net[0].use_ring_key("wolverine-19700101000000").is_err());
+}
@@ -66,6 +66,25 @@ fn six_members_meshed_partition_half_of_nodes_from_each_other_both_sides_confirm
     assert_wait_for_health_of!(net, [0..3, 3..6], Health::Confirmed);
 }
 
+#[test]
+fn three_members_meshed_over_ipv6_confirm_one_member() {
+    let mut net = btest::SwimNet::new_ipv6(3);
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+    trace_it!(TEST: &net[0], "Paused");
+    net[0].pause();
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+}
+
+#[test]
+fn four_members_unmeshed_become_fully_meshed_via_gossip_over_ipv6() {
+    let mut net = btest::SwimNet::new_ipv6(4);
+    net.connect(0, 1);
+    net.connect(1, 2);
+    net.connect(2, 3);
+    assert_wait_for_health_of!(net, [0..4, 0..4], Health::Alive);
+}
+
 #[test]
 fn six_members_unmeshed_become_fully_meshed_via_gossip() {
     let mut net = btest::SwimNet::new(6);
@@ -14,6 +14,11 @@
 
 use habitat_butterfly::member::Health;
 use habitat_butterfly::message::swim::Election_Status;
+use habitat_butterfly::rumor::election::{Election, LEADER_LEASE_MS};
+use habitat_butterfly::server::quorum::QuorumPolicy;
+
+use habitat_core::service::ServiceGroup;
+use time::{Duration, SteadyTime};
 
 use btest;
 
@@ -199,3 +204,198 @@ fn five_members_elect_a_new_leader_when_they_are_quorum_partitioned() {
         assert_eq!(new_leader_id, String::from(e.unwrap().get_member_id()));
     });
 }
+
+#[test]
+fn two_members_find_quorum_with_a_minimum_size_of_two() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    for i in 0..2 {
+        net.set_quorum_policy(i,
+                              "witcher",
+                              QuorumPolicy { min_size: 2, ..QuorumPolicy::default() });
+    }
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..2], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..2, 0..2], "witcher.prod");
+}
+
+#[test]
+fn two_members_elect_the_survivor_when_departed_members_do_not_count() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    for i in 0..2 {
+        net.set_quorum_policy(i,
+                              "witcher",
+                              QuorumPolicy {
+                                  min_size: 1,
+                                  count_departed: false,
+                                  expected_size: None,
+                              });
+    }
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..2], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..2, 0..2], "witcher.prod");
+
+    let mut leader_id = String::from("");
+    net[0].election_store.with_rumor("witcher.prod", "election", |e| {
+        leader_id = String::from(e.unwrap().get_member_id());
+    });
+    let (leader, survivor) = if net[0].member_id() == &leader_id[..] {
+        (0, 1)
+    } else {
+        (1, 0)
+    };
+
+    let survivor_id = String::from(net[survivor].member_id());
+
+    net[leader].pause();
+    assert_wait_for_health_of!(net, survivor, leader, Health::Confirmed);
+    net[survivor].restart_elections();
+    assert_wait_for_election_status!(net, survivor, "witcher.prod", Election_Status::Finished);
+    net[survivor].election_store.with_rumor("witcher.prod", "election", |e| {
+        assert_eq!(e.unwrap().get_term(), 1);
+        assert_eq!(e.unwrap().get_member_id(), survivor_id);
+    });
+}
+
+#[test]
+fn three_members_lose_quorum_when_departed_members_count() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+
+    net[2].pause();
+    net[1].pause();
+    assert_wait_for_health_of!(net, 0, 1, Health::Confirmed);
+    assert_wait_for_health_of!(net, 0, 2, Health::Confirmed);
+    assert!(!net[0].check_quorum("witcher.prod"));
+}
+
+#[test]
+fn three_members_fail_to_find_quorum_when_more_are_expected() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    for i in 0..3 {
+        net.set_quorum_policy(i,
+                              "witcher",
+                              QuorumPolicy { expected_size: Some(6), ..QuorumPolicy::default() });
+    }
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::NoQuorum);
+}
+
+#[test]
+fn three_members_find_quorum_when_five_are_expected() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    for i in 0..3 {
+        net.set_quorum_policy(i,
+                              "witcher",
+                              QuorumPolicy { expected_size: Some(5), ..QuorumPolicy::default() });
+    }
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+}
+
+fn leader_of(net: &btest::SwimNet, member: usize) -> (String, u64) {
+    let mut leader = (String::from(""), 0);
+    net[member].election_store.with_rumor("witcher.prod", "election", |e| {
+        leader = (String::from(e.unwrap().get_member_id()), e.unwrap().get_term());
+    });
+    leader
+}
+
+#[test]
+fn three_members_hand_leadership_to_a_nominee() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let (leader_id, _) = leader_of(&net, 0);
+    let nominee = (0..3).find(|&i| net[i].member_id() != &leader_id[..]).unwrap();
+    let nominee_id = String::from(net[nominee].member_id());
+    net[0].insert_election(Election::transfer_request("test",
+                                                      ServiceGroup::new("witcher", "prod", None),
+                                                      Some(&nominee_id)));
+
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_eq!(leader_of(&net, 0), (nominee_id, 1));
+}
+
+#[test]
+fn three_members_hand_leadership_to_anyone_but_the_leader() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let (leader_id, _) = leader_of(&net, 0);
+    net[1].insert_election(Election::transfer_request("test",
+                                                      ServiceGroup::new("witcher", "prod", None),
+                                                      None));
+
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    let (new_leader_id, term) = leader_of(&net, 1);
+    assert!(new_leader_id != leader_id);
+    assert_eq!(term, 1);
+}
+
+#[test]
+fn a_partitioned_leader_loses_its_term_when_its_lease_runs_out() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher", 0);
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let (leader_id, term) = leader_of(&net, 0);
+    let leader = (0..3).find(|&i| net[i].member_id() == &leader_id[..]).unwrap();
+    assert_eq!(net[leader].leader_term("witcher.prod"), Some(term));
+    for i in 0..3 {
+        if i != leader {
+            net.blacklist(leader, i);
+            net.blacklist(i, leader);
+        }
+    }
+
+    // However long the rest of the group still looks alive from its side, the leader can't
+    // renew its lease without the rest of the group acknowledging it
+    let deadline = SteadyTime::now() + Duration::milliseconds(LEADER_LEASE_MS + 1000);
+    while SteadyTime::now() < deadline {
+        for i in 0..3 {
+            net[i].restart_elections();
+        }
+        net.wait_protocol_period();
+    }
+    assert_eq!(net[leader].leader_term("witcher.prod"), None);
+}
@@ -22,3 +22,12 @@ fn two_members_share_services() {
     net.wait_for_rounds(2);
     net[1].service_store.with_rumor("witcher.prod", net[0].member_id(), |u| assert!(u.is_some()));
 }
+
+#[test]
+fn two_members_share_services_over_ipv6() {
+    let mut net = btest::SwimNet::new_ipv6(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.wait_for_rounds(2);
+    net[1].service_store.with_rumor("witcher.prod", net[0].member_id(), |u| assert!(u.is_some()));
+}
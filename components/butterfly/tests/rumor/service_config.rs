@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use btest;
+use habitat_core::crypto::SigKeyPair;
 use habitat_core::service::ServiceGroup;
 use habitat_butterfly::client::Client;
+use habitat_butterfly::message::swim::ServiceConfig_Mode;
 
 #[test]
 fn two_members_share_service_config() {
@@ -39,10 +41,70 @@ fn service_config_via_client() {
     client.send_service_config(ServiceGroup::new("witcher", "prod", None),
                              0,
                              payload,
-                             false)
+                             false,
+                             None,
+                             None,
+                             None)
         .expect("Cannot send the service configuration");
     net.wait_for_gossip_rounds(1);
     net[1]
         .service_config_store
         .with_rumor("witcher.prod", "service_config", |u| assert!(u.is_some()));
 }
+
+#[test]
+fn signed_service_config_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let signer = SigKeyPair::generate_in_memory("geralt").expect("Cannot generate a signing key");
+    let mut client = Client::new(net[0].gossip_addr(), None)
+        .expect("Cannot create Butterfly Client");
+    let payload = Vec::from("I want to get lost in you, tokyo".as_bytes());
+    client.send_service_config(ServiceGroup::new("witcher", "prod", None),
+                             0,
+                             payload,
+                             false,
+                             None,
+                             Some(&signer),
+                             Some("geralt"))
+        .expect("Cannot send the service configuration");
+    net.wait_for_gossip_rounds(1);
+    net[1]
+        .service_config_store
+        .with_rumor("witcher.prod", "service_config", |u| {
+            let sc = u.expect("Signed service config was not gossiped");
+            assert_eq!(sc.get_signer(), signer.name_with_rev());
+            assert_eq!(sc.get_applied_by(), "geralt");
+        });
+    assert_eq!(net[1].config_history.for_service_group("witcher.prod").len(), 1);
+}
+
+#[test]
+fn service_config_patch_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let mut client = Client::new(net[0].gossip_addr(), None)
+        .expect("Cannot create Butterfly Client");
+    client.send_service_config_patch(ServiceGroup::new("witcher", "prod", None),
+                                   1,
+                                   0,
+                                   Vec::from("[sign]\nname = \"igni\"".as_bytes()),
+                                   vec![String::from("sign.aard")],
+                                   false,
+                                   None,
+                                   None,
+                                   None)
+        .expect("Cannot send the service configuration patch");
+    net.wait_for_gossip_rounds(1);
+    net[1]
+        .service_config_store
+        .with_rumor("witcher.prod", "service_config", |u| {
+            let sc = u.expect("Service config patch was not gossiped");
+            assert_eq!(sc.get_mode(), ServiceConfig_Mode::Patch);
+            assert_eq!(sc.get_delete_keys(), &[String::from("sign.aard")]);
+        });
+}
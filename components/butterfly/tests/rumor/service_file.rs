@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use btest;
 use habitat_core::service::ServiceGroup;
+use habitat_butterfly::chunk::CHUNK_SIZE;
 use habitat_butterfly::client::Client;
 
+fn large_body() -> String {
+    (0..CHUNK_SIZE * 3 + 42).map(|i| (b'a' + (i % 26) as u8) as char).collect()
+}
+
 #[test]
 fn two_members_share_service_files() {
     let mut net = btest::SwimNet::new(2);
@@ -41,7 +48,8 @@ fn service_file_via_client() {
                            "devil-wears-prada.txt",
                            0,
                            payload,
-                           false)
+                           false,
+                           None)
         .expect("Cannot send the service file");
     net.wait_for_gossip_rounds(1);
     net[1]
@@ -50,3 +58,61 @@ fn service_file_via_client() {
                     "devil-wears-prada.txt",
                     |u| assert!(u.is_some()));
 }
+
+#[test]
+fn members_pull_the_chunks_of_large_service_files() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    let body = large_body();
+    net.add_service_file(0, "witcher", "geoip.dat", &body);
+    net.wait_for_gossip_rounds(3);
+    net[2].service_file_store.with_rumor("witcher.prod", "geoip.dat", |u| {
+        let sf = u.expect("The service file never arrived");
+        assert!(sf.is_chunked());
+        assert!(sf.get_body().is_empty());
+    });
+    let files = net[2].service_files_for("witcher.prod", &HashMap::new());
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].2, Some(Vec::from(body.as_bytes())));
+}
+
+#[test]
+fn large_service_file_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let mut client = Client::new(net[0].gossip_addr(), None)
+        .expect("Cannot create Butterfly Client");
+    let body = large_body();
+    client.send_service_file(ServiceGroup::new("witcher", "prod", None),
+                           "ca-bundle.pem",
+                           0,
+                           Vec::from(body.as_bytes()),
+                           false,
+                           None)
+        .expect("Cannot send the service file");
+    net.wait_for_gossip_rounds(3);
+    let files = net[1].service_files_for("witcher.prod", &HashMap::new());
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].2, Some(Vec::from(body.as_bytes())));
+}
+
+#[test]
+fn service_file_tombstone_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service_file(0, "witcher", "yeppers", "I like to have contents in my file");
+    net.wait_for_gossip_rounds(1);
+
+    let mut client = Client::new(net[0].gossip_addr(), None)
+        .expect("Cannot create Butterfly Client");
+    client.send_service_file_tombstone(ServiceGroup::new("witcher", "prod", None),
+                                     "yeppers",
+                                     1,
+                                     None)
+        .expect("Cannot send the service file tombstone");
+    net.wait_for_gossip_rounds(1);
+    let files = net[1].service_files_for("witcher.prod", &HashMap::new());
+    assert_eq!(files, vec![(1, String::from("yeppers"), None)]);
+}
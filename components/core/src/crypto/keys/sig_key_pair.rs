@@ -31,6 +31,15 @@ use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUF
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
 impl SigKeyPair {
+    pub fn generate_in_memory<S: ToString>(name: S) -> Result<Self> {
+        let revision = try!(mk_revision_string());
+        let (public_key, secret_key) = sign::gen_keypair();
+        Ok(Self::new(name.to_string(),
+                     revision,
+                     Some(public_key),
+                     Some(secret_key)))
+    }
+
     pub fn generate_pair_for_origin<P: AsRef<Path> + ?Sized>(name: &str,
                                                              cache_key_path: &P)
                                                              -> Result<Self> {
@@ -152,6 +161,29 @@ impl SigKeyPair {
         Ok(path)
     }
 
+    /// Sign arbitrary data with the secret key. The BLAKE2b hash of the data is signed, in the
+    /// same way as a Habitat artifact.
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let hash = try!(hash::hash_bytes(data));
+        Ok(sign::sign(hash.as_bytes(), try!(self.secret())))
+    }
+
+    /// Verify that `signature` was produced by `sign` over `data` with this key's secret.
+    pub fn verify(&self, signature: &[u8], data: &[u8]) -> Result<()> {
+        let expected_hash = match sign::verify(signature, try!(self.public())) {
+            Ok(signed_data) => signed_data,
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        };
+        let computed_hash = try!(hash::hash_bytes(data));
+        if computed_hash.as_bytes() == &expected_hash[..] {
+            Ok(())
+        } else {
+            let msg = format!("Signature from {} does not match the signed data",
+                              self.name_with_rev());
+            Err(Error::CryptoError(msg))
+        }
+    }
+
     /// Writes a sig key (public or secret) to the key cache from the contents of a string slice.
     ///
     /// The return is a `Result` of a `String` containing the key's name with revision.
@@ -460,6 +492,30 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn sign_and_verify_data() {
+        let pair = SigKeyPair::generate_in_memory("unicorn").unwrap();
+        let signature = pair.sign(b"so much data").unwrap();
+        pair.verify(&signature, b"so much data").unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn verify_tampered_data() {
+        let pair = SigKeyPair::generate_in_memory("unicorn").unwrap();
+        let signature = pair.sign(b"so much data").unwrap();
+        pair.verify(&signature, b"so much more data").unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn verify_with_another_key() {
+        let pair = SigKeyPair::generate_in_memory("unicorn").unwrap();
+        let other = SigKeyPair::generate_in_memory("narwhal").unwrap();
+        let signature = other.sign(b"so much data").unwrap();
+        pair.verify(&signature, b"so much data").unwrap();
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
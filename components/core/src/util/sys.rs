@@ -19,6 +19,7 @@ use error::Result;
 pub use os::system::{uname, Uname};
 
 static GOOGLE_DNS: &'static str = "8.8.8.8:53";
+static GOOGLE_DNS_IPV6: &'static str = "[2001:4860:4860::8888]:53";
 
 pub fn ip() -> Result<IpAddr> {
     let socket = try!(UdpSocket::bind("0.0.0.0:0"));
@@ -26,3 +27,11 @@ pub fn ip() -> Result<IpAddr> {
     let addr = try!(socket.local_addr());
     Ok(addr.ip())
 }
+
+/// Returns the IPv6 address this host would reach the internet with, if it has one.
+pub fn ipv6() -> Result<IpAddr> {
+    let socket = try!(UdpSocket::bind("[::]:0"));
+    let _ = try!(socket.connect(GOOGLE_DNS_IPV6));
+    let addr = try!(socket.local_addr());
+    Ok(addr.ip())
+}
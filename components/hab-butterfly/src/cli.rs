@@ -15,7 +15,7 @@
 use std::path::Path;
 use std::result;
 
-use clap::App;
+use clap::{App, Arg};
 use regex::Regex;
 
 pub fn get() -> App<'static, 'static> {
@@ -30,33 +30,186 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_show().aliases(&["s", "sh", "sho"]))
+            (subcommand: sub_config_history().aliases(&["h", "hi", "his", "hist"]))
+            (subcommand: sub_config_rollback().aliases(&["r", "ro", "rol", "roll"]))
+        )
+        (@subcommand election =>
+            (about: "Commands relating to Habitat leader elections")
+            (aliases: &["e", "el", "ele", "elec", "elect", "electi", "electio"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_election_list().aliases(&["l", "li", "lis"]))
+            (subcommand: sub_election_transfer().aliases(&["t", "tr", "tra", "tran", "trans"]))
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
             (aliases: &["f", "fi", "fil"])
             (@setting ArgRequiredElseHelp)
-            (@subcommand upload =>
-                (about: "Upload a file to the supervisor ring.")
-                (aliases: &["u", "up", "upl", "uplo", "uploa"])
-                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
-                    "Target service group (ex: redis.default)")
-                (@arg VERSION_NUMBER: +required
-                    "A version number (positive integer) for this configuration (ex: 42)")
-                (@arg FILE: +required {file_exists} "Path to local file on disk")
-                (@arg ORG: --org +takes_value "Name of service organization")
-                (@arg USER: -u --user +takes_value "Name of the user key")
-                (@arg PEER: -p --peer +takes_value
-                    "A comma-delimited list of one or more Habitat Supervisor peers \
-                    (default: 127.0.0.1:9638)")
-                (@arg RING: -r --ring +takes_value
-                    "Ring key name, which will encrypt communication messages")
-            )
+            (subcommand: sub_file_upload().aliases(&["u", "up", "upl", "uplo", "uploa"]))
+            (subcommand: sub_file_delete().aliases(&["d", "de", "del", "dele", "delet"]))
+            (subcommand: sub_file_sync().aliases(&["s", "sy", "syn"]))
+        )
+        (@subcommand ring =>
+            (about: "Commands relating to Habitat rings")
+            (aliases: &["r", "ri", "rin"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_ring_status().aliases(&["s", "st", "sta", "stat", "statu"]))
         )
     )
 }
 
+fn sub_ring_status() -> App<'static, 'static> {
+    clap_app!(@subcommand status =>
+        (about: "Prints every member of the ring, and the census of a service group")
+        (@arg SERVICE_GROUP: +takes_value {valid_service_group}
+            "Also print the census of this service group (ex: redis.default)")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to ask; the first \
+            to answer is used (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
+fn sub_config_show() -> App<'static, 'static> {
+    clap_app!(@subcommand show =>
+        (about: "Prints the configuration and files the ring has for a service group")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to ask; the first \
+            to answer is used (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
+fn sub_config_history() -> App<'static, 'static> {
+    clap_app!(@subcommand history =>
+        (about: "Prints the configurations the ring remembers for a service group, newest first")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to ask; the first \
+            to answer is used (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
+fn sub_config_rollback() -> App<'static, 'static> {
+    clap_app!(@subcommand rollback =>
+        (about: "Applies an earlier configuration of a service group again, as a new incarnation")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg INCARNATION: +required
+            "The incarnation to go back to, as printed by `config history` (ex: 41)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: -u --user +takes_value "Name of the user to record as applying it")
+        (@arg SIGNER: --signer +takes_value
+            "Name of the signing key to sign the configuration with")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
+fn sub_file_upload() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand upload =>
+        (about: "Upload a file to the supervisor ring.")
+        (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this configuration (ex: 42)")
+        (@arg FILE: +required {file_exists} "Path to local file on disk")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: -u --user +takes_value "Name of the user key")
+        (@arg SIGNER: --signer +takes_value "Name of the signing key to sign the file with")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    );
+    sub.arg(Arg::with_name("MAX_SIZE")
+        .help("Refuse to upload files larger than this many bytes (default: 10485760)")
+        .long("max-size")
+        .takes_value(true)
+        .validator(valid_size))
+}
+
+fn sub_file_delete() -> App<'static, 'static> {
+    clap_app!(@subcommand delete =>
+        (about: "Delete a file from every member of a service group")
+        (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg FILE: +required "Name of the file to delete")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg SIGNER: --signer +takes_value "Name of the signing key to sign the deletion with")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
+fn sub_file_sync() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand sync =>
+        (about: "Upload the files in a directory to a service group, and delete the files it \
+            has that the directory does not")
+        (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg DIR: +required {dir_exists} "Path to local directory on disk")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: -u --user +takes_value "Name of the user key")
+        (@arg SIGNER: --signer +takes_value "Name of the signing key to sign the files with")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    );
+    sub.arg(Arg::with_name("MAX_SIZE")
+        .help("Refuse to upload files larger than this many bytes (default: 10485760)")
+        .long("max-size")
+        .takes_value(true)
+        .validator(valid_size))
+}
+
+fn sub_election_list() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand list =>
+        (about: "Prints the term, status, votes and suitability of each election in the ring")
+        (@arg SERVICE_GROUP: +takes_value {valid_service_group}
+            "Only print elections for this service group (ex: redis.default)")
+    );
+    sub.arg(Arg::with_name("REMOTE_SUP")
+        .help("The HTTP gateway of the Supervisor to ask (default: 127.0.0.1:9631)")
+        .long("remote-sup")
+        .takes_value(true))
+}
+
+fn sub_election_transfer() -> App<'static, 'static> {
+    clap_app!(@subcommand transfer =>
+        (about: "Asks the leader of a service group to hand leadership to another member")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg MEMBER_ID: +takes_value
+            "The member to hand leadership to (default: any member but the current leader)")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+    )
+}
+
 fn sub_config_apply() -> App<'static, 'static> {
-    clap_app!(@subcommand apply =>
+    let sub = clap_app!(@subcommand apply =>
         (about: "Applies a configuration to a group of Habitat Supervisors")
         (@arg PEER: -p --peer +takes_value
             "A comma-delimited list of one or more Habitat Supervisor peers \
@@ -65,13 +218,47 @@ fn sub_config_apply() -> App<'static, 'static> {
             "Ring key name, which will encrypt communication messages")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
-        (@arg VERSION_NUMBER: +required
-            "A version number (positive integer) for this configuration (ex: 42)")
+        (@arg NUMBER: -n --number +takes_value {valid_version_number}
+            "A version number (positive integer) for this configuration (ex: 42, default: one \
+            more than the ring has)")
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
         (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
-    )
+        (@arg SIGNER: --signer +takes_value
+            "Name of the signing key to sign the configuration with")
+        (@arg PATCH: --patch
+            "Merge this configuration into the one the service group has, rather than replacing \
+            it")
+    );
+    sub.arg(Arg::with_name("DELETE")
+        .help("Remove this key, a dotted path like http.port, from the configuration the \
+               service group has; implies --patch (may be given more than once)")
+        .long("delete")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1))
+        .arg(Arg::with_name("BATCH_SIZE")
+            .help("Roll the configuration out this many Supervisors at a time, rather than to \
+                   all of them at once")
+            .long("batch-size")
+            .takes_value(true)
+            .conflicts_with("BATCH_PERCENT")
+            .validator(valid_batch_size))
+        .arg(Arg::with_name("BATCH_PERCENT")
+            .help("Roll the configuration out this percentage of Supervisors at a time")
+            .long("batch-percent")
+            .takes_value(true)
+            .validator(valid_batch_percent))
+        .arg(Arg::with_name("HEALTH_GATE")
+            .help("Have each batch pass its health check before the next takes the \
+                   configuration up, and halt the rollout when one fails")
+            .long("health-gate"))
+        .arg(Arg::with_name("PAUSE")
+            .help("Wait this many seconds between batches of a rollout")
+            .long("pause")
+            .takes_value(true)
+            .validator(valid_pause))
 }
 
 fn file_exists(val: String) -> result::Result<(), String> {
@@ -82,6 +269,14 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        Err(format!("Directory: '{}' cannot be found", &val))
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
@@ -94,3 +289,38 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
         Err(format!("SERVICE_GROUP: '{}' is invalid", &val))
     }
 }
+
+fn valid_size(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("MAX_SIZE: '{}' is not a number of bytes", &val)),
+    }
+}
+
+fn valid_version_number(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("NUMBER: '{}' is not a positive version number", &val)),
+    }
+}
+
+fn valid_batch_size(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("BATCH_SIZE: '{}' is not a positive number of Supervisors", &val)),
+    }
+}
+
+fn valid_batch_percent(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(n) if n > 0 && n <= 100 => Ok(()),
+        _ => Err(format!("BATCH_PERCENT: '{}' is not a percentage from 1 to 100", &val)),
+    }
+}
+
+fn valid_pause(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("PAUSE: '{}' is not a number of seconds", &val)),
+    }
+}
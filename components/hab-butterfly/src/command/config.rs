@@ -12,32 +12,274 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::thread;
+use std::time;
+
+use butterfly::client::Client;
+use butterfly::message::swim::{Membership_Health, Query, Query_Type, RolloutPolicy,
+                               ServiceConfig_Mode};
+use common::ui::{Status, UI};
+use hcore::crypto::{SymKey, SigKeyPair};
+use hcore::service::ServiceGroup;
+
+use command::ring::status::ask;
+use error::{Error, Result};
+
+/// The body of an incarnation of a service group's config, which was too large to come with the
+/// answer to a query, asked for a slice at a time from the peer that left it out.
+fn config_body(ui: &mut UI,
+               sg: &ServiceGroup,
+               incarnation: u64,
+               peer: &str,
+               ring_key: Option<&SymKey>)
+               -> Result<Vec<u8>> {
+    let mut query = Query::new();
+    query.set_field_type(Query_Type::ConfigBody);
+    query.set_service_group(sg.to_string());
+    query.set_incarnation(incarnation);
+    let (_, mut response) = try!(ask(ui, &vec![String::from(peer)], ring_key, query));
+    if !response.has_service_config() {
+        return Err(Error::ButterflyError(format!("{} no longer remembers incarnation {} of the \
+                                                  configuration for {}",
+                                                 peer,
+                                                 incarnation,
+                                                 sg)));
+    }
+    Ok(response.mut_service_config().take_config())
+}
+
+/// The incarnation to apply a configuration as: one more than the ring has, unless the operator
+/// asked for a particular one, in which case it has to be more than the ring has or it would be
+/// ignored. Also returns the incarnation the ring has, or 0 if it has none, for a patch to build
+/// on; a patch can't be sent without asking the ring.
+fn next_incarnation(ui: &mut UI,
+                    sg: &ServiceGroup,
+                    number: Option<u64>,
+                    patch: bool,
+                    peers: &Vec<String>,
+                    ring_key: Option<&SymKey>)
+                    -> Result<(u64, u64)> {
+    let mut query = Query::new();
+    query.set_field_type(Query_Type::Config);
+    query.set_service_group(sg.to_string());
+    let current = match ask(ui, peers, ring_key, query) {
+        Ok((_, response)) => {
+            if response.has_service_config() {
+                Some(response.get_service_config().get_incarnation())
+            } else {
+                None
+            }
+        }
+        Err(e) => {
+            match number {
+                Some(number) if !patch => {
+                    try!(ui.warn(format!("Cannot check the incarnation the ring has; applying \
+                                          {} anyway: {}",
+                                         number,
+                                         e)));
+                    return Ok((number, 0));
+                }
+                _ => return Err(e),
+            }
+        }
+    };
+    let base = current.unwrap_or(0);
+    match (number, current) {
+        (Some(number), Some(current)) if number <= current => {
+            Err(Error::ButterflyError(format!("The ring already has incarnation {} of the \
+                                               configuration for {}; use a higher number, or \
+                                               leave it out to apply it as {}",
+                                              current,
+                                              sg,
+                                              current + 1)))
+        }
+        (Some(number), _) => Ok((number, base)),
+        (None, Some(current)) => Ok((current + 1, base)),
+        (None, None) => Ok((1, base)),
+    }
+}
+
+/// The rollout policy to send: `rollout` with the alive members of the service group, ordered by
+/// member id, as its roster, so every Supervisor cuts the same batches from it whatever its own
+/// census says.
+fn with_roster(ui: &mut UI,
+               sg: &ServiceGroup,
+               rollout: &RolloutPolicy,
+               peers: &Vec<String>,
+               ring_key: Option<&SymKey>)
+               -> Result<RolloutPolicy> {
+    let mut query = Query::new();
+    query.set_field_type(Query_Type::Census);
+    query.set_service_group(sg.to_string());
+    let (_, census) = try!(ask(ui, peers, ring_key, query));
+    // The peer we asked leaves itself out of the membership; it is alive
+    let mut members: Vec<String> = census.get_service()
+        .iter()
+        .filter(|service| {
+            census.get_membership()
+                .iter()
+                .find(|m| m.get_member().get_id() == service.get_member_id())
+                .map(|m| m.get_health() == Membership_Health::ALIVE)
+                .unwrap_or(true)
+        })
+        .map(|service| String::from(service.get_member_id()))
+        .collect();
+    if members.is_empty() {
+        return Err(Error::ButterflyError(format!("No alive members of {} to roll the \
+                                                  configuration out to",
+                                                 sg)));
+    }
+    members.sort();
+    members.dedup();
+    let mut rollout = rollout.clone();
+    rollout.clear_members();
+    for id in members.into_iter() {
+        rollout.mut_members().push(id);
+    }
+    Ok(rollout)
+}
+
+/// How long to give a patch to meet any other sent at the same time, before asking the ring which
+/// one it kept.
+const PATCH_SETTLE_MS: u64 = 3000;
+
+/// Make sure the ring kept a patch we sent. Two patches sent at once as the same incarnation
+/// can't both be kept; every member settles on the same one, and the other is lost, even if it
+/// changed different keys. The lost one has to be applied again, on top of the one that was kept.
+fn confirm_patch(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 number: u64,
+                 base: u64,
+                 body: &[u8],
+                 delete_keys: &[String],
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+    thread::sleep(time::Duration::from_millis(PATCH_SETTLE_MS));
+    let mut query = Query::new();
+    query.set_field_type(Query_Type::Config);
+    query.set_service_group(sg.to_string());
+    let (_, response) = try!(ask(ui, peers, ring_key, query));
+    let kept = response.get_service_config();
+    if response.has_service_config() && kept.get_incarnation() == number &&
+       kept.get_base_incarnation() == base && kept.get_delete_keys() == delete_keys &&
+       (response.get_config_too_large() || response.get_bodies_withheld() ||
+        kept.get_config() == body) {
+        return Ok(());
+    }
+    Err(Error::ButterflyError(format!("The ring kept another configuration for {}, incarnation \
+                                       {}, in place of this patch; apply the patch again to \
+                                       build on it",
+                                      sg,
+                                      kept.get_incarnation())))
+}
+
+/// Send a configuration, or a patch to one, to every peer.
+fn send(ui: &mut UI,
+        sg: &ServiceGroup,
+        number: u64,
+        base: u64,
+        body: Vec<u8>,
+        encrypted: bool,
+        mode: ServiceConfig_Mode,
+        delete_keys: &[String],
+        rollout: Option<&RolloutPolicy>,
+        peers: &Vec<String>,
+        ring_key: Option<&SymKey>,
+        signer: Option<&SigKeyPair>,
+        applied_by: Option<&str>)
+        -> Result<()> {
+    match signer {
+        Some(signer) => {
+            try!(ui.status(Status::Signing,
+                           format!("configuration with {}", signer.name_with_rev())))
+        }
+        None => {
+            try!(ui.warn("This configuration is not signed; Supervisors with a signer \
+                          policy will refuse it"))
+        }
+    }
+
+    for peer in peers.iter() {
+        try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+        let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+            .map_err(|e| Error::ButterflyError(format!("{}", e))));
+        let sent = match mode {
+            ServiceConfig_Mode::Replace => {
+                client.send_service_config(sg.clone(),
+                                         number,
+                                         body.clone(),
+                                         encrypted,
+                                         rollout,
+                                         signer,
+                                         applied_by)
+            }
+            ServiceConfig_Mode::Patch => {
+                client.send_service_config_patch(sg.clone(),
+                                               number,
+                                               base,
+                                               body.clone(),
+                                               delete_keys.to_vec(),
+                                               encrypted,
+                                               rollout,
+                                               signer,
+                                               applied_by)
+            }
+        };
+        try!(sent.map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+        // please take a moment to weep over the following line
+        // of code. We must sleep to allow messages to be sent
+        // before freeing the socket to prevent loss.
+        // see https://github.com/zeromq/libzmq/issues/1264
+        thread::sleep(time::Duration::from_millis(100));
+    }
+    Ok(())
+}
+
 pub mod apply {
     use std::str;
     use std::path::Path;
     use std::io::{self, Read};
     use std::fs::File;
-    use std::thread;
-    use std::time;
 
-    use butterfly::client::Client;
+    use butterfly::message::swim::{RolloutPolicy, ServiceConfig_Mode};
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{SymKey, BoxKeyPair, SigKeyPair};
     use hcore::service::ServiceGroup;
     use toml;
 
     use error::{Error, Result};
+    use super::{config_body, confirm_patch, next_incarnation, send, with_roster};
 
+    /// Apply a configuration to a service group. With `patch`, it is merged into the
+    /// configuration the service group has, and `delete_keys` are then removed from it; with no
+    /// file to read, only the keys are removed. With a `rollout` policy, Supervisors take the
+    /// configuration up a batch at a time.
     pub fn start(ui: &mut UI,
                  sg: &ServiceGroup,
-                 number: u64,
+                 number: Option<u64>,
                  file_path: Option<&Path>,
+                 patch: bool,
+                 delete_keys: &[String],
+                 rollout: Option<&RolloutPolicy>,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  user_pair: Option<&BoxKeyPair>,
-                 service_pair: Option<&BoxKeyPair>)
+                 service_pair: Option<&BoxKeyPair>,
+                 signer: Option<&SigKeyPair>,
+                 applied_by: Option<&str>)
                  -> Result<()> {
-        try!(ui.begin(format!("Applying configuration for {} incarnation {}", sg, number,)));
+        let (number, base) = try!(next_incarnation(ui, sg, number, patch, peers, ring_key));
+        let mode = if patch {
+            ServiceConfig_Mode::Patch
+        } else {
+            ServiceConfig_Mode::Replace
+        };
+        try!(ui.begin(format!("Applying configuration {}for {} incarnation {}",
+                              if patch { "patch " } else { "" },
+                              sg,
+                              number)));
 
         try!(ui.status(Status::Creating, format!("service configuration")));
 
@@ -48,10 +290,40 @@ pub mod apply {
                 let mut file = try!(File::open(&p));
                 try!(file.read_to_end(&mut body));
             }
+            None if patch && !delete_keys.is_empty() => {}
             None => {
                 try!(io::stdin().read_to_end(&mut body));
             }
         };
+        for key in delete_keys.iter() {
+            try!(ui.status(Status::Deleting, format!("key {}", key)));
+        }
+        let rollout = match rollout {
+            Some(rollout) => Some(try!(with_roster(ui, sg, rollout, peers, ring_key))),
+            None => None,
+        };
+        if let Some(ref rollout) = rollout {
+            let batch = if rollout.get_batch_size() > 0 {
+                format!("{} member(s)", rollout.get_batch_size())
+            } else {
+                format!("{}% of members", rollout.get_batch_percent())
+            };
+            try!(ui.status(Status::Using,
+                           format!("a staged rollout of {} at a time{}{}",
+                                   batch,
+                                   if rollout.get_health_gate() {
+                                       ", gated on health checks"
+                                   } else {
+                                       ""
+                                   },
+                                   if rollout.get_pause_secs() > 0 {
+                                       format!(", {}s apart", rollout.get_pause_secs())
+                                   } else {
+                                       String::new()
+                                   })));
+            try!(ui.status(Status::Using,
+                           format!("a roster of {} member(s)", rollout.get_members().len())));
+        }
 
         // We want to expire the borrow of body before we check to see if we need
         // to encrypt the contents.
@@ -85,20 +357,244 @@ pub mod apply {
             encrypted = true;
         }
 
-        for peer in peers.iter() {
-            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
-            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
-                .map_err(|e| Error::ButterflyError(format!("{}", e))));
-            try!(client.send_service_config(sg.clone(), number, body.clone(), encrypted)
-                .map_err(|e| Error::ButterflyError(format!("{}", e))));
-
-            // please take a moment to weep over the following line
-            // of code. We must sleep to allow messages to be sent
-            // before freeing the socket to prevent loss.
-            // see https://github.com/zeromq/libzmq/issues/1264
-            thread::sleep(time::Duration::from_millis(100));
+        try!(send(ui,
+                  sg,
+                  number,
+                  base,
+                  body.clone(),
+                  encrypted,
+                  mode,
+                  delete_keys,
+                  rollout.as_ref(),
+                  peers,
+                  ring_key,
+                  signer,
+                  applied_by));
+        if patch {
+            try!(confirm_patch(ui, sg, number, base, &body, delete_keys, peers, ring_key));
+            try!(ui.status(Status::Verified, "the ring kept this patch"));
         }
         try!(ui.end("Applied configuration"));
         Ok(())
     }
 }
+
+pub mod show {
+    use std::str;
+
+    use butterfly::message::swim::{Query, Query_Type, ServiceConfig_Mode};
+    use common::ui::UI;
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use command::ring::status::ask;
+    use error::Result;
+    use super::config_body;
+
+    /// Print the latest config the ring has for a service group, and the files it has been sent.
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+        try!(ui.begin(format!("Asking the ring for the config of {}", sg)));
+        let mut query = Query::new();
+        query.set_field_type(Query_Type::Config);
+        query.set_service_group(sg.to_string());
+        let (peer, mut response) = try!(ask(ui, peers, ring_key, query));
+        if response.get_config_too_large() {
+            let incarnation = response.get_service_config().get_incarnation();
+            let body = try!(config_body(ui, sg, incarnation, &peer, ring_key));
+            response.mut_service_config().set_config(body);
+        }
+
+        if response.has_service_config() {
+            let config = response.get_service_config();
+            println!("Config {}incarnation {}, as {} ({}) sees it{}",
+                     if config.get_mode() == ServiceConfig_Mode::Patch {
+                         "patch "
+                     } else {
+                         ""
+                     },
+                     config.get_incarnation(),
+                     response.get_member_id(),
+                     peer,
+                     if config.has_signer() {
+                         format!(", signed by {}", config.get_signer())
+                     } else {
+                         String::new()
+                     });
+            if config.get_delete_keys().len() > 0 {
+                println!("Deletes {}", config.get_delete_keys().join(", "));
+            }
+            println!("");
+            if response.get_bodies_withheld() {
+                println!("(withheld; the ring has no key)");
+            } else if config.get_encrypted() {
+                println!("(encrypted)");
+            } else {
+                match str::from_utf8(config.get_config()) {
+                    Ok(text) => println!("{}", text),
+                    Err(_) => println!("(not UTF-8)"),
+                }
+            }
+        } else {
+            println!("No config has been applied to {}", sg);
+        }
+
+        // Deleted files are only there so a client knows which incarnation to beat
+        let files: Vec<_> = response.get_service_file()
+            .iter()
+            .filter(|file| !file.get_deleted())
+            .collect();
+        if files.len() > 0 {
+            println!("");
+            println!("{:<40} {:>11} {:>12} {}",
+                     "FILE",
+                     "INCARNATION",
+                     "SIZE",
+                     "ENCRYPTED");
+            for file in files.iter() {
+                println!("{:<40} {:>11} {:>12} {}",
+                         file.get_filename(),
+                         file.get_incarnation(),
+                         file.get_size(),
+                         file.get_encrypted());
+            }
+        }
+        try!(ui.end("Read the config"));
+        Ok(())
+    }
+}
+
+pub mod history {
+    use butterfly::message::swim::{Query, Query_Type};
+    use common::ui::UI;
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use command::ring::status::ask;
+    use error::Result;
+
+    /// Print the configurations a member of the ring remembers for a service group, newest first.
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+        try!(ui.begin(format!("Asking the ring for the configuration history of {}", sg)));
+        let mut query = Query::new();
+        query.set_field_type(Query_Type::ConfigHistory);
+        query.set_service_group(sg.to_string());
+        let (peer, response) = try!(ask(ui, peers, ring_key, query));
+        println!("As {} ({}) remembers it:", response.get_member_id(), peer);
+        println!("{:>11} {:<7} {:<24} {:<32} {:<9} {:>8}",
+                 "INCARNATION",
+                 "MODE",
+                 "APPLIED BY",
+                 "SIGNER",
+                 "ENCRYPTED",
+                 "SIZE");
+        for config in response.get_config_history().iter() {
+            // A body too large to come with the history is left out
+            let size = if response.get_bodies_withheld() {
+                String::from("withheld")
+            } else if response.get_configs_too_large().contains(&config.get_incarnation()) {
+                String::from("large")
+            } else {
+                format!("{}", config.get_config().len())
+            };
+            println!("{:>11} {:<7} {:<24} {:<32} {:<9} {:>8}",
+                     config.get_incarnation(),
+                     format!("{:?}", config.get_mode()),
+                     config.get_applied_by(),
+                     config.get_signer(),
+                     config.get_encrypted(),
+                     size);
+        }
+        try!(ui.end("Read the configuration history"));
+        Ok(())
+    }
+}
+
+pub mod rollback {
+    use butterfly::message::swim::{Query, Query_Type, ServiceConfig_Mode};
+    use common::ui::UI;
+    use hcore::crypto::{SigKeyPair, SymKey};
+    use hcore::service::ServiceGroup;
+
+    use command::ring::status::ask;
+    use error::{Error, Result};
+    use super::{next_incarnation, send};
+
+    /// Apply an earlier configuration of a service group again, as a new incarnation, so that
+    /// every member takes it up in place of the current one.
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 incarnation: u64,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 signer: Option<&SigKeyPair>,
+                 applied_by: Option<&str>)
+                 -> Result<()> {
+        let mut query = Query::new();
+        query.set_field_type(Query_Type::ConfigHistory);
+        query.set_service_group(sg.to_string());
+        let (peer, mut response) = try!(ask(ui, peers, ring_key, query));
+        if response.get_bodies_withheld() {
+            return Err(Error::ButterflyError(format!("{} ({}) only sends configurations to \
+                                                      clients with the ring key, and the ring \
+                                                      has none; apply incarnation {} again \
+                                                      from a file instead",
+                                                     response.get_member_id(),
+                                                     peer,
+                                                     incarnation)));
+        }
+        let history = response.take_config_history().into_vec();
+        let mut previous = match history.into_iter().find(|c| c.get_incarnation() == incarnation) {
+            Some(previous) => previous,
+            None => {
+                return Err(Error::ButterflyError(format!("{} ({}) does not remember incarnation \
+                                                          {} of the configuration for {}; see \
+                                                          `hab config history {}`",
+                                                         response.get_member_id(),
+                                                         peer,
+                                                         incarnation,
+                                                         sg,
+                                                         sg)))
+            }
+        };
+        if response.get_configs_too_large().contains(&incarnation) {
+            let body = try!(config_body(ui, sg, incarnation, &peer, ring_key));
+            previous.set_config(body);
+        }
+        let patch = previous.get_mode() == ServiceConfig_Mode::Patch;
+        let (number, base) = try!(next_incarnation(ui, sg, None, patch, peers, ring_key));
+        try!(ui.begin(format!("Rolling the configuration for {} back to incarnation {}, as \
+                               incarnation {}",
+                              sg,
+                              incarnation,
+                              number)));
+        if previous.has_signer() && signer.is_none() {
+            try!(ui.warn(format!("Incarnation {} was signed by {}; its signature does not carry \
+                                  over to a new incarnation, so sign it again with --signer",
+                                 incarnation,
+                                 previous.get_signer())));
+        }
+        try!(send(ui,
+                  sg,
+                  number,
+                  base,
+                  previous.get_config().to_vec(),
+                  previous.get_encrypted(),
+                  previous.get_mode(),
+                  previous.get_delete_keys(),
+                  None,
+                  peers,
+                  ring_key,
+                  signer,
+                  applied_by));
+        try!(ui.end("Rolled back configuration"));
+        Ok(())
+    }
+}
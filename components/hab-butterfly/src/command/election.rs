@@ -0,0 +1,145 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod list {
+    use std::io::Read;
+
+    use common::ui::UI;
+    use hcore::service::ServiceGroup;
+    use hyper;
+    use hyper::status::StatusCode;
+    use serde_json::{self, Value};
+
+    use error::{Error, Result};
+
+    /// Print every election a Supervisor knows about, as reported by its HTTP gateway.
+    pub fn start(ui: &mut UI, sup_addr: &str, sg: Option<&ServiceGroup>) -> Result<()> {
+        try!(ui.begin(format!("Reading elections from {}", sup_addr)));
+        let url = format!("http://{}/butterfly/elections", sup_addr);
+        let client = hyper::Client::new();
+        let mut response = try!(client.get(&url)
+            .send()
+            .map_err(|e| Error::ButterflyError(format!("Cannot reach {}: {}", url, e))));
+        if response.status != StatusCode::Ok {
+            return Err(Error::ButterflyError(format!("{} returned {}", url, response.status)));
+        }
+        let mut body = String::new();
+        try!(response.read_to_string(&mut body));
+        let elections: Value = try!(serde_json::from_str(&body)
+            .map_err(|e| Error::ButterflyError(format!("Invalid response from {}: {}", url, e))));
+
+        println!("{:<32} {:<8} {:>6} {:<10} {:<34} {:>12} {}",
+                 "SERVICE GROUP",
+                 "KIND",
+                 "TERM",
+                 "STATUS",
+                 "MEMBER",
+                 "SUITABILITY",
+                 "VOTES");
+        for &(field, kind) in &[("election", "leader"), ("election_update", "update")] {
+            let groups = elections.as_object()
+                .and_then(|e| e.get(field))
+                .and_then(|store| store.as_object())
+                .and_then(|store| store.get("list"))
+                .and_then(|list| list.as_object());
+            let groups = match groups {
+                Some(groups) => groups,
+                None => continue,
+            };
+            for (service_group, rumors) in groups.iter() {
+                if let Some(sg) = sg {
+                    if *service_group != sg.to_string() {
+                        continue;
+                    }
+                }
+                let election = match rumors.as_object().and_then(|r| r.get("election")) {
+                    Some(election) => election,
+                    None => continue,
+                };
+                let votes: Vec<&str> = field_of(election, "votes")
+                    .and_then(|v| v.as_array())
+                    .map(|v| v.iter().filter_map(|vote| vote.as_str()).collect())
+                    .unwrap_or(Vec::new());
+                println!("{:<32} {:<8} {:>6} {:<10} {:<34} {:>12} {}",
+                         service_group,
+                         kind,
+                         u64_of(election, "term"),
+                         status_name(u64_of(election, "status")),
+                         field_of(election, "member_id").and_then(|m| m.as_str()).unwrap_or(""),
+                         u64_of(election, "suitability"),
+                         votes.join(","));
+            }
+        }
+        try!(ui.end("Read elections"));
+        Ok(())
+    }
+
+    fn field_of<'a>(value: &'a Value, field: &str) -> Option<&'a Value> {
+        value.as_object().and_then(|o| o.get(field))
+    }
+
+    fn u64_of(value: &Value, field: &str) -> u64 {
+        field_of(value, field).and_then(|v| v.as_u64()).unwrap_or(0)
+    }
+
+    /// Election statuses are serialized as their protocol values.
+    fn status_name(status: u64) -> &'static str {
+        match status {
+            1 => "running",
+            2 => "no-quorum",
+            3 => "finished",
+            _ => "unknown",
+        }
+    }
+}
+
+pub mod transfer {
+    use std::thread;
+    use std::time;
+
+    use butterfly::client::Client;
+    use common::ui::{Status, UI};
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 member_id: Option<&str>,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+        match member_id {
+            Some(member_id) => {
+                try!(ui.begin(format!("Transferring leadership of {} to {}", sg, member_id)))
+            }
+            None => {
+                try!(ui.begin(format!("Transferring leadership of {} away from its leader", sg)))
+            }
+        }
+        for peer in peers.iter() {
+            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            try!(client.send_leader_transfer(sg.clone(), member_id)
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            // Give ZMQ time to send the message before the socket is dropped; see the comment in
+            // `config::apply`.
+            thread::sleep(time::Duration::from_millis(100));
+        }
+        try!(ui.end("Requested leader transfer"));
+        Ok(())
+    }
+}
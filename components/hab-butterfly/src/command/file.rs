@@ -12,19 +12,105 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::thread;
+use std::time;
+
+use butterfly::client::Client;
+use butterfly::message::swim::{Query, Query_Type, ServiceFile};
+use common::ui::{Status, UI};
+use hcore::crypto::{SymKey, SigKeyPair};
+use hcore::service::ServiceGroup;
+
+use command::ring::status::ask;
+use error::{Error, Result};
+
+/// A change to the files of a service group.
+enum Change {
+    /// Upload a file, with its (possibly encrypted) body, as an incarnation
+    Upload(String, u64, Vec<u8>),
+    /// Delete a file, as an incarnation
+    Delete(String, u64),
+}
+
+/// The files the ring has for a service group, by name, without their bodies. Files that have
+/// been deleted are there too, marked deleted, since a file sent again has to be a later
+/// incarnation than its tombstone.
+fn ring_files(ui: &mut UI,
+              sg: &ServiceGroup,
+              peers: &Vec<String>,
+              ring_key: Option<&SymKey>)
+              -> Result<HashMap<String, ServiceFile>> {
+    let mut query = Query::new();
+    query.set_field_type(Query_Type::Config);
+    query.set_service_group(sg.to_string());
+    let (_, mut response) = try!(ask(ui, peers, ring_key, query));
+    Ok(response.take_service_file()
+        .into_iter()
+        .map(|file| (String::from(file.get_filename()), file))
+        .collect())
+}
+
+/// Send changes to every peer.
+fn send(ui: &mut UI,
+        sg: &ServiceGroup,
+        changes: &[Change],
+        encrypted: bool,
+        peers: &Vec<String>,
+        ring_key: Option<&SymKey>,
+        signer: Option<&SigKeyPair>)
+        -> Result<()> {
+    match signer {
+        Some(signer) => {
+            try!(ui.status(Status::Signing, format!("file with {}", signer.name_with_rev())))
+        }
+        None => {
+            try!(ui.warn("This file is not signed; Supervisors with a signer policy will \
+                          refuse it"))
+        }
+    }
+
+    for peer in peers.iter() {
+        try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+        let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+            .map_err(|e| Error::ButterflyError(format!("{}", e))));
+        for change in changes.iter() {
+            let sent = match *change {
+                Change::Upload(ref filename, number, ref body) => {
+                    client.send_service_file(sg.clone(),
+                                             filename.clone(),
+                                             number,
+                                             body.clone(),
+                                             encrypted,
+                                             signer)
+                }
+                Change::Delete(ref filename, number) => {
+                    client.send_service_file_tombstone(sg.clone(), filename.clone(), number, signer)
+                }
+            };
+            try!(sent.map_err(|e| Error::ButterflyError(format!("{}", e))));
+        }
+
+        // please take a moment to weep over the following line
+        // of code. We must sleep to allow messages to be sent
+        // before freeing the socket to prevent loss.
+        // see https://github.com/zeromq/libzmq/issues/1264
+        thread::sleep(time::Duration::from_millis(100));
+    }
+    Ok(())
+}
+
 pub mod upload {
     use std::path::Path;
     use std::io::Read;
     use std::fs::File;
-    use std::thread;
-    use std::time;
 
-    use butterfly::client::Client;
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{SymKey, BoxKeyPair, SigKeyPair};
     use hcore::service::ServiceGroup;
 
-    use error::{Error, Result};
+    use error::Result;
+    use super::{send, Change};
 
     pub fn start(ui: &mut UI,
                  sg: &ServiceGroup,
@@ -33,7 +119,8 @@ pub mod upload {
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  user_pair: Option<&BoxKeyPair>,
-                 service_pair: Option<&BoxKeyPair>)
+                 service_pair: Option<&BoxKeyPair>,
+                 signer: Option<&SigKeyPair>)
                  -> Result<()> {
         try!(ui.begin(format!("Uploading file {} to {} incarnation {}",
                               &file_path.display(),
@@ -58,24 +145,158 @@ pub mod upload {
             encrypted = true;
         }
 
-        for peer in peers.iter() {
-            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
-            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
-                .map_err(|e| Error::ButterflyError(format!("{}", e))));
-            try!(client.send_service_file(sg.clone(),
-                                   filename.clone(),
-                                   number,
-                                   body.clone(),
-                                   encrypted)
-                .map_err(|e| Error::ButterflyError(format!("{}", e))));
-
-            // please take a moment to weep over the following line
-            // of code. We must sleep to allow messages to be sent
-            // before freeing the socket to prevent loss.
-            // see https://github.com/zeromq/libzmq/issues/1264
-            thread::sleep(time::Duration::from_millis(100));
-        }
+        try!(send(ui,
+                  sg,
+                  &[Change::Upload(filename, number, body)],
+                  encrypted,
+                  peers,
+                  ring_key,
+                  signer));
         try!(ui.end("Uploaded file"));
         Ok(())
     }
 }
+
+pub mod delete {
+    use common::ui::{Status, UI};
+    use hcore::crypto::{SymKey, SigKeyPair};
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+    use super::{ring_files, send, Change};
+
+    /// Delete a file from every member of a service group, as one incarnation more than the ring
+    /// has of it.
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 filename: &str,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 signer: Option<&SigKeyPair>)
+                 -> Result<()> {
+        let number = match try!(ring_files(ui, sg, peers, ring_key)).get(filename) {
+            Some(file) if file.get_deleted() => {
+                return Err(Error::ButterflyError(format!("File {} of {} is already deleted, as \
+                                                          incarnation {}",
+                                                         filename,
+                                                         sg,
+                                                         file.get_incarnation())))
+            }
+            Some(file) => file.get_incarnation() + 1,
+            None => {
+                return Err(Error::ButterflyError(format!("The ring has no file {} for {}",
+                                                         filename,
+                                                         sg)))
+            }
+        };
+        try!(ui.begin(format!("Deleting file {} from {} incarnation {}", filename, sg, number)));
+        try!(ui.status(Status::Deleting, format!("service file")));
+        try!(send(ui,
+                  sg,
+                  &[Change::Delete(String::from(filename), number)],
+                  false,
+                  peers,
+                  ring_key,
+                  signer));
+        try!(ui.end("Deleted file"));
+        Ok(())
+    }
+}
+
+pub mod sync {
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::Path;
+
+    use common::ui::{Status, UI};
+    use hcore::crypto::{hash, SymKey, BoxKeyPair, SigKeyPair};
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+    use super::{ring_files, send, Change};
+
+    /// Make the files of a service group the same as the files in a directory: upload every file
+    /// that is new or has changed, and delete every file the directory no longer has. Each goes
+    /// out as one incarnation more than the ring has of it. Subdirectories are left alone.
+    pub fn start(ui: &mut UI,
+                 sg: &ServiceGroup,
+                 dir: &Path,
+                 max_size: u64,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 user_pair: Option<&BoxKeyPair>,
+                 service_pair: Option<&BoxKeyPair>,
+                 signer: Option<&SigKeyPair>)
+                 -> Result<()> {
+        try!(ui.begin(format!("Syncing files in {} to {}", dir.display(), sg)));
+        let mut ring = try!(ring_files(ui, sg, peers, ring_key));
+        let encrypting = service_pair.is_some() && user_pair.is_some();
+
+        let mut paths = Vec::new();
+        for entry in try!(fs::read_dir(dir)) {
+            let entry = try!(entry);
+            if try!(entry.file_type()).is_file() {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+
+        let mut changes = Vec::new();
+        for path in paths.iter() {
+            let filename = path.file_name().unwrap().to_string_lossy().into_owned();
+            if try!(path.metadata()).len() > max_size {
+                return Err(Error::CryptoCLI(format!("{} is larger than the maximum file size \
+                                                     of {} bytes; use --max-size to raise it",
+                                                    path.display(),
+                                                    max_size)));
+            }
+            let mut body = Vec::new();
+            try!(try!(File::open(path)).read_to_end(&mut body));
+
+            let number = match ring.remove(&filename) {
+                Some(file) => {
+                    if !file.get_deleted() && !encrypting && !file.get_encrypted() &&
+                       file.get_content_hash() == try!(hash::hash_bytes(&body)) {
+                        try!(ui.status(Status::Using, format!("{}, which is unchanged", filename)));
+                        continue;
+                    }
+                    file.get_incarnation() + 1
+                }
+                None => 1,
+            };
+            try!(ui.status(Status::Creating,
+                           format!("service file {} incarnation {}", filename, number)));
+            if encrypting {
+                body = try!(user_pair.unwrap().encrypt(&body, service_pair.unwrap()));
+            }
+            changes.push(Change::Upload(filename, number, body));
+        }
+
+        // What is left is on the ring but no longer in the directory, unless it is deleted
+        // already
+        let mut gone: Vec<_> = ring.into_iter()
+            .filter(|&(_, ref file)| !file.get_deleted())
+            .collect();
+        gone.sort_by(|a, b| a.0.cmp(&b.0));
+        for (filename, file) in gone.into_iter() {
+            let number = file.get_incarnation() + 1;
+            try!(ui.status(Status::Deleting,
+                           format!("service file {} incarnation {}", filename, number)));
+            changes.push(Change::Delete(filename, number));
+        }
+
+        if changes.is_empty() {
+            try!(ui.end("Every file is already in sync"));
+            return Ok(());
+        }
+        if encrypting {
+            try!(ui.status(Status::Encrypting,
+                           format!("files as {} for {}",
+                                   user_pair.unwrap().name_with_rev(),
+                                   service_pair.unwrap().name_with_rev())));
+        }
+        try!(send(ui, sg, &changes, encrypting, peers, ring_key, signer));
+        try!(ui.end(format!("Synced {} file change(s)", changes.len())));
+        Ok(())
+    }
+}
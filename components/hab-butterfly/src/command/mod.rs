@@ -13,4 +13,6 @@
 // limitations under the License.
 
 pub mod config;
+pub mod election;
 pub mod file;
+pub mod ring;
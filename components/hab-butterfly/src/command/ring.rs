@@ -0,0 +1,119 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod status {
+    use butterfly::client;
+    use butterfly::message::swim::{Membership_Health, Query, QueryResponse, Query_Type};
+    use common::ui::{Status, UI};
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+
+    /// Print every member of the ring, as the first peer to answer sees it, and the census of
+    /// `sg` if there is one.
+    pub fn start(ui: &mut UI,
+                 sg: Option<&ServiceGroup>,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>)
+                 -> Result<()> {
+        try!(ui.begin("Asking the ring for its members"));
+        let mut query = Query::new();
+        query.set_field_type(Query_Type::Members);
+        let (peer, members) = try!(ask(ui, peers, ring_key, query));
+        println!("{:<34} {:<40} {:>11} {:<9} {}",
+                 "MEMBER",
+                 "ADDRESS",
+                 "INCARNATION",
+                 "HEALTH",
+                 "PERSISTENT");
+        for membership in members.get_membership().iter() {
+            let member = membership.get_member();
+            println!("{:<34} {:<40} {:>11} {:<9} {}",
+                     member.get_id(),
+                     format!("{}:{}", member.get_address(), member.get_swim_port()),
+                     member.get_incarnation(),
+                     health_name(membership.get_health()),
+                     member.get_persistent());
+        }
+
+        if let Some(sg) = sg {
+            let mut query = Query::new();
+            query.set_field_type(Query_Type::Census);
+            query.set_service_group(sg.to_string());
+            let census = try!(ask(ui, &vec![peer], ring_key, query)).1;
+            println!("");
+            println!("Census of {}:", sg);
+            println!("{:<34} {:<24} {:<24} {:<9} {:<11} {}",
+                     "MEMBER",
+                     "HOSTNAME",
+                     "IP",
+                     "HEALTH",
+                     "INITIALIZED",
+                     "PACKAGE");
+            for service in census.get_service().iter() {
+                let health = census.get_membership()
+                    .iter()
+                    .find(|m| m.get_member().get_id() == service.get_member_id())
+                    .map(|m| health_name(m.get_health()))
+                    .unwrap_or("alive");
+                let leader = if census.get_leader_id() == service.get_member_id() {
+                    " (leader)"
+                } else {
+                    ""
+                };
+                println!("{:<34} {:<24} {:<24} {:<9} {:<11} {}",
+                         format!("{}{}", service.get_member_id(), leader),
+                         service.get_hostname(),
+                         service.get_ip(),
+                         health,
+                         service.get_initialized(),
+                         service.get_package_ident());
+            }
+        }
+        try!(ui.end("Read the ring status"));
+        Ok(())
+    }
+
+    /// Ask each peer in turn until one answers.
+    pub fn ask(ui: &mut UI,
+               peers: &Vec<String>,
+               ring_key: Option<&SymKey>,
+               query: Query)
+               -> Result<(String, QueryResponse)> {
+        let mut last_error = None;
+        for peer in peers.iter() {
+            try!(ui.status(Status::Using, format!("peer {}", peer)));
+            match client::query(&peer[..], ring_key.map(|k| k.clone()), query.clone()) {
+                Ok(response) => return Ok((peer.clone(), response)),
+                Err(e) => {
+                    try!(ui.warn(format!("{}", e)));
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(Error::ButterflyError(match last_error {
+            Some(e) => format!("No peer answered our query; the last error was: {}", e),
+            None => String::from("No peers to query"),
+        }))
+    }
+
+    fn health_name(health: Membership_Health) -> &'static str {
+        match health {
+            Membership_Health::ALIVE => "alive",
+            Membership_Health::SUSPECT => "suspect",
+            Membership_Health::CONFIRMED => "confirmed",
+        }
+    }
+}
@@ -28,6 +28,7 @@ extern crate log;
 extern crate pbr;
 extern crate regex;
 extern crate retry;
+extern crate serde_json;
 extern crate toml;
 extern crate url;
 // Temporary dependency for gossip/rumor injection code duplication.
@@ -17,6 +17,7 @@ extern crate hab;
 extern crate clap;
 extern crate env_logger;
 extern crate hab_butterfly;
+extern crate habitat_butterfly as butterfly;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 #[macro_use]
@@ -28,11 +29,12 @@ use std::path::Path;
 use std::str::FromStr;
 use std::thread;
 
+use butterfly::message::swim::RolloutPolicy;
 use clap::ArgMatches;
 
 use common::ui::UI;
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SymKey};
+use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SigKeyPair, SymKey};
 use hcore::fs::FS_ROOT_PATH;
 use hcore::service::ServiceGroup;
 
@@ -44,12 +46,21 @@ use hab_butterfly::error::{Error, Result};
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 /// Makes the --user CLI param optional when this env var is set
 const HABITAT_USER_ENVVAR: &'static str = "HAB_USER";
+/// Makes the --signer CLI param optional when this env var is set
+const HABITAT_SIGNER_ENVVAR: &'static str = "HAB_SIGNER";
 
 const FS_ROOT_ENVVAR: &'static str = "FS_ROOT";
 
 const HABITAT_BUTTERFLY_PORT: u64 = 9638;
 
-const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
+const HABITAT_HTTP_GATEWAY_PORT: u64 = 9631;
+
+/// Makes the --max-size CLI param optional when this env var is set
+const HABITAT_MAX_FILE_SIZE_ENVVAR: &'static str = "HAB_MAX_FILE_SIZE";
+
+/// Files larger than a single chunk are gossiped in chunks, so this only guards against
+/// accidentally uploading something enormous.
+const DEFAULT_MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 10 * 1024 * 1024;
 
 fn main() {
     env_logger::init().unwrap();
@@ -74,12 +85,30 @@ fn start(ui: &mut UI) -> Result<()> {
         ("config", Some(matches)) => {
             match matches.subcommand() {
                 ("apply", Some(m)) => try!(sub_config_apply(ui, m)),
+                ("show", Some(m)) => try!(sub_config_show(ui, m)),
+                ("history", Some(m)) => try!(sub_config_history(ui, m)),
+                ("rollback", Some(m)) => try!(sub_config_rollback(ui, m)),
+                _ => unreachable!(),
+            }
+        }
+        ("election", Some(matches)) => {
+            match matches.subcommand() {
+                ("list", Some(m)) => try!(sub_election_list(ui, m)),
+                ("transfer", Some(m)) => try!(sub_election_transfer(ui, m)),
                 _ => unreachable!(),
             }
         }
         ("file", Some(matches)) => {
             match matches.subcommand() {
                 ("upload", Some(m)) => try!(sub_file_upload(ui, m)),
+                ("delete", Some(m)) => try!(sub_file_delete(ui, m)),
+                ("sync", Some(m)) => try!(sub_file_sync(ui, m)),
+                _ => unreachable!(),
+            }
+        }
+        ("ring", Some(matches)) => {
+            match matches.subcommand() {
+                ("status", Some(m)) => try!(sub_ring_status(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -99,11 +128,20 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
             p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
         }
     }
-    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let number = if m.is_present("NUMBER") {
+        Some(value_t!(m, "NUMBER", u64).unwrap_or_else(|e| e.exit()))
+    } else {
+        None
+    };
     let file_path = match m.value_of("FILE") {
         Some("-") | None => None,
         Some(p) => Some(Path::new(p)),
     };
+    let delete_keys: Vec<String> = match m.values_of("DELETE") {
+        Some(keys) => keys.map(|k| k.to_string()).collect(),
+        None => Vec::new(),
+    };
+    let patch = m.is_present("PATCH") || !delete_keys.is_empty();
 
     init();
     let cache = default_cache_key_path(fs_root_path);
@@ -127,14 +165,155 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         user_pair = Some(try!(BoxKeyPair::get_latest_pair_for(&user_name, &cache)));
     }
 
+    let signer = match signer_param_or_env(&m) {
+        Some(signer_name) => Some(try!(SigKeyPair::get_latest_pair_for(&signer_name, &cache))),
+        None => None,
+    };
+
+    let applied_by = applied_by_param_or_env(&m);
+    let rollout = rollout_param(&m);
+
     command::config::apply::start(ui,
                                   &sg,
                                   number,
                                   file_path,
+                                  patch,
+                                  &delete_keys,
+                                  rollout.as_ref(),
                                   &peers,
                                   ring_key.as_ref(),
                                   user_pair.as_ref(),
-                                  service_pair.as_ref())
+                                  service_pair.as_ref(),
+                                  signer.as_ref(),
+                                  applied_by.as_ref().map(|a| a.as_str()))
+}
+
+fn sub_config_show(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+    if let Some(org_name) = org_param_or_env(&m) {
+        sg.organization = Some(org_name);
+    }
+
+    command::config::show::start(ui, &sg, &peers, ring_key.as_ref())
+}
+
+fn sub_config_history(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+    if let Some(org_name) = org_param_or_env(&m) {
+        sg.organization = Some(org_name);
+    }
+
+    command::config::history::start(ui, &sg, &peers, ring_key.as_ref())
+}
+
+fn sub_config_rollback(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+    let incarnation = value_t!(m, "INCARNATION", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+    if let Some(org_name) = org_param_or_env(&m) {
+        sg.organization = Some(org_name);
+    }
+    let signer = match signer_param_or_env(&m) {
+        Some(signer_name) => Some(try!(SigKeyPair::get_latest_pair_for(&signer_name, &cache))),
+        None => None,
+    };
+    let applied_by = applied_by_param_or_env(&m);
+
+    command::config::rollback::start(ui,
+                                     &sg,
+                                     incarnation,
+                                     &peers,
+                                     ring_key.as_ref(),
+                                     signer.as_ref(),
+                                     applied_by.as_ref().map(|a| a.as_str()))
+}
+
+fn sub_ring_status(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let sg = match m.value_of("SERVICE_GROUP") {
+        Some(sg) => Some(try!(ServiceGroup::from_str(sg))),
+        None => None,
+    };
+
+    command::ring::status::start(ui, sg.as_ref(), &peers, ring_key.as_ref())
+}
+
+fn sub_election_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let mut sup_addr = String::from(m.value_of("REMOTE_SUP").unwrap_or("127.0.0.1"));
+    if sup_addr.find(':').is_none() {
+        sup_addr.push(':');
+        sup_addr.push_str(&HABITAT_HTTP_GATEWAY_PORT.to_string());
+    }
+    let sg = match m.value_of("SERVICE_GROUP") {
+        Some(sg) => Some(try!(ServiceGroup::from_str(sg))),
+        None => None,
+    };
+    command::election::list::start(ui, &sup_addr, sg.as_ref())
+}
+
+fn sub_election_transfer(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+
+    command::election::transfer::start(ui,
+                                       &sg,
+                                       m.value_of("MEMBER_ID"),
+                                       &peers,
+                                       ring_key.as_ref())
 }
 
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -151,11 +330,13 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
     let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
     let file_path = Path::new(m.value_of("FILE").unwrap()); // Required via clap
+    let max_size = try!(max_size_param_or_env(&m));
     match file_path.metadata() {
         Ok(md) => {
-            if md.len() > MAX_FILE_UPLOAD_SIZE_BYTES {
-                return Err(Error::CryptoCLI(format!("Maximum encrypted file size is {} bytes",
-                                                    MAX_FILE_UPLOAD_SIZE_BYTES)));
+            if md.len() > max_size {
+                return Err(Error::CryptoCLI(format!("Maximum file size is {} bytes; use \
+                                                     --max-size to raise it",
+                                                    max_size)));
             }
         }
         Err(e) => {
@@ -186,6 +367,11 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         user_pair = Some(try!(BoxKeyPair::get_latest_pair_for(&user_name, &cache)));
     }
 
+    let signer = match signer_param_or_env(&m) {
+        Some(signer_name) => Some(try!(SigKeyPair::get_latest_pair_for(&signer_name, &cache))),
+        None => None,
+    };
+
     command::file::upload::start(ui,
                                  &sg,
                                  number,
@@ -193,7 +379,79 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                  &peers,
                                  ring_key.as_ref(),
                                  user_pair.as_ref(),
-                                 service_pair.as_ref())
+                                 service_pair.as_ref(),
+                                 signer.as_ref())
+}
+
+fn sub_file_delete(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+    if let Some(org_name) = org_param_or_env(&m) {
+        sg.organization = Some(org_name);
+    }
+    let signer = match signer_param_or_env(&m) {
+        Some(signer_name) => Some(try!(SigKeyPair::get_latest_pair_for(&signer_name, &cache))),
+        None => None,
+    };
+
+    command::file::delete::start(ui,
+                                 &sg,
+                                 m.value_of("FILE").unwrap(), // Required via clap
+                                 &peers,
+                                 ring_key.as_ref(),
+                                 signer.as_ref())
+}
+
+fn sub_file_sync(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = peers_param(m);
+    let dir = Path::new(m.value_of("DIR").unwrap()); // Required via clap
+    let max_size = try!(max_size_param_or_env(&m));
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())); // Required via clap
+
+    let mut service_pair = None;
+    if let Some(org_name) = org_param_or_env(&m) {
+        sg.organization = Some(org_name.clone());
+        service_pair = Some(try!(BoxKeyPair::get_latest_pair_for(&sg.to_string(), &cache)));
+    }
+
+    let mut user_pair = None;
+    if let Some(user_name) = user_param_or_env(&m) {
+        user_pair = Some(try!(BoxKeyPair::get_latest_pair_for(&user_name, &cache)));
+    }
+
+    let signer = match signer_param_or_env(&m) {
+        Some(signer_name) => Some(try!(SigKeyPair::get_latest_pair_for(&signer_name, &cache))),
+        None => None,
+    };
+
+    command::file::sync::start(ui,
+                               &sg,
+                               dir,
+                               max_size,
+                               &peers,
+                               ring_key.as_ref(),
+                               user_pair.as_ref(),
+                               service_pair.as_ref(),
+                               signer.as_ref())
 }
 
 /// Parse the raw program arguments and split off any arguments that will skip clap's parsing.
@@ -214,6 +472,19 @@ fn raw_parse_args() -> (Vec<OsString>, Vec<OsString>) {
     }
 }
 
+/// The peers from the PEER param, each on the default Butterfly port unless it names its own.
+fn peers_param(m: &ArgMatches) -> Vec<String> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    peers
+}
+
 /// Check to see if the user has passed in an ORG param.
 /// If not, check the HABITAT_ORG env var. If that's
 /// empty too, then error.
@@ -243,3 +514,64 @@ fn user_param_or_env(m: &ArgMatches) -> Option<String> {
         }
     }
 }
+
+/// Check to see if the user has passed in a SIGNER param.
+/// If not, check the HAB_SIGNER env var. If that's
+/// empty too, the update goes out unsigned.
+fn signer_param_or_env(m: &ArgMatches) -> Option<String> {
+    match m.value_of("SIGNER") {
+        Some(s) => Some(s.to_string()),
+        None => {
+            match env::var(HABITAT_SIGNER_ENVVAR) {
+                Ok(v) => Some(v),
+                Err(_) => None,
+            }
+        }
+    }
+}
+
+/// Who to record as having applied a configuration: the user key name if there is one, then
+/// the signing key name, and failing both, the login name.
+fn applied_by_param_or_env(m: &ArgMatches) -> Option<String> {
+    user_param_or_env(m)
+        .or_else(|| signer_param_or_env(m))
+        .or_else(|| env::var("USER").ok())
+}
+
+/// Build a rollout policy from the staged rollout params, or None if none of them were passed, in
+/// which case every Supervisor takes the configuration up at once.
+fn rollout_param(m: &ArgMatches) -> Option<RolloutPolicy> {
+    if !(m.is_present("BATCH_SIZE") || m.is_present("BATCH_PERCENT") ||
+         m.is_present("HEALTH_GATE") || m.is_present("PAUSE")) {
+        return None;
+    }
+    let mut rollout = RolloutPolicy::new();
+    // Safe because clap validates these are numbers
+    match (m.value_of("BATCH_SIZE"), m.value_of("BATCH_PERCENT")) {
+        (Some(size), _) => rollout.set_batch_size(size.parse().unwrap()),
+        (None, Some(percent)) => rollout.set_batch_percent(percent.parse().unwrap()),
+        (None, None) => rollout.set_batch_size(1),
+    }
+    if let Some(pause) = m.value_of("PAUSE") {
+        rollout.set_pause_secs(pause.parse().unwrap());
+    }
+    rollout.set_health_gate(m.is_present("HEALTH_GATE"));
+    Some(rollout)
+}
+
+/// Check to see if the user has passed in a MAX_SIZE param.
+/// If not, check the HAB_MAX_FILE_SIZE env var. If that's
+/// empty too, then use the default.
+fn max_size_param_or_env(m: &ArgMatches) -> Result<u64> {
+    let max_size = match m.value_of("MAX_SIZE") {
+        Some(s) => s.to_string(),
+        None => {
+            match henv::var(HABITAT_MAX_FILE_SIZE_ENVVAR) {
+                Ok(v) => v,
+                Err(_) => return Ok(DEFAULT_MAX_FILE_UPLOAD_SIZE_BYTES),
+            }
+        }
+    };
+    max_size.parse::<u64>()
+        .map_err(|_| Error::CryptoCLI(format!("Invalid maximum file size: {}", max_size)))
+}
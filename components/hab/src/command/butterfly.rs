@@ -74,9 +74,15 @@ mod inner {
             match (args.next().map(|a| a.to_string_lossy()).unwrap_or_default().as_ref(),
                    args.next().map(|a| a.to_string_lossy()).unwrap_or_default().as_ref()) {
                 ("config", "apply") => "config apply",
+                ("config", "show") => "config show",
+                ("config", "history") => "config history",
+                ("config", "rollback") => "config rollback",
                 ("config", _) => "config",
                 ("file", "upload") => "file upload",
+                ("file", "delete") => "file delete",
+                ("file", "sync") => "file sync",
                 ("file", _) => "file",
+                ("ring", "status") => "ring status",
                 (_, _) => unreachable!(),
             };
         try!(ui.warn(format!("Running `{}` on this operating system is not currently \
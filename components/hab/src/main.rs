@@ -470,7 +470,7 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("election", _) | ("file", _) | ("ring", "status") => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
@@ -110,9 +110,10 @@ pub fn package() -> Result<()> {
                     // automatically receive updates for any releases, regardless of version
                     // number, for the started  package.
                     let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
-                    let latest_pkg_data = try!(depot_client.show_package(gconfig().package()));
-                    let latest_ident: PackageIdent = latest_pkg_data.get_ident().clone().into();
-                    if &latest_ident > package.ident() {
+                    let latest_ident = try!(show_latest(&depot_client, gconfig().package()));
+                    if should_install(&latest_ident,
+                                      package.ident(),
+                                      gconfig().channel().is_some()) {
                         outputln!("Downloading latest version from Depot: {}", latest_ident);
                         let new_pkg_data = try!(install::start(&mut ui,
                                                                url,
@@ -149,9 +150,18 @@ pub fn package() -> Result<()> {
                     outputln!("Searching for {} in remote {}",
                               Yellow.bold().paint(gconfig().package().to_string()),
                               url);
+                    // With a channel, find the latest release promoted to it here, and install
+                    // exactly that; otherwise the installer finds the latest uploaded.
+                    let ident = match *gconfig().channel() {
+                        Some(_) => {
+                            let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+                            try!(show_latest(&depot_client, gconfig().package()))
+                        }
+                        None => gconfig().package().clone(),
+                    };
                     try!(install::start(&mut ui,
                                         url,
-                                        &gconfig().package().to_string(),
+                                        &ident.to_string(),
                                         PRODUCT,
                                         VERSION,
                                         Path::new(FS_ROOT_PATH),
@@ -165,6 +175,30 @@ pub fn package() -> Result<()> {
     }
 }
 
+/// The latest release of `ident` in the depot, or in the channel we track if we were given one.
+fn show_latest(depot_client: &Client, ident: &PackageIdent) -> Result<PackageIdent> {
+    let package = match *gconfig().channel() {
+        Some(ref channel) => {
+            outputln!("Tracking channel {}", channel);
+            try!(depot_client.show_package_in_channel(ident, channel))
+        }
+        None => try!(depot_client.show_package(ident)),
+    };
+    Ok(package.get_ident().clone().into())
+}
+
+/// Whether to install the depot's `latest` release in place of the one we have `installed`.
+///
+/// Without a channel we only ever move forward. With one, we run exactly what has been promoted
+/// to the channel, so a newer release installed here that was never promoted is passed over.
+fn should_install(latest: &PackageIdent, installed: &PackageIdent, channel: bool) -> bool {
+    if channel {
+        latest != installed
+    } else {
+        latest > installed
+    }
+}
+
 fn start_package(package: Package) -> Result<()> {
     let run_path = try!(package.run_path());
     debug!("Setting the PATH to {}", run_path);
@@ -174,3 +208,23 @@ fn start_package(package: Package) -> Result<()> {
     try!(manager.add_service(package, *gconfig().topology(), gconfig().update_strategy()));
     manager.run()
 }
+
+#[cfg(test)]
+mod tests {
+    use test_fixtures::{ident, NEW, OLD};
+    use super::should_install;
+
+    #[test]
+    fn without_a_channel_only_a_newer_release_is_installed() {
+        assert!(should_install(&ident(NEW), &ident(OLD), false));
+        assert!(!should_install(&ident(OLD), &ident(NEW), false));
+        assert!(!should_install(&ident(OLD), &ident(OLD), false));
+    }
+
+    #[test]
+    fn with_a_channel_the_promoted_release_is_installed() {
+        assert!(should_install(&ident(NEW), &ident(OLD), true));
+        assert!(should_install(&ident(OLD), &ident(NEW), true));
+        assert!(!should_install(&ident(OLD), &ident(OLD), true));
+    }
+}
@@ -28,14 +28,20 @@ use std::option;
 use std::str::FromStr;
 use std::sync::{Once, ONCE_INIT};
 
+use butterfly::server::quorum::QuorumPolicy;
 use hcore::package::PackageIdent;
 
 use error::{Error, Result, SupError};
 use http_gateway;
+use manager::peer_discovery;
 use manager::service::{Topology, UpdateStrategy};
+use manager::update_schedule::UpdateSchedule;
 
 static LOGKEY: &'static str = "CFG";
 
+/// The longest, in seconds, we allow between checks for updates, and the most jitter we add.
+pub const UPDATE_POLL_MAX_SECS: u64 = 7 * 24 * 60 * 60;
+
 /// The Static Global Configuration.
 ///
 /// This sets up a raw pointer, which we are going to transmute to a Box<Config>
@@ -147,11 +153,17 @@ impl Default for Command {
 pub struct Config {
     pub http_listen_addr: http_gateway::ListenAddr,
     pub gossip_listen: GossipListenAddr,
+    gossip_advertise: Option<SocketAddr>,
+    gossip_max_datagram: Option<usize>,
+    gossip_plaintext_queries: bool,
+    http_admin: bool,
     command: Command,
     package: PackageIdent,
     local_artifact: Option<String>,
     url: String,
+    channel: Option<String>,
     topology: Topology,
+    quorum_policy: QuorumPolicy,
     group: String,
     path: String,
     archive: String,
@@ -164,13 +176,22 @@ pub struct Config {
     infile: Option<String>,
     outfile: Option<String>,
     gossip_peer: Vec<String>,
+    gossip_peer_srv: Vec<String>,
+    gossip_peer_file: Option<String>,
     gossip_permanent: bool,
     update_strategy: UpdateStrategy,
+    update_failure_threshold: Option<usize>,
+    update_rollback_window: Option<u64>,
+    update_bad_release_ttl: Option<u64>,
+    update_schedule: UpdateSchedule,
+    update_poll_interval: Option<u64>,
+    update_poll_jitter: Option<u64>,
     service_group: String,
     file_path: String,
     version_number: u64,
     organization: Option<String>,
     ring: Option<String>,
+    signer_policy: Option<String>,
     config_from: Option<String>,
 }
 
@@ -212,6 +233,79 @@ impl Config {
         self.update_strategy.clone()
     }
 
+    /// Set how many members may fail their health check with a new package before a rolling
+    /// update halts
+    pub fn set_update_failure_threshold(&mut self, threshold: usize) -> &mut Config {
+        self.update_failure_threshold = Some(threshold);
+        self
+    }
+
+    /// Return how many members may fail their health check with a new package before a rolling
+    /// update halts; the first failure halts it unless we were told otherwise. The command line
+    /// refuses 0.
+    pub fn update_failure_threshold(&self) -> usize {
+        self.update_failure_threshold.unwrap_or(1)
+    }
+
+    /// Set how many seconds after an update a failing service is rolled back to the release it
+    /// had before
+    pub fn set_update_rollback_window(&mut self, secs: u64) -> &mut Config {
+        self.update_rollback_window = Some(secs);
+        self
+    }
+
+    /// Return how many seconds after an update a failing service is rolled back to the release it
+    /// had before; 0 turns rollback off.
+    pub fn update_rollback_window(&self) -> u64 {
+        self.update_rollback_window.unwrap_or(300)
+    }
+
+    /// Set how many seconds a release that was rolled back is kept from being updated to
+    pub fn set_update_bad_release_ttl(&mut self, secs: u64) -> &mut Config {
+        self.update_bad_release_ttl = Some(secs);
+        self
+    }
+
+    /// Return how many seconds a release that was rolled back is kept from being updated to; 0
+    /// keeps it out for good.
+    pub fn update_bad_release_ttl(&self) -> u64 {
+        self.update_bad_release_ttl.unwrap_or(7 * 24 * 60 * 60)
+    }
+
+    /// Set the windows and blackouts that limit when updates are applied, to every service we run
+    pub fn set_update_schedule(&mut self, schedule: UpdateSchedule) -> &mut Config {
+        self.update_schedule = schedule;
+        self
+    }
+
+    /// Return the windows and blackouts that limit when updates are applied
+    pub fn update_schedule(&self) -> &UpdateSchedule {
+        &self.update_schedule
+    }
+
+    /// Set how many seconds apart we poll the depot for updates
+    pub fn set_update_poll_interval(&mut self, secs: u64) -> &mut Config {
+        self.update_poll_interval = Some(secs);
+        self
+    }
+
+    /// Return how many seconds apart we poll the depot for updates
+    pub fn update_poll_interval(&self) -> u64 {
+        self.update_poll_interval.unwrap_or(60)
+    }
+
+    /// Set the most seconds we add at random to each poll interval
+    pub fn set_update_poll_jitter(&mut self, secs: u64) -> &mut Config {
+        self.update_poll_jitter = Some(secs);
+        self
+    }
+
+    /// Return the most seconds we add at random to each poll interval, so a large service group
+    /// does not hit the depot all at once.
+    pub fn update_poll_jitter(&self) -> u64 {
+        self.update_poll_jitter.unwrap_or(0)
+    }
+
     /// Set the `Command` we used
     pub fn set_command(&mut self, command: Command) -> &mut Config {
         self.command = command;
@@ -343,6 +437,18 @@ impl Config {
         &self.url
     }
 
+    /// Set the depot channel to install and update from
+    pub fn set_channel(&mut self, channel: String) -> &mut Config {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Return the depot channel to install and update from, if we track one rather than every
+    /// package uploaded to the depot
+    pub fn channel(&self) -> &Option<String> {
+        &self.channel
+    }
+
     /// Set the topology
     pub fn set_topology(&mut self, topology: Topology) -> &mut Config {
         self.topology = topology;
@@ -354,6 +460,29 @@ impl Config {
         &self.topology
     }
 
+    /// Set the smallest service group that can hold an election
+    pub fn set_quorum_min_size(&mut self, min_size: usize) -> &mut Config {
+        self.quorum_policy.min_size = min_size;
+        self
+    }
+
+    /// Set the size the service group is expected to be when checking for quorum
+    pub fn set_quorum_expected_size(&mut self, expected_size: usize) -> &mut Config {
+        self.quorum_policy.expected_size = Some(expected_size);
+        self
+    }
+
+    /// Set whether members that are confirmed dead count toward quorum
+    pub fn set_quorum_count_departed(&mut self, count_departed: bool) -> &mut Config {
+        self.quorum_policy.count_departed = count_departed;
+        self
+    }
+
+    /// Return the quorum policy for our service group
+    pub fn quorum_policy(&self) -> &QuorumPolicy {
+        &self.quorum_policy
+    }
+
     pub fn gossip_listen(&self) -> &GossipListenAddr {
         &self.gossip_listen
     }
@@ -363,6 +492,30 @@ impl Config {
         self
     }
 
+    /// Set the address other members should reach our gossip on, when it isn't the one we listen
+    /// on
+    pub fn set_gossip_advertise(&mut self, addr: Option<SocketAddr>) -> &mut Config {
+        self.gossip_advertise = addr;
+        self
+    }
+
+    /// Return the address other members should reach our gossip on, if it isn't the one we
+    /// listen on
+    pub fn gossip_advertise(&self) -> Option<&SocketAddr> {
+        self.gossip_advertise.as_ref()
+    }
+
+    /// Set the largest SWIM datagram to send, in bytes
+    pub fn set_gossip_max_datagram(&mut self, bytes: Option<usize>) -> &mut Config {
+        self.gossip_max_datagram = bytes;
+        self
+    }
+
+    /// Return the largest SWIM datagram to send, in bytes, if it isn't butterfly's default
+    pub fn gossip_max_datagram(&self) -> Option<usize> {
+        self.gossip_max_datagram
+    }
+
     pub fn http_listen_addr(&self) -> &SocketAddr {
         &self.http_listen_addr
     }
@@ -377,6 +530,28 @@ impl Config {
         self
     }
 
+    /// Set whether to answer ring queries when the ring has no key
+    pub fn set_gossip_plaintext_queries(&mut self, answer: bool) -> &mut Config {
+        self.gossip_plaintext_queries = answer;
+        self
+    }
+
+    /// Return whether to answer ring queries when the ring has no key
+    pub fn gossip_plaintext_queries(&self) -> bool {
+        self.gossip_plaintext_queries
+    }
+
+    /// Set whether the HTTP gateway accepts butterfly admin actions
+    pub fn set_http_admin(&mut self, admin: bool) -> &mut Config {
+        self.http_admin = admin;
+        self
+    }
+
+    /// Return whether the HTTP gateway accepts butterfly admin actions
+    pub fn http_admin(&self) -> bool {
+        self.http_admin
+    }
+
     pub fn gossip_permanent(&self) -> bool {
         self.gossip_permanent
     }
@@ -390,6 +565,28 @@ impl Config {
         &self.gossip_peer
     }
 
+    /// Set the DNS names whose SRV records point to our peers
+    pub fn set_gossip_peer_srv(&mut self, names: Vec<String>) -> &mut Config {
+        self.gossip_peer_srv = names;
+        self
+    }
+
+    /// Return the DNS names whose SRV records point to our peers
+    pub fn gossip_peer_srv(&self) -> &[String] {
+        &self.gossip_peer_srv
+    }
+
+    /// Set the path to a file listing our peers
+    pub fn set_gossip_peer_file(&mut self, path: Option<String>) -> &mut Config {
+        self.gossip_peer_file = path;
+        self
+    }
+
+    /// Return the path to a file listing our peers
+    pub fn gossip_peer_file(&self) -> Option<&String> {
+        self.gossip_peer_file.as_ref()
+    }
+
     /// Set the service group
     pub fn set_service_group(&mut self, sg: String) -> &mut Config {
         self.service_group = sg;
@@ -423,13 +620,8 @@ impl Config {
         &self.version_number
     }
 
-    pub fn set_gossip_peer(&mut self, mut gp: Vec<String>) -> &mut Config {
-        for p in gp.iter_mut() {
-            if p.find(':').is_none() {
-                p.push_str(&format!(":{}", 9638));
-            }
-        }
-        self.gossip_peer = gp;
+    pub fn set_gossip_peer(&mut self, gp: Vec<String>) -> &mut Config {
+        self.gossip_peer = gp.iter().map(|p| peer_discovery::with_default_port(p)).collect();
         self
     }
 
@@ -470,6 +662,17 @@ impl Config {
     pub fn ring(&self) -> &Option<String> {
         &self.ring
     }
+
+    /// Set the path to the signer policy
+    pub fn set_signer_policy(&mut self, signer_policy: String) -> &mut Config {
+        self.signer_policy = Some(signer_policy);
+        self
+    }
+
+    /// Return the path to the signer policy
+    pub fn signer_policy(&self) -> &Option<String> {
+        &self.signer_policy
+    }
 }
 
 #[cfg(test)]
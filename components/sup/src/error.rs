@@ -114,9 +114,13 @@ pub enum Error {
     HookFailed(HookType, i32),
     InvalidBinding(String),
     InvalidKeyParameter(String),
+    InvalidMaxDatagram(String),
     InvalidPidFile,
     InvalidPort(num::ParseIntError),
     InvalidServiceGroupString(String),
+    InvalidSignerPolicy(String),
+    InvalidUpdateOption(String),
+    InvalidUpdateSchedule(String),
     Io(io::Error),
     IPFailed,
     KeyNotFound(String),
@@ -179,10 +183,16 @@ impl fmt::Display for SupError {
             Error::InvalidPort(ref e) => {
                 format!("Invalid port number in package expose metadata: {}", e)
             }
+            Error::InvalidMaxDatagram(ref e) => {
+                format!("Invalid gossip datagram size, must be a number of bytes: {}", e)
+            }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
+            Error::InvalidSignerPolicy(ref e) => format!("Invalid signer policy: {}", e),
+            Error::InvalidUpdateOption(ref e) => format!("Invalid update option: {}", e),
+            Error::InvalidUpdateSchedule(ref e) => format!("Invalid update schedule: {}", e),
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::KeyNotFound(ref e) => format!("Key not found in key cache: {}", e),
@@ -263,10 +273,18 @@ impl error::Error for SupError {
             Error::InvalidBinding(_) => "Invalid binding parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPort(_) => "Invalid port number in package expose metadata",
+            Error::InvalidMaxDatagram(_) => "Invalid gossip datagram size",
             Error::InvalidPidFile => "Invalid child process PID file",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
+            Error::InvalidSignerPolicy(_) => {
+                "Signer policy must map service groups to lists of signing key names"
+            }
+            Error::InvalidUpdateOption(_) => "Invalid update option",
+            Error::InvalidUpdateSchedule(_) => {
+                "Update windows and blackouts must be days and times of day, or dates"
+            }
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::KeyNotFound(_) => "Key not found in key cache",
@@ -16,15 +16,19 @@ use std::io;
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
+use std::result;
 use std::str::FromStr;
 use std::thread::{self, JoinHandle};
 
+use butterfly::rumor::RumorStore;
+use butterfly::rumor::election::{Election, ElectionUpdate};
 use hcore::service::ServiceGroup;
 use iron::prelude::*;
 use iron::status;
 use iron::typemap;
 use persistent;
 use router::Router;
+use serde::{Serialize, Serializer};
 use serde_json;
 
 use config::gconfig;
@@ -97,6 +101,15 @@ impl Server {
     pub fn new(manager_state: manager::State) -> Self {
         let router = router!(
             butterfly: get "/butterfly" => butterfly,
+            butterfly_members: get "/butterfly/members" => butterfly_members,
+            butterfly_rumors: get "/butterfly/rumors" => butterfly_rumors,
+            butterfly_elections: get "/butterfly/elections" => butterfly_elections,
+            butterfly_blacklist: get "/butterfly/blacklist" => butterfly_blacklist,
+            butterfly_metrics: get "/butterfly/metrics" => butterfly_metrics,
+            blacklist_add: post "/butterfly/blacklist/:member_id" => butterfly_blacklist_add,
+            blacklist_remove: delete "/butterfly/blacklist/:member_id" => butterfly_unblacklist,
+            butterfly_pause: post "/butterfly/pause" => butterfly_pause,
+            butterfly_unpause: post "/butterfly/unpause" => butterfly_unpause,
             census: get "/census" => census,
             services: get "/services" => services,
             service_config: get "/services/:svc/:group/config" => config,
@@ -126,6 +139,83 @@ fn butterfly(req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, serde_json::to_string(&state.butterfly).unwrap())))
 }
 
+fn butterfly_members(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    Ok(Response::with((status::Ok,
+                       serde_json::to_string(&state.butterfly.member_list).unwrap())))
+}
+
+fn butterfly_rumors(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    Ok(Response::with((status::Ok,
+                       serde_json::to_string(&state.butterfly.rumor_list).unwrap())))
+}
+
+fn butterfly_elections(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let elections = Elections {
+        election: &state.butterfly.election_store,
+        election_update: &state.butterfly.update_store,
+    };
+    Ok(Response::with((status::Ok, serde_json::to_string(&elections).unwrap())))
+}
+
+fn butterfly_blacklist(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    Ok(Response::with((status::Ok,
+                       serde_json::to_string(&state.butterfly.blacklist()).unwrap())))
+}
+
+fn butterfly_metrics(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    Ok(Response::with((status::Ok,
+                       serde_json::to_string(&state.butterfly.metrics()).unwrap())))
+}
+
+fn butterfly_blacklist_add(req: &mut Request) -> IronResult<Response> {
+    if !gconfig().http_admin() {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let member_id = req.extensions.get::<Router>().unwrap().find("member_id").unwrap();
+    outputln!("Blacklisting member {} at the request of the HTTP gateway",
+              member_id);
+    state.butterfly.add_to_blacklist(String::from(member_id));
+    Ok(Response::with(status::NoContent))
+}
+
+fn butterfly_unblacklist(req: &mut Request) -> IronResult<Response> {
+    if !gconfig().http_admin() {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    let member_id = req.extensions.get::<Router>().unwrap().find("member_id").unwrap();
+    outputln!("Removing member {} from the blacklist at the request of the HTTP gateway",
+              member_id);
+    state.butterfly.remove_from_blacklist(member_id);
+    Ok(Response::with(status::NoContent))
+}
+
+fn butterfly_pause(req: &mut Request) -> IronResult<Response> {
+    if !gconfig().http_admin() {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    outputln!("Pausing butterfly at the request of the HTTP gateway");
+    state.butterfly.pause();
+    Ok(Response::with(status::NoContent))
+}
+
+fn butterfly_unpause(req: &mut Request) -> IronResult<Response> {
+    if !gconfig().http_admin() {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let state = req.get::<persistent::Read<ManagerState>>().unwrap();
+    outputln!("Unpausing butterfly at the request of the HTTP gateway");
+    state.butterfly.unpause();
+    Ok(Response::with(status::NoContent))
+}
+
 fn census(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerState>>().unwrap();
     let data = state.census_list.read().unwrap();
@@ -180,6 +270,23 @@ fn services(req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, serde_json::to_string(&*data).unwrap())))
 }
 
+/// Every election we know about, along with the elections for rolling updates.
+struct Elections<'a> {
+    election: &'a RumorStore<Election>,
+    election_update: &'a RumorStore<ElectionUpdate>,
+}
+
+impl<'a> Serialize for Elections<'a> {
+    fn serialize<S>(&self, serializer: &mut S) -> result::Result<(), S::Error>
+        where S: Serializer
+    {
+        let mut state = try!(serializer.serialize_struct("elections", 2));
+        try!(serializer.serialize_struct_elt(&mut state, "election", self.election));
+        try!(serializer.serialize_struct_elt(&mut state, "election_update", self.election_update));
+        serializer.serialize_struct_end(state)
+    }
+}
+
 impl Into<Response> for health_check::CheckResult {
     fn into(self) -> Response {
         let status: status::Status = self.into();
@@ -197,3 +304,46 @@ impl Into<status::Status> for health_check::CheckResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use butterfly::rumor::RumorStore;
+    use butterfly::rumor::election::{Election, ElectionUpdate};
+    use hcore::service::ServiceGroup;
+    use serde_json::{self, Value};
+
+    use super::Elections;
+
+    #[test]
+    fn elections_serialize_by_service_group_with_the_update_elections() {
+        let election = RumorStore::default();
+        let election_update = RumorStore::default();
+        let sg = ServiceGroup::new("redis", "default", None);
+        election.insert(Election::new("a", sg.clone(), 10));
+        election_update.insert(ElectionUpdate::new("b", sg.clone(), 20));
+        let elections = Elections {
+            election: &election,
+            election_update: &election_update,
+        };
+        let value: Value = serde_json::from_str(&serde_json::to_string(&elections).unwrap())
+            .unwrap();
+        let value = value.as_object().expect("Elections are not an object");
+        for &(kind, member_id) in [("election", "a"), ("election_update", "b")].iter() {
+            let store = value.get(kind).and_then(|v| v.as_object()).unwrap();
+            assert_eq!(store.get("update_counter").and_then(|v| v.as_u64()), Some(1));
+            let rumor = store.get("list")
+                .and_then(|v| v.as_object())
+                .and_then(|list| list.get("redis.default"))
+                .and_then(|v| v.as_object())
+                .and_then(|rumors| rumors.get("election"))
+                .and_then(|v| v.as_object())
+                .unwrap();
+            let election = rumor.get("election").and_then(|v| v.as_object()).unwrap();
+            assert_eq!(election.get("member_id").and_then(|v| v.as_str()), Some(member_id));
+            assert_eq!(election.get("service_group").and_then(|v| v.as_str()),
+                       Some("redis.default"));
+            assert!(election.get("term").and_then(|v| v.as_u64()).is_some());
+            assert!(election.get("votes").and_then(|v| v.as_array()).is_some());
+        }
+    }
+}
@@ -50,6 +50,7 @@ extern crate tempdir;
 extern crate toml;
 extern crate ansi_term;
 extern crate regex;
+extern crate resolve;
 extern crate libc;
 extern crate url;
 extern crate iron;
@@ -59,6 +60,7 @@ extern crate serde;
 extern crate serde_json;
 extern crate time;
 extern crate persistent;
+extern crate rand;
 #[macro_use]
 extern crate lazy_static;
 
@@ -275,6 +277,8 @@ pub mod package;
 pub mod supervisor;
 pub mod templating;
 pub mod util;
+#[cfg(test)]
+mod test_fixtures;
 
 use std::env;
 use std::path::PathBuf;
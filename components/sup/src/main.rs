@@ -23,6 +23,7 @@ extern crate libc;
 #[macro_use]
 extern crate clap;
 
+use std::net::{IpAddr, SocketAddr};
 use std::path::Path;
 use std::process;
 use std::result;
@@ -37,11 +38,12 @@ use hcore::crypto::init as crypto_init;
 use hcore::package::{PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{gcache, gconfig, Command, Config, GossipListenAddr};
+use sup::config::{gcache, gconfig, Command, Config, GossipListenAddr, UPDATE_POLL_MAX_SECS};
 use sup::error::{Error, Result, SupError};
 use sup::command::*;
 use sup::http_gateway;
 use sup::manager::service::{UpdateStrategy, Topology};
+use sup::manager::update_schedule::UpdateSchedule;
 
 /// Our output key
 static LOGKEY: &'static str = "MN";
@@ -67,6 +69,57 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    if sub_args.value_of("update-failure-threshold").is_some() {
+        let threshold = value_t!(sub_args.value_of("update-failure-threshold"), usize)
+            .unwrap_or_else(|e| e.exit());
+        if threshold == 0 {
+            let reason = "--update-failure-threshold must be at least 1";
+            return Err(sup_error!(Error::InvalidUpdateOption(String::from(reason))));
+        }
+        config.set_update_failure_threshold(threshold);
+    }
+    if sub_args.value_of("update-rollback-window").is_some() {
+        let secs = value_t!(sub_args.value_of("update-rollback-window"), u64)
+            .unwrap_or_else(|e| e.exit());
+        config.set_update_rollback_window(secs);
+    }
+    if sub_args.value_of("update-bad-release-ttl").is_some() {
+        let secs = value_t!(sub_args.value_of("update-bad-release-ttl"), u64)
+            .unwrap_or_else(|e| e.exit());
+        config.set_update_bad_release_ttl(secs);
+    }
+    let mut schedule = UpdateSchedule::new();
+    if let Some(windows) = sub_args.values_of("update-window") {
+        for window in windows {
+            try!(schedule.add_window(window));
+        }
+    }
+    if let Some(blackouts) = sub_args.values_of("update-blackout") {
+        for blackout in blackouts {
+            try!(schedule.add_blackout(blackout));
+        }
+    }
+    config.set_update_schedule(schedule);
+    if sub_args.value_of("update-poll-interval").is_some() {
+        let secs = value_t!(sub_args.value_of("update-poll-interval"), u64)
+            .unwrap_or_else(|e| e.exit());
+        if secs == 0 || secs > UPDATE_POLL_MAX_SECS {
+            let reason = format!("--update-poll-interval must be between 1 and {} seconds",
+                                 UPDATE_POLL_MAX_SECS);
+            return Err(sup_error!(Error::InvalidUpdateOption(reason)));
+        }
+        config.set_update_poll_interval(secs);
+    }
+    if sub_args.value_of("update-poll-jitter").is_some() {
+        let secs = value_t!(sub_args.value_of("update-poll-jitter"), u64)
+            .unwrap_or_else(|e| e.exit());
+        if secs > UPDATE_POLL_MAX_SECS {
+            let reason = format!("--update-poll-jitter must be at most {} seconds",
+                                 UPDATE_POLL_MAX_SECS);
+            return Err(sup_error!(Error::InvalidUpdateOption(reason)));
+        }
+        config.set_update_poll_jitter(secs);
+    }
     if let Some(ref archive) = sub_args.value_of("archive") {
         config.set_archive(archive.to_string());
     }
@@ -112,6 +165,19 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
             t => return Err(sup_error!(Error::UnknownTopology(String::from(t)))),
         }
     }
+    if sub_args.value_of("quorum-min-size").is_some() {
+        let size = value_t!(sub_args.value_of("quorum-min-size"), usize)
+            .unwrap_or_else(|e| e.exit());
+        config.set_quorum_min_size(size);
+    }
+    if sub_args.value_of("quorum-expected-size").is_some() {
+        let size = value_t!(sub_args.value_of("quorum-expected-size"), usize)
+            .unwrap_or_else(|e| e.exit());
+        config.set_quorum_expected_size(size);
+    }
+    if sub_args.is_present("quorum-exclude-departed") {
+        config.set_quorum_count_departed(false);
+    }
     if sub_args.value_of("expire-days").is_some() {
         let ed = value_t!(sub_args.value_of("expire-days"), u16).unwrap_or_else(|e| e.exit());
         config.set_expire_days(ed);
@@ -119,6 +185,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = sub_args.value_of("url").unwrap_or(&env_or_default);
     config.set_url(url.to_string());
+    if let Some(channel) = sub_args.value_of("channel") {
+        config.set_channel(channel.to_string());
+    }
     config.set_group(sub_args.value_of("group").unwrap_or(DEFAULT_GROUP).to_string());
     let bindings = match sub_args.values_of("bind") {
         Some(bind) => bind.map(|s| s.to_string()).collect(),
@@ -142,14 +211,43 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(addr_str) = sub_args.value_of("listen-gossip") {
         config.gossip_listen = try!(GossipListenAddr::from_str(addr_str));
     }
+    if let Some(addr_str) = sub_args.value_of("advertise-gossip") {
+        // An address without a port is reached on the port we listen on
+        let advertise = match SocketAddr::from_str(addr_str) {
+            Ok(addr) => addr,
+            Err(_) => {
+                let ip = try!(IpAddr::from_str(addr_str.trim_matches(|c| c == '[' || c == ']'))
+                    .map_err(|_| sup_error!(Error::IPFailed)));
+                SocketAddr::new(ip, config.gossip_listen.port())
+            }
+        };
+        config.set_gossip_advertise(Some(advertise));
+    }
+    if let Some(bytes) = sub_args.value_of("gossip-max-datagram") {
+        let bytes = try!(bytes.parse::<usize>()
+            .map_err(|_| sup_error!(Error::InvalidMaxDatagram(bytes.to_string()))));
+        config.set_gossip_max_datagram(Some(bytes));
+    }
+    if sub_args.is_present("gossip-plaintext-queries") {
+        config.set_gossip_plaintext_queries(true);
+    }
     if let Some(addr_str) = sub_args.value_of("listen-http") {
         config.http_listen_addr = try!(http_gateway::ListenAddr::from_str(addr_str));
     }
+    if sub_args.is_present("http-admin") {
+        config.set_http_admin(true);
+    }
     let gossip_peers = match sub_args.values_of("peer") {
         Some(gp) => gp.map(|s| s.to_string()).collect(),
         None => vec![],
     };
     config.set_gossip_peer(gossip_peers);
+    let gossip_peer_srv = match sub_args.values_of("peer-srv") {
+        Some(names) => names.map(|s| s.to_string()).collect(),
+        None => vec![],
+    };
+    config.set_gossip_peer_srv(gossip_peer_srv);
+    config.set_gossip_peer_file(sub_args.value_of("peer-file").map(|s| s.to_string()));
     if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
@@ -184,6 +282,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(ring) = ring {
         config.set_ring(ring.name_with_rev());
     }
+    if let Some(signer_policy) = sub_args.value_of("signer-policy") {
+        config.set_signer_policy(signer_policy.to_string());
+    }
     if sub_args.is_present("verbose") {
         sup::output::set_verbose(true);
     }
@@ -250,9 +351,52 @@ fn main() {
             .help("A Habitat package identifier (ex: acme/redis) or a filepath to a Habitat \
                    Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)"))
         .arg(arg_url())
+        .arg(Arg::with_name("channel")
+            .long("channel")
+            .value_name("channel")
+            .help("Install and update from the latest release promoted to this depot channel, \
+                   rather than the latest uploaded"))
         .arg(arg_group())
         .arg(arg_org())
         .arg(arg_strategy())
+        .arg(Arg::with_name("update-failure-threshold")
+            .long("update-failure-threshold")
+            .value_name("count")
+            .help("Halt a rolling update once this many members fail their health check with \
+                   the new package; must be at least 1 [default: 1]"))
+        .arg(Arg::with_name("update-rollback-window")
+            .long("update-rollback-window")
+            .value_name("seconds")
+            .help("Roll back to the previous release if the service crash-loops or fails its \
+                   health check this soon after an update; 0 turns rollback off [default: 300]"))
+        .arg(Arg::with_name("update-bad-release-ttl")
+            .long("update-bad-release-ttl")
+            .value_name("seconds")
+            .help("Keep from updating to a release that was rolled back for this long; 0 keeps \
+                   it out for good [default: 604800]"))
+        .arg(Arg::with_name("update-window")
+            .long("update-window")
+            .value_name("window")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Only apply updates inside this window, in UTC, like 'mon-fri 02:00-04:00'; \
+                   may be given more than once"))
+        .arg(Arg::with_name("update-blackout")
+            .long("update-blackout")
+            .value_name("blackout")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Never apply updates during this window, date or range of dates, like \
+                   '2017-12-24..2017-12-26'; may be given more than once"))
+        .arg(Arg::with_name("update-poll-interval")
+            .long("update-poll-interval")
+            .value_name("seconds")
+            .help("How often to check the depot for updates, at most a week [default: 60]"))
+        .arg(Arg::with_name("update-poll-jitter")
+            .long("update-poll-jitter")
+            .value_name("seconds")
+            .help("Wait up to this much longer at random between checks for updates, at most a \
+                   week [default: 0]"))
         .arg(Arg::with_name("config-from")
             .short("C")
             .long("config-from")
@@ -263,6 +407,19 @@ fn main() {
             .long("topology")
             .value_name("topology")
             .help("Service topology"))
+        .arg(Arg::with_name("quorum-min-size")
+            .long("quorum-min-size")
+            .value_name("count")
+            .help("The smallest the service group can be and still elect a leader [default: 3]"))
+        .arg(Arg::with_name("quorum-expected-size")
+            .long("quorum-expected-size")
+            .value_name("count")
+            .help("Measure quorum against this many members, rather than every member we have \
+                   heard of"))
+        .arg(Arg::with_name("quorum-exclude-departed")
+            .long("quorum-exclude-departed")
+            .help("Leave members that are confirmed dead out of the population when measuring \
+                   quorum"))
         .arg(Arg::with_name("bind")
             .long("bind")
             .value_name("bind")
@@ -273,15 +430,44 @@ fn main() {
             .long("ring")
             .value_name("ring")
             .help("Ring key name"))
+        .arg(Arg::with_name("signer-policy")
+            .long("signer-policy")
+            .value_name("path")
+            .help("Only accept service configs and files signed by the keys allowed in this \
+                   policy file"))
         .arg(Arg::with_name("peer")
             .long("peer")
             .value_name("ip:port")
             .multiple(true)
             .help("The listen address of an initial peer"))
+        .arg(Arg::with_name("peer-srv")
+            .long("peer-srv")
+            .value_name("name")
+            .multiple(true)
+            .help("A DNS name whose SRV records point to initial peers"))
+        .arg(Arg::with_name("peer-file")
+            .long("peer-file")
+            .value_name("path")
+            .help("A file listing initial peers, one ip:port per line; re-read when it changes"))
         .arg(Arg::with_name("listen-gossip")
             .long("listen-gossip")
             .value_name("ip:port")
             .help("The listen address [default: 0.0.0.0:9638]"))
+        .arg(Arg::with_name("advertise-gossip")
+            .long("advertise-gossip")
+            .value_name("ip:port")
+            .help("The address other members reach this one's gossip on, when it differs from \
+                   the listen address, such as behind NAT or in a container [default: the \
+                   listen port]"))
+        .arg(Arg::with_name("gossip-max-datagram")
+            .long("gossip-max-datagram")
+            .value_name("bytes")
+            .help("The largest SWIM datagram to send; membership rumors that don't fit wait for \
+                   the next one [default: 1024]"))
+        .arg(Arg::with_name("gossip-plaintext-queries")
+            .long("gossip-plaintext-queries")
+            .help("Answer queries such as `hab ring status` even though the ring has no key; \
+                   configuration bodies are still never sent"))
         .arg(Arg::with_name("listen-peer")
             .long("listen-peer")
             .value_name("ip:port")
@@ -291,6 +477,9 @@ fn main() {
             .long("listen-http")
             .value_name("ip:port")
             .help("The HTTP API listen address [default: 0.0.0.0:9631]"))
+        .arg(Arg::with_name("http-admin")
+            .long("http-admin")
+            .help("Allow blacklisting members and pausing gossip through the HTTP API"))
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
@@ -20,6 +20,7 @@ use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use butterfly::rumor::service::Service as ServiceRumor;
 use butterfly::rumor::election::{Election as ElectionRumor, Election_Status};
+use butterfly::message::swim::Service_Health;
 use butterfly::member::{Member, Health};
 
 pub use types::census::*;
@@ -32,19 +33,22 @@ pub struct CensusUpdate {
     election_counter: usize,
     election_update_counter: usize,
     membership_counter: usize,
+    held_leases: Vec<String>,
 }
 
 impl CensusUpdate {
     pub fn new(service_counter: usize,
                election_counter: usize,
                election_update_counter: usize,
-               membership_counter: usize)
+               membership_counter: usize,
+               held_leases: Vec<String>)
                -> CensusUpdate {
         CensusUpdate {
             service_counter: service_counter,
             election_counter: election_counter,
             election_update_counter: election_update_counter,
             membership_counter: membership_counter,
+            held_leases: held_leases,
         }
     }
 }
@@ -138,6 +142,17 @@ impl CensusEntry {
         self.ip = Some(value);
     }
 
+    pub fn get_ipv6(&self) -> &str {
+        match self.ipv6.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    pub fn set_ipv6(&mut self, value: String) {
+        self.ipv6 = Some(value);
+    }
+
     pub fn get_port(&self) -> &str {
         match self.port.as_ref() {
             Some(v) => &v,
@@ -173,6 +188,14 @@ impl CensusEntry {
         self.leader.unwrap_or(false)
     }
 
+    pub fn set_leader_term(&mut self, value: Option<u64>) {
+        self.leader_term = value;
+    }
+
+    pub fn get_leader_term(&self) -> Option<u64> {
+        self.leader_term
+    }
+
     pub fn set_follower(&mut self, value: bool) {
         self.follower = Some(value);
     }
@@ -285,6 +308,44 @@ impl CensusEntry {
         self.persistent.unwrap_or(false)
     }
 
+    pub fn set_config_incarnation(&mut self, value: Option<u64>) {
+        self.config_incarnation = value;
+    }
+
+    /// The incarnation of the service group's config this member has applied, if it has said.
+    pub fn get_config_incarnation(&self) -> Option<u64> {
+        self.config_incarnation
+    }
+
+    pub fn set_config_healthy(&mut self, value: Option<bool>) {
+        self.config_healthy = value;
+    }
+
+    /// Whether the service passed its health check with the config it has applied, or `None`
+    /// until it has been checked.
+    pub fn get_config_healthy(&self) -> Option<bool> {
+        self.config_healthy
+    }
+
+    pub fn set_package_healthy(&mut self, value: Option<bool>) {
+        self.package_healthy = value;
+    }
+
+    /// Whether the service passed its health check with the package it is running, or `None`
+    /// until it has been checked.
+    pub fn get_package_healthy(&self) -> Option<bool> {
+        self.package_healthy
+    }
+
+    pub fn set_bad_releases(&mut self, value: Vec<PackageIdent>) {
+        self.bad_releases = value;
+    }
+
+    /// Releases this member rolled back from, or learned others did, and will not update to again.
+    pub fn get_bad_releases(&self) -> &Vec<PackageIdent> {
+        &self.bad_releases
+    }
+
     pub fn populate_from_service(&mut self, service_rumor: &ServiceRumor) {
         self.set_member_id(String::from(service_rumor.get_member_id()));
         let sg = match ServiceGroup::from_str(service_rumor.get_service_group()) {
@@ -302,16 +363,31 @@ impl CensusEntry {
             self.set_org(sg.organization.unwrap().clone());
         }
         self.set_ip(String::from(service_rumor.get_ip()));
+        if service_rumor.has_ipv6() {
+            self.set_ipv6(String::from(service_rumor.get_ipv6()));
+        }
         self.set_hostname(String::from(service_rumor.get_hostname()));
         self.set_port(format!("{}", service_rumor.get_port()));
         self.set_exposes(service_rumor.get_exposes().iter().map(|p| format!("{}", p)).collect());
         self.set_package_ident(PackageIdent::from_str(service_rumor.get_package_ident())
             .expect("Received invalid package ident in gossip data. This shouldn't be possible!"));
+        if service_rumor.has_config_incarnation() {
+            self.set_config_incarnation(Some(service_rumor.get_config_incarnation()));
+            self.set_config_healthy(healthy(service_rumor.get_config_health()));
+        }
+        self.set_package_healthy(healthy(service_rumor.get_package_health()));
+        self.set_bad_releases(service_rumor.get_bad_releases()
+            .iter()
+            .filter_map(|ident| PackageIdent::from_str(ident).ok())
+            .collect());
     }
 
     pub fn populate_from_member(&mut self, member: &Member) {
         self.set_member_id(String::from(member.get_id()));
-        self.set_address(String::from(member.get_address()));
+        match member.ip() {
+            Some(ip) => self.set_address(ip.to_string()),
+            None => self.set_address(String::from(member.get_address())),
+        }
         self.set_persistent(true);
     }
 
@@ -335,11 +411,14 @@ impl CensusEntry {
         }
     }
 
-    pub fn populate_from_election(&mut self, election: &ElectionRumor) {
+    /// Fill in the election for our service group. `leader_term` is the term of its leader while
+    /// the leader's lease holds, from our point of view; see `Server::leader_term`.
+    pub fn populate_from_election(&mut self, election: &ElectionRumor, leader_term: Option<u64>) {
         match election.get_status() {
             Election_Status::Running => {
                 self.set_leader(false);
                 self.set_follower(false);
+                self.set_leader_term(None);
                 self.set_election_is_running(true);
                 self.set_election_is_no_quorum(false);
                 self.set_election_is_finished(false);
@@ -347,6 +426,7 @@ impl CensusEntry {
             Election_Status::NoQuorum => {
                 self.set_leader(false);
                 self.set_follower(false);
+                self.set_leader_term(None);
                 self.set_election_is_running(false);
                 self.set_election_is_no_quorum(true);
                 self.set_election_is_finished(false);
@@ -359,6 +439,7 @@ impl CensusEntry {
                     self.set_leader(false);
                     self.set_follower(true);
                 }
+                self.set_leader_term(leader_term);
                 self.set_election_is_running(false);
                 self.set_election_is_no_quorum(false);
                 self.set_election_is_finished(true);
@@ -452,6 +533,13 @@ impl Census {
         self.population.values().find(|&ce| ce.get_leader())
     }
 
+    /// Return the term the current leader was elected in, or None if there is no leader or its
+    /// lease has run out. Terms only ever increase, so this is a fencing token that tells a new
+    /// leader from a stale one.
+    pub fn get_leader_term(&self) -> Option<u64> {
+        self.get_leader().and_then(|ce| ce.get_leader_term())
+    }
+
     /// Return the leader of the currently running update election or None if there is no leader.
     pub fn get_update_leader(&self) -> Option<&CensusEntry> {
         self.population.values().find(|&ce| ce.get_update_leader())
@@ -541,10 +629,10 @@ impl CensusList {
         self.censuses.get(service_group)
     }
 
-    pub fn populate_from_election(&mut self, election: &ElectionRumor) {
+    pub fn populate_from_election(&mut self, election: &ElectionRumor, leader_term: Option<u64>) {
         if let Some(census_entries) = self.censuses.get_mut(election.get_service_group()) {
             for census_entry in census_entries.values_mut() {
-                census_entry.populate_from_election(election);
+                census_entry.populate_from_election(election, leader_term);
             }
         }
     }
@@ -574,11 +662,21 @@ impl CensusList {
     }
 }
 
+/// The health a member gossiped, or `None` while it is still checking.
+fn healthy(health: Service_Health) -> Option<bool> {
+    match health {
+        Service_Health::Pending => None,
+        Service_Health::Healthy => Some(true),
+        Service_Health::Unhealthy => Some(false),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod census_entry {
         use std::str::FromStr;
 
+        use butterfly::message::swim::Service_Health;
         use butterfly::rumor::service::Service;
         use butterfly::member::Member;
         use hcore::package::ident::PackageIdent;
@@ -622,6 +720,64 @@ mod tests {
             assert_eq!(ce.get_package_ident(), &ident);
         }
 
+        #[test]
+        fn populate_from_service_rumor_with_ipv6() {
+            let mut ce = CensusEntry::default();
+            let ident = PackageIdent::from_str("core/overwatch/1.2.3/20161208121212").unwrap();
+            let mut service = Service::new("neurosis",
+                                           &ident,
+                                           "times",
+                                           None,
+                                           "foo.com",
+                                           "162.42.150.33",
+                                           vec![6060]);
+            assert_eq!(ce.get_ipv6(), "");
+            service.set_ipv6(String::from("2001:db8::33"));
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_ip(), "162.42.150.33");
+            assert_eq!(ce.get_ipv6(), "2001:db8::33");
+        }
+
+        #[test]
+        fn populate_from_service_rumor_with_package_health() {
+            let mut ce = CensusEntry::default();
+            let ident = PackageIdent::from_str("core/overwatch/1.2.3/20161208121212").unwrap();
+            let mut service = Service::new("neurosis",
+                                           &ident,
+                                           "times",
+                                           None,
+                                           "foo.com",
+                                           "162.42.150.33",
+                                           vec![6060]);
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_package_healthy(), None);
+            service.set_package_health(Service_Health::Unhealthy);
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_package_healthy(), Some(false));
+            service.set_package_health(Service_Health::Healthy);
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_package_healthy(), Some(true));
+        }
+
+        #[test]
+        fn populate_from_service_rumor_with_bad_releases() {
+            let mut ce = CensusEntry::default();
+            let ident = PackageIdent::from_str("core/overwatch/1.2.3/20161208121212").unwrap();
+            let mut service = Service::new("neurosis",
+                                           &ident,
+                                           "times",
+                                           None,
+                                           "foo.com",
+                                           "162.42.150.33",
+                                           vec![6060]);
+            service.mut_bad_releases().push(String::from("core/overwatch/1.2.4/20161209121212"));
+            service.mut_bad_releases().push(String::from("not an ident"));
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_bad_releases(),
+                       &vec![PackageIdent::from_str("core/overwatch/1.2.4/20161209121212")
+                                 .unwrap()]);
+        }
+
         #[test]
         fn populate_from_member() {
             let mut ce = CensusEntry::default();
@@ -633,5 +789,15 @@ mod tests {
             assert_eq!(ce.get_address(), member.get_address());
             assert_eq!(ce.get_persistent(), member.get_persistent());
         }
+
+        #[test]
+        fn populate_from_member_prefers_the_advertised_address() {
+            let mut ce = CensusEntry::default();
+            let mut member = Member::new();
+            member.set_address(String::from("172.17.0.2"));
+            member.set_advertised_address(String::from("203.0.113.7"));
+            ce.populate_from_member(&member);
+            assert_eq!(ce.get_address(), "203.0.113.7");
+        }
     }
 }
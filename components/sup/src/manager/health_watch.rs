@@ -0,0 +1,116 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::result;
+
+use butterfly::message::swim::Service_Health;
+use hcore::service::ServiceGroup;
+use time::{SteadyTime, Duration as TimeDuration};
+
+use health_check::CheckResult;
+use manager::service::Service;
+
+static LOGKEY: &'static str = "HW";
+/// How long a service has to pass its health check after a change before it is counted as
+/// unhealthy.
+const HEALTH_CHECK_GRACE_SECS: i64 = 60;
+const HEALTH_CHECK_FREQUENCY_MS: i64 = 5_000;
+
+/// The last result of each service's health check, so that everything watching a service shares
+/// one run of its health check hook per interval rather than each running its own.
+pub struct HealthChecks {
+    results: HashMap<ServiceGroup, (SteadyTime, result::Result<CheckResult, String>)>,
+}
+
+impl HealthChecks {
+    pub fn new() -> Self {
+        HealthChecks { results: HashMap::new() }
+    }
+
+    /// The result of the service's health check, running it only if the last result is older
+    /// than the check interval or was taken before `since`.
+    pub fn check(&mut self,
+                 service: &Service,
+                 since: SteadyTime)
+                 -> result::Result<CheckResult, String> {
+        let now = SteadyTime::now();
+        let fresh = match self.results.get(&service.service_group) {
+            Some(&(at, _)) => {
+                at >= since && now - at < TimeDuration::milliseconds(HEALTH_CHECK_FREQUENCY_MS)
+            }
+            None => false,
+        };
+        if !fresh {
+            let result = service.health_check().map_err(|e| e.to_string());
+            self.results.insert(service.service_group.clone(), (now, result));
+        }
+        self.results[&service.service_group].1.clone()
+    }
+}
+
+/// Watches the health of a service after a change to it, such as a new config or package, until
+/// it can tell whether the service took the change well.
+pub struct HealthWatch {
+    /// When the change was made; health check results from before it say nothing about it
+    created: SteadyTime,
+    /// When we first found the service up to check; the grace period runs from here
+    started: Option<SteadyTime>,
+    next_check: SteadyTime,
+}
+
+impl HealthWatch {
+    pub fn new() -> Self {
+        let now = SteadyTime::now();
+        HealthWatch {
+            created: now,
+            started: None,
+            next_check: now,
+        }
+    }
+
+    /// Look at the health check of the service if one is due, sharing the result with anything
+    /// else watching it through `checks`. Returns `Healthy` once the service passes it, or
+    /// `Unhealthy` once the grace period runs out without it doing so; `change` names what we are
+    /// watching, for the log.
+    pub fn check(&mut self,
+                 service: &Service,
+                 checks: &mut HealthChecks,
+                 change: &str)
+                 -> Option<Service_Health> {
+        let now = SteadyTime::now();
+        if !service.initialized || service.is_down() || now < self.next_check {
+            return None;
+        }
+        self.next_check = now + TimeDuration::milliseconds(HEALTH_CHECK_FREQUENCY_MS);
+        if self.started.is_none() {
+            self.started = Some(now);
+        }
+        match checks.check(service, self.created) {
+            Ok(CheckResult::Ok) |
+            Ok(CheckResult::Warning) => Some(Service_Health::Healthy),
+            result => {
+                if now - self.started.unwrap() < TimeDuration::seconds(HEALTH_CHECK_GRACE_SECS) {
+                    None
+                } else {
+                    outputln!(preamble service.service_group_str(),
+                              "{} failed its health check: {:?}",
+                              change,
+                              result);
+                    Some(Service_Health::Unhealthy)
+                }
+            }
+        }
+    }
+}
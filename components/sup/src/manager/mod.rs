@@ -13,23 +13,37 @@
 // limitations under the License.
 
 pub mod census;
+pub mod health_watch;
+pub mod peer_discovery;
+pub mod rollout;
 pub mod service;
 pub mod signals;
 pub mod service_updater;
+pub mod signer_policy;
+pub mod update_schedule;
 
-use std::net::{SocketAddr, ToSocketAddrs};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::result;
 use std::thread;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use butterfly;
 use butterfly::member::Member;
+use butterfly::message::swim::Service_Health;
 use butterfly::trace::Trace;
 use butterfly::rumor::service::Service as ServiceRumor;
+use butterfly::rumor::service_config::{ConfigChange, ServiceConfig};
 use butterfly::server::timing::Timing;
 use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::fs::sup_data_path;
 use time::{SteadyTime, Duration as TimeDuration};
 
+use self::health_watch::HealthChecks;
+use self::peer_discovery::{PeerDiscovery, PeerSource};
+use self::rollout::ConfigRollout;
 use self::service_updater::ServiceUpdater;
 use error::{Error, Result};
 use config::gconfig;
@@ -41,6 +55,10 @@ use http_gateway;
 use util;
 
 static LOGKEY: &'static str = "MR";
+const RING_KEY_CHECK_FREQUENCY_MS: i64 = 5_000;
+/// How long we trust a new ring key revision before we encrypt with it, so that it can be given
+/// to every member of the ring first.
+const RING_KEY_SWITCH_DELAY_MS: i64 = 10 * 60 * 1000;
 
 #[derive(Clone)]
 pub struct State {
@@ -62,14 +80,38 @@ impl State {
 pub struct Manager {
     state: State,
     updater: ServiceUpdater,
+    rollout: ConfigRollout,
+    /// The last health check result of each service, shared by the updater and the rollout
+    health_checks: HealthChecks,
+    next_ring_key_check: SteadyTime,
+    /// When we first trusted each ring key revision we found in the key cache
+    ring_keys_trusted_at: HashMap<String, SteadyTime>,
+    /// The latest incarnation of each service group's config we could not apply, so we only
+    /// say why once
+    held_configs: HashMap<String, u64>,
 }
 
 impl Manager {
     pub fn new() -> Result<Manager> {
         let mut member = Member::new();
         member.set_persistent(gconfig().gossip_permanent());
-        member.set_swim_port(gconfig().gossip_listen().port() as i32);
-        member.set_gossip_port(gconfig().gossip_listen().port() as i32);
+        match gconfig().gossip_advertise() {
+            Some(advertise) => {
+                outputln!("Advertising gossip on {}", advertise);
+                member.set_advertised_address(advertise.ip().to_string());
+                member.set_swim_port(advertise.port() as i32);
+                member.set_gossip_port(advertise.port() as i32);
+            }
+            None => {
+                member.set_swim_port(gconfig().gossip_listen().port() as i32);
+                member.set_gossip_port(gconfig().gossip_listen().port() as i32);
+            }
+        }
+        // Advertise every address we have, so members on either side of a dual-stack ring can
+        // reach us.
+        for ip in util::sys::ip().into_iter().chain(util::sys::ipv6().into_iter()) {
+            member.mut_additional_addresses().push(ip.to_string());
+        }
 
         let ring_key = match gconfig().ring() {
             &Some(ref ring_with_revision) => {
@@ -79,31 +121,39 @@ impl Manager {
             &None => None,
         };
 
+        let signer_policy = match gconfig().signer_policy() {
+            &Some(ref path) => {
+                outputln!("Enforcing signer policy {}", path);
+                Some(try!(signer_policy::from_file(path)))
+            }
+            &None => None,
+        };
+
         let server = try!(butterfly::Server::new(gconfig().gossip_listen(),
                                                  gconfig().gossip_listen(),
                                                  member,
                                                  Trace::default(),
                                                  ring_key,
-                                                 None));
-        outputln!("Butterfly Member ID {}", server.member_id());
-        for peer_addr in gconfig().gossip_peer() {
-            let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
-                Ok(addrs) => addrs.collect(),
-                Err(e) => {
-                    outputln!("Failed to resolve: {}", peer_addr);
-                    return Err(sup_error!(Error::NameLookup(e)));
-                }
-            };
-            let addr: SocketAddr = addrs[0];
-            let mut peer = Member::new();
-            peer.set_address(format!("{}", addr.ip()));
-            peer.set_swim_port(addr.port() as i32);
-            peer.set_gossip_port(addr.port() as i32);
-            server.member_list.add_initial_member(peer);
+                                                 None,
+                                                 Some(sup_data_path(&gconfig().package().name))));
+        if let Some(bytes) = gconfig().gossip_max_datagram() {
+            server.set_max_datagram_bytes(bytes);
         }
+        server.set_plaintext_queries(gconfig().gossip_plaintext_queries());
+        // Before the server starts, so a refused rumor is never taken from the ring, and any
+        // we restored from an earlier run are forgotten
+        if let Some(policy) = signer_policy {
+            server.set_signer_policy(policy);
+        }
+        outputln!("Butterfly Member ID {}", server.member_id());
         Ok(Manager {
             updater: ServiceUpdater::new(server.clone()),
+            rollout: ConfigRollout::new(Some(sup_data_path(&gconfig().package().name))),
+            health_checks: HealthChecks::new(),
             state: State::new(server),
+            next_ring_key_check: SteadyTime::now(),
+            ring_keys_trusted_at: HashMap::new(),
+            held_configs: HashMap::new(),
         })
     }
 
@@ -118,7 +168,11 @@ impl Manager {
                                         topology,
                                         update_strategy));
         let hostname = try!(util::sys::hostname());
-        let ip = try!(util::sys::ip());
+        // Peers reach us on the address we advertise, so the census should too
+        let ip = match gconfig().gossip_advertise() {
+            Some(advertise) => advertise.ip(),
+            None => try!(util::sys::ip()),
+        };
         // TODO: We should do this much earlier, to confirm that the ports we expose are not
         //       bullshit.
         let mut exposes = Vec::new();
@@ -126,15 +180,21 @@ impl Manager {
             let port_num = try!(port.parse::<u32>().map_err(|e| sup_error!(Error::InvalidPort(e))));
             exposes.push(port_num);
         }
-        let service_rumor = ServiceRumor::new(self.state.butterfly.member_id(),
-                                              package.ident(),
-                                              service.service_group.group.clone(),
-                                              service.service_group.organization.clone(),
-                                              hostname,
-                                              ip.to_string(),
-                                              exposes);
+        let mut service_rumor = ServiceRumor::new(self.state.butterfly.member_id(),
+                                                  package.ident(),
+                                                  service.service_group.group.clone(),
+                                                  service.service_group.organization.clone(),
+                                                  hostname,
+                                                  ip.to_string(),
+                                                  exposes);
+        if let Ok(ipv6) = util::sys::ipv6() {
+            service_rumor.set_ipv6(ipv6.to_string());
+        }
         self.state.butterfly.insert_service(service_rumor);
 
+        self.state
+            .butterfly
+            .set_quorum_policy(&service.service_group, gconfig().quorum_policy().clone());
         if topology == Topology::Leader || topology == Topology::Initializer {
             // Note - eventually, we need to deal with suitability here. The original implementation
             // didn't have this working either.
@@ -150,7 +210,9 @@ impl Manager {
         let update = CensusUpdate::new(self.state.butterfly.service_store.get_update_counter(),
                                        self.state.butterfly.election_store.get_update_counter(),
                                        self.state.butterfly.update_store.get_update_counter(),
-                                       self.state.butterfly.member_list.get_update_counter());
+                                       self.state.butterfly.member_list.get_update_counter(),
+                                       // A lease running out changes the leader term
+                                       self.state.butterfly.leader_leases.held());
 
         if &update != last_update {
             let mut cl = CensusList::new();
@@ -165,7 +227,9 @@ impl Manager {
             self.state.butterfly.election_store.with_keys(|(_service_group, rumors)| {
                 // We know you have an election, and this is the only key in the hash
                 let election = rumors.get("election").unwrap();
-                cl.populate_from_election(election);
+                let leader_term =
+                    self.state.butterfly.leader_term(election.get_service_group());
+                cl.populate_from_election(election, leader_term);
             });
             self.state.butterfly.update_store.with_keys(|(_service_group, rumors)| {
                 // We know you have an election, and this is the only key in the hash
@@ -224,7 +288,11 @@ impl Manager {
             self.state.butterfly.member_id().to_string()
         };
         let census_list = self.state.census_list.read().expect("Census list lock is poisoned!");
+        let expired = self.updater.learn_bad_releases(&census_list);
         for service in self.state.services.write().expect("Services lock is poisoned!").iter_mut() {
+            if expired {
+                self.publish_bad_releases(service);
+            }
             if self.updater.check_for_updated_package(service, &census_list) {
                 let mut rumor = {
                     let list = self.state
@@ -240,15 +308,150 @@ impl Manager {
                 };
                 let incarnation = rumor.get_incarnation() + 1;
                 rumor.set_package_ident(service.package.to_string());
+                rumor.set_package_health(Service_Health::Pending);
                 rumor.set_incarnation(incarnation);
                 self.state.butterfly.insert_service(rumor);
             }
         }
     }
 
+    /// Gossip the incarnation of its config a service has applied, and how it did on its health
+    /// check with it, so members in later batches of a staged rollout know when it is their turn.
+    fn publish_config_state(&self, service: &Service, incarnation: u64, health: Service_Health) {
+        self.update_service_rumor(service, |rumor| {
+            rumor.set_config_incarnation(incarnation);
+            rumor.set_config_health(health);
+        });
+    }
+
+    /// Gossip how a rolling service did on its health check with the package it is running, so
+    /// the next member knows when to take its turn.
+    fn publish_package_health(&self, service: &Service, health: Service_Health) {
+        self.update_service_rumor(service, |rumor| rumor.set_package_health(health));
+    }
+
+    /// Gossip that a service has rolled back to the package it is running now, along with every
+    /// release of it we rolled back from.
+    fn publish_rollback(&self, service: &Service) {
+        let ident = service.package.to_string();
+        let bad_releases = self.updater.bad_releases(service);
+        self.update_service_rumor(service, |rumor| {
+            rumor.set_package_ident(ident);
+            rumor.set_package_health(Service_Health::Pending);
+            rumor.mut_bad_releases().clear();
+            for release in bad_releases.into_iter() {
+                rumor.mut_bad_releases().push(release);
+            }
+        });
+    }
+
+    /// Gossip the releases of a service's package we rolled back from, once some have expired.
+    fn publish_bad_releases(&self, service: &Service) {
+        let bad_releases = self.updater.bad_releases(service);
+        self.update_service_rumor(service, |rumor| {
+            rumor.mut_bad_releases().clear();
+            for release in bad_releases.into_iter() {
+                rumor.mut_bad_releases().push(release);
+            }
+        });
+    }
+
+    /// Change our service rumor for a service, and gossip it as a new incarnation.
+    fn update_service_rumor<F>(&self, service: &Service, change: F)
+        where F: FnOnce(&mut ServiceRumor)
+    {
+        let member_id = self.state.butterfly.member_id().to_string();
+        let rumor = {
+            let list = self.state
+                .butterfly
+                .service_store
+                .list
+                .read()
+                .expect("Rumor store lock poisoned");
+            list.get(&service.service_group.as_string())
+                .and_then(|r| r.get(&member_id))
+                .map(|r| r.clone())
+        };
+        if let Some(mut rumor) = rumor {
+            let incarnation = rumor.get_incarnation() + 1;
+            change(&mut rumor);
+            rumor.set_incarnation(incarnation);
+            self.state.butterfly.insert_service(rumor);
+        }
+    }
+
+    /// Bring the ring key revisions butterfly trusts in line with the key cache. Any new revision
+    /// of our ring key is trusted at once, but butterfly only encrypts with the newest once it has
+    /// been trusted for `RING_KEY_SWITCH_DELAY_MS`, by when the rest of the ring should trust it
+    /// too. Any revision that has been removed from the cache is retired.
+    fn check_for_ring_key_changes(&mut self) {
+        if SteadyTime::now() < self.next_ring_key_check {
+            return;
+        }
+        self.next_ring_key_check = SteadyTime::now() +
+                                   TimeDuration::milliseconds(RING_KEY_CHECK_FREQUENCY_MS);
+        let ring_name = match gconfig().ring() {
+            &Some(ref ring_with_revision) => {
+                match parse_name_with_rev(ring_with_revision) {
+                    Ok((name, _)) => name,
+                    Err(e) => {
+                        outputln!("Cannot parse ring key {}: {}", ring_with_revision, e);
+                        return;
+                    }
+                }
+            }
+            &None => return,
+        };
+        let cached_keys = match SymKey::get_pairs_for(&ring_name, &default_cache_key_path(None)) {
+            Ok(keys) => keys,
+            Err(e) => {
+                debug!("Cannot read ring keys for {} from the key cache: {}",
+                       ring_name,
+                       e);
+                return;
+            }
+        };
+        let cached_revisions: Vec<String> = cached_keys.iter().map(|k| k.name_with_rev()).collect();
+        for key in cached_keys.into_iter() {
+            let name_with_rev = key.name_with_rev();
+            if self.state.butterfly.add_ring_key(key) {
+                outputln!("Trusting ring key {}", name_with_rev);
+            }
+            self.ring_keys_trusted_at.entry(name_with_rev).or_insert_with(SteadyTime::now);
+        }
+        let newest = self.state.butterfly.ring_key_revisions().into_iter().next();
+        if let Some(newest) = newest {
+            let due = self.ring_keys_trusted_at
+                .get(&newest)
+                .map_or(false, |trusted_at| {
+                    SteadyTime::now() - *trusted_at >=
+                    TimeDuration::milliseconds(RING_KEY_SWITCH_DELAY_MS)
+                });
+            if due && self.state.butterfly.current_ring_key_revision().as_ref() != Some(&newest) {
+                match self.state.butterfly.use_ring_key(&newest) {
+                    Ok(()) => outputln!("Encrypting with ring key {}", newest),
+                    Err(e) => outputln!("Cannot encrypt with ring key {}: {}", newest, e),
+                }
+            }
+        }
+        for name_with_rev in self.state.butterfly.ring_key_revisions() {
+            if cached_revisions.contains(&name_with_rev) {
+                continue;
+            }
+            match self.state.butterfly.retire_ring_key(&name_with_rev) {
+                Ok(()) => {
+                    self.ring_keys_trusted_at.remove(&name_with_rev);
+                    outputln!("Retired ring key {}", name_with_rev)
+                }
+                Err(e) => debug!("Not retiring ring key {}: {}", name_with_rev, e),
+            }
+        }
+    }
+
     //  * Start butterfly
     //  Loop {
     //    * Check for incoming signals; forward them; shut down if necessary
+    //    * Pick up any ring key revisions added to or removed from the key cache
     //    * Check if each service needs its package updated
     //      * Update the package
     //    * Check if the Census needs building from Butterfly, or the package changed
@@ -265,12 +468,25 @@ impl Manager {
                   gconfig().gossip_listen().to_string());
         try!(self.state.butterfly.start(Timing::default()));
         debug!("butterfly server started");
+        let mut peer_sources: Vec<PeerSource> = gconfig()
+            .gossip_peer()
+            .iter()
+            .map(|peer| PeerSource::Address(peer.clone()))
+            .collect();
+        peer_sources.extend(gconfig()
+            .gossip_peer_srv()
+            .iter()
+            .map(|name| PeerSource::Srv(name.clone())));
+        if let Some(path) = gconfig().gossip_peer_file() {
+            peer_sources.push(PeerSource::File(PathBuf::from(path)));
+        }
+        try!(PeerDiscovery::new(self.state.butterfly.clone(), peer_sources).start());
         outputln!("Starting http-gateway on {}", gconfig().http_listen_addr());
         try!(http_gateway::Server::new(self.state.clone()).start());
         debug!("http-gateway server started");
 
         // Watch for updates
-        let mut last_census_update = CensusUpdate::new(0, 0, 0, 0);
+        let mut last_census_update = CensusUpdate::new(0, 0, 0, 0, Vec::new());
 
         'services: loop {
             let next_check = SteadyTime::now() + TimeDuration::milliseconds(1000);
@@ -288,6 +504,10 @@ impl Manager {
                 return Ok(());
             }
 
+            // Trust new ring key revisions, and retire old ones, so the ring key can be rotated
+            // without restarting every member at once.
+            self.check_for_ring_key_changes();
+
             // Check for updated packages; this updates the Service to point to the new service
             // struct, and then marks it for restarting.
             self.check_for_updated_packages();
@@ -328,13 +548,60 @@ impl Manager {
 
                 // Write out any service configuration we received via butterfly
                 let mut service_config_updated = false;
-                if let Some((incarnation, config)) =
+                if let Some((incarnation, chain)) =
                     self.state
                         .butterfly
                         .service_config_for(&service.service_group_str(),
                                             service.service_config_incarnation) {
-                    service_config_updated = service.write_butterfly_service_config(config);
-                    service.service_config_incarnation = Some(incarnation);
+                    let changes = chain.map_err(|e| format!("{}", e))
+                        .and_then(|chain| service_config_changes(&chain));
+                    match changes {
+                        Err(reason) => {
+                            // Leave the incarnation alone, so a later config that we can apply
+                            // still brings along everything since the one we have.
+                            let sg = service.service_group_str();
+                            if self.held_configs.insert(sg.clone(), incarnation) !=
+                               Some(incarnation) {
+                                outputln!(preamble sg,
+                                          "Not applying service config {} from butterfly: {}",
+                                          incarnation,
+                                          reason);
+                            }
+                        }
+                        Ok(changes) => {
+                            self.held_configs.remove(&service.service_group_str());
+                            let policy = self.state
+                                .butterfly
+                                .service_config_rollout(&service.service_group_str(), incarnation);
+                            let ready = {
+                                let census_list = self.state
+                                    .census_list
+                                    .read()
+                                    .expect("Census list lock is poisoned!");
+                                self.rollout.may_apply(&service.service_group_str(),
+                                                       incarnation,
+                                                       policy.as_ref(),
+                                                       census_list.get(&service.service_group
+                                                           .as_string()))
+                            };
+                            // Until it is our turn, leave the incarnation alone so we look
+                            // again on the next pass.
+                            if ready {
+                                service_config_updated =
+                                    service.update_butterfly_service_config(&changes);
+                                service.service_config_incarnation = Some(incarnation);
+                                let health = if policy.map(|p| p.get_health_gate())
+                                    .unwrap_or(false) {
+                                    self.rollout.applied(service, incarnation);
+                                    Service_Health::Pending
+                                } else {
+                                    self.rollout.forget(service);
+                                    Service_Health::Healthy
+                                };
+                                self.publish_config_state(service, incarnation, health);
+                            }
+                        }
+                    }
                 }
 
                 // Reconfigure if necessary
@@ -351,6 +618,23 @@ impl Manager {
                 // Reap dead children
                 let _ = service.check_process();
 
+                // Roll back an update that has left the service failing, and tell the ring not
+                // to update to it
+                if self.updater.check_for_failed_update(service, &mut self.health_checks) {
+                    self.publish_rollback(service);
+                }
+
+                // Tell the ring how a config applied as part of a staged rollout is doing
+                if let Some((incarnation, health)) =
+                    self.rollout.check_health(service, &mut self.health_checks) {
+                    self.publish_config_state(service, incarnation, health);
+                }
+
+                // Tell the ring how a rolling service is doing with its package
+                if let Some(health) = self.updater.check_health(service, &mut self.health_checks) {
+                    self.publish_package_health(service, health);
+                }
+
                 // Start or restart the service
                 if service.initialized && (service.needs_restart || service.is_down()) {
                     match service.restart(&self.state
@@ -371,6 +655,24 @@ impl Manager {
     }
 }
 
+/// Returns the changes to make to a service group's gossip layer for a chain of service configs,
+/// or the reason we can't: one of them cannot be decrypted. The gossip layer has already refused
+/// any the signer policy does not allow.
+fn service_config_changes(chain: &[ServiceConfig]) -> result::Result<Vec<ConfigChange>, String> {
+    let mut changes = Vec::with_capacity(chain.len());
+    for sc in chain.iter() {
+        match sc.change() {
+            Ok(change) => changes.push(change),
+            Err(e) => {
+                return Err(format!("cannot decrypt incarnation {}: {}",
+                                   sc.get_incarnation(),
+                                   e))
+            }
+        }
+    }
+    Ok(changes)
+}
+
 impl Default for Topology {
     fn default() -> Topology {
         Topology::Standalone
@@ -0,0 +1,472 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Finds the initial peers to join the gossip ring through.
+//!
+//! Peers come from three kinds of source:
+//!
+//! * `--peer host:port`, resolved to every A and AAAA record for the host
+//! * `--peer-srv name`, resolved to the targets of the SRV records for the name
+//! * `--peer-file path`, a file with one `host:port` per line; blank lines and lines starting
+//!   with `#` are ignored
+//!
+//! Names are looked up with the `resolve` crate, which follows `/etc/resolv.conf`. Each source
+//! counts as one initial peer, however many addresses it resolves to, so a dual-stack host does
+//! not raise the number of peers butterfly waits to hear from before it stops pinging them.
+//!
+//! Sources are resolved again every `PEER_REFRESH_MS`, and whenever the peer file changes. Until
+//! at least one peer is found, we retry with a backoff, so a Supervisor that boots before its
+//! peers are in DNS still joins them once they are.
+
+use std::cmp;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use butterfly;
+use butterfly::member::Member;
+use rand::{self, Rng};
+use resolve::{DnsConfig, DnsResolver};
+use resolve::record::Srv;
+use time::{SteadyTime, Duration as TimeDuration};
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "PD";
+const GOSSIP_DEFAULT_PORT: u16 = 9638;
+const PEER_REFRESH_MS: i64 = 30_000;
+const PEER_RETRY_MIN_MS: i64 = 1_000;
+const PEER_FILE_CHECK_MS: u64 = 1_000;
+
+/// Somewhere to find peers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerSource {
+    /// A `host:port`, resolved to all of its addresses.
+    Address(String),
+    /// A DNS name with SRV records.
+    Srv(String),
+    /// A file of `host:port` lines.
+    File(PathBuf),
+}
+
+impl fmt::Display for PeerSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PeerSource::Address(ref host) => write!(f, "--peer {}", host),
+            PeerSource::Srv(ref name) => write!(f, "--peer-srv {}", name),
+            PeerSource::File(ref path) => write!(f, "--peer-file {}", path.display()),
+        }
+    }
+}
+
+impl PeerSource {
+    /// Every address this source currently points to. A target of an SRV record or a line of a
+    /// peer file that fails to resolve is reported and skipped, so one bad entry doesn't hide
+    /// the others.
+    fn resolve(&self, dns: &mut Dns) -> Result<Vec<SocketAddr>> {
+        let mut addrs = Vec::new();
+        match *self {
+            PeerSource::Address(ref host) => {
+                let (host, port) = try!(split_host_port(host));
+                addrs = try!(dns.host(host, port));
+            }
+            PeerSource::Srv(ref name) => {
+                let records = try!(dns.srv(name));
+                for record in order_srv(records, &mut rand::thread_rng()) {
+                    let target = record.target.trim_right_matches('.');
+                    match dns.host(target, record.port) {
+                        Ok(found) => push_new(&mut addrs, found),
+                        Err(e) => {
+                            outputln!("Cannot resolve {}, a target of {}: {}", target, name, e)
+                        }
+                    }
+                }
+            }
+            PeerSource::File(ref path) => {
+                for line in try!(read_peer_file(path)) {
+                    match split_host_port(&line).and_then(|(host, port)| dns.host(host, port)) {
+                        Ok(found) => push_new(&mut addrs, found),
+                        Err(e) => outputln!("Cannot resolve {} from {}: {}", line, self, e),
+                    }
+                }
+            }
+        }
+        Ok(addrs)
+    }
+}
+
+fn push_new(addrs: &mut Vec<SocketAddr>, found: Vec<SocketAddr>) {
+    for addr in found {
+        if !addrs.contains(&addr) {
+            addrs.push(addr);
+        }
+    }
+}
+
+/// Looks up names, creating the resolver the first time one is needed, so sources that are
+/// already IP addresses never read the DNS configuration.
+struct Dns {
+    resolver: Option<DnsResolver>,
+}
+
+impl Dns {
+    fn new() -> Dns {
+        Dns { resolver: None }
+    }
+
+    fn resolver(&mut self) -> Result<&DnsResolver> {
+        if self.resolver.is_none() {
+            let config = try!(DnsConfig::load_default()
+                .map_err(|e| sup_error!(Error::NameLookup(e))));
+            let resolver = try!(DnsResolver::new(config)
+                .map_err(|e| sup_error!(Error::NameLookup(e))));
+            self.resolver = Some(resolver);
+        }
+        match self.resolver {
+            Some(ref resolver) => Ok(resolver),
+            None => unreachable!(),
+        }
+    }
+
+    /// Every A and AAAA record for `host`, at `port`.
+    fn host(&mut self, host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return Ok(vec![SocketAddr::new(ip, port)]);
+        }
+        let resolver = try!(self.resolver());
+        let found: Vec<SocketAddr> = try!(resolver.resolve_host(host)
+                .map_err(|e| sup_error!(Error::NameLookup(e))))
+            .map(|ip| SocketAddr::new(ip, port))
+            .collect();
+        if found.is_empty() {
+            return Err(lookup_error(&format!("{} has no addresses", host)));
+        }
+        Ok(found)
+    }
+
+    fn srv(&mut self, name: &str) -> Result<Vec<Srv>> {
+        let resolver = try!(self.resolver());
+        resolver.resolve_record::<Srv>(name).map_err(|e| sup_error!(Error::NameLookup(e)))
+    }
+}
+
+fn lookup_error(message: &str) -> ::error::SupError {
+    sup_error!(Error::NameLookup(io::Error::new(io::ErrorKind::InvalidInput, message)))
+}
+
+/// Order SRV records the way RFC 2782 asks: by priority, and within a priority by a weighted
+/// random choice, so the targets with the most weight tend to come first.
+fn order_srv<R: Rng>(mut records: Vec<Srv>, rng: &mut R) -> Vec<Srv> {
+    let mut ordered = Vec::with_capacity(records.len());
+    while !records.is_empty() {
+        let priority = records.iter().map(|r| r.priority).min().unwrap_or(0);
+        let (mut group, rest): (Vec<Srv>, Vec<Srv>) =
+            records.into_iter().partition(|r| r.priority == priority);
+        records = rest;
+        // Records with no weight go first, so they are only chosen on a draw of zero
+        group.sort_by_key(|r| r.weight != 0);
+        while !group.is_empty() {
+            let total = group.iter().fold(0u32, |sum, r| sum + r.weight as u32);
+            let draw = rng.gen_range(0, total + 1);
+            let mut running = 0;
+            let index = group.iter()
+                .position(|r| {
+                    running += r.weight as u32;
+                    running >= draw
+                })
+                .unwrap_or(0);
+            ordered.push(group.remove(index));
+        }
+    }
+    ordered
+}
+
+/// Split a `host:port`, where an IPv6 host is in brackets.
+fn split_host_port(host_port: &str) -> Result<(&str, u16)> {
+    let split = if host_port.starts_with('[') {
+        host_port.rfind("]:").map(|i| (&host_port[1..i], &host_port[i + 2..]))
+    } else {
+        host_port.rfind(':').map(|i| (&host_port[..i], &host_port[i + 1..]))
+    };
+    match split.and_then(|(host, port)| port.parse::<u16>().ok().map(|port| (host, port))) {
+        Some(host_port) => Ok(host_port),
+        None => Err(lookup_error(&format!("{} is not a host:port", host_port))),
+    }
+}
+
+/// Append the default gossip port to `host` if it doesn't have one. IPv6 addresses need to be in
+/// brackets to carry a port.
+pub fn with_default_port(host: &str) -> String {
+    let has_port = if host.starts_with('[') {
+        host.contains("]:")
+    } else {
+        host.matches(':').count() == 1
+    };
+    if has_port {
+        host.to_string()
+    } else if host.contains(':') && !host.starts_with('[') {
+        format!("[{}]:{}", host, GOSSIP_DEFAULT_PORT)
+    } else {
+        format!("{}:{}", host, GOSSIP_DEFAULT_PORT)
+    }
+}
+
+fn read_peer_file(path: &Path) -> Result<Vec<String>> {
+    let file = try!(File::open(path));
+    let mut hosts = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = try!(line);
+        if let Some(host) = parse_peer_line(&line) {
+            hosts.push(host);
+        }
+    }
+    Ok(hosts)
+}
+
+fn parse_peer_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        None
+    } else {
+        Some(with_default_port(line))
+    }
+}
+
+/// The peers in a form that does not change with the order the addresses came back in, to tell
+/// whether they have changed.
+fn normalized(peers: &[Vec<SocketAddr>]) -> Vec<Vec<SocketAddr>> {
+    let mut peers: Vec<Vec<SocketAddr>> = peers.iter()
+        .map(|addrs| {
+            let mut addrs = addrs.clone();
+            addrs.sort();
+            addrs
+        })
+        .collect();
+    peers.sort();
+    peers
+}
+
+pub struct PeerDiscovery {
+    server: butterfly::Server,
+    sources: Vec<PeerSource>,
+    /// The addresses of each source that resolved to any
+    peers: Vec<Vec<SocketAddr>>,
+    file_modified: Option<SystemTime>,
+    next_refresh: SteadyTime,
+    retry_ms: i64,
+}
+
+impl PeerDiscovery {
+    pub fn new(server: butterfly::Server, sources: Vec<PeerSource>) -> PeerDiscovery {
+        PeerDiscovery {
+            server: server,
+            sources: sources,
+            peers: Vec::new(),
+            file_modified: None,
+            next_refresh: SteadyTime::now(),
+            retry_ms: PEER_RETRY_MIN_MS,
+        }
+    }
+
+    /// Resolve every source, and hand the peers to butterfly if they changed. Returns the number
+    /// of sources that resolved to any peers.
+    pub fn refresh(&mut self) -> usize {
+        let mut dns = Dns::new();
+        let mut peers = Vec::new();
+        for source in self.sources.iter() {
+            match source.resolve(&mut dns) {
+                Ok(ref addrs) if addrs.is_empty() => outputln!("No peers found from {}", source),
+                Ok(addrs) => peers.push(addrs),
+                Err(e) => outputln!("Cannot find peers from {}: {}", source, e),
+            }
+        }
+        if normalized(&peers) != normalized(&self.peers) {
+            if peers.is_empty() {
+                outputln!("No peers found yet; will keep looking");
+            } else {
+                let mut found: Vec<String> = peers.iter()
+                    .flat_map(|addrs| addrs.iter())
+                    .map(|p| p.to_string())
+                    .collect();
+                found.sort();
+                outputln!("Found {} peers: {}", peers.len(), found.join(", "));
+            }
+            let members = peers.iter()
+                .map(|addrs| {
+                    addrs.iter()
+                        .map(|addr| {
+                            let mut peer = Member::new();
+                            peer.set_address(format!("{}", addr.ip()));
+                            peer.set_swim_port(addr.port() as i32);
+                            peer.set_gossip_port(addr.port() as i32);
+                            peer
+                        })
+                        .collect()
+                })
+                .collect();
+            self.server.member_list.set_initial_peers(members);
+            self.peers = peers;
+        }
+        if self.peers.is_empty() {
+            self.next_refresh = SteadyTime::now() + TimeDuration::milliseconds(self.retry_ms);
+            self.retry_ms = cmp::min(self.retry_ms * 2, PEER_REFRESH_MS);
+        } else {
+            self.next_refresh = SteadyTime::now() + TimeDuration::milliseconds(PEER_REFRESH_MS);
+            self.retry_ms = PEER_RETRY_MIN_MS;
+        }
+        self.peers.len()
+    }
+
+    /// Start a thread that keeps the peers up to date. Does nothing if there are no sources.
+    ///
+    /// # Failures
+    ///
+    /// * A `--peer` that does not resolve at all; SRV records and peer files may fill in later,
+    ///   but a peer given by name is a mistake we should point out straight away
+    pub fn start(mut self) -> Result<()> {
+        if self.sources.is_empty() {
+            return Ok(());
+        }
+        {
+            let mut dns = Dns::new();
+            for source in self.sources.iter() {
+                if let PeerSource::Address(ref host) = *source {
+                    if let Err(e) = source.resolve(&mut dns) {
+                        outputln!("Failed to resolve: {}", host);
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        try!(thread::Builder::new()
+            .name("peer-discovery".to_string())
+            .spawn(move || loop {
+                if self.peer_file_changed() || SteadyTime::now() >= self.next_refresh {
+                    self.refresh();
+                }
+                thread::sleep(Duration::from_millis(PEER_FILE_CHECK_MS));
+            }));
+        Ok(())
+    }
+
+    fn peer_file_changed(&mut self) -> bool {
+        let mut latest = None;
+        for source in self.sources.iter() {
+            if let PeerSource::File(ref path) = *source {
+                if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+                    if latest.map(|l| modified > l).unwrap_or(true) {
+                        latest = Some(modified);
+                    }
+                }
+            }
+        }
+        if latest != self.file_modified {
+            self.file_modified = latest;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use rand::{SeedableRng, XorShiftRng};
+    use resolve::record::Srv;
+
+    use super::{order_srv, parse_peer_line, split_host_port, with_default_port, Dns, PeerSource};
+
+    fn srv(priority: u16, weight: u16, target: &str) -> Srv {
+        Srv {
+            priority: priority,
+            weight: weight,
+            port: 9638,
+            target: String::from(target),
+        }
+    }
+
+    fn targets(records: &[Srv]) -> Vec<&str> {
+        records.iter().map(|r| &r.target[..]).collect()
+    }
+
+    #[test]
+    fn default_port() {
+        assert_eq!(with_default_port("10.0.0.1"), "10.0.0.1:9638");
+        assert_eq!(with_default_port("10.0.0.1:4000"), "10.0.0.1:4000");
+        assert_eq!(with_default_port("peer.example.com"), "peer.example.com:9638");
+        assert_eq!(with_default_port("::1"), "[::1]:9638");
+        assert_eq!(with_default_port("[::1]"), "[::1]:9638");
+        assert_eq!(with_default_port("[::1]:4000"), "[::1]:4000");
+    }
+
+    #[test]
+    fn peer_file_lines() {
+        assert_eq!(parse_peer_line("  10.0.0.1 "), Some(String::from("10.0.0.1:9638")));
+        assert_eq!(parse_peer_line("# the old leader"), None);
+        assert_eq!(parse_peer_line(""), None);
+    }
+
+    #[test]
+    fn host_and_port_split() {
+        assert_eq!(split_host_port("peer.example.com:9638").unwrap(),
+                   ("peer.example.com", 9638));
+        assert_eq!(split_host_port("[fd00::1]:4000").unwrap(), ("fd00::1", 4000));
+        assert!(split_host_port("peer.example.com").is_err());
+        assert!(split_host_port("peer.example.com:http").is_err());
+    }
+
+    #[test]
+    fn ip_addresses_need_no_lookup() {
+        let mut dns = Dns::new();
+        let addrs = PeerSource::Address(String::from("10.0.0.1:9638")).resolve(&mut dns).unwrap();
+        assert_eq!(addrs, vec!["10.0.0.1:9638".parse::<SocketAddr>().unwrap()]);
+        let addrs = PeerSource::Address(String::from("[fd00::1]:4000")).resolve(&mut dns).unwrap();
+        assert_eq!(addrs, vec!["[fd00::1]:4000".parse::<SocketAddr>().unwrap()]);
+        assert!(dns.resolver.is_none());
+    }
+
+    #[test]
+    fn srv_records_are_ordered_by_priority() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let records = vec![srv(20, 5, "c"), srv(10, 5, "a"), srv(30, 0, "d"), srv(10, 5, "b")];
+        let ordered = order_srv(records, &mut rng);
+        assert_eq!(ordered.len(), 4);
+        let mut first_two = targets(&ordered[..2]);
+        first_two.sort();
+        assert_eq!(first_two, vec!["a", "b"]);
+        assert_eq!(targets(&ordered[2..]), vec!["c", "d"]);
+    }
+
+    #[test]
+    fn srv_records_of_a_priority_are_chosen_by_weight() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let mut heavy_first = 0;
+        for _ in 0..1000 {
+            let records = vec![srv(10, 10, "light"), srv(10, 90, "heavy"), srv(10, 0, "none")];
+            let ordered = order_srv(records, &mut rng);
+            if ordered[0].target == "heavy" {
+                heavy_first += 1;
+            }
+        }
+        assert!(heavy_first > 800 && heavy_first < 980,
+                "heavy came first {} times",
+                heavy_first);
+    }
+}
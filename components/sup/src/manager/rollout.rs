@@ -0,0 +1,589 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Staged rollout of service configs.
+//!
+//! A config sent with a rollout policy is not applied by every member at once. The policy carries
+//! the roster of the rollout: the alive members of the service group when it was sent, ordered by
+//! member id, which is split into batches. Every member cuts the same batches from it, whatever
+//! its own census says. A policy sent without a roster, by an older `hab`, has each member fix one
+//! from its own census when it first sees the config. Members that are not on the roster go in
+//! the last batch, and members that leave are no longer waited on. A member applies the config
+//! once every member in the batches before its own has applied it (and, with a health gate,
+//! passed its health check with it), and the pause between batches has gone by. A member that has
+//! not reported applying the config after `MEMBER_TIMEOUT_SECS`, say because it runs a supervisor
+//! too old to gossip it, is no longer waited on either.
+//!
+//! If any member in an earlier batch fails its health check, the rollout halts for every later
+//! batch. The halt is saved to the `halted-rollouts` file in the supervisor's data path, so it
+//! holds across restarts and whatever the census says later, until a newer config comes along.
+
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use butterfly::message::swim::{RolloutPolicy, Service_Health};
+use time::{SteadyTime, Duration as TimeDuration};
+
+use error::Result;
+use manager::census::Census;
+use manager::health_watch::{HealthChecks, HealthWatch};
+use manager::service::Service;
+
+static LOGKEY: &'static str = "RO";
+const HALTED_ROLLOUTS_FILE: &'static str = "halted-rollouts";
+/// How long we wait on a member in an earlier batch to report applying a config, or passing its
+/// health check with it, before we go on without it.
+pub const MEMBER_TIMEOUT_SECS: i64 = 600;
+
+/// Whether this member may apply a config yet.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Turn {
+    /// Every earlier batch has applied the config
+    Apply,
+    /// The members of earlier batches we are waiting on to apply the config, or check their
+    /// health with it
+    Wait(Vec<String>),
+    /// A member in an earlier batch failed its health check with the config
+    Halted(String),
+}
+
+/// The number of members in each batch of a rollout across `members` members.
+pub fn batch_width(policy: &RolloutPolicy, members: usize) -> usize {
+    let width = if policy.get_batch_size() > 0 {
+        policy.get_batch_size() as usize
+    } else if policy.get_batch_percent() > 0 {
+        (members * policy.get_batch_percent() as usize + 99) / 100
+    } else {
+        members
+    };
+    if width == 0 { 1 } else { width }
+}
+
+/// The member ids of a rollout, in the order they are batched: those the policy carries, or for a
+/// policy without any, the alive members of `census` now.
+pub fn roster(policy: &RolloutPolicy, census: &Census) -> Vec<String> {
+    if !policy.get_members().is_empty() {
+        return policy.get_members().to_vec();
+    }
+    census.alive_members_ordered()
+        .iter()
+        .map(|ce| String::from(ce.get_member_id()))
+        .collect()
+}
+
+/// Work out whether it is our turn to apply `incarnation` of a config in `census`, along with the
+/// batch we are in. Batches are cut from `roster`; if we are not on it, we are in the last batch.
+/// Members in `skipped` are not waited on, though a failed health check from one still halts
+/// the rollout.
+pub fn turn(policy: &RolloutPolicy,
+            incarnation: u64,
+            roster: &[String],
+            census: &Census,
+            skipped: &HashSet<String>)
+            -> (Turn, usize) {
+    let me = match census.me() {
+        Some(me) => me.get_member_id(),
+        None => return (Turn::Wait(Vec::new()), 0),
+    };
+    let width = batch_width(policy, roster.len());
+    let index = roster.iter().position(|id| id == me).unwrap_or(roster.len());
+    let batch = index / width;
+    let earlier = &roster[..cmp::min(batch * width, roster.len())];
+
+    let mut waiting = Vec::new();
+    for id in earlier.iter() {
+        // A member that has left the service group cannot apply the config
+        let ce = match census.get(id) {
+            Some(ce) => ce,
+            None => continue,
+        };
+        let reported = match ce.get_config_incarnation() {
+            Some(applied) if applied > incarnation => true,
+            Some(applied) if applied == incarnation => {
+                match ce.get_config_healthy() {
+                    Some(false) => {
+                        return (Turn::Halted(format!("{} failed its health check", id)), batch)
+                    }
+                    Some(true) => true,
+                    None => !policy.get_health_gate(),
+                }
+            }
+            _ => false,
+        };
+        if !reported && ce.get_alive() && !skipped.contains(id) {
+            waiting.push(id.clone());
+        }
+    }
+    if waiting.is_empty() {
+        (Turn::Apply, batch)
+    } else {
+        (Turn::Wait(waiting), batch)
+    }
+}
+
+/// Where a service group's rollout of a config stands on this member.
+struct RolloutState {
+    incarnation: u64,
+    /// The members of the rollout, in batch order
+    roster: Vec<String>,
+    /// When the batches before ours were first seen done
+    ready_since: Option<SteadyTime>,
+    /// When we started waiting on each member we are still waiting on
+    waiting_since: HashMap<String, SteadyTime>,
+    /// Members we gave up waiting on
+    skipped: HashSet<String>,
+}
+
+impl RolloutState {
+    fn new(incarnation: u64, roster: Vec<String>) -> Self {
+        RolloutState {
+            incarnation: incarnation,
+            roster: roster,
+            ready_since: None,
+            waiting_since: HashMap::new(),
+            skipped: HashSet::new(),
+        }
+    }
+}
+
+pub struct ConfigRollout {
+    rollouts: HashMap<String, RolloutState>,
+    /// The incarnation of the config each service group's rollout halted at
+    halted: HashMap<String, u64>,
+    /// Where we save `halted`, if anywhere
+    halted_path: Option<PathBuf>,
+    /// The incarnation of a gated config each service group is checking its health with
+    health: HashMap<String, (u64, HealthWatch)>,
+}
+
+impl ConfigRollout {
+    /// Create a rollout tracker, loading the halted rollouts saved under `data_path`.
+    pub fn new(data_path: Option<PathBuf>) -> Self {
+        let halted_path = data_path.map(|path| path.join(HALTED_ROLLOUTS_FILE));
+        ConfigRollout {
+            rollouts: HashMap::new(),
+            halted: halted_path.as_ref().map(load_halted).unwrap_or(HashMap::new()),
+            halted_path: halted_path,
+            health: HashMap::new(),
+        }
+    }
+
+    /// Returns true if the service group may apply `incarnation` of its config now: at once
+    /// without a rollout policy, and when `ready` says so with one.
+    pub fn may_apply(&mut self,
+                     sg: &str,
+                     incarnation: u64,
+                     policy: Option<&RolloutPolicy>,
+                     census: Option<&Census>)
+                     -> bool {
+        match policy {
+            Some(policy) => self.ready(sg, incarnation, policy, census),
+            None => {
+                self.rollouts.remove(sg);
+                true
+            }
+        }
+    }
+
+    /// Returns true once it is this member's turn to apply `incarnation` of the service group's
+    /// config, and the pause since the batch before ours finished has gone by.
+    pub fn ready(&mut self,
+                 sg: &str,
+                 incarnation: u64,
+                 policy: &RolloutPolicy,
+                 census: Option<&Census>)
+                 -> bool {
+        self.ready_at(sg, incarnation, policy, census, SteadyTime::now())
+    }
+
+    fn ready_at(&mut self,
+                sg: &str,
+                incarnation: u64,
+                policy: &RolloutPolicy,
+                census: Option<&Census>,
+                now: SteadyTime)
+                -> bool {
+        match self.halted.get(sg).cloned() {
+            Some(halted) if halted == incarnation => return false,
+            Some(_) => {
+                self.halted.remove(sg);
+                self.save_halted();
+            }
+            None => {}
+        }
+        let census = match census {
+            Some(census) if census.me().is_some() => census,
+            _ => return false,
+        };
+        let (next, batch) = {
+            let state = self.rollouts
+                .entry(String::from(sg))
+                .or_insert_with(|| RolloutState::new(incarnation, roster(policy, census)));
+            if state.incarnation != incarnation {
+                *state = RolloutState::new(incarnation, roster(policy, census));
+            }
+            let (next, batch) = turn(policy, incarnation, &state.roster, census, &state.skipped);
+            match next {
+                Turn::Apply => {
+                    if state.ready_since.is_none() {
+                        state.ready_since = Some(now);
+                    }
+                }
+                Turn::Wait(ref ids) => {
+                    state.ready_since = None;
+                    let timeout = TimeDuration::seconds(MEMBER_TIMEOUT_SECS);
+                    let waiting_since: HashMap<String, SteadyTime> = ids.iter()
+                        .map(|id| (id.clone(), state.waiting_since.get(id).cloned().unwrap_or(now)))
+                        .collect();
+                    for (id, since) in waiting_since.iter() {
+                        if now - *since >= timeout {
+                            outputln!(preamble sg,
+                                      "Not waiting any longer for {} to apply service config {}",
+                                      id,
+                                      incarnation);
+                            state.skipped.insert(id.clone());
+                        }
+                    }
+                    state.waiting_since = waiting_since;
+                }
+                Turn::Halted(_) => {}
+            }
+            (next, batch)
+        };
+        match next {
+            Turn::Apply => {
+                let pause = TimeDuration::seconds(policy.get_pause_secs() as i64);
+                let since = self.rollouts[sg].ready_since.unwrap();
+                if batch > 0 && now - since < pause {
+                    return false;
+                }
+                outputln!(preamble sg,
+                          "Applying service config {} with rollout batch {}",
+                          incarnation,
+                          batch + 1);
+                self.rollouts.remove(sg);
+                true
+            }
+            Turn::Wait(_) => false,
+            Turn::Halted(reason) => {
+                outputln!(preamble sg,
+                          "Rollout of service config {} has halted: {}",
+                          incarnation,
+                          reason);
+                self.rollouts.remove(sg);
+                self.halted.insert(String::from(sg), incarnation);
+                self.save_halted();
+                false
+            }
+        }
+    }
+
+    fn save_halted(&self) {
+        if let Some(ref path) = self.halted_path {
+            if let Err(e) = save_halted(path, &self.halted) {
+                outputln!("Cannot save the halted service config rollouts: {}", e);
+            }
+        }
+    }
+
+    /// Start checking the health of a service that has applied `incarnation` of its config with a
+    /// health gate.
+    pub fn applied(&mut self, service: &Service, incarnation: u64) {
+        self.health.insert(service.service_group_str(), (incarnation, HealthWatch::new()));
+    }
+
+    /// Forget any health check we were running for the service, say because a config without a
+    /// health gate replaced the one we were checking.
+    pub fn forget(&mut self, service: &Service) {
+        self.health.remove(&service.service_group_str());
+    }
+
+    /// Run the health check of a service that is checking a gated config, if one is due. Returns
+    /// the incarnation and health to gossip once the service passes its health check, or once the
+    /// grace period runs out without it doing so.
+    pub fn check_health(&mut self,
+                        service: &Service,
+                        checks: &mut HealthChecks)
+                        -> Option<(u64, Service_Health)> {
+        let sg = service.service_group_str();
+        let verdict = match self.health.get_mut(&sg) {
+            Some(&mut (incarnation, ref mut watch)) => {
+                watch.check(service, checks, &format!("Service config {}", incarnation))
+                    .map(|health| (incarnation, health))
+            }
+            None => None,
+        };
+        if verdict.is_some() {
+            self.health.remove(&sg);
+        }
+        verdict
+    }
+}
+
+/// Read the halted rollouts we saved before we last stopped, one service group and incarnation
+/// per line.
+fn load_halted(path: &PathBuf) -> HashMap<String, u64> {
+    let mut contents = String::new();
+    if let Ok(mut file) = File::open(path) {
+        if let Err(e) = file.read_to_string(&mut contents) {
+            outputln!("Cannot read the halted service config rollouts: {}", e);
+        }
+    }
+    contents.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next().and_then(|n| n.parse().ok())) {
+                (Some(sg), Some(incarnation)) => Some((String::from(sg), incarnation)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn save_halted(path: &PathBuf, halted: &HashMap<String, u64>) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        try!(fs::create_dir_all(dir));
+    }
+    let mut rollouts: Vec<(&String, &u64)> = halted.iter().collect();
+    rollouts.sort();
+    let mut file = try!(File::create(path));
+    for &(sg, incarnation) in rollouts.iter() {
+        try!(writeln!(file, "{} {}", sg, incarnation));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use butterfly::message::swim::RolloutPolicy;
+    use tempdir::TempDir;
+    use time::{SteadyTime, Duration as TimeDuration};
+
+    use manager::census::{Census, CensusEntry};
+    use super::{batch_width, roster, turn, ConfigRollout, Turn, MEMBER_TIMEOUT_SECS};
+
+    fn census(me: &str, members: &[(&str, Option<u64>, Option<bool>)]) -> Census {
+        let mut census = Census::new(String::from(me));
+        for &(id, incarnation, healthy) in members.iter() {
+            let mut ce = CensusEntry::default();
+            ce.set_member_id(String::from(id));
+            ce.set_alive(true);
+            ce.set_config_incarnation(incarnation);
+            ce.set_config_healthy(healthy);
+            census.insert(String::from(id), ce);
+        }
+        census
+    }
+
+    fn policy(batch_size: u32, batch_percent: u32, health_gate: bool) -> RolloutPolicy {
+        let mut policy = RolloutPolicy::new();
+        policy.set_batch_size(batch_size);
+        policy.set_batch_percent(batch_percent);
+        policy.set_health_gate(health_gate);
+        policy
+    }
+
+    fn turn_now(policy: &RolloutPolicy, incarnation: u64, census: &Census) -> (Turn, usize) {
+        turn(policy, incarnation, &roster(policy, census), census, &HashSet::new())
+    }
+
+    fn waiting(ids: &[&str]) -> Turn {
+        Turn::Wait(ids.iter().map(|id| String::from(*id)).collect())
+    }
+
+    #[test]
+    fn batch_width_from_size_or_percent() {
+        assert_eq!(batch_width(&policy(2, 0, false), 10), 2);
+        assert_eq!(batch_width(&policy(0, 25, false), 10), 3);
+        assert_eq!(batch_width(&policy(0, 1, false), 10), 1);
+        assert_eq!(batch_width(&policy(0, 0, false), 10), 10);
+    }
+
+    #[test]
+    fn first_batch_applies_at_once() {
+        let census = census("a", &[("a", None, None), ("b", None, None), ("c", None, None)]);
+        assert_eq!(turn_now(&policy(1, 0, true), 2, &census), (Turn::Apply, 0));
+    }
+
+    #[test]
+    fn later_batches_wait_for_earlier_ones() {
+        let census = census("c", &[("a", Some(2), None), ("b", Some(1), None), ("c", None, None)]);
+        assert_eq!(turn_now(&policy(1, 0, false), 2, &census), (waiting(&["b"]), 2));
+    }
+
+    #[test]
+    fn health_gate_waits_for_a_passing_check() {
+        let members = [("a", Some(2), None), ("b", None, None)];
+        assert_eq!(turn_now(&policy(1, 0, false), 2, &census("b", &members)),
+                   (Turn::Apply, 1));
+        assert_eq!(turn_now(&policy(1, 0, true), 2, &census("b", &members)),
+                   (waiting(&["a"]), 1));
+        let members = [("a", Some(2), Some(true)), ("b", None, None)];
+        assert_eq!(turn_now(&policy(1, 0, true), 2, &census("b", &members)),
+                   (Turn::Apply, 1));
+    }
+
+    #[test]
+    fn failed_health_check_halts_the_rollout() {
+        let members = [("a", Some(2), Some(false)), ("b", Some(2), Some(true)), ("c", None, None)];
+        match turn_now(&policy(2, 0, true), 2, &census("c", &members)).0 {
+            Turn::Halted(_) => {}
+            other => panic!("Expected the rollout to halt, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn members_of_the_same_batch_do_not_wait_on_each_other() {
+        let members = [("a", None, None), ("b", None, None), ("c", None, None)];
+        assert_eq!(turn_now(&policy(2, 0, true), 2, &census("b", &members)),
+                   (Turn::Apply, 0));
+    }
+
+    #[test]
+    fn members_that_left_or_were_skipped_are_not_waited_on() {
+        let roster = vec![String::from("a"), String::from("b"), String::from("c")];
+        let census = census("c", &[("b", None, None), ("c", None, None)]);
+        assert_eq!(turn(&policy(1, 0, false), 2, &roster, &census, &HashSet::new()),
+                   (waiting(&["b"]), 2));
+        let skipped = vec![String::from("b")].into_iter().collect();
+        assert_eq!(turn(&policy(1, 0, false), 2, &roster, &census, &skipped),
+                   (Turn::Apply, 2));
+    }
+
+    #[test]
+    fn every_member_batches_from_the_roster_the_policy_carries() {
+        let mut carried = policy(1, 0, false);
+        for id in ["a", "b"].iter() {
+            carried.mut_members().push(String::from(*id));
+        }
+        // Only b has heard of a member that sorts first; it still goes in the batch after a
+        let members = [("0", None, None), ("a", None, None), ("b", None, None)];
+        assert_eq!(roster(&carried, &census("b", &members)), roster(&carried, &census("a", &[])));
+        assert_eq!(turn_now(&carried, 2, &census("b", &members)), (waiting(&["a"]), 1));
+        assert_eq!(turn_now(&carried, 2, &census("0", &members)), (waiting(&["a", "b"]), 2));
+        assert_eq!(turn_now(&policy(1, 0, false), 2, &census("b", &members)).1, 2);
+    }
+
+    #[test]
+    fn ready_pauses_between_batches() {
+        let mut rollout = ConfigRollout::new(None);
+        let mut policy = policy(1, 0, false);
+        policy.set_pause_secs(60);
+        let census = census("b", &[("a", Some(2), None), ("b", None, None)]);
+        let now = SteadyTime::now();
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&census), now));
+        assert!(!rollout.ready_at("redis.default",
+                                  2,
+                                  &policy,
+                                  Some(&census),
+                                  now + TimeDuration::seconds(59)));
+        assert!(rollout.ready_at("redis.default",
+                                 2,
+                                 &policy,
+                                 Some(&census),
+                                 now + TimeDuration::seconds(60)));
+    }
+
+    #[test]
+    fn ready_keeps_the_batches_it_started_with() {
+        let mut rollout = ConfigRollout::new(None);
+        let policy = policy(1, 0, false);
+        let now = SteadyTime::now();
+        let started = census("c", &[("a", None, None), ("b", None, None), ("c", None, None)]);
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&started), now));
+        // A member that sorts first joins, and the others apply; we do not wait on the newcomer
+        let joined = census("c",
+                            &[("0", None, None),
+                              ("a", Some(2), None),
+                              ("b", Some(2), None),
+                              ("c", None, None)]);
+        assert!(rollout.ready_at("redis.default", 2, &policy, Some(&joined), now));
+    }
+
+    #[test]
+    fn ready_stops_waiting_on_members_that_never_report() {
+        let mut rollout = ConfigRollout::new(None);
+        let policy = policy(1, 0, false);
+        let census = census("b", &[("a", None, None), ("b", None, None)]);
+        let now = SteadyTime::now();
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&census), now));
+        assert!(!rollout.ready_at("redis.default",
+                                  2,
+                                  &policy,
+                                  Some(&census),
+                                  now + TimeDuration::seconds(MEMBER_TIMEOUT_SECS - 1)));
+        assert!(!rollout.ready_at("redis.default",
+                                  2,
+                                  &policy,
+                                  Some(&census),
+                                  now + TimeDuration::seconds(MEMBER_TIMEOUT_SECS)));
+        assert!(rollout.ready_at("redis.default",
+                                 2,
+                                 &policy,
+                                 Some(&census),
+                                 now + TimeDuration::seconds(MEMBER_TIMEOUT_SECS)));
+    }
+
+    #[test]
+    fn a_halt_holds_until_a_new_incarnation() {
+        let mut rollout = ConfigRollout::new(None);
+        let policy = policy(1, 0, true);
+        let now = SteadyTime::now();
+        let failed = census("b", &[("a", Some(2), Some(false)), ("b", None, None)]);
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&failed), now));
+        // The failed member recovering, or leaving, does not resume the rollout
+        let recovered = census("b", &[("a", Some(2), Some(true)), ("b", None, None)]);
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&recovered), now));
+        let left = census("b", &[("b", None, None)]);
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&left), now));
+        // A new config starts a new rollout
+        let next = census("b", &[("a", Some(3), Some(true)), ("b", None, None)]);
+        assert!(rollout.ready_at("redis.default", 3, &policy, Some(&next), now));
+    }
+
+    #[test]
+    fn a_halt_is_saved_across_restarts() {
+        let dir = TempDir::new("rollout").unwrap();
+        let policy = policy(1, 0, true);
+        let now = SteadyTime::now();
+        let failed = census("b", &[("a", Some(2), Some(false)), ("b", None, None)]);
+        let recovered = census("b", &[("a", Some(2), Some(true)), ("b", None, None)]);
+        {
+            let mut rollout = ConfigRollout::new(Some(dir.path().to_path_buf()));
+            assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&failed), now));
+        }
+        let mut rollout = ConfigRollout::new(Some(dir.path().to_path_buf()));
+        assert!(!rollout.ready_at("redis.default", 2, &policy, Some(&recovered), now));
+        assert!(ConfigRollout::new(None)
+            .ready_at("redis.default", 2, &policy, Some(&recovered), now));
+    }
+
+    #[test]
+    fn may_apply_gates_only_configs_with_a_policy() {
+        let mut rollout = ConfigRollout::new(None);
+        let policy = policy(1, 0, false);
+        let second = census("b", &[("a", None, None), ("b", None, None)]);
+        assert!(rollout.may_apply("redis.default", 2, None, Some(&second)));
+        assert!(rollout.may_apply("redis.default", 2, None, None));
+        assert!(!rollout.may_apply("redis.default", 2, Some(&policy), Some(&second)));
+        assert!(!rollout.may_apply("redis.default", 2, Some(&policy), None));
+        let first = census("a", &[("a", None, None), ("b", None, None)]);
+        assert!(rollout.may_apply("redis.default", 2, Some(&policy), Some(&first)));
+    }
+}
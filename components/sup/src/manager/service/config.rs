@@ -20,6 +20,7 @@ use std::fs::File;
 use std::io::prelude::*;
 
 use ansi_term::Colour::Purple;
+use butterfly::rumor::service_config::ConfigChange;
 use hcore::package::PackageInstall;
 use hcore::crypto;
 use toml;
@@ -272,6 +273,9 @@ fn service_entry(census: &Census) -> toml::Table {
     if let Some(l) = leader {
         result.insert("leader".to_string(), l);
     }
+    if let Some(term) = census.get_leader_term() {
+        result.insert("leader_term".to_string(), toml::Value::Integer(term as i64));
+    }
     result.insert("members".to_string(), toml::Value::Array(members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
     result
@@ -309,6 +313,46 @@ fn toml_merge_recurse(me: &mut toml::Table, other: &toml::Table, depth: u16) ->
     Ok(())
 }
 
+/// Apply `changes`, in order, to the gossip layer of a service's config, `current` being the
+/// `gossip.toml` we have now, and return the new `gossip.toml`.
+pub fn gossip_layer(current: Option<&str>, changes: &[ConfigChange]) -> Result<String> {
+    let mut layer = match current {
+        Some(current) => try!(parse_toml(current)),
+        None => toml::Table::new(),
+    };
+    for change in changes.iter() {
+        match *change {
+            ConfigChange::Replace(ref config) => layer = try!(parse_toml(config)),
+            ConfigChange::Patch(ref patch, ref delete_keys) => {
+                try!(toml_merge(&mut layer, &try!(parse_toml(patch))));
+                for key in delete_keys.iter() {
+                    toml_delete(&mut layer, key);
+                }
+            }
+        }
+    }
+    Ok(toml::encode_str(&toml::Value::Table(layer)))
+}
+
+fn parse_toml(config: &str) -> Result<toml::Table> {
+    let mut toml_parser = toml::Parser::new(config);
+    toml_parser.parse().ok_or(sup_error!(Error::TomlParser(toml_parser.errors)))
+}
+
+// Removes the key at a dotted path, like `http.port`, from `me`, if it is there
+fn toml_delete(me: &mut toml::Table, path: &str) {
+    match path.find('.') {
+        Some(dot) => {
+            if let Some(&mut toml::Value::Table(ref mut inner)) = me.get_mut(&path[..dot]) {
+                toml_delete(inner, &path[dot + 1..]);
+            }
+        }
+        None => {
+            me.remove(path);
+        }
+    }
+}
+
 fn is_toml_value_a_table(key: &str, table: &toml::Table) -> bool {
     match table.get(key) {
         None => return false,
@@ -541,6 +585,8 @@ impl Sys {
                 String::from("127.0.0.1")
             }
         };
+        // Plenty of hosts have no IPv6 route, so there is nothing to fall back to
+        let ipv6 = util::sys::ipv6().ok().map(|ip| ip.to_string());
         let hostname = match util::sys::hostname() {
             Ok(ip) => ip,
             Err(e) => {
@@ -552,6 +598,7 @@ impl Sys {
 
         Sys {
             ip: ip,
+            ipv6: ipv6,
             hostname: hostname,
             sidecar_ip: gconfig().http_listen_addr().ip().to_string(),
             sidecar_port: gconfig().http_listen_addr().port(),
@@ -590,7 +637,8 @@ mod test {
     use package::Package;
     use super::ServiceConfig;
     use VERSION;
-    use super::toml_merge;
+    use super::{gossip_layer, toml_merge};
+    use butterfly::rumor::service_config::ConfigChange;
 
     fn gen_pkg() -> Package {
         let pkg_install = PackageInstall::new_from_parts(
@@ -856,4 +904,42 @@ mod test {
             assert_eq!(version, VERSION);
         }
     }
+
+    #[test]
+    fn gossip_layer_merges_patches_and_deletes_keys() {
+        let current = r#"
+            fruit = "apple"
+
+            [veggies]
+            root = "carrot"
+            leaf = "kale"
+            "#;
+        let changes = vec![ConfigChange::Patch(String::from("veggies.bulb = \"onion\""),
+                                               vec![String::from("veggies.leaf"),
+                                                    String::from("nuts.pecan")])];
+        let layer = gossip_layer(Some(current), &changes).unwrap();
+
+        let expected = toml_from_string(r#"
+            fruit = "apple"
+
+            [veggies]
+            root = "carrot"
+            bulb = "onion"
+            "#);
+        assert_eq!(toml_from_string(&layer), expected);
+    }
+
+    #[test]
+    fn gossip_layer_starts_again_from_a_replacement() {
+        let changes = vec![ConfigChange::Patch(String::from("fruit = \"pear\""), Vec::new()),
+                           ConfigChange::Replace(String::from("veggie = \"carrot\"")),
+                           ConfigChange::Patch(String::from("fruit = \"apple\""), Vec::new())];
+        let layer = gossip_layer(Some("nut = \"pecan\""), &changes).unwrap();
+
+        let expected = toml_from_string(r#"
+            veggie = "carrot"
+            fruit = "apple"
+            "#);
+        assert_eq!(toml_from_string(&layer), expected);
+    }
 }
@@ -21,6 +21,8 @@ use std::fs::File;
 use std::io::prelude::*;
 
 use ansi_term::Colour::{Yellow, Red, Green};
+use butterfly::rumor::service_config::ConfigChange;
+use butterfly::rumor::service_file::is_safe_filename;
 use hcore::service::ServiceGroup;
 use hcore::crypto::hash;
 use hcore::fs;
@@ -144,13 +146,41 @@ impl Service {
         self.supervisor.check_process()
     }
 
+    /// Write a service file from butterfly to the service's files directory, or remove it there
+    /// when `body` is `None`, because the file has been deleted. Returns whether anything on disk
+    /// changed.
     pub fn write_butterfly_service_file(&mut self,
                                         filename: String,
                                         incarnation: u64,
-                                        body: Vec<u8>)
+                                        body: Option<Vec<u8>>)
                                         -> bool {
+        if !is_safe_filename(&filename) {
+            outputln!(preamble self.service_group_str(),
+                "Refusing service file {} from butterfly; its name is not a plain file name",
+                filename);
+            return false;
+        }
         self.current_service_files.insert(filename.clone(), incarnation);
         let on_disk_path = fs::svc_files_path(&self.service_group.service).join(filename);
+        let body = match body {
+            Some(body) => body,
+            None => {
+                if !on_disk_path.exists() {
+                    return false;
+                }
+                if let Err(e) = std::fs::remove_file(&on_disk_path) {
+                    outputln!(preamble self.service_group_str(),
+                        "Service file from butterfly failed to remove {}: {}",
+                        on_disk_path.to_string_lossy(),
+                        Red.bold().paint(format!("{}", e)));
+                    return false;
+                }
+                outputln!(preamble self.service_group_str(),
+                    "Service file removed by butterfly {}",
+                    on_disk_path.to_string_lossy());
+                return true;
+            }
+        };
         let current_checksum = match hash::hash_file(&on_disk_path) {
             Ok(current_checksum) => current_checksum,
             Err(e) => {
@@ -222,6 +252,34 @@ impl Service {
         }
     }
 
+    /// Apply configuration changes from butterfly to `gossip.toml`. A replacement is written as
+    /// it came; patches are merged into what we have.
+    pub fn update_butterfly_service_config(&mut self, changes: &[ConfigChange]) -> bool {
+        if let Some(&ConfigChange::Replace(ref config)) = changes.last() {
+            return self.write_butterfly_service_config(config.clone());
+        }
+        let on_disk_path = fs::svc_path(&self.service_group.service).join("gossip.toml");
+        let mut current = String::new();
+        let read = File::open(&on_disk_path).and_then(|mut file| file.read_to_string(&mut current));
+        if let Err(ref e) = read {
+            debug!("Failed to read {:?}: {}", on_disk_path, e);
+        }
+        let current = if read.is_ok() {
+            Some(current.as_str())
+        } else {
+            None
+        };
+        match config::gossip_layer(current, changes) {
+            Ok(config) => self.write_butterfly_service_config(config),
+            Err(e) => {
+                outputln!(preamble self.service_group_str(),
+                    "Service configuration from butterfly failed to apply: {}",
+                    Red.bold().paint(format!("{}", e)));
+                false
+            }
+        }
+    }
+
     pub fn write_butterfly_service_config(&mut self, config: String) -> bool {
         let on_disk_path = fs::svc_path(&self.service_group.service).join("gossip.toml");
         let current_checksum = match hash::hash_file(&on_disk_path) {
@@ -12,35 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
 use butterfly;
+use butterfly::message::swim::Service_Health;
 use common::ui::UI;
 use depot_client;
 use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
-use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
-use time::{SteadyTime, Duration as TimeDuration};
+use hcore::fs::{sup_data_path, CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
+use rand::{self, Rng};
+use time::{self, SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
 use config::gconfig;
 use error::Result;
-use manager::census::CensusList;
+use manager::census::{Census, CensusEntry, CensusList};
+use manager::health_watch::{HealthChecks, HealthWatch};
 use manager::service::{Service, Topology, UpdateStrategy};
+use manager::update_schedule::UpdateSchedule;
 use package::Package;
 
 static LOGKEY: &'static str = "SU";
-const UPDATE_STRATEGY_FREQUENCY_MS: i64 = 60_000;
+/// How many times a service may go down within the rollback window after an update before we
+/// count it as crash-looping
+const CRASH_LOOP_RESTARTS: u32 = 3;
+const BAD_RELEASES_FILE: &'static str = "bad-releases";
+/// How long a follower waits on the member before it to report its health with a new package
+/// before taking its turn anyway, as supervisors too old to report it never will
+const PEER_HEALTH_TIMEOUT_SECS: i64 = 300;
 
 type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
+type BadReleases = Arc<RwLock<BadReleaseList>>;
 
 enum UpdaterState {
-    AtOnce(Receiver<Package>),
+    /// The worker polling for updates, and the release it counts as ours
+    AtOnce(Receiver<Package>, Arc<RwLock<PackageIdent>>),
     Rolling(RollingState),
 }
 
@@ -59,28 +75,203 @@ enum LeaderState {
 enum FollowerState {
     Waiting,
     Updating(Receiver<Package>),
+    /// Too many members failed their health check with the leader's package; stay on ours until
+    /// the leader moves on to another.
+    Halted(PackageIdent),
+}
+
+/// A service that has just updated, and is rolled back to the release it had before if it fails
+/// before the rollback window closes.
+struct Probation {
+    previous: PackageIdent,
+    until: SteadyTime,
+    watch: HealthWatch,
+    was_up: bool,
+    crashes: u32,
+}
+
+/// How a service on probation is doing.
+#[derive(Debug, PartialEq, Eq)]
+enum ProbationCheck {
+    /// The rollback window has closed
+    Over,
+    /// The service has gone down this many times since the update
+    CrashLooping(u32),
+    /// Still in the rollback window; its health check decides
+    Watching,
+}
+
+impl Probation {
+    fn new(previous: PackageIdent, window_secs: u64, now: SteadyTime) -> Self {
+        Probation {
+            previous: previous,
+            until: now + TimeDuration::seconds(window_secs as i64),
+            watch: HealthWatch::new(),
+            was_up: false,
+            crashes: 0,
+        }
+    }
+
+    /// Note whether the service is `up` now. Going down after having been up counts as a crash;
+    /// a service that has yet to come up since the update has not crashed.
+    fn check(&mut self, up: bool, now: SteadyTime) -> ProbationCheck {
+        if now > self.until {
+            return ProbationCheck::Over;
+        }
+        if self.was_up && !up {
+            self.crashes += 1;
+        }
+        self.was_up = up;
+        if self.crashes >= CRASH_LOOP_RESTARTS {
+            ProbationCheck::CrashLooping(self.crashes)
+        } else {
+            ProbationCheck::Watching
+        }
+    }
+}
+
+/// A release not to update to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BadRelease {
+    /// When we rolled back from it, or heard of it, in seconds since the epoch
+    since: i64,
+    /// True if we rolled back from it ourselves, rather than hearing another member did
+    own: bool,
+}
+
+/// Releases not to update to: the ones we rolled back from, and the ones members running the
+/// same package told us they rolled back from. Both are saved, so they hold across restarts,
+/// and both expire after `ttl` seconds, so a release can be tried again. Only our own are
+/// gossiped, so each member that failed with a release is counted once.
+pub struct BadReleaseList {
+    releases: HashMap<PackageIdent, BadRelease>,
+    ttl: u64,
+    path: Option<PathBuf>,
+}
+
+impl BadReleaseList {
+    /// Load the releases saved at `path` before we last stopped.
+    pub fn load(path: Option<PathBuf>, ttl: u64) -> Self {
+        let releases = path.as_ref().map(load_bad_releases).unwrap_or(HashMap::new());
+        BadReleaseList {
+            releases: releases,
+            ttl: ttl,
+            path: path,
+        }
+    }
+
+    pub fn contains(&self, ident: &PackageIdent) -> bool {
+        self.releases.contains_key(ident)
+    }
+
+    /// Add a release not to update to. Returns true if we did not already have it; rolling back
+    /// from a release ourselves makes it our own even if we heard of it first.
+    pub fn mark(&mut self, ident: PackageIdent, own: bool, now: i64) -> bool {
+        let added = match self.releases.get(&ident) {
+            Some(release) => own && !release.own,
+            None => true,
+        };
+        if added {
+            self.releases.insert(ident,
+                                 BadRelease {
+                                     since: now,
+                                     own: own,
+                                 });
+            self.save();
+        }
+        added
+    }
+
+    /// Drop the releases we have kept out for longer than the ttl, returning them along with
+    /// whether each was our own.
+    pub fn expire(&mut self, now: i64) -> Vec<(PackageIdent, bool)> {
+        if self.ttl == 0 {
+            return Vec::new();
+        }
+        let ttl = self.ttl as i64;
+        let expired: Vec<(PackageIdent, bool)> = self.releases
+            .iter()
+            .filter(|&(_, release)| now - release.since >= ttl)
+            .map(|(ident, release)| (ident.clone(), release.own))
+            .collect();
+        if !expired.is_empty() {
+            for &(ref ident, _) in expired.iter() {
+                self.releases.remove(ident);
+            }
+            self.save();
+        }
+        expired
+    }
+
+    /// The releases of the same package as `ident` that we rolled back from ourselves, to gossip
+    /// to the rest of the ring.
+    pub fn own(&self, ident: &PackageIdent) -> Vec<String> {
+        let mut releases: Vec<String> = self.releases
+            .iter()
+            .filter(|&(bad, release)| {
+                release.own && bad.origin == ident.origin && bad.name == ident.name
+            })
+            .map(|(bad, _)| bad.to_string())
+            .collect();
+        releases.sort();
+        releases
+    }
+
+    fn save(&self) {
+        if let Some(ref path) = self.path {
+            if let Err(e) = save_bad_releases(path, &self.releases) {
+                outputln!("Cannot save the releases not to update to: {}", e);
+            }
+        }
+    }
 }
 
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    /// Health checks of rolling services since they last started or updated, so the next member
+    /// knows when to take its turn
+    health: HashMap<ServiceGroup, HealthWatch>,
+    probation: HashMap<ServiceGroup, Probation>,
+    /// Releases we rolled back from, or heard another member did, which we do not update to
+    bad_releases: BadReleases,
+    failure_threshold: usize,
+    rollback_window: u64,
+    schedule: UpdateSchedule,
+    /// Service groups we last found outside their update window, so we only say so once
+    held: HashSet<ServiceGroup>,
+    /// The member before us each following service group is waiting on to report its health,
+    /// and since when
+    health_waits: HashMap<ServiceGroup, (String, SteadyTime)>,
     butterfly: butterfly::Server,
 }
 
 impl ServiceUpdater {
     pub fn new(butterfly: butterfly::Server) -> Self {
+        let bad_releases = BadReleaseList::load(Some(bad_releases_path()),
+                                                gconfig().update_bad_release_ttl());
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            health: HashMap::new(),
+            probation: HashMap::new(),
+            bad_releases: Arc::new(RwLock::new(bad_releases)),
+            failure_threshold: gconfig().update_failure_threshold(),
+            rollback_window: gconfig().update_rollback_window(),
+            schedule: gconfig().update_schedule().clone(),
+            held: HashSet::new(),
+            health_waits: HashMap::new(),
             butterfly: butterfly,
         }
     }
 
     pub fn add(&mut self, service: &Service) -> bool {
+        let bad_releases = self.bad_releases.clone();
         match service.update_strategy {
             UpdateStrategy::None => false,
             UpdateStrategy::AtOnce => {
                 self.states.entry(service.service_group.clone()).or_insert_with(|| {
-                    let rx = Worker::new(service).start(&service.service_group, None);
-                    UpdaterState::AtOnce(rx)
+                    let worker = Worker::new(service, &bad_releases);
+                    let current = worker.current.clone();
+                    UpdaterState::AtOnce(worker.start(&service.service_group, None), current)
                 });
                 true
             }
@@ -88,17 +279,70 @@ impl ServiceUpdater {
                 self.states
                     .entry(service.service_group.clone())
                     .or_insert(UpdaterState::Rolling(RollingState::AwaitingElection));
+                self.health.insert(service.service_group.clone(), HealthWatch::new());
                 true
             }
         }
     }
 
+    /// Run the health check of a rolling service that has started or updated, if one is due.
+    /// Returns the health to gossip once the service passes it, or once it has had long enough.
+    pub fn check_health(&mut self,
+                        service: &Service,
+                        checks: &mut HealthChecks)
+                        -> Option<Service_Health> {
+        let verdict = match self.health.get_mut(&service.service_group) {
+            Some(watch) => watch.check(service, checks, &format!("Package {}", service.package)),
+            None => None,
+        };
+        if verdict.is_some() {
+            self.health.remove(&service.service_group);
+        }
+        verdict
+    }
+
     pub fn check_for_updated_package(&mut self,
                                      service: &mut Service,
                                      census_list: &CensusList)
                                      -> bool {
+        // A service that never updates has no worker and no window to wait on.
+        if service.update_strategy == UpdateStrategy::None {
+            return false;
+        }
+        // Outside the update window we neither take part in a rolling update nor take a
+        // package from the worker, which waits for us with whatever it has found.
+        if !self.schedule.allows_now() {
+            if self.held.insert(service.service_group.clone()) {
+                outputln!(preamble service.service_group_str(),
+                          "Outside the update window; holding any update until it opens");
+            }
+            return false;
+        }
+        if self.held.remove(&service.service_group) {
+            outputln!(preamble service.service_group_str(), "Update window is open");
+        }
+        let previous = service.package.ident().clone();
+        let updated = self.poll_for_updated_package(service, census_list);
+        if updated && service.package.ident() != &previous {
+            if service.update_strategy == UpdateStrategy::Rolling {
+                self.health.insert(service.service_group.clone(), HealthWatch::new());
+            }
+            if self.rollback_window > 0 {
+                self.probation.insert(service.service_group.clone(),
+                                      Probation::new(previous,
+                                                     self.rollback_window,
+                                                     SteadyTime::now()));
+            }
+        }
+        updated
+    }
+
+    fn poll_for_updated_package(&mut self,
+                                service: &mut Service,
+                                census_list: &CensusList)
+                                -> bool {
         match self.states.get_mut(&service.service_group) {
-            Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
+            Some(&mut UpdaterState::AtOnce(ref mut rx, ref mut current)) => {
                 match rx.try_recv() {
                     Ok(package) => {
                         service.package = package;
@@ -110,7 +354,9 @@ impl ServiceUpdater {
                 }
                 outputln!(preamble service.service_group_str(),
                     "Service Updater worker has died {}", "; restarting...");
-                *rx = Worker::new(service).start(&service.service_group, None);
+                let worker = Worker::new(service, &self.bad_releases);
+                *current = worker.current.clone();
+                *rx = worker.start(&service.service_group, None);
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census) = census_list.get(&service.service_group.as_string()) {
@@ -171,28 +417,44 @@ impl ServiceUpdater {
                             Err(TryRecvError::Disconnected) => {
                                 outputln!(preamble service.service_group_str(),
                                     "Service Updater has died {}", "; restarting...");
-                                *rx = Worker::new(service).start(&service.service_group, None);
+                                *rx = Worker::new(service, &self.bad_releases)
+                                    .start(&service.service_group, None);
                             }
                         }
                     }
                     LeaderState::Waiting => {
-                        match census_list.get(&service.service_group.as_string()) {
-                            Some(census) => {
-                                if census.members_ordered().iter().any(|ce| {
-                                    ce.package_ident.as_ref().unwrap() !=
-                                    census.me().unwrap().package_ident.as_ref().unwrap()
-                                }) {
-                                    debug!("Update leader still waiting for followers...");
-                                    return false;
-                                }
-                                let rx = Worker::new(service).start(&service.service_group, None);
-                                *state = LeaderState::Polling(rx);
-                            }
+                        let census = match census_list.get(&service.service_group.as_string()) {
+                            Some(census) => census,
                             None => {
                                 panic!("Expected census list to have service group '{}'!",
                                        &service.service_group.as_string())
                             }
+                        };
+                        match leader_turn(census, self.failure_threshold) {
+                            LeaderTurn::Unknown => {
+                                debug!("Update leader is not in the census with a package yet");
+                                return false;
+                            }
+                            LeaderTurn::Halt(ident, failed) => {
+                                outputln!(preamble service.service_group_str(),
+                                    "Rolling update to {} halted; {} member(s) failed \
+                                     their health check",
+                                    ident,
+                                    failed);
+                                let rx = Worker::new(service, &self.bad_releases)
+                                    .start(&service.service_group, None);
+                                *state = LeaderState::Polling(rx);
+                                return false;
+                            }
+                            LeaderTurn::AwaitFollowers => {
+                                debug!("Update leader still waiting for followers...");
+                                return false;
+                            }
+                            LeaderTurn::Poll => {}
                         }
+                        let rx = Worker::new(service, &self.bad_releases)
+                            .start(&service.service_group, None);
+                        *state = LeaderState::Polling(rx);
                     }
                 }
                 if service.needs_restart {
@@ -202,35 +464,101 @@ impl ServiceUpdater {
             Some(&mut UpdaterState::Rolling(RollingState::Follower(ref mut state))) => {
                 match *state {
                     FollowerState::Waiting => {
-                        match census_list.get(&service.service_group.as_string()) {
-                            Some(census) => {
-                                match (census.get_update_leader(),
-                                       census.previous_peer(),
-                                       census.me()) {
-                                    (Some(leader), Some(peer), Some(me)) => {
-                                        if leader.package_ident == me.package_ident {
-                                            debug!("We're not in an update");
-                                            return false;
-                                        }
-                                        if leader.package_ident != peer.package_ident {
-                                            debug!("We're in an update but it's not our turn");
-                                            return false;
-                                        }
-                                        debug!("We're in an update and it's our turn");
-                                        let package = leader.package_ident.clone();
-                                        let rx = Worker::new(service)
-                                            .start(&service.service_group, package);
-                                        *state = FollowerState::Updating(rx);
-                                    }
-                                    _ => return false,
-                                }
-                            }
+                        let census = match census_list.get(&service.service_group.as_string()) {
+                            Some(census) => census,
                             None => {
                                 panic!("Expected census list to have service group '{}'!",
                                        &service.service_group.as_string())
                             }
+                        };
+                        let now = SteadyTime::now();
+                        let gave_up_on = self.health_waits
+                            .get(&service.service_group)
+                            .and_then(|&(ref peer, since)| {
+                                if now - since >= TimeDuration::seconds(PEER_HEALTH_TIMEOUT_SECS) {
+                                    Some(peer.clone())
+                                } else {
+                                    None
+                                }
+                            });
+                        let turn = follower_turn(census,
+                                                 &self.bad_releases
+                                                     .read()
+                                                     .expect("Bad releases lock is poisoned"),
+                                                 self.failure_threshold,
+                                                 gave_up_on.as_ref().map(|peer| &peer[..]));
+                        match turn {
+                            FollowerTurn::AwaitHealth(ref peer) => {
+                                let started = self.health_waits
+                                    .get(&service.service_group)
+                                    .map(|&(ref waiting_on, _)| waiting_on == peer)
+                                    .unwrap_or(false);
+                                if !started {
+                                    self.health_waits
+                                        .insert(service.service_group.clone(), (peer.clone(), now));
+                                }
+                            }
+                            _ => {
+                                self.health_waits.remove(&service.service_group);
+                            }
+                        }
+                        match turn {
+                            FollowerTurn::Idle => {
+                                debug!("We're not in an update");
+                                return false;
+                            }
+                            FollowerTurn::NotOurTurn => {
+                                debug!("We're in an update but it's not our turn");
+                                return false;
+                            }
+                            FollowerTurn::Skip(ident) => {
+                                outputln!(preamble service.service_group_str(),
+                                    "Not updating to {}, which was rolled back; staying on {}",
+                                    ident,
+                                    service.package);
+                                *state = FollowerState::Halted(ident);
+                                return false;
+                            }
+                            FollowerTurn::Halt(ident, failed) => {
+                                outputln!(preamble service.service_group_str(),
+                                    "Rolling update to {} halted; {} member(s) failed their \
+                                     health check; staying on {}",
+                                    ident,
+                                    failed,
+                                    service.package);
+                                *state = FollowerState::Halted(ident);
+                                return false;
+                            }
+                            FollowerTurn::AwaitHealth(peer) => {
+                                debug!("We're in an update but {} is still checking its health",
+                                       peer);
+                                return false;
+                            }
+                            FollowerTurn::Update(ident) => {
+                                if let Some(peer) = gave_up_on {
+                                    outputln!(preamble service.service_group_str(),
+                                        "{} has not reported its health with {}; not waiting \
+                                         on it any longer",
+                                        peer,
+                                        ident);
+                                }
+                                debug!("We're in an update and it's our turn");
+                                let rx = Worker::new(service, &self.bad_releases)
+                                    .start(&service.service_group, Some(ident));
+                                *state = FollowerState::Updating(rx);
+                            }
                         }
                     }
+                    FollowerState::Halted(ref ident) => {
+                        let moved_on = census_list.get(&service.service_group.as_string())
+                            .and_then(|census| census.get_update_leader())
+                            .map(|leader| leader.package_ident.as_ref() != Some(ident))
+                            .unwrap_or(false);
+                        if !moved_on {
+                            return false;
+                        }
+                        debug!("The update leader has moved on from {}", ident);
+                    }
                     FollowerState::Updating(ref mut rx) => {
                         match census_list.get(&service.service_group.as_string()) {
                             Some(census) => {
@@ -247,7 +575,7 @@ impl ServiceUpdater {
                                             .unwrap()
                                             .package_ident
                                             .clone();
-                                        *rx = Worker::new(service)
+                                        *rx = Worker::new(service, &self.bad_releases)
                                             .start(&service.service_group, package);
                                     }
                                 }
@@ -259,6 +587,9 @@ impl ServiceUpdater {
                         }
                     }
                 }
+                if let FollowerState::Halted(_) = *state {
+                    *state = FollowerState::Waiting;
+                }
                 if service.needs_restart {
                     *state = FollowerState::Waiting;
                 }
@@ -267,18 +598,304 @@ impl ServiceUpdater {
         }
         service.needs_restart
     }
+
+    /// Watch a service that has updated for the rollback window. If it crash-loops or fails its
+    /// health check before the window closes, roll it back to the release it had before, and
+    /// never update to the failed release again. Returns true if the service was rolled back.
+    pub fn check_for_failed_update(&mut self,
+                                   service: &mut Service,
+                                   checks: &mut HealthChecks)
+                                   -> bool {
+        let failure = match self.probation.get_mut(&service.service_group) {
+            Some(probation) => {
+                let up = service.initialized && !service.is_down();
+                match probation.check(up, SteadyTime::now()) {
+                    ProbationCheck::Over => None,
+                    ProbationCheck::CrashLooping(crashes) => {
+                        Some((probation.previous.clone(),
+                              format!("it went down {} times", crashes)))
+                    }
+                    ProbationCheck::Watching => {
+                        match probation.watch
+                            .check(service, checks, &format!("Package {}", service.package)) {
+                            Some(Service_Health::Unhealthy) => {
+                                Some((probation.previous.clone(),
+                                      String::from("it failed its health check")))
+                            }
+                            _ => return false,
+                        }
+                    }
+                }
+            }
+            None => return false,
+        };
+        // Either the window closed with the service doing fine, or we are about to roll back
+        self.probation.remove(&service.service_group);
+        let (previous, reason) = match failure {
+            Some(failure) => failure,
+            None => return false,
+        };
+
+        let failed = service.package.ident().clone();
+        if self.bad_releases.read().expect("Bad releases lock is poisoned").contains(&previous) {
+            outputln!(preamble service.service_group_str(),
+                "{} failed since the update, as {}, but {} was rolled back from too; staying put",
+                failed,
+                reason,
+                previous);
+            return false;
+        }
+        let package = match Package::load(&previous, None) {
+            Ok(package) => package,
+            Err(e) => {
+                outputln!(preamble service.service_group_str(),
+                    "{} failed since the update, as {}, but {} cannot be loaded to roll back \
+                     to: {}",
+                    failed,
+                    reason,
+                    previous,
+                    e);
+                return false;
+            }
+        };
+        outputln!(preamble service.service_group_str(),
+            "Rolling back from {} to {}, as {} since the update; {} will not be retried",
+            failed,
+            previous,
+            reason,
+            failed);
+        self.bad_releases
+            .write()
+            .expect("Bad releases lock is poisoned")
+            .mark(failed, true, time::get_time().sec);
+        service.package = package;
+        service.needs_restart = true;
+        if service.update_strategy == UpdateStrategy::Rolling {
+            self.health.insert(service.service_group.clone(), HealthWatch::new());
+        }
+        // The worker counts the failed release as ours; move it back too, so once the failed
+        // release is let go it is offered again.
+        if let Some(&mut UpdaterState::AtOnce(_, ref current)) =
+            self.states.get_mut(&service.service_group) {
+            *current.write().expect("Updater current release lock is poisoned") =
+                service.package.ident().clone();
+        }
+        true
+    }
+
+    /// The releases of the service's package we rolled back from ourselves, to gossip to the
+    /// rest of the ring.
+    pub fn bad_releases(&self, service: &Service) -> Vec<String> {
+        self.bad_releases
+            .read()
+            .expect("Bad releases lock is poisoned")
+            .own(service.package.ident())
+    }
+
+    /// Let go of releases we have kept out for long enough, and take up any release a member
+    /// running the same package rolled back from, so we do not update to it either. Returns true
+    /// if any release we rolled back from ourselves was let go, so we stop gossiping it.
+    pub fn learn_bad_releases(&mut self, census_list: &CensusList) -> bool {
+        let now = time::get_time().sec;
+        let mut bad_releases = self.bad_releases.write().expect("Bad releases lock is poisoned");
+        let mut own_expired = false;
+        for (ident, own) in bad_releases.expire(now).into_iter() {
+            outputln!("{} was rolled back long enough ago; it may be updated to again",
+                      ident);
+            own_expired = own_expired || own;
+        }
+        for census in census_list.values() {
+            for ce in census.values() {
+                for ident in ce.get_bad_releases().iter() {
+                    if runs_package_of(ce, ident) && bad_releases.mark(ident.clone(), false, now) {
+                        outputln!("{} rolled back from {}; it will not be updated to",
+                                  ce.get_member_id(),
+                                  ident);
+                    }
+                }
+            }
+        }
+        own_expired
+    }
+}
+
+fn bad_releases_path() -> PathBuf {
+    sup_data_path(&gconfig().package().name).join(BAD_RELEASES_FILE)
+}
+
+/// Returns true if the member runs some release of the same package as `ident`. Only those
+/// members are believed about rolling back from it.
+fn runs_package_of(ce: &CensusEntry, ident: &PackageIdent) -> bool {
+    ce.package_ident
+        .as_ref()
+        .map(|running| running.origin == ident.origin && running.name == ident.name)
+        .unwrap_or(false)
+}
+
+/// Read the releases not to update to that we saved before we last stopped, one per line with
+/// when we marked it and whether we rolled back from it ourselves.
+fn load_bad_releases(path: &PathBuf) -> HashMap<PackageIdent, BadRelease> {
+    let mut contents = String::new();
+    if let Ok(mut file) = File::open(path) {
+        if let Err(e) = file.read_to_string(&mut contents) {
+            outputln!("Cannot read the releases not to update to: {}", e);
+        }
+    }
+    contents.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return None;
+            }
+            match (PackageIdent::from_str(fields[0]), fields[1].parse()) {
+                (Ok(ident), Ok(since)) => {
+                    Some((ident,
+                          BadRelease {
+                              since: since,
+                              own: fields[2] == "own",
+                          }))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn save_bad_releases(path: &PathBuf, releases: &HashMap<PackageIdent, BadRelease>) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        try!(fs::create_dir_all(dir));
+    }
+    let mut lines: Vec<String> = releases.iter()
+        .map(|(ident, release)| {
+            format!("{} {} {}",
+                    ident,
+                    release.since,
+                    if release.own { "own" } else { "learned" })
+        })
+        .collect();
+    lines.sort();
+    let mut file = try!(File::create(path));
+    for line in lines.iter() {
+        try!(writeln!(file, "{}", line));
+    }
+    Ok(())
+}
+
+/// What the update leader does while it waits on its followers.
+#[derive(Debug, PartialEq, Eq)]
+enum LeaderTurn {
+    /// We are not in the census with a package yet
+    Unknown,
+    /// This many members failed their health check with our package; poll for another
+    Halt(PackageIdent, usize),
+    /// Some members are not on our package yet
+    AwaitFollowers,
+    /// Every member is on our package; poll for the next one
+    Poll,
+}
+
+fn leader_turn(census: &Census, threshold: usize) -> LeaderTurn {
+    let ident = match census.me().and_then(|me| me.package_ident.as_ref()) {
+        Some(ident) => ident,
+        None => return LeaderTurn::Unknown,
+    };
+    let failed = failed_members(census, ident);
+    if failed >= threshold {
+        return LeaderTurn::Halt(ident.clone(), failed);
+    }
+    if census.members_ordered().iter().any(|ce| ce.package_ident.as_ref() != Some(ident)) {
+        LeaderTurn::AwaitFollowers
+    } else {
+        LeaderTurn::Poll
+    }
+}
+
+/// What a follower does about the package the update leader is running.
+#[derive(Debug, PartialEq, Eq)]
+enum FollowerTurn {
+    /// There is no update going on, or we already run the leader's package
+    Idle,
+    /// The update has not reached the member before us yet
+    NotOurTurn,
+    /// The leader's package was rolled back from
+    Skip(PackageIdent),
+    /// This many members failed their health check with the leader's package
+    Halt(PackageIdent, usize),
+    /// The member before us is still checking its health with the leader's package
+    AwaitHealth(String),
+    /// Our turn to update to the leader's package
+    Update(PackageIdent),
+}
+
+/// Work out what a follower does next in `census`. `gave_up_on` names a member before us we
+/// have waited on long enough to report its health, which we go ahead without.
+fn follower_turn(census: &Census,
+                 bad_releases: &BadReleaseList,
+                 threshold: usize,
+                 gave_up_on: Option<&str>)
+                 -> FollowerTurn {
+    let (leader, peer, me) = match (census.get_update_leader(),
+                                    census.previous_peer(),
+                                    census.me()) {
+        (Some(leader), Some(peer), Some(me)) => (leader, peer, me),
+        _ => return FollowerTurn::Idle,
+    };
+    let ident = match leader.package_ident {
+        Some(ref ident) if leader.package_ident != me.package_ident => ident.clone(),
+        _ => return FollowerTurn::Idle,
+    };
+    if peer.package_ident.as_ref() != Some(&ident) {
+        return FollowerTurn::NotOurTurn;
+    }
+    if bad_releases.contains(&ident) {
+        return FollowerTurn::Skip(ident);
+    }
+    let failed = failed_members(census, &ident);
+    if failed >= threshold {
+        return FollowerTurn::Halt(ident, failed);
+    }
+    if peer.get_package_healthy().is_none() && gave_up_on != Some(peer.get_member_id()) {
+        return FollowerTurn::AwaitHealth(String::from(peer.get_member_id()));
+    }
+    FollowerTurn::Update(ident)
+}
+
+/// The number of alive members that failed their health check with `ident`, whether they are
+/// still running it or have since rolled back from it. Members gossip only the releases they
+/// rolled back from themselves, so each member that ran `ident` counts once.
+fn failed_members(census: &Census, ident: &PackageIdent) -> usize {
+    census.alive_members()
+        .iter()
+        .filter(|ce| {
+            (ce.package_ident.as_ref() == Some(ident) && ce.get_package_healthy() == Some(false)) ||
+            ce.get_bad_releases().contains(ident)
+        })
+        .count()
 }
 
 struct Worker {
-    current: PackageIdent,
+    /// The release we run, shared with the updater so a rollback can move it back
+    current: Arc<RwLock<PackageIdent>>,
+    /// The package we were started with, which may leave out the version or release; with a
+    /// channel, we poll for the latest release of it promoted there.
+    wanted: PackageIdent,
+    channel: Option<String>,
+    bad_releases: BadReleases,
+    poll_interval: u64,
+    poll_jitter: u64,
     depot: depot_client::Client,
     ui: UI,
 }
 
 impl Worker {
-    pub fn new(service: &Service) -> Self {
+    pub fn new(service: &Service, bad_releases: &BadReleases) -> Self {
         Worker {
-            current: service.package.ident().clone(),
+            current: Arc::new(RwLock::new(service.package.ident().clone())),
+            wanted: gconfig().package().clone(),
+            channel: gconfig().channel().clone(),
+            bad_releases: bad_releases.clone(),
+            poll_interval: gconfig().update_poll_interval(),
+            poll_jitter: gconfig().update_poll_jitter(),
             depot: depot_client::Client::new(gconfig().url(), PRODUCT, VERSION, None).unwrap(),
             ui: UI::default(),
         }
@@ -302,13 +919,12 @@ impl Worker {
     }
 
     fn run_once(&mut self, sender: SyncSender<Package>, ident: PackageIdent) {
-        outputln!("Updating from {} to {}", self.current, ident);
+        outputln!("Updating from {} to {}", self.current(), ident);
         loop {
-            let next_check = SteadyTime::now() +
-                             TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
+            let next_check = SteadyTime::now() + poll_delay(self.poll_interval, self.poll_jitter);
             match self.install(&ident, true) {
                 Ok(package) => {
-                    self.current = package.ident().clone();
+                    self.set_current(package.ident().clone());
                     sender.send(package).expect("Main thread has gone away!");
                     break;
                 }
@@ -323,16 +939,28 @@ impl Worker {
 
     fn run_poll(&mut self, sender: SyncSender<Package>) {
         loop {
-            let next_check = SteadyTime::now() +
-                             TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
-            match self.depot.show_package(&self.current) {
+            let next_check = SteadyTime::now() + poll_delay(self.poll_interval, self.poll_jitter);
+            let current = self.current();
+            let remote = match self.channel {
+                Some(ref channel) => self.depot.show_package_in_channel(&self.wanted, channel),
+                None => self.depot.show_package(&current),
+            };
+            match remote {
                 Ok(remote) => {
                     let latest: PackageIdent = remote.get_ident().clone().into();
-                    if latest > self.current {
-                        outputln!("Updating from {} to {}", self.current, latest);
+                    let bad = self.bad_releases
+                        .read()
+                        .expect("Bad releases lock is poisoned")
+                        .contains(&latest);
+                    if bad {
+                        info!("Skipping {}, which was rolled back", latest);
+                    } else if latest > current {
+                        // Only ever forward, even with a channel: moving a channel back does not
+                        // move back the services already running its release.
+                        outputln!("Updating from {} to {}", current, latest);
                         match self.install(&latest, true) {
                             Ok(package) => {
-                                self.current = latest;
+                                self.set_current(latest);
                                 sender.send(package).expect("Main thread has gone away!");
                                 break;
                             }
@@ -351,6 +979,14 @@ impl Worker {
         }
     }
 
+    fn current(&self) -> PackageIdent {
+        self.current.read().expect("Updater current release lock is poisoned").clone()
+    }
+
+    fn set_current(&mut self, ident: PackageIdent) {
+        *self.current.write().expect("Updater current release lock is poisoned") = ident;
+    }
+
     fn install(&mut self, package: &PackageIdent, recurse: bool) -> Result<Package> {
         let package = match Package::load(package, None) {
             Ok(pkg) => pkg,
@@ -376,3 +1012,213 @@ impl Worker {
         Package::load(archive.ident().as_ref().unwrap(), None)
     }
 }
+
+/// How long to wait before polling again: the poll interval, plus up to the jitter so the members
+/// of a service group spread out their requests to the depot.
+fn poll_delay(interval_secs: u64, jitter_secs: u64) -> TimeDuration {
+    let jitter_ms = jitter_secs * 1000;
+    let jitter = if jitter_ms > 0 {
+        rand::thread_rng().gen_range(0, jitter_ms + 1)
+    } else {
+        0
+    };
+    TimeDuration::milliseconds((interval_secs * 1000 + jitter) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+    use time::{SteadyTime, Duration as TimeDuration};
+
+    use manager::census::{Census, CensusEntry};
+    use test_fixtures::{ident, NEW, OLD};
+    use super::{failed_members, follower_turn, leader_turn, poll_delay, BadReleaseList,
+                FollowerTurn, LeaderTurn, Probation, ProbationCheck, BAD_RELEASES_FILE,
+                CRASH_LOOP_RESTARTS};
+
+    fn member(id: &str, running: &str, healthy: Option<bool>, bad: &[&str]) -> CensusEntry {
+        let mut ce = CensusEntry::default();
+        ce.set_member_id(String::from(id));
+        ce.set_alive(true);
+        ce.set_package_ident(ident(running));
+        ce.set_package_healthy(healthy);
+        ce.set_bad_releases(bad.iter().map(|b| ident(b)).collect());
+        ce
+    }
+
+    /// A census of a rolling update where `a` leads; each member is (id, package, healthy).
+    fn rolling(me: &str, members: &[(&str, &str, Option<bool>)]) -> Census {
+        let mut census = Census::new(String::from(me));
+        for &(id, running, healthy) in members.iter() {
+            let mut ce = member(id, running, healthy, &[]);
+            ce.set_update_leader(id == "a");
+            census.insert(String::from(id), ce);
+        }
+        census
+    }
+
+    #[test]
+    fn follower_waits_for_the_member_before_it_to_report_its_health() {
+        let bad = BadReleaseList::load(None, 0);
+        let census = rolling("c", &[("a", NEW, Some(true)), ("b", NEW, None), ("c", OLD, None)]);
+        assert_eq!(follower_turn(&census, &bad, 1, None),
+                   FollowerTurn::AwaitHealth(String::from("b")));
+        // Past the timeout, we go ahead without it
+        assert_eq!(follower_turn(&census, &bad, 1, Some("b")),
+                   FollowerTurn::Update(ident(NEW)));
+        let census = rolling("c",
+                             &[("a", NEW, Some(true)), ("b", NEW, Some(true)), ("c", OLD, None)]);
+        assert_eq!(follower_turn(&census, &bad, 1, None),
+                   FollowerTurn::Update(ident(NEW)));
+    }
+
+    #[test]
+    fn follower_waits_its_turn() {
+        let bad = BadReleaseList::load(None, 0);
+        let census = rolling("c", &[("a", NEW, Some(true)), ("b", OLD, None), ("c", OLD, None)]);
+        assert_eq!(follower_turn(&census, &bad, 1, None), FollowerTurn::NotOurTurn);
+        let census = rolling("c", &[("a", NEW, None), ("b", NEW, None), ("c", NEW, None)]);
+        assert_eq!(follower_turn(&census, &bad, 1, None), FollowerTurn::Idle);
+    }
+
+    #[test]
+    fn follower_halts_at_the_failure_threshold() {
+        let bad = BadReleaseList::load(None, 0);
+        let census = rolling("d",
+                             &[("a", NEW, Some(false)),
+                               ("b", NEW, Some(true)),
+                               ("c", NEW, Some(false)),
+                               ("d", OLD, None)]);
+        assert_eq!(follower_turn(&census, &bad, 2, None),
+                   FollowerTurn::Halt(ident(NEW), 2));
+        assert_eq!(follower_turn(&census, &bad, 3, None),
+                   FollowerTurn::Update(ident(NEW)));
+    }
+
+    #[test]
+    fn follower_skips_a_release_that_was_rolled_back() {
+        let mut bad = BadReleaseList::load(None, 0);
+        bad.mark(ident(NEW), false, 0);
+        let census = rolling("c",
+                             &[("a", NEW, Some(true)), ("b", NEW, Some(true)), ("c", OLD, None)]);
+        assert_eq!(follower_turn(&census, &bad, 1, None), FollowerTurn::Skip(ident(NEW)));
+    }
+
+    #[test]
+    fn leader_halts_at_the_failure_threshold() {
+        let census = rolling("a",
+                             &[("a", NEW, Some(true)), ("b", NEW, Some(false)), ("c", OLD, None)]);
+        assert_eq!(leader_turn(&census, 1), LeaderTurn::Halt(ident(NEW), 1));
+        assert_eq!(leader_turn(&census, 2), LeaderTurn::AwaitFollowers);
+        let census = rolling("a", &[("a", NEW, Some(true)), ("b", NEW, Some(true))]);
+        assert_eq!(leader_turn(&census, 1), LeaderTurn::Poll);
+    }
+
+    #[test]
+    fn leader_without_a_package_waits() {
+        let mut census = Census::new(String::from("a"));
+        let mut ce = CensusEntry::default();
+        ce.set_member_id(String::from("a"));
+        ce.set_alive(true);
+        census.insert(String::from("a"), ce);
+        assert_eq!(leader_turn(&census, 1), LeaderTurn::Unknown);
+        assert_eq!(leader_turn(&Census::new(String::from("a")), 1), LeaderTurn::Unknown);
+    }
+
+    #[test]
+    fn probation_counts_going_down_after_being_up() {
+        let now = SteadyTime::now();
+        let mut probation = Probation::new(ident(OLD), 300, now);
+        // Not yet up since the update is not a crash
+        assert_eq!(probation.check(false, now), ProbationCheck::Watching);
+        for _ in 0..(CRASH_LOOP_RESTARTS - 1) {
+            assert_eq!(probation.check(true, now), ProbationCheck::Watching);
+            assert_eq!(probation.check(false, now), ProbationCheck::Watching);
+        }
+        assert_eq!(probation.check(true, now), ProbationCheck::Watching);
+        assert_eq!(probation.check(false, now),
+                   ProbationCheck::CrashLooping(CRASH_LOOP_RESTARTS));
+    }
+
+    #[test]
+    fn probation_is_over_once_the_window_closes() {
+        let now = SteadyTime::now();
+        let mut probation = Probation::new(ident(OLD), 300, now);
+        assert_eq!(probation.check(true, now + TimeDuration::seconds(300)),
+                   ProbationCheck::Watching);
+        assert_eq!(probation.check(false, now + TimeDuration::seconds(301)),
+                   ProbationCheck::Over);
+    }
+
+    #[test]
+    fn only_our_own_bad_releases_are_gossiped() {
+        let mut list = BadReleaseList::load(None, 0);
+        assert!(list.mark(ident(OLD), false, 0));
+        assert!(list.mark(ident(NEW), true, 0));
+        assert!(list.mark(ident("core/nginx/1.0.0/20170102000000"), true, 0));
+        assert!(!list.mark(ident(NEW), false, 0));
+        assert!(list.contains(&ident(OLD)));
+        assert_eq!(list.own(&ident("core/redis/3.2.3/20161201000000")),
+                   vec![String::from(NEW)]);
+        // Rolling back from one we heard of makes it ours
+        assert!(list.mark(ident(OLD), true, 0));
+        assert_eq!(list.own(&ident("core/redis")).len(), 2);
+    }
+
+    #[test]
+    fn bad_releases_expire_after_the_ttl() {
+        let mut list = BadReleaseList::load(None, 60);
+        list.mark(ident(OLD), true, 100);
+        list.mark(ident(NEW), false, 130);
+        assert!(list.expire(159).is_empty());
+        assert_eq!(list.expire(160),
+                   vec![(ident(OLD), true)]);
+        assert!(!list.contains(&ident(OLD)));
+        assert!(list.contains(&ident(NEW)));
+        // A ttl of 0 keeps them for good
+        let mut list = BadReleaseList::load(None, 0);
+        list.mark(ident(OLD), true, 0);
+        assert!(list.expire(i64::max_value()).is_empty());
+    }
+
+    #[test]
+    fn bad_releases_are_saved_and_loaded() {
+        let dir = TempDir::new("bad-releases").unwrap();
+        let path = dir.path().join(BAD_RELEASES_FILE);
+        {
+            let mut list = BadReleaseList::load(Some(path.clone()), 60);
+            list.mark(ident(OLD), true, 100);
+            list.mark(ident(NEW), false, 130);
+        }
+        let mut list = BadReleaseList::load(Some(path.clone()), 60);
+        assert!(list.contains(&ident(OLD)));
+        assert!(list.contains(&ident(NEW)));
+        assert_eq!(list.own(&ident("core/redis")), vec![String::from(OLD)]);
+        // Expiring one is saved too
+        list.expire(160);
+        let list = BadReleaseList::load(Some(path), 60);
+        assert!(!list.contains(&ident(OLD)));
+        assert!(list.contains(&ident(NEW)));
+    }
+
+    #[test]
+    fn each_member_that_failed_counts_once() {
+        let bad = NEW;
+        let mut census = Census::new(String::from("a"));
+        census.insert(String::from("a"), member("a", bad, Some(false), &[]));
+        census.insert(String::from("b"), member("b", OLD, None, &[bad]));
+        census.insert(String::from("c"), member("c", bad, Some(false), &[bad]));
+        census.insert(String::from("d"), member("d", bad, Some(true), &[]));
+        assert_eq!(failed_members(&census, &ident(bad)), 3);
+    }
+
+    #[test]
+    fn poll_delay_stays_within_the_jitter() {
+        assert_eq!(poll_delay(60, 0), TimeDuration::seconds(60));
+        for _ in 0..100 {
+            let delay = poll_delay(60, 30);
+            assert!(delay >= TimeDuration::seconds(60));
+            assert!(delay <= TimeDuration::seconds(90));
+        }
+    }
+}
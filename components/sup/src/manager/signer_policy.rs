@@ -0,0 +1,105 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads the signer policy, which says which signing keys may change the configuration and files
+//! of which service groups.
+//!
+//! The policy is a TOML file mapping a service group to the names of the signing keys allowed to
+//! update it. The special `"*"` entry applies to every service group.
+//!
+//! ```toml
+//! "redis.default" = ["adam", "jamie"]
+//! "*" = ["ops"]
+//! ```
+//!
+//! Once a policy is in place, the Supervisor's gossip layer refuses any service config or service
+//! file that is unsigned, whose signature does not verify against the public key in the key cache,
+//! or whose signer is not listed for the service group; see `butterfly::server::signer_policy`.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use butterfly::server::signer_policy::SignerPolicy;
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "SP";
+
+pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SignerPolicy> {
+    let mut file = try!(File::open(path.as_ref()));
+    let mut content = String::new();
+    try!(file.read_to_string(&mut content));
+    from_toml(&content)
+}
+
+pub fn from_toml(content: &str) -> Result<SignerPolicy> {
+    let mut toml_parser = toml::Parser::new(content);
+    let table = try!(toml_parser.parse()
+        .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+    let mut signers = HashMap::new();
+    for (service_group, value) in table.into_iter() {
+        let names = match value {
+            toml::Value::Array(names) => names,
+            _ => {
+                return Err(sup_error!(Error::InvalidSignerPolicy(format!("{} must be a list of \
+                                                                          signing key names",
+                                                                         service_group))))
+            }
+        };
+        let mut allowed = Vec::with_capacity(names.len());
+        for name in names.into_iter() {
+            match name {
+                toml::Value::String(name) => allowed.push(name),
+                other => {
+                    return Err(sup_error!(Error::InvalidSignerPolicy(format!("{} is not a \
+                                                                              signing key name",
+                                                                             other))))
+                }
+            }
+        }
+        signers.insert(service_group, allowed);
+    }
+    Ok(SignerPolicy::new(signers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_toml;
+
+    #[test]
+    fn allows_listed_signers() {
+        let policy = from_toml("\"redis.default\" = [\"adam\", \"jamie\"]").unwrap();
+        assert!(policy.allows("redis.default", "adam"));
+        assert!(policy.allows("redis.default", "jamie"));
+        assert!(!policy.allows("redis.default", "fletcher"));
+        assert!(!policy.allows("redis.production", "adam"));
+    }
+
+    #[test]
+    fn wildcard_applies_to_every_service_group() {
+        let policy = from_toml("\"*\" = [\"ops\"]").unwrap();
+        assert!(policy.allows("redis.default", "ops"));
+        assert!(policy.allows("haproxy.production@acme", "ops"));
+        assert!(!policy.allows("redis.default", "adam"));
+    }
+
+    #[test]
+    fn rejects_signers_that_are_not_lists() {
+        assert!(from_toml("\"redis.default\" = \"adam\"").is_err());
+        assert!(from_toml("\"redis.default\" = [1, 2]").is_err());
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! When the update strategy may update a service.
+//!
+//! A schedule is a set of weekly windows, outside of which no update is applied, and a set of
+//! blackouts, during which no update is applied even inside a window. With no windows, updates
+//! may happen at any time outside a blackout. Times are UTC. There is one schedule per supervisor,
+//! taken from its command line, and it applies to every service the supervisor runs.
+//!
+//! A window is an optional list of days, by their three letter or full names, followed by a time
+//! range; a range that ends before it starts runs past midnight into the next day, and one that
+//! ends when it starts is refused:
+//!
+//! ```text
+//! mon-fri 02:00-04:00
+//! sat,sun 22:00-06:00
+//! 01:00-02:00
+//! ```
+//!
+//! A blackout is either a window, or a date or inclusive range of dates:
+//!
+//! ```text
+//! fri 12:00-23:59
+//! 2017-12-24
+//! 2017-11-23..2017-11-27
+//! ```
+
+use time::{self, Tm};
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "US";
+const DAYS: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const DAY_NAMES: [&'static str; 7] = ["sunday",
+                                      "monday",
+                                      "tuesday",
+                                      "wednesday",
+                                      "thursday",
+                                      "friday",
+                                      "saturday"];
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A time range on some days of the week, in minutes since midnight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Window {
+    days: [bool; 7],
+    start: u32,
+    end: u32,
+}
+
+impl Window {
+    fn parse(spec: &str) -> Result<Window> {
+        let fields: Vec<&str> = spec.split_whitespace().collect();
+        let (days, range) = match fields.len() {
+            1 => ("*", fields[0]),
+            2 => (fields[0], fields[1]),
+            _ => return Err(invalid(spec, "expected [DAYS] HH:MM-HH:MM")),
+        };
+        let mut times = range.splitn(2, '-');
+        let start = try!(parse_time(spec, times.next().unwrap_or("")));
+        let end = try!(parse_time(spec, times.next().unwrap_or("")));
+        if start == end {
+            return Err(invalid(spec, "the window ends when it starts"));
+        }
+        Ok(Window {
+            days: try!(parse_days(spec, days)),
+            start: start,
+            end: end,
+        })
+    }
+
+    fn contains(&self, tm: &Tm) -> bool {
+        let minute = (tm.tm_hour * 60 + tm.tm_min) as u32;
+        let today = tm.tm_wday as usize;
+        let yesterday = (today + 6) % 7;
+        if self.start <= self.end {
+            self.days[today] && minute >= self.start && minute < self.end
+        } else {
+            // Runs past midnight, so the small hours belong to the day before
+            (self.days[today] && minute >= self.start) || (self.days[yesterday] && minute < self.end)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Blackout {
+    Weekly(Window),
+    /// An inclusive range of dates, as (year, month, day)
+    Dates((i32, i32, i32), (i32, i32, i32)),
+}
+
+impl Blackout {
+    fn parse(spec: &str) -> Result<Blackout> {
+        let spec = spec.trim();
+        if spec.starts_with(|c: char| c.is_digit(10)) && !spec.contains(':') {
+            let mut dates = spec.splitn(2, "..");
+            let first = try!(parse_date(spec, dates.next().unwrap_or("")));
+            let last = match dates.next() {
+                Some(date) => try!(parse_date(spec, date)),
+                None => first,
+            };
+            Ok(Blackout::Dates(first, last))
+        } else {
+            Ok(Blackout::Weekly(try!(Window::parse(spec))))
+        }
+    }
+
+    fn contains(&self, tm: &Tm) -> bool {
+        match *self {
+            Blackout::Weekly(ref window) => window.contains(tm),
+            Blackout::Dates(first, last) => {
+                let today = (tm.tm_year + 1900, tm.tm_mon + 1, tm.tm_mday);
+                today >= first && today <= last
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateSchedule {
+    windows: Vec<Window>,
+    blackouts: Vec<Blackout>,
+}
+
+impl UpdateSchedule {
+    pub fn new() -> Self {
+        UpdateSchedule::default()
+    }
+
+    /// Only update inside this window, or any other one we are given.
+    pub fn add_window(&mut self, spec: &str) -> Result<()> {
+        let window = try!(Window::parse(spec));
+        self.windows.push(window);
+        Ok(())
+    }
+
+    /// Never update during this blackout.
+    pub fn add_blackout(&mut self, spec: &str) -> Result<()> {
+        let blackout = try!(Blackout::parse(spec));
+        self.blackouts.push(blackout);
+        Ok(())
+    }
+
+    /// Whether an update may be applied at `tm`, a UTC time.
+    pub fn allows(&self, tm: &Tm) -> bool {
+        (self.windows.is_empty() || self.windows.iter().any(|w| w.contains(tm))) &&
+        !self.blackouts.iter().any(|b| b.contains(tm))
+    }
+
+    pub fn allows_now(&self) -> bool {
+        self.allows(&time::now_utc())
+    }
+}
+
+fn invalid(spec: &str, reason: &str) -> SupError {
+    sup_error!(Error::InvalidUpdateSchedule(format!("'{}': {}", spec, reason)))
+}
+
+/// Parse `HH:MM` into minutes since midnight. `24:00` is allowed, to end a window at midnight.
+fn parse_time(spec: &str, value: &str) -> Result<u32> {
+    let mut parts = value.splitn(2, ':');
+    let hour = parts.next().and_then(|h| h.parse::<u32>().ok());
+    let minute = parts.next().and_then(|m| m.parse::<u32>().ok());
+    match (hour, minute) {
+        (Some(hour), Some(minute)) if minute < 60 && hour * 60 + minute <= MINUTES_PER_DAY => {
+            Ok(hour * 60 + minute)
+        }
+        _ => Err(invalid(spec, &format!("'{}' is not a time of day as HH:MM", value))),
+    }
+}
+
+/// Parse `*`, or a comma-separated list of days and ranges of days, like `mon-fri,sun`.
+fn parse_days(spec: &str, value: &str) -> Result<[bool; 7]> {
+    if value == "*" {
+        return Ok([true; 7]);
+    }
+    let mut days = [false; 7];
+    for part in value.split(',') {
+        let mut ends = part.splitn(2, '-');
+        let first = try!(parse_day(spec, ends.next().unwrap_or("")));
+        let last = match ends.next() {
+            Some(day) => try!(parse_day(spec, day)),
+            None => first,
+        };
+        // Ranges may wrap around the end of the week, like fri-mon
+        let mut day = first;
+        loop {
+            days[day] = true;
+            if day == last {
+                break;
+            }
+            day = (day + 1) % 7;
+        }
+    }
+    Ok(days)
+}
+
+fn parse_day(spec: &str, value: &str) -> Result<usize> {
+    let value = value.to_lowercase();
+    let day = DAYS.iter()
+        .position(|day| *day == value)
+        .or_else(|| DAY_NAMES.iter().position(|day| *day == value));
+    match day {
+        Some(day) => Ok(day),
+        None => Err(invalid(spec, &format!("'{}' is not a day of the week", value))),
+    }
+}
+
+fn parse_date(spec: &str, value: &str) -> Result<(i32, i32, i32)> {
+    let parts: Vec<Option<i32>> = value.split('-').map(|p| p.parse::<i32>().ok()).collect();
+    match (parts.len(), parts.get(0), parts.get(1), parts.get(2)) {
+        (3, Some(&Some(year)), Some(&Some(month)), Some(&Some(day)))
+            if month >= 1 && month <= 12 && day >= 1 && day <= days_in_month(year, month) => {
+            Ok((year, month, day))
+        }
+        _ => Err(invalid(spec, &format!("'{}' is not a date as YYYY-MM-DD", value))),
+    }
+}
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{self, Timespec, Tm};
+
+    use super::UpdateSchedule;
+
+    fn at(secs: i64) -> Tm {
+        time::at_utc(Timespec::new(secs, 0))
+    }
+
+    // Monday 2017-03-06 03:30 UTC
+    const MONDAY_0330: i64 = 1488771000;
+    // Monday 2017-03-06 05:00 UTC
+    const MONDAY_0500: i64 = 1488776400;
+    // Saturday 2017-03-11 23:30 UTC
+    const SATURDAY_2330: i64 = 1489275000;
+    // Sunday 2017-03-12 01:00 UTC
+    const SUNDAY_0100: i64 = 1489280400;
+    // Tuesday 2017-03-07 01:00 UTC
+    const TUESDAY_0100: i64 = 1488848400;
+
+    #[test]
+    fn no_windows_allows_any_time() {
+        let schedule = UpdateSchedule::new();
+        assert!(schedule.allows(&at(MONDAY_0330)));
+        assert!(schedule.allows(&at(SATURDAY_2330)));
+    }
+
+    #[test]
+    fn windows_limit_the_days_and_times() {
+        let mut schedule = UpdateSchedule::new();
+        schedule.add_window("mon-fri 02:00-04:00").unwrap();
+        assert!(schedule.allows(&at(MONDAY_0330)));
+        assert!(!schedule.allows(&at(MONDAY_0500)));
+        assert!(!schedule.allows(&at(SUNDAY_0100)));
+    }
+
+    #[test]
+    fn windows_may_run_past_midnight() {
+        let mut schedule = UpdateSchedule::new();
+        schedule.add_window("sat 22:00-02:00").unwrap();
+        assert!(schedule.allows(&at(SATURDAY_2330)));
+        assert!(schedule.allows(&at(SUNDAY_0100)));
+        assert!(!schedule.allows(&at(TUESDAY_0100)));
+    }
+
+    #[test]
+    fn blackouts_override_windows() {
+        let mut schedule = UpdateSchedule::new();
+        schedule.add_window("02:00-04:00").unwrap();
+        schedule.add_blackout("2017-03-05..2017-03-06").unwrap();
+        assert!(!schedule.allows(&at(MONDAY_0330)));
+        let mut schedule = UpdateSchedule::new();
+        schedule.add_blackout("fri-mon 00:00-24:00").unwrap();
+        assert!(!schedule.allows(&at(MONDAY_0330)));
+        assert!(schedule.allows(&at(TUESDAY_0100)));
+    }
+
+    #[test]
+    fn invalid_specs_are_refused() {
+        let mut schedule = UpdateSchedule::new();
+        assert!(schedule.add_window("02:00").is_err());
+        assert!(schedule.add_window("someday 02:00-04:00").is_err());
+        assert!(schedule.add_window("monkey 02:00-04:00").is_err());
+        assert!(schedule.add_window("mon-thurs 02:00-04:00").is_err());
+        assert!(schedule.add_window("mon 02:00-02:00").is_err());
+        assert!(schedule.add_window("Monday-Friday 02:00-04:00").is_ok());
+        assert!(schedule.add_window("mon 00:00-24:00").is_ok());
+        assert!(schedule.add_window("mon 25:00-26:00").is_err());
+        assert!(schedule.add_blackout("2017-13").is_err());
+        assert!(schedule.add_blackout("2017-13-45").is_err());
+        assert!(schedule.add_blackout("2017-00-10").is_err());
+        assert!(schedule.add_blackout("2017-02-29").is_err());
+        assert!(schedule.add_blackout("2017-04-31").is_err());
+        assert!(schedule.add_blackout("2016-02-29").is_ok());
+    }
+}
@@ -39,6 +39,7 @@ pub mod census {
         pub hostname: Option<String>,
         pub address: Option<String>,
         pub ip: Option<String>,
+        pub ipv6: Option<String>,
         pub port: Option<String>,
         pub exposes: Vec<String>,
         pub package_ident: Option<PackageIdent>,
@@ -52,11 +53,16 @@ pub mod census {
         pub update_election_is_running: Option<bool>,
         pub update_election_is_no_quorum: Option<bool>,
         pub update_election_is_finished: Option<bool>,
+        pub leader_term: Option<u64>,
         pub initialized: Option<bool>,
         pub alive: Option<bool>,
         pub suspect: Option<bool>,
         pub confirmed: Option<bool>,
         pub persistent: Option<bool>,
+        pub config_incarnation: Option<u64>,
+        pub config_healthy: Option<bool>,
+        pub package_healthy: Option<bool>,
+        pub bad_releases: Vec<PackageIdent>,
     }
 
     #[derive(Debug, Deserialize, Serialize)]
@@ -191,6 +197,7 @@ pub mod service_config {
     #[derive(Clone, Debug, Deserialize, Serialize)]
     pub struct Sys {
         pub ip: String,
+        pub ipv6: Option<String>,
         pub hostname: String,
         pub sidecar_ip: String,
         pub sidecar_port: u16,
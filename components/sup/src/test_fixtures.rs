@@ -0,0 +1,27 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fixtures shared by the unit tests of more than one module.
+
+use std::str::FromStr;
+
+use hcore::package::PackageIdent;
+
+/// A release of a package, and a later release of it.
+pub const OLD: &'static str = "core/redis/3.2.4/20170101000000";
+pub const NEW: &'static str = "core/redis/3.2.5/20170102000000";
+
+pub fn ident(ident: &str) -> PackageIdent {
+    PackageIdent::from_str(ident).unwrap()
+}
@@ -29,6 +29,12 @@ pub fn ip() -> Result<IpAddr> {
     }
 }
 
+pub fn ipv6() -> Result<IpAddr> {
+    match sys::ipv6() {
+        Ok(s) => Ok(s),
+        Err(e) => Err(sup_error!(Error::HabitatCore(e))),
+    }
+}
 
 extern "C" {
     pub fn gethostname(name: *mut libc::c_char, size: libc::size_t) -> libc::c_int;